@@ -0,0 +1,449 @@
+use std::{
+    path::Path,
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use tokio::{
+    fs::{File, OpenOptions},
+    io::AsyncWriteExt,
+    sync::{Mutex, OwnedRwLockReadGuard, RwLock},
+};
+
+use crate::{
+    commands::{CommandHandler, is_value_expired, validate_stream_id},
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+    sorted_set::format_score,
+    state::State,
+    stream::format_stream_id,
+};
+
+pub const AOF_FILENAME: &str = "appendonly.aof";
+const AOF_CLIENT_ADDRESS: &str = "append-only-file";
+const EVERYSEC_FSYNC_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AppendFsync {
+    Always,
+    EverySec,
+    No,
+}
+
+impl AppendFsync {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "always" => Some(Self::Always),
+            "everysec" => Some(Self::EverySec),
+            "no" => Some(Self::No),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> &str {
+        match self {
+            Self::Always => "always",
+            Self::EverySec => "everysec",
+            Self::No => "no",
+        }
+    }
+}
+
+/// Handle to the append-only file that write commands are logged to
+#[derive(Debug, Clone)]
+pub struct AppendOnlyFile {
+    file: Arc<Mutex<File>>,
+    fsync: AppendFsync,
+    rewrite_lock: Arc<RwLock<()>>,
+}
+
+/// Held by a write command from before it runs until it is appended, so that a rewrite
+/// cannot snapshot the store in between and have the write logged twice
+#[derive(Debug, Default)]
+pub struct RewriteGuard {
+    rewrite_lock: Option<Arc<RwLock<()>>>,
+    guard: Option<OwnedRwLockReadGuard<()>>,
+}
+
+impl RewriteGuard {
+    pub async fn acquire(rewrite_lock: Option<Arc<RwLock<()>>>) -> Self {
+        let mut rewrite_guard = Self {
+            rewrite_lock,
+            guard: None,
+        };
+        rewrite_guard.reacquire().await;
+
+        rewrite_guard
+    }
+
+    /// Lets rewrites run while a blocking command waits for data
+    pub fn release(&mut self) {
+        self.guard = None;
+    }
+
+    pub async fn reacquire(&mut self) {
+        if let Some(ref rewrite_lock) = self.rewrite_lock {
+            self.guard = Some(Arc::clone(rewrite_lock).read_owned().await);
+        }
+    }
+}
+
+impl AppendOnlyFile {
+    /// Opens the file for appending, creating it if needed. With `everysec`
+    /// a task fsyncs the file once per second until the handle is dropped.
+    pub async fn open(file_path: &Path, fsync: AppendFsync) -> tokio::io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path)
+            .await?;
+        let file = Arc::new(Mutex::new(file));
+
+        if fsync == AppendFsync::EverySec {
+            let file = Arc::downgrade(&file);
+
+            tokio::spawn(async move {
+                fsync_every_second(file).await;
+            });
+        }
+
+        Ok(Self {
+            file,
+            fsync,
+            rewrite_lock: Arc::new(RwLock::new(())),
+        })
+    }
+
+    pub fn rewrite_lock(&self) -> Arc<RwLock<()>> {
+        Arc::clone(&self.rewrite_lock)
+    }
+
+    pub async fn append(&self, input: RespValue) -> tokio::io::Result<()> {
+        let mut file_guard = self.file.lock().await;
+        file_guard.write_all(input.encode().as_bytes()).await?;
+        file_guard.flush().await?;
+
+        if self.fsync == AppendFsync::Always {
+            file_guard.sync_data().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the file with a rewritten one. It waits for the write commands holding a
+    /// `RewriteGuard` to be appended, and new ones wait for it, so every write is either
+    /// in the snapshot or appended to the new file, but never both.
+    async fn rewrite(
+        &self,
+        store: Arc<Mutex<KeyValueStore>>,
+        file_path: &Path,
+        temp_file_path: &Path,
+    ) -> tokio::io::Result<()> {
+        let _rewrite_guard = self.rewrite_lock.write().await;
+        let mut file_guard = self.file.lock().await;
+        write_rewritten_file(store, file_path, temp_file_path).await?;
+
+        *file_guard = OpenOptions::new().append(true).open(file_path).await?;
+
+        Ok(())
+    }
+}
+
+async fn fsync_every_second(file: Weak<Mutex<File>>) {
+    let mut interval = tokio::time::interval(EVERYSEC_FSYNC_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let Some(file) = file.upgrade() else {
+            break;
+        };
+
+        if let Err(e) = file.lock().await.sync_data().await {
+            eprintln!("Failed to fsync append-only file: {}", e);
+        }
+    }
+}
+
+/// Rebuilds the store by replaying every command logged in the append-only file.
+///
+/// Commands are replayed the way a replica applies the replication stream,
+/// so nothing is propagated or appended again while loading.
+pub async fn load_append_only_file(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+) -> tokio::io::Result<()> {
+    let (file_path, proto_max_bulk_len) = {
+        let server_guard = server.read().await;
+        (
+            Path::new(&server_guard.rdb_directory).join(AOF_FILENAME),
+            server_guard.proto_max_bulk_len,
+        )
+    };
+
+    let contents = tokio::fs::read_to_string(&file_path).await?;
+    let requests = RespValue::parse_requests(
+        contents.split_terminator("\r\n").collect(),
+        proto_max_bulk_len,
+    );
+
+    for request in requests {
+        let request = request.map_err(|e| {
+            tokio::io::Error::new(
+                tokio::io::ErrorKind::InvalidData,
+                format!("Bad command in append-only file: {}", e),
+            )
+        })?;
+
+        let command_handler = CommandHandler::new(request).map_err(|e| {
+            tokio::io::Error::new(
+                tokio::io::ErrorKind::InvalidData,
+                format!("Unknown command in append-only file: {}", e),
+            )
+        })?;
+
+        if let Err(e) = command_handler
+            .handle_command_for_replica_master_connection(
+                AOF_CLIENT_ADDRESS,
+                Arc::clone(&server),
+                Arc::clone(&store),
+                Arc::clone(&state),
+            )
+            .await
+        {
+            eprintln!("Failed to replay command from append-only file: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrites the append-only file into the minimal set of commands that rebuild the store.
+///
+/// The new file is written to a temporary file first and then renamed over the old one.
+pub async fn rewrite_append_only_file(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+) -> tokio::io::Result<()> {
+    let (file_path, temp_file_path, append_only_file) = {
+        let server_guard = server.read().await;
+        let rdb_directory = Path::new(&server_guard.rdb_directory);
+
+        (
+            rdb_directory.join(AOF_FILENAME),
+            rdb_directory.join(format!("temp-rewriteaof-{}.aof", std::process::id())),
+            server_guard.append_only_file.clone(),
+        )
+    };
+
+    match append_only_file {
+        Some(append_only_file) => {
+            append_only_file
+                .rewrite(store, &file_path, &temp_file_path)
+                .await
+        }
+        None => write_rewritten_file(store, &file_path, &temp_file_path).await,
+    }
+}
+
+async fn write_rewritten_file(
+    store: Arc<Mutex<KeyValueStore>>,
+    file_path: &Path,
+    temp_file_path: &Path,
+) -> tokio::io::Result<()> {
+    let contents = {
+        let store_guard = store.lock().await;
+
+        encode_store_as_commands(&store_guard)
+            .into_iter()
+            .map(|command| command.encode())
+            .collect::<String>()
+    };
+
+    let mut file = File::create(temp_file_path).await?;
+    file.write_all(contents.as_bytes()).await?;
+    file.sync_all().await?;
+
+    tokio::fs::rename(temp_file_path, file_path).await
+}
+
+/// Serializes the store into one write command per key, followed by a
+/// PEXPIREAT with the absolute expiration for keys that have one
+pub fn encode_store_as_commands(store: &KeyValueStore) -> Vec<RespValue> {
+    let mut keys = store
+        .iter()
+        .filter(|(_, value)| !is_value_expired(value))
+        .collect::<Vec<(&String, &Value)>>();
+    keys.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut commands = Vec::new();
+
+    for (key, value) in keys {
+        let key = key.clone();
+
+        match &value.data {
+            DataType::String(string) => {
+                commands.push(encode_command("SET", key.clone(), [string.clone()]));
+            }
+            DataType::Array(list) => {
+                commands.push(encode_command("RPUSH", key.clone(), list.iter().cloned()));
+            }
+            DataType::Hash(hash) => {
+                let mut fields = hash.iter().collect::<Vec<(&String, &String)>>();
+                fields.sort();
+
+                let arguments = fields
+                    .into_iter()
+                    .flat_map(|(field, value)| [field.clone(), value.clone()]);
+                commands.push(encode_command("HSET", key.clone(), arguments));
+            }
+            DataType::Set(set) => {
+                let mut members = set.iter().cloned().collect::<Vec<String>>();
+                members.sort();
+
+                commands.push(encode_command("SADD", key.clone(), members));
+            }
+            DataType::SortedSet(sorted_set) => {
+                let arguments = sorted_set
+                    .iter()
+                    .flat_map(|(member, score)| [format_score(score), member.clone()]);
+                commands.push(encode_command("ZADD", key.clone(), arguments));
+            }
+            DataType::Stream(stream) => {
+                // Stream IDs are compared numerically by XADD, not as strings
+                let mut entries = stream.iter().collect::<Vec<_>>();
+                entries.sort_by_key(|(stream_id, _)| validate_stream_id(stream_id, false).ok());
+
+                for (stream_id, fields) in entries {
+                    let arguments = std::iter::once(stream_id.clone()).chain(
+                        fields
+                            .iter()
+                            .flat_map(|(field, value)| [field.clone(), value.clone()]),
+                    );
+                    commands.push(encode_command("XADD", key.clone(), arguments));
+                }
+
+                // XSETID can move the last ID past the top entry, which XADD alone
+                // would not restore
+                if stream
+                    .top_entry_id()
+                    .is_some_and(|top_entry_id| top_entry_id != stream.last_id())
+                {
+                    commands.push(encode_command(
+                        "XSETID",
+                        key.clone(),
+                        [format_stream_id(stream.last_id())],
+                    ));
+                }
+            }
+        }
+
+        if let Some(expiration) = value.expiration {
+            commands.push(encode_command(
+                "PEXPIREAT",
+                key,
+                [expiration.as_millisecond().to_string()],
+            ));
+        }
+    }
+
+    commands
+}
+
+fn encode_command(
+    name: &str,
+    key: String,
+    arguments: impl IntoIterator<Item = String>,
+) -> RespValue {
+    let command = [name.to_string(), key]
+        .into_iter()
+        .chain(arguments)
+        .map(RespValue::BulkString)
+        .collect();
+
+    RespValue::Array(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use jiff::Timestamp;
+
+    use super::encode_store_as_commands;
+    use crate::{
+        key_value_store::{DataType, Value},
+        resp::RespValue,
+        stream::Stream,
+    };
+
+    fn command(parts: &[&str]) -> RespValue {
+        RespValue::Array(
+            parts
+                .iter()
+                .map(|part| RespValue::BulkString(part.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_encode_store_as_commands() {
+        let expiration = Timestamp::from_millisecond(1956528000000).unwrap();
+        let mut ticks = Stream::from([("3-0".to_string(), BTreeMap::new())]);
+        ticks.set_last_id((5, 5));
+
+        let store = HashMap::from([
+            (
+                "mango".to_string(),
+                Value {
+                    data: DataType::String("pineapple".to_string()),
+                    expiration: Some(expiration),
+                },
+            ),
+            (
+                "events".to_string(),
+                Value {
+                    data: DataType::Stream(Stream::from([
+                        (
+                            "10-0".to_string(),
+                            BTreeMap::from([("type".to_string(), "stop".to_string())]),
+                        ),
+                        (
+                            "9-1".to_string(),
+                            BTreeMap::from([("type".to_string(), "start".to_string())]),
+                        ),
+                    ])),
+                    expiration: None,
+                },
+            ),
+            (
+                "ticks".to_string(),
+                Value {
+                    data: DataType::Stream(ticks),
+                    expiration: None,
+                },
+            ),
+            (
+                "expired".to_string(),
+                Value {
+                    data: DataType::String("gone".to_string()),
+                    expiration: Some(Timestamp::UNIX_EPOCH),
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            encode_store_as_commands(&store),
+            vec![
+                command(&["XADD", "events", "9-1", "type", "start"]),
+                command(&["XADD", "events", "10-0", "type", "stop"]),
+                command(&["SET", "mango", "pineapple"]),
+                command(&["PEXPIREAT", "mango", "1956528000000"]),
+                command(&["XADD", "ticks", "3-0"]),
+                command(&["XSETID", "ticks", "5-5"]),
+            ]
+        );
+    }
+}
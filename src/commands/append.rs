@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct AppendArguments {
+    key: String,
+    value: String,
+}
+
+impl AppendArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidAppendCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            value: arguments[1].clone(),
+        })
+    }
+}
+
+/// Appends the value to the string at the key, creating it when missing,
+/// and replies with the new length of the string
+pub async fn append(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let append_arguments = AppendArguments::parse(arguments)?;
+
+    let (length, is_modified_in_place) = {
+        let mut store_guard = store.lock().await;
+
+        match store_guard.get_mut(&append_arguments.key) {
+            Some(value) if !is_value_expired(value) => {
+                let DataType::String(ref mut string) = value.data else {
+                    return Err(CommandError::WrongType);
+                };
+
+                string.push_str(&append_arguments.value);
+                (string.len(), true)
+            }
+            _ => {
+                let length = append_arguments.value.len();
+                store_guard.insert(
+                    append_arguments.key.clone(),
+                    Value {
+                        data: DataType::String(append_arguments.value),
+                        expiration: None,
+                    },
+                );
+                (length, false)
+            }
+        }
+    };
+
+    // A string created by APPEND is encoded like one created by SET,
+    // while growing an existing string leaves it raw
+    let mut server_guard = server.write().await;
+    match is_modified_in_place {
+        true => server_guard.raw_string_keys.insert(append_arguments.key),
+        false => server_guard.raw_string_keys.remove(&append_arguments.key),
+    };
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(length as i64).encode(),
+    ))
+}
@@ -0,0 +1,225 @@
+use crate::commands::command_error::CommandError;
+
+/// How many arguments each command accepts, not counting the command and subcommand
+/// names: `(command, minimum, maximum)`, where a maximum of -1 means the command is
+/// variadic. Commands missing from the table are left to their own argument parsing.
+const COMMAND_ARITIES: &[(&str, usize, i64)] = &[
+    ("APPEND", 2, 2),
+    ("BGREWRITEAOF", 0, 0),
+    ("BGSAVE", 0, -1),
+    ("BITFIELD", 1, -1),
+    ("BLPOP", 2, -1),
+    ("BZPOPMAX", 2, -1),
+    ("BZPOPMIN", 2, -1),
+    ("CLIENT HELP", 0, 0),
+    ("CLIENT INFO", 0, 0),
+    ("CLIENT NO-EVICT", 1, 1),
+    ("CLIENT NO-TOUCH", 1, 1),
+    ("CLIENT TRACKING", 1, -1),
+    ("COMMAND LIST", 0, 3),
+    ("CONFIG GET", 1, -1),
+    ("CONFIG HELP", 0, 0),
+    ("DBSIZE", 0, 0),
+    ("DISCARD", 0, 0),
+    ("ECHO", 1, 1),
+    ("EXEC", 0, 0),
+    ("EXPIRE", 2, -1),
+    ("FAILOVER", 0, -1),
+    ("GEOADD", 4, -1),
+    ("GEODIST", 3, -1),
+    ("GEOPOS", 1, -1),
+    ("GEOSEARCH", 6, -1),
+    ("GET", 1, 1),
+    ("GETDEL", 1, 1),
+    ("GETSET", 2, 2),
+    ("HELLO", 0, -1),
+    ("HGET", 2, 2),
+    ("HGETALL", 1, 1),
+    ("HMGET", 2, -1),
+    ("HRANDFIELD", 1, -1),
+    ("HSCAN", 2, -1),
+    ("HSET", 3, -1),
+    ("HSETNX", 3, 3),
+    ("HSTRLEN", 2, 2),
+    ("INCR", 1, 1),
+    ("INFO", 0, -1),
+    ("KEYS", 1, 1),
+    ("LASTSAVE", 0, 0),
+    ("LATENCY HELP", 0, 0),
+    ("LATENCY HISTORY", 1, 1),
+    ("LATENCY LATEST", 0, 0),
+    ("LATENCY RESET", 0, -1),
+    ("LLEN", 1, 1),
+    ("LMPOP", 3, -1),
+    ("LOLWUT", 0, -1),
+    ("LPOP", 1, 2),
+    ("LPUSH", 2, -1),
+    ("LRANGE", 3, 3),
+    ("MEMORY DOCTOR", 0, 0),
+    ("MEMORY HELP", 0, 0),
+    ("MEMORY STATS", 0, 0),
+    ("MEMORY USAGE", 1, -1),
+    ("MULTI", 0, 0),
+    ("OBJECT ENCODING", 1, 1),
+    ("OBJECT HELP", 0, 0),
+    ("OBJECT IDLETIME", 1, 1),
+    ("PEXPIRE", 2, -1),
+    ("PEXPIREAT", 2, -1),
+    ("PFADD", 1, -1),
+    ("PFCOUNT", 1, -1),
+    ("PFMERGE", 1, -1),
+    ("PING", 0, 1),
+    ("PSYNC", 2, -1),
+    ("PUBLISH", 2, 2),
+    ("QUIT", 0, -1),
+    ("REPLCONF", 0, -1),
+    ("RESET", 0, 0),
+    ("RPUSH", 2, -1),
+    ("SADD", 2, -1),
+    ("SAVE", 0, 0),
+    ("SDIFFSTORE", 2, -1),
+    ("SET", 2, -1),
+    ("SETRANGE", 3, 3),
+    ("SINTERCARD", 2, -1),
+    ("SINTERSTORE", 2, -1),
+    ("SLOWLOG GET", 0, 1),
+    ("SLOWLOG HELP", 0, 0),
+    ("SLOWLOG LEN", 0, 0),
+    ("SLOWLOG RESET", 0, 0),
+    ("SMOVE", 3, 3),
+    ("SORT", 1, -1),
+    ("SPUBLISH", 2, 2),
+    ("SSCAN", 2, -1),
+    ("SSUBSCRIBE", 1, -1),
+    ("SUBSCRIBE", 1, -1),
+    ("SUNIONSTORE", 2, -1),
+    ("SUNSUBSCRIBE", 0, -1),
+    ("TYPE", 1, 1),
+    ("WAIT", 2, 2),
+    ("WAITAOF", 3, 3),
+    ("XADD", 4, -1),
+    ("XDEL", 2, -1),
+    ("XLEN", 1, 1),
+    ("XRANGE", 3, -1),
+    ("XREAD", 3, -1),
+    ("XSETID", 2, -1),
+    ("XTRIM", 3, -1),
+    ("ZADD", 3, -1),
+    ("ZCARD", 1, 1),
+    ("ZINCRBY", 3, 3),
+    ("ZMPOP", 3, -1),
+    ("ZMSCORE", 2, -1),
+    ("ZPOPMAX", 1, -1),
+    ("ZPOPMIN", 1, -1),
+    ("ZRANDMEMBER", 1, -1),
+    ("ZRANGE", 3, -1),
+    ("ZRANK", 2, -1),
+    ("ZREM", 2, -1),
+    ("ZREMRANGEBYRANK", 3, 3),
+    ("ZREMRANGEBYSCORE", 3, 3),
+    ("ZREVRANGE", 3, -1),
+    ("ZREVRANK", 2, -1),
+    ("ZSCORE", 2, 2),
+];
+
+/// Rejects a command called with a number of arguments it can never accept,
+/// before its handler gets to parse them
+pub fn validate_command_arity(name: &str, argument_count: usize) -> Result<(), CommandError> {
+    let Some((_, minimum, maximum)) = COMMAND_ARITIES
+        .iter()
+        .find(|(command, _, _)| *command == name)
+    else {
+        return Ok(());
+    };
+
+    if argument_count < *minimum || (*maximum >= 0 && argument_count as i64 > *maximum) {
+        return Err(CommandError::WrongNumberOfArguments(name.to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{COMMAND_ARITIES, validate_command_arity};
+    use crate::commands::{CommandError, command_handler::COMMAND_TABLE};
+
+    #[test]
+    fn test_validate_command_arity() {
+        let test_cases = vec![
+            ("GET", 1, Ok(())),
+            (
+                "GET",
+                0,
+                Err(CommandError::WrongNumberOfArguments("GET".to_string())),
+            ),
+            (
+                "GET",
+                2,
+                Err(CommandError::WrongNumberOfArguments("GET".to_string())),
+            ),
+            ("SET", 5, Ok(())),
+            (
+                "SET",
+                1,
+                Err(CommandError::WrongNumberOfArguments("SET".to_string())),
+            ),
+            ("PING", 0, Ok(())),
+            (
+                "PING",
+                2,
+                Err(CommandError::WrongNumberOfArguments("PING".to_string())),
+            ),
+            (
+                "CONFIG GET",
+                0,
+                Err(CommandError::WrongNumberOfArguments(
+                    "CONFIG GET".to_string(),
+                )),
+            ),
+            ("DEBUG SLEEP", 7, Ok(())),
+        ];
+
+        for (name, argument_count, expected) in test_cases {
+            assert_eq!(
+                validate_command_arity(name, argument_count),
+                expected,
+                "validating {} with {} arguments",
+                name,
+                argument_count
+            );
+        }
+    }
+
+    #[test]
+    fn test_command_arities_are_consistent() {
+        for (command, minimum, maximum) in COMMAND_ARITIES {
+            assert!(
+                *maximum == -1 || *maximum >= *minimum as i64,
+                "{} accepts fewer arguments than its minimum",
+                command
+            );
+        }
+    }
+
+    #[test]
+    fn test_command_arities_are_listed() {
+        for (command, _, _) in COMMAND_ARITIES {
+            let mut words = command.split_whitespace();
+            let name = words.next().unwrap();
+
+            let Some((_, subcommands)) = COMMAND_TABLE.iter().find(|(listed, _)| *listed == name)
+            else {
+                panic!("{} is missing from COMMAND_TABLE", name);
+            };
+
+            if let Some(subcommand) = words.next() {
+                assert!(
+                    subcommands.contains(&subcommand),
+                    "{} is missing from COMMAND_TABLE",
+                    command
+                );
+            }
+        }
+    }
+}
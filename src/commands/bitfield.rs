@@ -0,0 +1,377 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult,
+        keyword_utils::normalize_keyword,
+    },
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+};
+
+// Strings are capped at 512MB, like in SETRANGE
+const MAX_BIT_OFFSET: u64 = 512 * 1024 * 1024 * 8;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct BitfieldType {
+    is_signed: bool,
+    bits: u32,
+}
+
+impl BitfieldType {
+    fn parse(field_type: &str) -> Result<Self, CommandError> {
+        let (is_signed, bits) = match field_type.split_at_checked(1) {
+            Some(("i" | "I", bits)) => (true, bits),
+            Some(("u" | "U", bits)) => (false, bits),
+            _ => return Err(CommandError::InvalidBitfieldType),
+        };
+
+        let bits = bits
+            .parse::<u32>()
+            .map_err(|_| CommandError::InvalidBitfieldType)?;
+
+        // Unsigned 64 bit fields are not supported because the replies are signed 64 bit integers
+        let max_bits = if is_signed { 64 } else { 63 };
+
+        if bits == 0 || bits > max_bits {
+            return Err(CommandError::InvalidBitfieldType);
+        }
+
+        Ok(Self { is_signed, bits })
+    }
+
+    fn min_value(&self) -> i128 {
+        if self.is_signed {
+            -(1i128 << (self.bits - 1))
+        } else {
+            0
+        }
+    }
+
+    fn max_value(&self) -> i128 {
+        if self.is_signed {
+            (1i128 << (self.bits - 1)) - 1
+        } else {
+            (1i128 << self.bits) - 1
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum OverflowBehavior {
+    Wrap,
+    Saturate,
+    Fail,
+}
+
+#[derive(Debug, PartialEq)]
+enum BitfieldOperation {
+    Get(BitfieldType, u64),
+    Set(BitfieldType, u64, i64, OverflowBehavior),
+    IncrBy(BitfieldType, u64, i64, OverflowBehavior),
+}
+
+pub struct BitfieldArguments {
+    key: String,
+    operations: Vec<BitfieldOperation>,
+}
+
+impl BitfieldArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return Err(CommandError::InvalidBitfieldCommand);
+        }
+
+        let mut operations = Vec::new();
+        let mut overflow = OverflowBehavior::Wrap;
+        let mut index = 1;
+
+        while index < arguments.len() {
+            let subcommand = normalize_keyword(&arguments[index]);
+
+            match subcommand.as_str() {
+                "GET" => {
+                    let [field_type, offset] = Self::subcommand_arguments(&arguments, index, 2)?;
+                    let field_type = BitfieldType::parse(field_type)?;
+                    let offset = parse_offset(offset, field_type)?;
+
+                    operations.push(BitfieldOperation::Get(field_type, offset));
+                    index += 3;
+                }
+                "SET" | "INCRBY" => {
+                    let [field_type, offset, value] =
+                        Self::subcommand_arguments(&arguments, index, 3)?;
+                    let field_type = BitfieldType::parse(field_type)?;
+                    let offset = parse_offset(offset, field_type)?;
+                    let value = value
+                        .parse::<i64>()
+                        .map_err(|_| CommandError::InvalidBitfieldValue)?;
+
+                    if subcommand == "SET" {
+                        operations
+                            .push(BitfieldOperation::Set(field_type, offset, value, overflow));
+                    } else {
+                        operations.push(BitfieldOperation::IncrBy(
+                            field_type, offset, value, overflow,
+                        ));
+                    }
+
+                    index += 4;
+                }
+                "OVERFLOW" => {
+                    let [behavior] = Self::subcommand_arguments(&arguments, index, 1)?;
+
+                    overflow = match normalize_keyword(behavior).as_str() {
+                        "WRAP" => OverflowBehavior::Wrap,
+                        "SAT" => OverflowBehavior::Saturate,
+                        "FAIL" => OverflowBehavior::Fail,
+                        _ => return Err(CommandError::InvalidBitfieldOverflowType),
+                    };
+
+                    index += 2;
+                }
+                _ => return Err(CommandError::InvalidBitfieldCommand),
+            }
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            operations,
+        })
+    }
+
+    fn subcommand_arguments<const N: usize>(
+        arguments: &[String],
+        index: usize,
+        count: usize,
+    ) -> Result<[&String; N], CommandError> {
+        arguments
+            .get(index + 1..index + 1 + count)
+            .and_then(|slice| slice.iter().collect::<Vec<&String>>().try_into().ok())
+            .ok_or(CommandError::InvalidBitfieldCommand)
+    }
+}
+
+/// Offsets prefixed with '#' are multiplied by the field width, so "#2" on a u8 is bit 16
+fn parse_offset(offset: &str, field_type: BitfieldType) -> Result<u64, CommandError> {
+    let (offset, multiplier) = match offset.strip_prefix('#') {
+        Some(offset) => (offset, field_type.bits as u64),
+        None => (offset, 1),
+    };
+
+    offset
+        .parse::<u64>()
+        .ok()
+        .and_then(|offset| offset.checked_mul(multiplier))
+        .filter(|offset| *offset < MAX_BIT_OFFSET)
+        .ok_or(CommandError::InvalidBitfieldOffset)
+}
+
+pub async fn bitfield(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let bitfield_arguments = BitfieldArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let (mut bytes, expiration) = match store_guard.get(&bitfield_arguments.key) {
+        Some(value) => {
+            let DataType::String(ref string) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            (string_to_bytes(string), value.expiration)
+        }
+        None => (Vec::new(), None),
+    };
+
+    let mut has_write_operation = false;
+    let mut response = Vec::with_capacity(bitfield_arguments.operations.len());
+
+    for operation in bitfield_arguments.operations {
+        let reply = match operation {
+            BitfieldOperation::Get(field_type, offset) => {
+                Some(read_field(&bytes, offset, field_type))
+            }
+            BitfieldOperation::Set(field_type, offset, value, overflow) => {
+                has_write_operation = true;
+                let old_value = read_field(&bytes, offset, field_type);
+
+                apply_overflow(value as i128, field_type, overflow).map(|new_value| {
+                    write_field(&mut bytes, offset, field_type, new_value);
+                    old_value
+                })
+            }
+            BitfieldOperation::IncrBy(field_type, offset, increment, overflow) => {
+                has_write_operation = true;
+                let old_value = read_field(&bytes, offset, field_type);
+
+                let new_value =
+                    apply_overflow(old_value as i128 + increment as i128, field_type, overflow);
+
+                if let Some(new_value) = new_value {
+                    write_field(&mut bytes, offset, field_type, new_value);
+                }
+
+                new_value
+            }
+        };
+
+        match reply {
+            Some(value) => response.push(RespValue::Integer(value)),
+            None => response.push(RespValue::NullBulkString),
+        }
+    }
+
+    if has_write_operation {
+        store_guard.insert(
+            bitfield_arguments.key,
+            Value {
+                data: DataType::String(bytes_to_string(&bytes)),
+                expiration,
+            },
+        );
+    }
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
+
+/// Returns the value that should be stored, or None if the operation must fail
+fn apply_overflow(
+    value: i128,
+    field_type: BitfieldType,
+    overflow: OverflowBehavior,
+) -> Option<i64> {
+    let (min_value, max_value) = (field_type.min_value(), field_type.max_value());
+
+    if (min_value..=max_value).contains(&value) {
+        return Some(value as i64);
+    }
+
+    match overflow {
+        OverflowBehavior::Wrap => {
+            let range = 1i128 << field_type.bits;
+            Some(((value - min_value).rem_euclid(range) + min_value) as i64)
+        }
+        OverflowBehavior::Saturate => Some(value.clamp(min_value, max_value) as i64),
+        OverflowBehavior::Fail => None,
+    }
+}
+
+/// Bits are addressed from the most significant bit of the first byte, like in Redis
+fn read_field(bytes: &[u8], offset: u64, field_type: BitfieldType) -> i64 {
+    let mut value: u64 = 0;
+
+    for bit_index in offset..offset + field_type.bits as u64 {
+        let byte = bytes.get((bit_index / 8) as usize).copied().unwrap_or(0);
+        let bit = (byte >> (7 - bit_index % 8)) & 1;
+
+        value = (value << 1) | bit as u64;
+    }
+
+    // Sign extend negative values of signed fields
+    if field_type.is_signed && field_type.bits < 64 && value & (1 << (field_type.bits - 1)) != 0 {
+        value |= u64::MAX << field_type.bits;
+    }
+
+    value as i64
+}
+
+fn write_field(bytes: &mut Vec<u8>, offset: u64, field_type: BitfieldType, value: i64) {
+    let end_bit = offset + field_type.bits as u64;
+    let required_length = end_bit.div_ceil(8) as usize;
+
+    if bytes.len() < required_length {
+        bytes.resize(required_length, 0);
+    }
+
+    for (position, bit_index) in (offset..end_bit).enumerate() {
+        let bit = (value as u64 >> (field_type.bits as usize - 1 - position)) & 1;
+        let byte = &mut bytes[(bit_index / 8) as usize];
+        let mask = 1 << (7 - bit_index % 8);
+
+        if bit == 1 {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+}
+
+/// Values are stored as UTF-8 strings, so arbitrary bytes are kept as one char per byte
+/// (U+0000..U+00FF). Strings with other characters are read as their UTF-8 bytes.
+pub fn string_to_bytes(string: &str) -> Vec<u8> {
+    if string.chars().all(|char| (char as u32) <= 0xFF) {
+        string.chars().map(|char| char as u8).collect()
+    } else {
+        string.as_bytes().to_vec()
+    }
+}
+
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| *byte as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitfieldType, OverflowBehavior, apply_overflow, read_field, write_field};
+
+    #[test]
+    fn test_read_and_write_field() {
+        let u8_type = BitfieldType {
+            is_signed: false,
+            bits: 8,
+        };
+        let i4_type = BitfieldType {
+            is_signed: true,
+            bits: 4,
+        };
+
+        let mut bytes = Vec::new();
+        write_field(&mut bytes, 4, u8_type, 0xAB);
+
+        assert_eq!(bytes, vec![0x0A, 0xB0]);
+        assert_eq!(read_field(&bytes, 4, u8_type), 0xAB);
+        assert_eq!(read_field(&bytes, 4, i4_type), -6);
+        assert_eq!(read_field(&bytes, 100, u8_type), 0);
+    }
+
+    #[test]
+    fn test_apply_overflow() {
+        let u8_type = BitfieldType {
+            is_signed: false,
+            bits: 8,
+        };
+        let i8_type = BitfieldType {
+            is_signed: true,
+            bits: 8,
+        };
+
+        let test_cases = vec![
+            (100, u8_type, OverflowBehavior::Wrap, Some(100)),
+            (300, u8_type, OverflowBehavior::Wrap, Some(44)),
+            (-1, u8_type, OverflowBehavior::Wrap, Some(255)),
+            (300, u8_type, OverflowBehavior::Saturate, Some(255)),
+            (-5, u8_type, OverflowBehavior::Saturate, Some(0)),
+            (256, u8_type, OverflowBehavior::Fail, None),
+            (128, i8_type, OverflowBehavior::Wrap, Some(-128)),
+            (-129, i8_type, OverflowBehavior::Wrap, Some(127)),
+            (200, i8_type, OverflowBehavior::Saturate, Some(127)),
+            (-200, i8_type, OverflowBehavior::Fail, None),
+        ];
+
+        for (value, field_type, overflow, expected) in test_cases {
+            assert_eq!(
+                apply_overflow(value, field_type, overflow),
+                expected,
+                "applying {:?} to {} for {:?}",
+                overflow,
+                value,
+                field_type
+            );
+        }
+    }
+}
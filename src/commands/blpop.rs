@@ -3,6 +3,7 @@ use std::{sync::Arc, time::Duration};
 use tokio::sync::{Mutex, oneshot};
 
 use crate::{
+    aof::RewriteGuard,
     commands::{command_error::CommandError, command_handler::CommandResult},
     key_value_store::{DataType, KeyValueStore},
     resp::RespValue,
@@ -36,6 +37,7 @@ pub async fn blpop(
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
     arguments: Vec<String>,
+    rewrite_guard: &mut RewriteGuard,
 ) -> Result<CommandResult, CommandError> {
     let blpop_arguments = BlpopArguments::parse(arguments)?;
 
@@ -56,7 +58,9 @@ pub async fn blpop(
     )
     .await;
 
+    rewrite_guard.release();
     let data = wait_for_data(&mut receiver, blpop_arguments.block_duration_secs).await;
+    rewrite_guard.reacquire().await;
     remove_subscriber(state, &blpop_arguments.key, &client_address).await;
 
     if data.is_none() {
@@ -82,11 +86,17 @@ async fn remove_first_element_from_list(
         return None;
     };
 
-    if let DataType::Array(ref mut list) = stored_data.data {
-        list.pop_front()
-    } else {
-        None
+    let DataType::Array(ref mut list) = stored_data.data else {
+        return None;
+    };
+
+    let removed = list.pop_front();
+
+    if list.is_empty() {
+        store_guard.remove(key);
     }
+
+    removed
 }
 
 async fn add_subscriber(
@@ -125,14 +135,13 @@ async fn wait_for_data(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::key_value_store::Value;
-    use std::collections::VecDeque;
+    use crate::{key_value_store::Value, list::List};
 
     #[tokio::test]
     async fn test_remove_first_element_from_list_success() {
         let store = Arc::new(Mutex::new(KeyValueStore::new()));
 
-        let mut list = VecDeque::new();
+        let mut list = List::new();
         list.push_back("first".to_string());
         list.push_back("second".to_string());
         list.push_back("third".to_string());
@@ -164,7 +173,7 @@ mod tests {
     async fn test_remove_first_element_from_empty_list() {
         let store = Arc::new(Mutex::new(KeyValueStore::new()));
 
-        let empty_list = VecDeque::new();
+        let empty_list = List::new();
         let value = Value {
             data: DataType::Array(empty_list),
             expiration: None,
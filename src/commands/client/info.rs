@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct ClientInfoArguments;
+
+impl ClientInfoArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidClientCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn client_info(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    ClientInfoArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    let is_resp3 = server_guard.resp3_clients.contains(client_address);
+    let client = server_guard.register_client(client_address);
+
+    Ok(CommandResult::Response(
+        RespValue::BulkString(client.format_info(is_resp3)).encode(),
+    ))
+}
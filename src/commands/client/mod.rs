@@ -0,0 +1,9 @@
+mod info;
+mod no_evict;
+mod no_touch;
+mod tracking;
+
+pub use info::{ClientInfoArguments, client_info};
+pub use no_evict::{ClientNoEvictArguments, client_no_evict};
+pub use no_touch::{ClientNoTouchArguments, client_no_touch};
+pub use tracking::{ClientTrackingArguments, client_tracking, read_keys, written_keys};
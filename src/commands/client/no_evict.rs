@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult, keyword_utils::normalize_keyword},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct ClientNoEvictArguments {
+    enabled: bool,
+}
+
+impl ClientNoEvictArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidClientCommand);
+        }
+
+        let enabled = match normalize_keyword(&arguments[0]).as_str() {
+            "ON" => true,
+            "OFF" => false,
+            _ => return Err(CommandError::InvalidClientCommandArgument),
+        };
+
+        Ok(Self { enabled })
+    }
+}
+
+pub async fn client_no_evict(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let client_no_evict_arguments = ClientNoEvictArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    server_guard.register_client(client_address).no_evict = client_no_evict_arguments.enabled;
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
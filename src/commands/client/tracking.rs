@@ -0,0 +1,246 @@
+use std::sync::Arc;
+
+use tokio::sync::{RwLock, mpsc};
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult, keyword_utils::normalize_keyword},
+    resp::RespValue,
+    server::{InvalidationTarget, RedisServer},
+};
+
+pub struct ClientTrackingArguments {
+    enabled: bool,
+    redirect: Option<u64>,
+}
+
+impl ClientTrackingArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        let Some(mode) = arguments.first() else {
+            return Err(CommandError::InvalidClientCommand);
+        };
+
+        let enabled = match normalize_keyword(mode).as_str() {
+            "ON" => true,
+            "OFF" => false,
+            _ => return Err(CommandError::InvalidClientCommandArgument),
+        };
+
+        let mut redirect = None;
+        let mut options = arguments[1..].iter();
+
+        while let Some(option) = options.next() {
+            match normalize_keyword(option).as_str() {
+                "REDIRECT" => {
+                    let client_id = options
+                        .next()
+                        .ok_or(CommandError::InvalidClientCommandArgument)?
+                        .parse::<u64>()
+                        .map_err(|_| CommandError::InvalidTrackingRedirectClientId)?;
+                    redirect = Some(client_id);
+                }
+                // Only the default mode, which tracks the keys each connection read, is supported
+                "BCAST" => return Err(CommandError::UnsupportedTrackingBroadcastMode),
+                "PREFIX" => return Err(CommandError::TrackingPrefixRequiresBroadcastMode),
+                _ => return Err(CommandError::InvalidClientCommandArgument),
+            }
+        }
+
+        Ok(Self { enabled, redirect })
+    }
+}
+
+/// Turns client-side caching on or off for the connection. While it is on, the server
+/// remembers the keys the connection reads and tells it when one of them is modified.
+pub async fn client_tracking(
+    client_address: &str,
+    sender: mpsc::UnboundedSender<Vec<u8>>,
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let client_tracking_arguments = ClientTrackingArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+
+    if !client_tracking_arguments.enabled {
+        server_guard.stop_tracking(client_address);
+
+        return Ok(CommandResult::Response(
+            RespValue::SimpleString("OK".to_string()).encode(),
+        ));
+    }
+
+    let target = match client_tracking_arguments.redirect {
+        Some(client_id) => {
+            let Some(redirect_client) = server_guard
+                .clients
+                .values()
+                .find(|client| client.id == client_id)
+            else {
+                return Err(CommandError::TrackingRedirectClientNotFound);
+            };
+
+            InvalidationTarget::Redirect(redirect_client.address.clone())
+        }
+        None => InvalidationTarget::Connection(sender),
+    };
+
+    server_guard.register_client(client_address).tracking = Some(target);
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
+
+/// Keys a command looks up, which a tracking connection may then cache when the command
+/// is a read. For a write command these are the keys it reads besides the ones it writes to.
+pub fn read_keys(command_name: &str, arguments: &[String]) -> Vec<String> {
+    match command_name {
+        "GET" | "HGET" | "HGETALL" | "HMGET" | "HSTRLEN" | "HRANDFIELD" | "HSCAN" | "SSCAN"
+        | "LLEN" | "LRANGE" | "TYPE" | "XLEN" | "XRANGE" | "ZCARD" | "ZMSCORE" | "ZRANDMEMBER"
+        | "ZRANGE" | "ZRANK" | "ZREVRANGE" | "ZREVRANK" | "ZSCORE" | "GEODIST" | "GEOPOS"
+        | "GEOSEARCH" | "SORT_RO" | "SORT" => arguments.iter().take(1).cloned().collect(),
+        "PFCOUNT" => arguments.to_vec(),
+        // The source keys follow the destination
+        "PFMERGE" | "SINTERSTORE" | "SUNIONSTORE" | "SDIFFSTORE" => {
+            arguments.iter().skip(1).cloned().collect()
+        }
+        // The keys follow their count
+        "SINTERCARD" => {
+            let number_of_keys = arguments
+                .first()
+                .and_then(|number_of_keys| number_of_keys.parse::<usize>().ok())
+                .unwrap_or(0);
+
+            arguments
+                .iter()
+                .skip(1)
+                .take(number_of_keys)
+                .cloned()
+                .collect()
+        }
+        // The keys make up the first half of what follows STREAMS, the IDs the second
+        "XREAD" => {
+            let streams = arguments
+                .iter()
+                .position(|argument| normalize_keyword(argument) == "STREAMS")
+                .map(|index| &arguments[index + 1..])
+                .unwrap_or_default();
+
+            streams.iter().take(streams.len() / 2).cloned().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Keys a write command may modify: the first argument, except for the commands
+/// that write to several keys or to a key given later on
+pub fn written_keys(command_name: &str, arguments: &[String]) -> Vec<String> {
+    match command_name {
+        "SMOVE" => arguments.iter().take(2).cloned().collect(),
+        // The keys follow their count
+        "LMPOP" | "ZMPOP" => {
+            let number_of_keys = arguments
+                .first()
+                .and_then(|number_of_keys| number_of_keys.parse::<usize>().ok())
+                .unwrap_or(0);
+
+            arguments
+                .iter()
+                .skip(1)
+                .take(number_of_keys)
+                .cloned()
+                .collect()
+        }
+        // The last argument is the timeout
+        "BLPOP" | "BZPOPMIN" | "BZPOPMAX" => arguments
+            .iter()
+            .take(arguments.len().saturating_sub(1))
+            .cloned()
+            .collect(),
+        "SORT" => arguments
+            .iter()
+            .position(|argument| normalize_keyword(argument) == "STORE")
+            .and_then(|index| arguments.get(index + 1))
+            .cloned()
+            .into_iter()
+            .collect(),
+        _ => arguments.iter().take(1).cloned().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_keys, written_keys};
+
+    fn to_strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_read_keys() {
+        let test_cases = vec![
+            ("GET", vec!["grape"], vec!["grape"]),
+            ("HMGET", vec!["fruits", "mango", "kiwi"], vec!["fruits"]),
+            ("HSCAN", vec!["fruits", "0", "MATCH", "m*"], vec!["fruits"]),
+            ("PFCOUNT", vec!["visits", "views"], vec!["visits", "views"]),
+            (
+                "SINTERCARD",
+                vec!["2", "fruits", "basket", "LIMIT", "1"],
+                vec!["fruits", "basket"],
+            ),
+            (
+                "XREAD",
+                vec!["COUNT", "2", "streams", "fruits", "basket", "0-0", "0-1"],
+                vec!["fruits", "basket"],
+            ),
+            (
+                "SINTERSTORE",
+                vec!["basket", "fruits", "colors"],
+                vec!["fruits", "colors"],
+            ),
+            ("ECHO", vec!["grape"], vec![]),
+        ];
+
+        for (command_name, arguments, expected_keys) in test_cases {
+            assert_eq!(
+                read_keys(command_name, &to_strings(&arguments)),
+                to_strings(&expected_keys)
+            );
+        }
+    }
+
+    #[test]
+    fn test_written_keys() {
+        let test_cases = vec![
+            ("SET", vec!["grape", "mango"], vec!["grape"]),
+            (
+                "SMOVE",
+                vec!["fruits", "basket", "kiwi"],
+                vec!["fruits", "basket"],
+            ),
+            (
+                "BLPOP",
+                vec!["fruits", "basket", "0"],
+                vec!["fruits", "basket"],
+            ),
+            (
+                "SORT",
+                vec!["fruits", "ALPHA", "store", "sorted"],
+                vec!["sorted"],
+            ),
+            ("SORT", vec!["fruits", "ALPHA"], vec![]),
+            (
+                "LMPOP",
+                vec!["2", "fruits", "basket", "LEFT"],
+                vec!["fruits", "basket"],
+            ),
+        ];
+
+        for (command_name, arguments, expected_keys) in test_cases {
+            assert_eq!(
+                written_keys(command_name, &to_strings(&arguments)),
+                to_strings(&expected_keys)
+            );
+        }
+    }
+}
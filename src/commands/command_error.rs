@@ -6,8 +6,12 @@ use crate::{resp::RespValue, state::StateError};
 pub enum CommandError {
     #[error("invalid command")]
     InvalidCommand,
+    #[error("unknown command '{0}'")]
+    UnknownCommand(String, Vec<String>),
     #[error("invalid command argument")]
     InvalidCommandArgument,
+    #[error("wrong number of arguments for '{0}' command")]
+    WrongNumberOfArguments(String),
     #[error("invalid PING command")]
     InvalidPingCommand,
     #[error("invalid ECHO command")]
@@ -36,26 +40,50 @@ pub enum CommandError {
     InvalidLPopCommand,
     #[error("invalid LPOP command argument")]
     InvalidLPopCommandArgument,
+    #[error("invalid LMPOP command")]
+    InvalidLMPopCommand,
+    #[error("numkeys should be greater than 0")]
+    InvalidLMPopNumberOfKeys,
+    #[error("number of keys can't be greater than number of args")]
+    LMPopNumberOfKeysMismatch,
+    #[error("count should be greater than 0")]
+    InvalidLMPopCount,
     #[error("invalid BLPOP command")]
     InvalidBLPopCommand,
     #[error("invalid BLPOP command argument")]
     InvalidBLPopCommandArgument,
     #[error("invalid TYPE command")]
     InvalidTypeCommand,
+    #[error("invalid OBJECT ENCODING command")]
+    InvalidObjectEncodingCommand,
+    #[error("invalid OBJECT IDLETIME command")]
+    InvalidObjectIdletimeCommand,
     #[error("invalid XADD command")]
     InvalidXAddCommand,
     #[error("{0}")]
     InvalidStreamId(String),
-    #[error("invalid data type for key")]
-    InvalidDataTypeForKey,
+    #[error("operation against a key holding the wrong kind of value")]
+    WrongType,
     #[error("invalid XRANGE command")]
     InvalidXRangeCommand,
     #[error("invalid XREAD command")]
     InvalidXReadCommand,
+    #[error("invalid XSETID command")]
+    InvalidXSetIdCommand,
+    #[error("invalid XLEN command")]
+    InvalidXLenCommand,
+    #[error("invalid XDEL command")]
+    InvalidXDelCommand,
+    #[error("invalid XTRIM command")]
+    InvalidXTrimCommand,
+    #[error("{0}")]
+    InvalidStreamTrimStrategy(String),
     #[error("invalid XREAD command option")]
     InvalidXReadOption,
     #[error("invalid XREAD block duration")]
     InvalidXReadBlockDuration,
+    #[error("invalid XREAD count")]
+    InvalidXReadCount,
     #[error("invalid INCR command")]
     InvalidIncrCommand,
     #[error("invalid INCR value")]
@@ -104,10 +132,268 @@ pub enum CommandError {
     InvalidSubscribeCommand,
     #[error("invalid command in subscribed mode")]
     InvalidCommandInSubscribedMode(String),
+    #[error("command is not allowed in transactions")]
+    CommandNotAllowedInTransaction(String),
     #[error("invalid PUBLISH command")]
     InvalidPublishCommand,
-    #[error("error during IO operation")]
-    IoError,
+    #[error("invalid SSUBSCRIBE command")]
+    InvalidSsubscribeCommand,
+    #[error("invalid SPUBLISH command")]
+    InvalidSpublishCommand,
+    #[error("invalid HELLO command")]
+    InvalidHelloCommand,
+    #[error("unsupported protocol version")]
+    UnsupportedProtocolVersion,
+    #[error("invalid username-password pair or user is disabled")]
+    WrongPassword,
+    #[error("invalid RESET command")]
+    InvalidResetCommand,
+    #[error("invalid QUIT command")]
+    InvalidQuitCommand,
+    #[error("invalid DEBUG command")]
+    InvalidDebugCommand,
+    #[error("invalid DEBUG command argument")]
+    InvalidDebugCommandArgument,
+    #[error("no such key")]
+    NoSuchKey,
+    #[error("value is not listpack encoded")]
+    NotListpackEncoded,
+    #[error("FAILOVER is not supported")]
+    FailoverNotSupported,
+    #[error("invalid DBSIZE command")]
+    InvalidDbsizeCommand,
+    #[error("invalid COMMAND LIST command")]
+    InvalidCommandListCommand,
+    #[error("invalid LOLWUT command")]
+    InvalidLolwutCommand,
+    #[error("invalid LOLWUT command option")]
+    InvalidLolwutCommandOption,
+    #[error("invalid LOLWUT command argument")]
+    InvalidLolwutCommandArgument,
+    #[error("invalid HSET command")]
+    InvalidHSetCommand,
+    #[error("invalid HGET command")]
+    InvalidHGetCommand,
+    #[error("invalid HGETALL command")]
+    InvalidHGetAllCommand,
+    #[error("invalid HMGET command")]
+    InvalidHMGetCommand,
+    #[error("invalid HSETNX command")]
+    InvalidHSetNxCommand,
+    #[error("invalid HSTRLEN command")]
+    InvalidHStrLenCommand,
+    #[error("invalid HRANDFIELD command")]
+    InvalidHRandFieldCommand,
+    #[error("value is not an integer or out of range")]
+    InvalidHRandFieldCount,
+    #[error("invalid HSCAN command")]
+    InvalidHScanCommand,
+    #[error("invalid ZADD command")]
+    InvalidZAddCommand,
+    #[error("INCR option supports a single increment-element pair")]
+    InvalidZAddIncrPairs,
+    #[error("XX and NX options at the same time are not compatible")]
+    IncompatibleZAddNxXxOptions,
+    #[error("GT, LT, and/or NX options at the same time are not compatible")]
+    IncompatibleZAddGtLtNxOptions,
+    #[error("value is not a valid float")]
+    InvalidSortedSetScore,
+    #[error("invalid ZSCORE command")]
+    InvalidZScoreCommand,
+    #[error("invalid ZMSCORE command")]
+    InvalidZMScoreCommand,
+    #[error("invalid ZRANDMEMBER command")]
+    InvalidZRandMemberCommand,
+    #[error("value is not an integer or out of range")]
+    InvalidZRandMemberCount,
+    #[error("invalid ZRANGE command")]
+    InvalidZRangeCommand,
+    #[error("invalid ZREVRANGE command")]
+    InvalidZRevRangeCommand,
+    #[error("value is not an integer or out of range")]
+    InvalidZRangeCommandArgument,
+    #[error("invalid ZRANK command")]
+    InvalidZRankCommand,
+    #[error("invalid ZREVRANK command")]
+    InvalidZRevRankCommand,
+    #[error("invalid ZCARD command")]
+    InvalidZCardCommand,
+    #[error("invalid ZREM command")]
+    InvalidZRemCommand,
+    #[error("invalid ZREMRANGEBYRANK command")]
+    InvalidZRemRangeByRankCommand,
+    #[error("invalid ZREMRANGEBYSCORE command")]
+    InvalidZRemRangeByScoreCommand,
+    #[error("min or max is not a float")]
+    InvalidSortedSetScoreRange,
+    #[error("invalid ZINCRBY command")]
+    InvalidZIncrByCommand,
+    #[error("resulting score is not a number (NaN)")]
+    SortedSetScoreNaN,
+    #[error("invalid PFADD command")]
+    InvalidPfAddCommand,
+    #[error("invalid PFCOUNT command")]
+    InvalidPfCountCommand,
+    #[error("key is not a valid HyperLogLog string value")]
+    InvalidHyperLogLogValue,
+    #[error("invalid PFMERGE command")]
+    InvalidPfMergeCommand,
+    #[error("invalid BITFIELD command")]
+    InvalidBitfieldCommand,
+    #[error("invalid bitfield type")]
+    InvalidBitfieldType,
+    #[error("bit offset is not an integer or out of range")]
+    InvalidBitfieldOffset,
+    #[error("value is not an integer or out of range")]
+    InvalidBitfieldValue,
+    #[error("invalid OVERFLOW type specified")]
+    InvalidBitfieldOverflowType,
+    #[error("invalid GETSET command")]
+    InvalidGetSetCommand,
+    #[error("invalid GETDEL command")]
+    InvalidGetDelCommand,
+    #[error("invalid APPEND command")]
+    InvalidAppendCommand,
+    #[error("invalid SETRANGE command")]
+    InvalidSetRangeCommand,
+    #[error("value is not an integer or out of range")]
+    InvalidSetRangeOffset,
+    #[error("offset is out of range")]
+    SetRangeOffsetOutOfRange,
+    #[error("string exceeds maximum allowed size (proto-max-bulk-len)")]
+    StringExceedsMaximumSize,
+    #[error("invalid CLIENT command")]
+    InvalidClientCommand,
+    #[error("invalid CLIENT command argument")]
+    InvalidClientCommandArgument,
+    #[error("value is not an integer or out of range")]
+    InvalidTrackingRedirectClientId,
+    #[error("the client ID you want redirect to does not exist")]
+    TrackingRedirectClientNotFound,
+    #[error("BCAST mode is not supported")]
+    UnsupportedTrackingBroadcastMode,
+    #[error("PREFIX option requires BCAST mode to be enabled")]
+    TrackingPrefixRequiresBroadcastMode,
+    #[error("invalid SADD command")]
+    InvalidSAddCommand,
+    #[error("invalid SINTERCARD command")]
+    InvalidSInterCardCommand,
+    #[error("numkeys should be greater than 0")]
+    InvalidSInterCardNumberOfKeys,
+    #[error("number of keys can't be greater than number of args")]
+    SInterCardNumberOfKeysMismatch,
+    #[error("LIMIT can't be negative")]
+    InvalidSInterCardLimit,
+    #[error("invalid SSCAN command")]
+    InvalidSScanCommand,
+    #[error("invalid cursor")]
+    InvalidScanCursor,
+    #[error("value is not an integer or out of range")]
+    InvalidScanCount,
+    #[error("syntax error")]
+    ScanSyntaxError,
+    #[error("invalid SMOVE command")]
+    InvalidSMoveCommand,
+    #[error("invalid SINTERSTORE command")]
+    InvalidSInterStoreCommand,
+    #[error("invalid SUNIONSTORE command")]
+    InvalidSUnionStoreCommand,
+    #[error("invalid SDIFFSTORE command")]
+    InvalidSDiffStoreCommand,
+    #[error("invalid ZPOPMIN command")]
+    InvalidZPopMinCommand,
+    #[error("invalid ZPOPMAX command")]
+    InvalidZPopMaxCommand,
+    #[error("value is out of range, must be positive")]
+    InvalidZPopCount,
+    #[error("invalid ZMPOP command")]
+    InvalidZMPopCommand,
+    #[error("numkeys should be greater than 0")]
+    InvalidZMPopNumberOfKeys,
+    #[error("number of keys can't be greater than number of args")]
+    ZMPopNumberOfKeysMismatch,
+    #[error("count should be greater than 0")]
+    InvalidZMPopCount,
+    #[error("invalid BZPOPMIN command")]
+    InvalidBZPopMinCommand,
+    #[error("invalid BZPOPMAX command")]
+    InvalidBZPopMaxCommand,
+    #[error("timeout is not a float or out of range")]
+    InvalidBZPopTimeout,
+    #[error("invalid SORT command")]
+    InvalidSortCommand,
+    #[error("syntax error")]
+    SortSyntaxError,
+    #[error("value is not an integer or out of range")]
+    InvalidSortLimit,
+    #[error("one or more scores can't be converted into double")]
+    SortScoreNotANumber,
+    #[error("invalid SORT_RO command")]
+    InvalidSortRoCommand,
+    #[error("invalid HELP command")]
+    InvalidHelpCommand,
+    #[error("invalid SAVE command")]
+    InvalidSaveCommand,
+    #[error("invalid BGSAVE command")]
+    InvalidBgSaveCommand,
+    #[error("invalid LASTSAVE command")]
+    InvalidLastSaveCommand,
+    #[error("background save already in progress")]
+    BackgroundSaveInProgress,
+    #[error("failed to save the RDB file")]
+    RdbSaveFailed,
+    #[error("failed to reload the RDB file")]
+    RdbReloadFailed,
+    #[error("invalid WAITAOF command")]
+    InvalidWaitaofCommand,
+    #[error("invalid WAITAOF command argument")]
+    InvalidWaitaofCommandArgument,
+    #[error("invalid WAITAOF command for replica")]
+    InvalidWaitaofCommandForReplica,
+    #[error("WAITAOF numlocal set while appendonly is disabled")]
+    WaitaofAppendOnlyDisabled,
+    #[error("invalid EXPIRE command")]
+    InvalidExpireCommand,
+    #[error("invalid PEXPIRE command")]
+    InvalidPexpireCommand,
+    #[error("invalid expire time")]
+    InvalidExpireTime,
+    #[error("invalid PEXPIREAT command")]
+    InvalidPexpireatCommand,
+    #[error("invalid PEXPIREAT timestamp")]
+    InvalidPexpireatTimestamp,
+    #[error("invalid BGREWRITEAOF command")]
+    InvalidBgRewriteAofCommand,
+    #[error("background append only file rewriting already in progress")]
+    AofRewriteInProgress,
+    #[error("invalid GEOADD command")]
+    InvalidGeoAddCommand,
+    #[error("invalid GEOPOS command")]
+    InvalidGeoPosCommand,
+    #[error("invalid longitude,latitude pair")]
+    InvalidGeoCoordinates(String),
+    #[error("invalid GEODIST command")]
+    InvalidGeoDistCommand,
+    #[error("invalid GEOSEARCH command")]
+    InvalidGeoSearchCommand,
+    #[error("invalid GEOSEARCH radius")]
+    InvalidGeoSearchRadius,
+    #[error("invalid GEOSEARCH count")]
+    InvalidGeoSearchCount,
+    #[error("unsupported distance unit")]
+    UnsupportedGeoUnit,
+    #[error("geo member not found")]
+    GeoMemberNotFound,
+    #[error("invalid SLOWLOG command")]
+    InvalidSlowlogCommand,
+    #[error("count should be greater than or equal to -1")]
+    InvalidSlowlogCount,
+    #[error("invalid LATENCY command")]
+    InvalidLatencyCommand,
+    #[error("invalid MEMORY command")]
+    InvalidMemoryCommand,
+    #[error("value is not an integer or out of range")]
+    InvalidMemoryUsageSamples,
 }
 
 impl CommandError {
@@ -116,6 +402,12 @@ impl CommandError {
             CommandError::InvalidCommand => {
                 RespValue::Error("ERR Invalid command".to_string()).encode()
             }
+            CommandError::UnknownCommand(name, arguments) => RespValue::Error(format!(
+                "ERR unknown command '{}', with args beginning with: {}",
+                name,
+                format_leading_arguments(arguments)
+            ))
+            .encode(),
             CommandError::InvalidCommandArgument => {
                 RespValue::Error("ERR Invalid command argument".to_string()).encode()
             }
@@ -161,6 +453,18 @@ impl CommandError {
             CommandError::InvalidLPopCommandArgument => {
                 RespValue::Error("ERR Invalid LPOP command argument".to_string()).encode()
             }
+            CommandError::InvalidLMPopCommand => {
+                RespValue::Error("ERR Invalid LMPOP command".to_string()).encode()
+            }
+            CommandError::InvalidLMPopNumberOfKeys => {
+                RespValue::Error("ERR numkeys should be greater than 0".to_string()).encode()
+            }
+            CommandError::LMPopNumberOfKeysMismatch => {
+                RespValue::Error("ERR Number of keys can't be greater than number of args".to_string()).encode()
+            }
+            CommandError::InvalidLMPopCount => {
+                RespValue::Error("ERR count should be greater than 0".to_string()).encode()
+            }
             CommandError::InvalidBLPopCommand => {
                 RespValue::Error("ERR Invalid BLPOP command".to_string()).encode()
             }
@@ -170,12 +474,21 @@ impl CommandError {
             CommandError::InvalidTypeCommand => {
                 RespValue::Error("ERR Invalid TYPE command".to_string()).encode()
             }
+            CommandError::InvalidObjectEncodingCommand => {
+                RespValue::Error("ERR Invalid OBJECT ENCODING command".to_string()).encode()
+            }
+            CommandError::InvalidObjectIdletimeCommand => {
+                RespValue::Error("ERR Invalid OBJECT IDLETIME command".to_string()).encode()
+            }
             CommandError::InvalidXAddCommand => {
                 RespValue::Error("ERR Invalid XADD command".to_string()).encode()
             }
             CommandError::InvalidStreamId(str) => RespValue::Error(format!("ERR {}", str)).encode(),
-            CommandError::InvalidDataTypeForKey => {
-                RespValue::Error("ERR Invalid data type for key".to_string()).encode()
+            CommandError::WrongType => {
+                RespValue::Error(
+                "WRONGTYPE Operation against a key holding the wrong kind of value".to_string(),
+            )
+            .encode()
             }
             CommandError::InvalidXRangeCommand => {
                 RespValue::Error("ERR Invalid XRANGE command".to_string()).encode()
@@ -186,9 +499,27 @@ impl CommandError {
             CommandError::InvalidXReadCommand => {
                 RespValue::Error("ERR Invalid XREAD command".to_string()).encode()
             }
+            CommandError::InvalidXSetIdCommand => {
+                RespValue::Error("ERR Invalid XSETID command".to_string()).encode()
+            }
+            CommandError::InvalidXLenCommand => {
+                RespValue::Error("ERR Invalid XLEN command".to_string()).encode()
+            }
+            CommandError::InvalidXDelCommand => {
+                RespValue::Error("ERR Invalid XDEL command".to_string()).encode()
+            }
+            CommandError::InvalidXTrimCommand => {
+                RespValue::Error("ERR Invalid XTRIM command".to_string()).encode()
+            }
+            CommandError::InvalidStreamTrimStrategy(str) => {
+                RespValue::Error(format!("ERR {}", str)).encode()
+            }
             CommandError::InvalidXReadBlockDuration => {
                 RespValue::Error("ERR Invalid XREAD block duration".to_string()).encode()
             }
+            CommandError::InvalidXReadCount => {
+                RespValue::Error("ERR Invalid XREAD count".to_string()).encode()
+            }
             CommandError::InvalidIncrCommand => {
                 RespValue::Error("ERR Invalid INCR command".to_string()).encode()
             }
@@ -259,15 +590,486 @@ impl CommandError {
             CommandError::InvalidSubscribeCommand => {
                 RespValue::Error("ERR Invalid SUBSCRIBE command".to_string()).encode()
             }
+            CommandError::WrongNumberOfArguments(command_name) => RespValue::Error(format!(
+                "ERR wrong number of arguments for '{}' command",
+                command_name.to_lowercase().replace(' ', "|")
+            ))
+            .encode(),
+            CommandError::CommandNotAllowedInTransaction(command_name) => RespValue::Error(
+                format!("ERR {} is not allowed in transactions", command_name),
+            )
+            .encode(),
             CommandError::InvalidCommandInSubscribedMode(command_name) => {
-                RespValue::Error(format!("ERR Can't execute '{}': only (P|S)SUBSCRIBE / (P|S)UNSUBSCRIBE / PING / QUIT / RESET are allowed in this subscribed mode", command_name)).encode()
+                RespValue::Error(format!("ERR Can't execute '{}': only (P|S)SUBSCRIBE / (P|S)UNSUBSCRIBE / PING / QUIT / RESET are allowed in this context", command_name.to_lowercase())).encode()
             }
             CommandError::InvalidPublishCommand => {
                 RespValue::Error("ERR Invalid PUBLISH command".to_string()).encode()
             }
-            CommandError::IoError => {
-                RespValue::Error("ERR error during IO operation".to_string()).encode()
+            CommandError::InvalidSsubscribeCommand => {
+                RespValue::Error("ERR Invalid SSUBSCRIBE command".to_string()).encode()
+            }
+            CommandError::InvalidSpublishCommand => {
+                RespValue::Error("ERR Invalid SPUBLISH command".to_string()).encode()
+            }
+            CommandError::InvalidHelloCommand => {
+                RespValue::Error("ERR Invalid HELLO command".to_string()).encode()
+            }
+            CommandError::UnsupportedProtocolVersion => {
+                RespValue::Error("NOPROTO unsupported protocol version".to_string()).encode()
+            }
+            CommandError::WrongPassword => RespValue::Error(
+                "WRONGPASS invalid username-password pair or user is disabled.".to_string(),
+            )
+            .encode(),
+            CommandError::InvalidResetCommand => {
+                RespValue::Error("ERR Invalid RESET command".to_string()).encode()
+            }
+            CommandError::InvalidQuitCommand => {
+                RespValue::Error("ERR Invalid QUIT command".to_string()).encode()
+            }
+            CommandError::InvalidDebugCommand => {
+                RespValue::Error("ERR Invalid DEBUG command".to_string()).encode()
+            }
+            CommandError::InvalidDebugCommandArgument => {
+                RespValue::Error("ERR Invalid DEBUG command argument".to_string()).encode()
+            }
+            CommandError::NoSuchKey => RespValue::Error("ERR no such key".to_string()).encode(),
+            CommandError::NotListpackEncoded => RespValue::Error(
+                "ERR The value stored at the specified key is not represented using an listpack"
+                    .to_string(),
+            )
+            .encode(),
+            CommandError::FailoverNotSupported => RespValue::Error(
+                "ERR FAILOVER is not supported, only FAILOVER ABORT is accepted".to_string(),
+            )
+            .encode(),
+            CommandError::InvalidDbsizeCommand => {
+                RespValue::Error("ERR Invalid DBSIZE command".to_string()).encode()
+            }
+            CommandError::InvalidCommandListCommand => {
+                RespValue::Error("ERR Invalid COMMAND LIST command".to_string()).encode()
+            }
+            CommandError::InvalidLolwutCommand => {
+                RespValue::Error("ERR Invalid LOLWUT command".to_string()).encode()
+            }
+            CommandError::InvalidLolwutCommandOption => {
+                RespValue::Error("ERR Invalid LOLWUT command option".to_string()).encode()
+            }
+            CommandError::InvalidLolwutCommandArgument => {
+                RespValue::Error("ERR Invalid LOLWUT command argument".to_string()).encode()
+            }
+            CommandError::InvalidHSetCommand => {
+                RespValue::Error("ERR Invalid HSET command".to_string()).encode()
+            }
+            CommandError::InvalidHGetCommand => {
+                RespValue::Error("ERR Invalid HGET command".to_string()).encode()
+            }
+            CommandError::InvalidHGetAllCommand => {
+                RespValue::Error("ERR Invalid HGETALL command".to_string()).encode()
+            }
+            CommandError::InvalidHMGetCommand => {
+                RespValue::Error("ERR Invalid HMGET command".to_string()).encode()
+            }
+            CommandError::InvalidHSetNxCommand => {
+                RespValue::Error("ERR Invalid HSETNX command".to_string()).encode()
+            }
+            CommandError::InvalidHStrLenCommand => {
+                RespValue::Error("ERR Invalid HSTRLEN command".to_string()).encode()
+            }
+            CommandError::InvalidHRandFieldCommand => {
+                RespValue::Error("ERR Invalid HRANDFIELD command".to_string()).encode()
+            }
+            CommandError::InvalidHRandFieldCount => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidHScanCommand => {
+                RespValue::Error("ERR Invalid HSCAN command".to_string()).encode()
+            }
+            CommandError::InvalidZMScoreCommand => {
+                RespValue::Error("ERR Invalid ZMSCORE command".to_string()).encode()
+            }
+            CommandError::InvalidZRandMemberCommand => {
+                RespValue::Error("ERR Invalid ZRANDMEMBER command".to_string()).encode()
+            }
+            CommandError::InvalidZRandMemberCount => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidZAddCommand => {
+                RespValue::Error("ERR Invalid ZADD command".to_string()).encode()
+            }
+            CommandError::InvalidZAddIncrPairs => {
+                RespValue::Error(
+                    "ERR INCR option supports a single increment-element pair".to_string(),
+                )
+                .encode()
+            }
+            CommandError::IncompatibleZAddNxXxOptions => {
+                RespValue::Error("ERR XX and NX options at the same time are not compatible".to_string()).encode()
+            }
+            CommandError::IncompatibleZAddGtLtNxOptions => {
+                RespValue::Error("ERR GT, LT, and/or NX options at the same time are not compatible".to_string()).encode()
+            }
+            CommandError::InvalidSortedSetScore => {
+                RespValue::Error("ERR value is not a valid float".to_string()).encode()
+            }
+            CommandError::InvalidZScoreCommand => {
+                RespValue::Error("ERR Invalid ZSCORE command".to_string()).encode()
+            }
+            CommandError::InvalidZRangeCommand => {
+                RespValue::Error("ERR Invalid ZRANGE command".to_string()).encode()
+            }
+            CommandError::InvalidZRevRangeCommand => {
+                RespValue::Error("ERR Invalid ZREVRANGE command".to_string()).encode()
+            }
+            CommandError::InvalidZRangeCommandArgument => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidZRankCommand => {
+                RespValue::Error("ERR Invalid ZRANK command".to_string()).encode()
+            }
+            CommandError::InvalidZRevRankCommand => {
+                RespValue::Error("ERR Invalid ZREVRANK command".to_string()).encode()
+            }
+            CommandError::InvalidZCardCommand => {
+                RespValue::Error("ERR Invalid ZCARD command".to_string()).encode()
+            }
+            CommandError::InvalidZRemCommand => {
+                RespValue::Error("ERR Invalid ZREM command".to_string()).encode()
+            }
+            CommandError::InvalidZRemRangeByRankCommand => {
+                RespValue::Error("ERR Invalid ZREMRANGEBYRANK command".to_string()).encode()
+            }
+            CommandError::InvalidZRemRangeByScoreCommand => {
+                RespValue::Error("ERR Invalid ZREMRANGEBYSCORE command".to_string()).encode()
+            }
+            CommandError::InvalidSortedSetScoreRange => {
+                RespValue::Error("ERR min or max is not a float".to_string()).encode()
+            }
+            CommandError::InvalidZIncrByCommand => {
+                RespValue::Error("ERR Invalid ZINCRBY command".to_string()).encode()
+            }
+            CommandError::SortedSetScoreNaN => {
+                RespValue::Error("ERR resulting score is not a number (NaN)".to_string()).encode()
+            }
+            CommandError::InvalidPfAddCommand => {
+                RespValue::Error("ERR Invalid PFADD command".to_string()).encode()
+            }
+            CommandError::InvalidPfCountCommand => {
+                RespValue::Error("ERR Invalid PFCOUNT command".to_string()).encode()
+            }
+            CommandError::InvalidHyperLogLogValue => {
+                RespValue::Error("WRONGTYPE Key is not a valid HyperLogLog string value.".to_string()).encode()
+            }
+            CommandError::InvalidPfMergeCommand => {
+                RespValue::Error("ERR Invalid PFMERGE command".to_string()).encode()
+            }
+            CommandError::InvalidBitfieldCommand => {
+                RespValue::Error("ERR Invalid BITFIELD command".to_string()).encode()
+            }
+            CommandError::InvalidBitfieldType => {
+                RespValue::Error("ERR Invalid bitfield type. Use something like i16 u8. Note that u64 is not supported but i64 is.".to_string()).encode()
+            }
+            CommandError::InvalidBitfieldOffset => {
+                RespValue::Error("ERR bit offset is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidBitfieldValue => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidBitfieldOverflowType => {
+                RespValue::Error("ERR Invalid OVERFLOW type specified".to_string()).encode()
+            }
+            CommandError::InvalidGetSetCommand => {
+                RespValue::Error("ERR Invalid GETSET command".to_string()).encode()
+            }
+            CommandError::InvalidGetDelCommand => {
+                RespValue::Error("ERR Invalid GETDEL command".to_string()).encode()
+            }
+            CommandError::InvalidAppendCommand => {
+                RespValue::Error("ERR Invalid APPEND command".to_string()).encode()
+            }
+            CommandError::InvalidSetRangeCommand => {
+                RespValue::Error("ERR Invalid SETRANGE command".to_string()).encode()
+            }
+            CommandError::InvalidSetRangeOffset => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::SetRangeOffsetOutOfRange => {
+                RespValue::Error("ERR offset is out of range".to_string()).encode()
+            }
+            CommandError::StringExceedsMaximumSize => RespValue::Error(
+                "ERR string exceeds maximum allowed size (proto-max-bulk-len)".to_string(),
+            )
+            .encode(),
+            CommandError::InvalidClientCommand => {
+                RespValue::Error("ERR Invalid CLIENT command".to_string()).encode()
+            }
+            CommandError::InvalidClientCommandArgument => {
+                RespValue::Error("ERR Invalid CLIENT command argument".to_string()).encode()
             }
+            CommandError::InvalidTrackingRedirectClientId => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::TrackingRedirectClientNotFound => RespValue::Error(
+                "ERR The client ID you want redirect to does not exist".to_string(),
+            )
+            .encode(),
+            CommandError::UnsupportedTrackingBroadcastMode => {
+                RespValue::Error("ERR BCAST mode is not supported".to_string()).encode()
+            }
+            CommandError::TrackingPrefixRequiresBroadcastMode => RespValue::Error(
+                "ERR PREFIX option requires BCAST mode to be enabled".to_string(),
+            )
+            .encode(),
+            CommandError::InvalidSAddCommand => {
+                RespValue::Error("ERR Invalid SADD command".to_string()).encode()
+            }
+            CommandError::InvalidSInterCardCommand => {
+                RespValue::Error("ERR Invalid SINTERCARD command".to_string()).encode()
+            }
+            CommandError::InvalidSInterCardNumberOfKeys => {
+                RespValue::Error("ERR numkeys should be greater than 0".to_string()).encode()
+            }
+            CommandError::SInterCardNumberOfKeysMismatch => {
+                RespValue::Error("ERR Number of keys can't be greater than number of args".to_string()).encode()
+            }
+            CommandError::InvalidSInterCardLimit => {
+                RespValue::Error("ERR LIMIT can't be negative".to_string()).encode()
+            }
+            CommandError::InvalidSScanCommand => {
+                RespValue::Error("ERR Invalid SSCAN command".to_string()).encode()
+            }
+            CommandError::InvalidScanCursor => {
+                RespValue::Error("ERR invalid cursor".to_string()).encode()
+            }
+            CommandError::InvalidScanCount => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::ScanSyntaxError => {
+                RespValue::Error("ERR syntax error".to_string()).encode()
+            }
+            CommandError::InvalidSMoveCommand => {
+                RespValue::Error("ERR Invalid SMOVE command".to_string()).encode()
+            }
+            CommandError::InvalidSInterStoreCommand => {
+                RespValue::Error("ERR Invalid SINTERSTORE command".to_string()).encode()
+            }
+            CommandError::InvalidSUnionStoreCommand => {
+                RespValue::Error("ERR Invalid SUNIONSTORE command".to_string()).encode()
+            }
+            CommandError::InvalidSDiffStoreCommand => {
+                RespValue::Error("ERR Invalid SDIFFSTORE command".to_string()).encode()
+            }
+            CommandError::InvalidZPopMinCommand => {
+                RespValue::Error("ERR Invalid ZPOPMIN command".to_string()).encode()
+            }
+            CommandError::InvalidZPopMaxCommand => {
+                RespValue::Error("ERR Invalid ZPOPMAX command".to_string()).encode()
+            }
+            CommandError::InvalidZPopCount => {
+                RespValue::Error("ERR value is out of range, must be positive".to_string()).encode()
+            }
+            CommandError::InvalidZMPopCommand => {
+                RespValue::Error("ERR Invalid ZMPOP command".to_string()).encode()
+            }
+            CommandError::InvalidZMPopNumberOfKeys => {
+                RespValue::Error("ERR numkeys should be greater than 0".to_string()).encode()
+            }
+            CommandError::ZMPopNumberOfKeysMismatch => {
+                RespValue::Error("ERR Number of keys can't be greater than number of args".to_string()).encode()
+            }
+            CommandError::InvalidZMPopCount => {
+                RespValue::Error("ERR count should be greater than 0".to_string()).encode()
+            }
+            CommandError::InvalidBZPopMinCommand => {
+                RespValue::Error("ERR Invalid BZPOPMIN command".to_string()).encode()
+            }
+            CommandError::InvalidBZPopMaxCommand => {
+                RespValue::Error("ERR Invalid BZPOPMAX command".to_string()).encode()
+            }
+            CommandError::InvalidBZPopTimeout => {
+                RespValue::Error("ERR timeout is not a float or out of range".to_string()).encode()
+            }
+            CommandError::InvalidSortCommand => {
+                RespValue::Error("ERR Invalid SORT command".to_string()).encode()
+            }
+            CommandError::SortSyntaxError => {
+                RespValue::Error("ERR syntax error".to_string()).encode()
+            }
+            CommandError::InvalidSortLimit => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::SortScoreNotANumber => {
+                RespValue::Error("ERR One or more scores can't be converted into double".to_string()).encode()
+            }
+            CommandError::InvalidSortRoCommand => {
+                RespValue::Error("ERR Invalid SORT_RO command".to_string()).encode()
+            }
+            CommandError::InvalidHelpCommand => {
+                RespValue::Error("ERR Invalid HELP command".to_string()).encode()
+            }
+            CommandError::InvalidSaveCommand => {
+                RespValue::Error("ERR Invalid SAVE command".to_string()).encode()
+            }
+            CommandError::InvalidBgSaveCommand => {
+                RespValue::Error("ERR Invalid BGSAVE command".to_string()).encode()
+            }
+            CommandError::InvalidLastSaveCommand => {
+                RespValue::Error("ERR Invalid LASTSAVE command".to_string()).encode()
+            }
+            CommandError::BackgroundSaveInProgress => {
+                RespValue::Error("ERR Background save already in progress".to_string()).encode()
+            }
+            CommandError::RdbSaveFailed => {
+                RespValue::Error("ERR Failed to save the RDB file".to_string()).encode()
+            }
+            CommandError::RdbReloadFailed => RespValue::Error(
+                "ERR Error trying to load the RDB dump, check server logs.".to_string(),
+            )
+            .encode(),
+            CommandError::InvalidWaitaofCommand => {
+                RespValue::Error("ERR Invalid WAITAOF command".to_string()).encode()
+            }
+            CommandError::InvalidWaitaofCommandArgument => {
+                RespValue::Error("ERR Invalid WAITAOF command argument".to_string()).encode()
+            }
+            CommandError::InvalidWaitaofCommandForReplica => {
+                RespValue::Error("ERR WAITAOF cannot be used with replica instances. Please also note that writes to replicas are just local and are not propagated.".to_string()).encode()
+            }
+            CommandError::WaitaofAppendOnlyDisabled => {
+                RespValue::Error("ERR WAITAOF cannot be used when numlocal is set but appendonly is disabled.".to_string()).encode()
+            }
+            CommandError::InvalidExpireCommand => {
+                RespValue::Error("ERR Invalid EXPIRE command".to_string()).encode()
+            }
+            CommandError::InvalidPexpireCommand => {
+                RespValue::Error("ERR Invalid PEXPIRE command".to_string()).encode()
+            }
+            CommandError::InvalidExpireTime => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidPexpireatCommand => {
+                RespValue::Error("ERR Invalid PEXPIREAT command".to_string()).encode()
+            }
+            CommandError::InvalidPexpireatTimestamp => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidBgRewriteAofCommand => {
+                RespValue::Error("ERR Invalid BGREWRITEAOF command".to_string()).encode()
+            }
+            CommandError::AofRewriteInProgress => {
+                RespValue::Error("ERR Background append only file rewriting already in progress".to_string()).encode()
+            }
+            CommandError::InvalidGeoAddCommand => {
+                RespValue::Error("ERR Invalid GEOADD command".to_string()).encode()
+            }
+            CommandError::InvalidGeoPosCommand => {
+                RespValue::Error("ERR Invalid GEOPOS command".to_string()).encode()
+            }
+            CommandError::InvalidGeoCoordinates(coordinates) => {
+                RespValue::Error(format!("ERR invalid longitude,latitude pair {}", coordinates))
+                    .encode()
+            }
+            CommandError::InvalidGeoDistCommand => {
+                RespValue::Error("ERR Invalid GEODIST command".to_string()).encode()
+            }
+            CommandError::InvalidGeoSearchCommand => {
+                RespValue::Error("ERR syntax error".to_string()).encode()
+            }
+            CommandError::InvalidGeoSearchRadius => {
+                RespValue::Error("ERR need numeric radius".to_string()).encode()
+            }
+            CommandError::InvalidGeoSearchCount => {
+                RespValue::Error("ERR COUNT must be > 0".to_string()).encode()
+            }
+            CommandError::UnsupportedGeoUnit => {
+                RespValue::Error("ERR unsupported unit provided. please use M, KM, FT, MI".to_string()).encode()
+            }
+            CommandError::GeoMemberNotFound => {
+                RespValue::Error("ERR could not decode requested zset member".to_string()).encode()
+            }
+            CommandError::InvalidSlowlogCommand => {
+                RespValue::Error("ERR Invalid SLOWLOG command".to_string()).encode()
+            }
+            CommandError::InvalidLatencyCommand => {
+                RespValue::Error("ERR Invalid LATENCY command".to_string()).encode()
+            }
+            CommandError::InvalidMemoryCommand => {
+                RespValue::Error("ERR Invalid MEMORY command".to_string()).encode()
+            }
+            CommandError::InvalidMemoryUsageSamples => {
+                RespValue::Error("ERR value is not an integer or out of range".to_string()).encode()
+            }
+            CommandError::InvalidSlowlogCount => {
+                RespValue::Error("ERR count should be greater than or equal to -1".to_string())
+                    .encode()
+            }
+        }
+    }
+}
+
+// Like Redis, quotes the arguments until about 128 characters were echoed, cutting off
+// the last argument to fit
+const UNKNOWN_COMMAND_ARGUMENTS_MAX_LENGTH: usize = 128;
+
+fn format_leading_arguments(arguments: &[String]) -> String {
+    let mut formatted_arguments = String::new();
+
+    for argument in arguments {
+        let remaining_length =
+            UNKNOWN_COMMAND_ARGUMENTS_MAX_LENGTH.saturating_sub(formatted_arguments.len());
+
+        if remaining_length == 0 {
+            break;
+        }
+
+        let argument = argument
+            .char_indices()
+            .nth(remaining_length)
+            .map_or(argument.as_str(), |(index, _)| &argument[..index]);
+
+        formatted_arguments.push_str(&format!("'{}' ", argument));
+    }
+
+    formatted_arguments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandError;
+
+    #[test]
+    fn test_unknown_command_error() {
+        let test_cases = vec![
+            (
+                vec![],
+                "-ERR unknown command 'foobar', with args beginning with: \r\n".to_string(),
+            ),
+            (
+                vec!["a".to_string(), "b".to_string()],
+                "-ERR unknown command 'foobar', with args beginning with: 'a' 'b' \r\n".to_string(),
+            ),
+            (
+                vec!["a".repeat(200), "b".to_string()],
+                format!(
+                    "-ERR unknown command 'foobar', with args beginning with: '{}' \r\n",
+                    "a".repeat(128)
+                ),
+            ),
+            (
+                vec!["a".repeat(120), "b".repeat(20)],
+                format!(
+                    "-ERR unknown command 'foobar', with args beginning with: '{}' '{}' \r\n",
+                    "a".repeat(120),
+                    "b".repeat(5)
+                ),
+            ),
+        ];
+
+        for (arguments, expected) in test_cases {
+            assert_eq!(
+                CommandError::UnknownCommand("foobar".to_string(), arguments).as_string(),
+                expected
+            );
         }
     }
 }
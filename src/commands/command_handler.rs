@@ -1,46 +1,270 @@
-use std::sync::Arc;
-
-use tokio::{
-    net::tcp::OwnedWriteHalf,
-    sync::{Mutex, RwLock},
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
+use tokio::sync::{Mutex, RwLock, mpsc};
+
 use crate::{
+    aof::RewriteGuard,
     commands::{
+        append::{AppendArguments, append},
+        arity::validate_command_arity,
+        bitfield::{BitfieldArguments, bitfield},
         blpop::{BlpopArguments, blpop},
+        client::{
+            ClientInfoArguments, ClientNoEvictArguments, ClientNoTouchArguments,
+            ClientTrackingArguments, client_info, client_no_evict, client_no_touch,
+            client_tracking, read_keys, written_keys,
+        },
         command_error::CommandError,
+        command_list::{CommandListArguments, command_list},
         config_get::{ConfigGetArguments, config_get},
+        dbsize::{DbsizeArguments, dbsize},
+        debug::{
+            DebugChangeReplIdArguments, DebugListpackArguments, DebugObjectArguments,
+            DebugReloadArguments, DebugSetActiveExpireArguments, DebugSetStreamMaxEntriesArguments,
+            DebugSleepArguments, DebugStreamArguments, DebugStringmatchLenArguments,
+            debug_change_repl_id, debug_listpack, debug_listpack_entries, debug_object,
+            debug_reload, debug_set_active_expire, debug_set_stream_max_entries, debug_sleep,
+            debug_stream, debug_stringmatch_len,
+        },
         echo::{EchoArguments, echo},
+        expire::{ExpireArguments, expire, pexpire},
         get::{GetArguments, get},
+        getdel::{GetdelArguments, getdel},
+        getset::{GetsetArguments, getset},
+        hash::{
+            HgetArguments, HgetallArguments, HmgetArguments, HrandfieldArguments, HscanArguments,
+            HsetArguments, HsetnxArguments, HstrlenArguments, hget, hgetall, hmget, hrandfield,
+            hscan, hset, hsetnx, hstrlen,
+        },
+        hello::{HelloArguments, hello},
+        help::{HelpArguments, help},
+        hyperloglog::{
+            PfaddArguments, PfcountArguments, PfmergeArguments, pfadd, pfcount, pfmerge,
+        },
         incr::{IncrArguments, incr},
         info::{InfoArguments, info},
+        is_value_expired,
         keys::{KeysArguments, keys},
+        latency::{
+            LatencyHistoryArguments, LatencyLatestArguments, LatencyResetArguments,
+            latency_history, latency_latest, latency_reset,
+        },
         llen::{LlenArguments, llen},
+        lmpop::{LmpopArguments, lmpop},
+        lolwut::{LolwutArguments, lolwut},
         lpop::{LpopArguments, lpop},
         lrange::{LrangeArguments, lrange},
+        memory::{
+            MemoryDoctorArguments, MemoryStatsArguments, MemoryUsageArguments, memory_doctor,
+            memory_stats, memory_usage,
+        },
+        object::{
+            ObjectEncodingArguments, ObjectIdletimeArguments, object_encoding, object_idletime,
+        },
+        persistence::{
+            BgrewriteaofArguments, BgsaveArguments, LastsaveArguments, SaveArguments, bgrewriteaof,
+            bgsave, lastsave, save,
+        },
+        pexpireat::{PexpireatArguments, pexpireat},
         ping::{PingArguments, ping},
-        pub_sub::{publish, subscribe, subscribe_ping},
-        replication::{PsyncArguments, ReplconfArguments, WaitArguments, psync, replconf, wait},
+        pub_sub::{publish, spublish, ssubscribe, subscribe, subscribe_ping, sunsubscribe},
+        quit::{QuitArguments, quit},
+        remove_expired_key,
+        replication::{
+            FailoverArguments, PsyncArguments, ReplconfArguments, WaitArguments, WaitaofArguments,
+            failover, psync, replconf, wait, waitaof,
+        },
+        reset::{ResetArguments, reset},
         rpush_and_lpush::{PushArrayOperations, lpush, rpush},
         set::{SetArguments, set},
+        setrange::{SetrangeArguments, setrange},
+        sets::{
+            SaddArguments, SetOperation, SetStoreArguments, SintercardArguments, SmoveArguments,
+            SscanArguments, sadd, sdiffstore, sintercard, sinterstore, smove, sscan, sunionstore,
+        },
+        slowlog::{
+            SlowlogGetArguments, SlowlogLenArguments, SlowlogResetArguments, slowlog_get,
+            slowlog_len, slowlog_reset,
+        },
+        sort::{SortArguments, sort, sort_ro},
+        sorted_set::{
+            BzpopArguments, GeoaddArguments, GeodistArguments, GeoposArguments, GeosearchArguments,
+            ZaddArguments, ZcardArguments, ZincrbyArguments, ZmpopArguments, ZmscoreArguments,
+            ZpopArguments, ZrandmemberArguments, ZrangeArguments, ZrankArguments, ZremArguments,
+            ZremrangebyrankArguments, ZremrangebyscoreArguments, ZscoreArguments, bzpopmax,
+            bzpopmin, geoadd, geodist, geopos, geosearch, zadd, zcard, zincrby, zmpop, zmscore,
+            zpopmax, zpopmin, zrandmember, zrange, zrank, zrem, zremrangebyrank, zremrangebyscore,
+            zrevrange, zrevrank, zscore,
+        },
         transactions::{DiscardArguments, ExecArguments, MultiArguments, discard, exec, multi},
         type_command::{TypeArguments, type_command},
         xadd::{XaddArguments, xadd},
+        xdel::{XdelArguments, xdel},
+        xlen::{XlenArguments, xlen},
         xrange::{XrangeArguments, xrange},
         xread::{XreadArguments, xread},
+        xsetid::{XsetidArguments, xsetid},
+        xtrim::{XtrimArguments, xtrim},
     },
     key_value_store::KeyValueStore,
+    latency::LatencyMonitor,
     resp::RespValue,
     server::RedisServer,
+    slowlog::SlowLog,
     state::State,
 };
 
+// Time spent waiting for data is not execution time, so these are left out of the slow
+// log and the latency monitor
+const BLOCKING_COMMANDS: [&str; 6] = ["BLPOP", "BZPOPMIN", "BZPOPMAX", "XREAD", "WAIT", "WAITAOF"];
+
+// (Un)subscribing switches the connection in or out of subscribed mode, which cannot
+// be deferred until EXEC
+const SUBSCRIBE_COMMANDS: [&str; 6] = [
+    "SUBSCRIBE",
+    "UNSUBSCRIBE",
+    "PSUBSCRIBE",
+    "PUNSUBSCRIBE",
+    "SSUBSCRIBE",
+    "SUNSUBSCRIBE",
+];
+
+/// Every top-level command the handler dispatches, with the subcommands of the commands
+/// that group several. Both the parsing of a command's name and COMMAND LIST read it.
+pub const COMMAND_TABLE: &[(&str, &[&str])] = &[
+    ("APPEND", &[]),
+    ("BGREWRITEAOF", &[]),
+    ("BGSAVE", &[]),
+    ("BITFIELD", &[]),
+    ("BLPOP", &[]),
+    ("BZPOPMAX", &[]),
+    ("BZPOPMIN", &[]),
+    (
+        "CLIENT",
+        &["INFO", "NO-EVICT", "NO-TOUCH", "TRACKING", "HELP"],
+    ),
+    ("COMMAND", &["LIST"]),
+    ("CONFIG", &["GET", "HELP"]),
+    ("DBSIZE", &[]),
+    (
+        "DEBUG",
+        &[
+            "SET-ACTIVE-EXPIRE",
+            "SET-STREAM-MAX-ENTRIES",
+            "CHANGE-REPL-ID",
+            "LISTPACK",
+            "LISTPACK-ENTRIES",
+            "OBJECT",
+            "RELOAD",
+            "STREAM",
+            "STRINGMATCH-LEN",
+            "SLEEP",
+            "DEBUG",
+            "HELP",
+        ],
+    ),
+    ("DISCARD", &[]),
+    ("ECHO", &[]),
+    ("EXEC", &[]),
+    ("EXPIRE", &[]),
+    ("FAILOVER", &[]),
+    ("GEOADD", &[]),
+    ("GEODIST", &[]),
+    ("GEOPOS", &[]),
+    ("GEOSEARCH", &[]),
+    ("GET", &[]),
+    ("GETDEL", &[]),
+    ("GETSET", &[]),
+    ("HELLO", &[]),
+    ("HGET", &[]),
+    ("HGETALL", &[]),
+    ("HMGET", &[]),
+    ("HRANDFIELD", &[]),
+    ("HSCAN", &[]),
+    ("HSET", &[]),
+    ("HSETNX", &[]),
+    ("HSTRLEN", &[]),
+    ("INCR", &[]),
+    ("INFO", &[]),
+    ("KEYS", &[]),
+    ("LASTSAVE", &[]),
+    ("LATENCY", &["LATEST", "HISTORY", "RESET", "HELP"]),
+    ("LLEN", &[]),
+    ("LMPOP", &[]),
+    ("LOLWUT", &[]),
+    ("LPOP", &[]),
+    ("LPUSH", &[]),
+    ("LRANGE", &[]),
+    ("MEMORY", &["USAGE", "DOCTOR", "STATS", "HELP"]),
+    ("MULTI", &[]),
+    ("OBJECT", &["ENCODING", "IDLETIME", "HELP"]),
+    ("PEXPIRE", &[]),
+    ("PEXPIREAT", &[]),
+    ("PFADD", &[]),
+    ("PFCOUNT", &[]),
+    ("PFMERGE", &[]),
+    ("PING", &[]),
+    ("PSYNC", &[]),
+    ("PUBLISH", &[]),
+    ("QUIT", &[]),
+    ("REPLCONF", &[]),
+    ("RESET", &[]),
+    ("RPUSH", &[]),
+    ("SADD", &[]),
+    ("SAVE", &[]),
+    ("SDIFFSTORE", &[]),
+    ("SET", &[]),
+    ("SETRANGE", &[]),
+    ("SINTERCARD", &[]),
+    ("SINTERSTORE", &[]),
+    ("SLOWLOG", &["GET", "LEN", "RESET", "HELP"]),
+    ("SMOVE", &[]),
+    ("SORT", &[]),
+    ("SORT_RO", &[]),
+    ("SPUBLISH", &[]),
+    ("SSCAN", &[]),
+    ("SSUBSCRIBE", &[]),
+    ("SUBSCRIBE", &[]),
+    ("SUNIONSTORE", &[]),
+    ("SUNSUBSCRIBE", &[]),
+    ("TYPE", &[]),
+    ("WAIT", &[]),
+    ("WAITAOF", &[]),
+    ("XADD", &[]),
+    ("XDEL", &[]),
+    ("XLEN", &[]),
+    ("XRANGE", &[]),
+    ("XREAD", &[]),
+    ("XSETID", &[]),
+    ("XTRIM", &[]),
+    ("ZADD", &[]),
+    ("ZCARD", &[]),
+    ("ZINCRBY", &[]),
+    ("ZMPOP", &[]),
+    ("ZMSCORE", &[]),
+    ("ZPOPMAX", &[]),
+    ("ZPOPMIN", &[]),
+    ("ZRANDMEMBER", &[]),
+    ("ZRANGE", &[]),
+    ("ZRANK", &[]),
+    ("ZREM", &[]),
+    ("ZREMRANGEBYRANK", &[]),
+    ("ZREMRANGEBYSCORE", &[]),
+    ("ZREVRANGE", &[]),
+    ("ZREVRANK", &[]),
+    ("ZSCORE", &[]),
+];
+
 #[derive(Debug)]
 pub enum CommandResult {
     NoResponse,
     Response(String),
     Sync(String),
     Batch(Vec<CommandHandler>),
+    Quit(String),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -61,20 +285,25 @@ impl CommandHandler {
             _ => return Err(CommandError::InvalidCommandArgument),
         };
 
-        let (name, rest_of_data) = match name.as_str() {
-            "CONFIG" => {
-                let sub_command = match elements.get(1) {
-                    Some(RespValue::BulkString(s)) => s.to_uppercase(),
-                    _ => return Err(CommandError::InvalidCommandArgument),
-                };
+        let subcommands = COMMAND_TABLE
+            .iter()
+            .find(|(command, _)| *command == name)
+            .map(|(_, subcommands)| *subcommands)
+            .unwrap_or_default();
 
-                if sub_command == "GET" {
-                    ("CONFIG GET".to_string(), elements[2..].to_vec())
-                } else {
-                    return Err(CommandError::InvalidCommandArgument);
-                }
+        let (name, rest_of_data) = if subcommands.is_empty() {
+            (name, elements[1..].to_vec())
+        } else {
+            let sub_command = match elements.get(1) {
+                Some(RespValue::BulkString(s)) => s.to_uppercase(),
+                _ => return Err(CommandError::InvalidCommandArgument),
+            };
+
+            if !subcommands.contains(&sub_command.as_str()) {
+                return Err(CommandError::InvalidCommandArgument);
             }
-            _ => (name, elements[1..].to_vec()),
+
+            (format!("{} {}", name, sub_command), elements[2..].to_vec())
         };
 
         let mut arguments: Vec<String> = Vec::new();
@@ -88,6 +317,8 @@ impl CommandHandler {
             arguments.push(arg);
         }
 
+        validate_command_arity(&name, arguments.len())?;
+
         Ok(Self {
             name,
             arguments,
@@ -106,11 +337,18 @@ impl CommandHandler {
             "LRANGE" => LrangeArguments::parse(self.arguments.clone()).err(),
             "LLEN" => LlenArguments::parse(self.arguments.clone()).err(),
             "LPOP" => LpopArguments::parse(self.arguments.clone()).err(),
+            "LMPOP" => LmpopArguments::parse(self.arguments.clone()).err(),
             "BLPOP" => BlpopArguments::parse(self.arguments.clone()).err(),
             "TYPE" => TypeArguments::parse(self.arguments.clone()).err(),
+            "OBJECT ENCODING" => ObjectEncodingArguments::parse(self.arguments.clone()).err(),
+            "OBJECT IDLETIME" => ObjectIdletimeArguments::parse(self.arguments.clone()).err(),
             "XADD" => XaddArguments::parse(self.arguments.clone()).err(),
             "XRANGE" => XrangeArguments::parse(self.arguments.clone()).err(),
             "XREAD" => XreadArguments::parse(self.arguments.clone()).err(),
+            "XSETID" => XsetidArguments::parse(self.arguments.clone()).err(),
+            "XLEN" => XlenArguments::parse(self.arguments.clone()).err(),
+            "XDEL" => XdelArguments::parse(self.arguments.clone()).err(),
+            "XTRIM" => XtrimArguments::parse(self.arguments.clone()).err(),
             "INCR" => IncrArguments::parse(self.arguments.clone()).err(),
             "MULTI" => MultiArguments::parse(self.arguments.clone()).err(),
             "EXEC" => ExecArguments::parse(self.arguments.clone()).err(),
@@ -121,7 +359,159 @@ impl CommandHandler {
             "WAIT" => WaitArguments::parse(self.arguments.clone()).err(),
             "CONFIG GET" => ConfigGetArguments::parse(self.arguments.clone()).err(),
             "KEYS" => KeysArguments::parse(self.arguments.clone()).err(),
-            _ => Some(CommandError::InvalidCommand),
+            "HELLO" => HelloArguments::parse(self.arguments.clone()).err(),
+            "RESET" => ResetArguments::parse(self.arguments.clone()).err(),
+            "QUIT" => QuitArguments::parse(self.arguments.clone()).err(),
+            "DEBUG SET-ACTIVE-EXPIRE" => {
+                DebugSetActiveExpireArguments::parse(self.arguments.clone()).err()
+            }
+            "DEBUG SET-STREAM-MAX-ENTRIES" => {
+                DebugSetStreamMaxEntriesArguments::parse(self.arguments.clone()).err()
+            }
+            "DEBUG CHANGE-REPL-ID" => {
+                DebugChangeReplIdArguments::parse(self.arguments.clone()).err()
+            }
+            "DEBUG LISTPACK" => DebugListpackArguments::parse(self.arguments.clone()).err(),
+            "DEBUG OBJECT" => DebugObjectArguments::parse(self.arguments.clone()).err(),
+            "DEBUG RELOAD" => DebugReloadArguments::parse(self.arguments.clone()).err(),
+            "DEBUG STREAM" => DebugStreamArguments::parse(self.arguments.clone()).err(),
+            "DEBUG STRINGMATCH-LEN" => {
+                DebugStringmatchLenArguments::parse(self.arguments.clone()).err()
+            }
+            "DEBUG SLEEP" | "DEBUG DEBUG" => {
+                DebugSleepArguments::parse(self.arguments.clone()).err()
+            }
+            "FAILOVER" => FailoverArguments::parse(self.arguments.clone()).err(),
+            "DBSIZE" => DbsizeArguments::parse(self.arguments.clone()).err(),
+            "LOLWUT" => LolwutArguments::parse(self.arguments.clone()).err(),
+            "COMMAND LIST" => CommandListArguments::parse(self.arguments.clone()).err(),
+            "HSET" => HsetArguments::parse(self.arguments.clone()).err(),
+            "HGET" => HgetArguments::parse(self.arguments.clone()).err(),
+            "HGETALL" => HgetallArguments::parse(self.arguments.clone()).err(),
+            "HMGET" => HmgetArguments::parse(self.arguments.clone()).err(),
+            "HSETNX" => HsetnxArguments::parse(self.arguments.clone()).err(),
+            "HSTRLEN" => HstrlenArguments::parse(self.arguments.clone()).err(),
+            "HRANDFIELD" => HrandfieldArguments::parse(self.arguments.clone()).err(),
+            "HSCAN" => HscanArguments::parse(self.arguments.clone()).err(),
+            "ZADD" => ZaddArguments::parse(self.arguments.clone()).err(),
+            "ZSCORE" => ZscoreArguments::parse(self.arguments.clone()).err(),
+            "ZMSCORE" => ZmscoreArguments::parse(self.arguments.clone()).err(),
+            "ZRANDMEMBER" => ZrandmemberArguments::parse(self.arguments.clone()).err(),
+            "ZRANGE" => ZrangeArguments::parse(self.arguments.clone(), false).err(),
+            "ZREVRANGE" => ZrangeArguments::parse(self.arguments.clone(), true).err(),
+            "ZRANK" => ZrankArguments::parse(self.arguments.clone(), false).err(),
+            "ZREVRANK" => ZrankArguments::parse(self.arguments.clone(), true).err(),
+            "ZCARD" => ZcardArguments::parse(self.arguments.clone()).err(),
+            "ZINCRBY" => ZincrbyArguments::parse(self.arguments.clone()).err(),
+            "PFADD" => PfaddArguments::parse(self.arguments.clone()).err(),
+            "PFCOUNT" => PfcountArguments::parse(self.arguments.clone()).err(),
+            "PFMERGE" => PfmergeArguments::parse(self.arguments.clone()).err(),
+            "BITFIELD" => BitfieldArguments::parse(self.arguments.clone()).err(),
+            "GETSET" => GetsetArguments::parse(self.arguments.clone()).err(),
+            "GETDEL" => GetdelArguments::parse(self.arguments.clone()).err(),
+            "APPEND" => AppendArguments::parse(self.arguments.clone()).err(),
+            "SETRANGE" => SetrangeArguments::parse(self.arguments.clone()).err(),
+            "CLIENT INFO" => ClientInfoArguments::parse(self.arguments.clone()).err(),
+            "CLIENT NO-EVICT" => ClientNoEvictArguments::parse(self.arguments.clone()).err(),
+            "CLIENT TRACKING" => ClientTrackingArguments::parse(self.arguments.clone()).err(),
+            "CLIENT NO-TOUCH" => ClientNoTouchArguments::parse(self.arguments.clone()).err(),
+            "SLOWLOG GET" => SlowlogGetArguments::parse(self.arguments.clone()).err(),
+            "SLOWLOG LEN" => SlowlogLenArguments::parse(self.arguments.clone()).err(),
+            "SLOWLOG RESET" => SlowlogResetArguments::parse(self.arguments.clone()).err(),
+            "LATENCY LATEST" => LatencyLatestArguments::parse(self.arguments.clone()).err(),
+            "LATENCY HISTORY" => LatencyHistoryArguments::parse(self.arguments.clone()).err(),
+            "LATENCY RESET" => LatencyResetArguments::parse(self.arguments.clone()).err(),
+            "MEMORY USAGE" => MemoryUsageArguments::parse(self.arguments.clone()).err(),
+            "MEMORY DOCTOR" => MemoryDoctorArguments::parse(self.arguments.clone()).err(),
+            "MEMORY STATS" => MemoryStatsArguments::parse(self.arguments.clone()).err(),
+            "SADD" => SaddArguments::parse(self.arguments.clone()).err(),
+            "SINTERCARD" => SintercardArguments::parse(self.arguments.clone()).err(),
+            "SSCAN" => SscanArguments::parse(self.arguments.clone()).err(),
+            "SMOVE" => SmoveArguments::parse(self.arguments.clone()).err(),
+            "SINTERSTORE" => {
+                SetStoreArguments::parse(self.arguments.clone(), SetOperation::Intersection).err()
+            }
+            "SUNIONSTORE" => {
+                SetStoreArguments::parse(self.arguments.clone(), SetOperation::Union).err()
+            }
+            "SDIFFSTORE" => {
+                SetStoreArguments::parse(self.arguments.clone(), SetOperation::Difference).err()
+            }
+            "ZPOPMIN" => ZpopArguments::parse(self.arguments.clone(), false).err(),
+            "ZMPOP" => ZmpopArguments::parse(self.arguments.clone()).err(),
+            "ZPOPMAX" => ZpopArguments::parse(self.arguments.clone(), true).err(),
+            "ZREM" => ZremArguments::parse(self.arguments.clone()).err(),
+            "ZREMRANGEBYRANK" => ZremrangebyrankArguments::parse(self.arguments.clone()).err(),
+            "ZREMRANGEBYSCORE" => ZremrangebyscoreArguments::parse(self.arguments.clone()).err(),
+            "BZPOPMIN" => BzpopArguments::parse(self.arguments.clone(), false).err(),
+            "BZPOPMAX" => BzpopArguments::parse(self.arguments.clone(), true).err(),
+            "SORT" => SortArguments::parse(self.arguments.clone(), false).err(),
+            "SORT_RO" => SortArguments::parse(self.arguments.clone(), true).err(),
+            "CONFIG HELP" | "CLIENT HELP" | "DEBUG HELP" | "OBJECT HELP" | "SLOWLOG HELP"
+            | "LATENCY HELP" | "MEMORY HELP" => HelpArguments::parse(self.arguments.clone()).err(),
+            "SAVE" => SaveArguments::parse(self.arguments.clone()).err(),
+            "BGSAVE" => BgsaveArguments::parse(self.arguments.clone()).err(),
+            "LASTSAVE" => LastsaveArguments::parse(self.arguments.clone()).err(),
+            "WAITAOF" => WaitaofArguments::parse(self.arguments.clone()).err(),
+            "EXPIRE" => ExpireArguments::parse(self.arguments.clone(), false).err(),
+            "PEXPIRE" => ExpireArguments::parse(self.arguments.clone(), true).err(),
+            "PEXPIREAT" => PexpireatArguments::parse(self.arguments.clone()).err(),
+            "BGREWRITEAOF" => BgrewriteaofArguments::parse(self.arguments.clone()).err(),
+            "GEOADD" => GeoaddArguments::parse(self.arguments.clone()).err(),
+            "GEOPOS" => GeoposArguments::parse(self.arguments.clone()).err(),
+            "GEODIST" => GeodistArguments::parse(self.arguments.clone()).err(),
+            "GEOSEARCH" => GeosearchArguments::parse(self.arguments.clone()).err(),
+            _ => Some(self.unknown_command_error()),
+        }
+    }
+
+    /// Echoes the command name as it was sent, along with its arguments, like Redis does
+    /// for commands it does not know
+    fn unknown_command_error(&self) -> CommandError {
+        let name = match &self.input {
+            RespValue::Array(elements) => match elements.first() {
+                Some(RespValue::BulkString(name)) => name.clone(),
+                _ => self.name.clone(),
+            },
+            _ => self.name.clone(),
+        };
+
+        CommandError::UnknownCommand(name, self.arguments.clone())
+    }
+
+    /// Turns commands with a relative expiration into their absolute counterpart, so that the
+    /// master, its replicas and the append-only file all expire the key at the same moment no
+    /// matter how late the command reaches them. Other commands, and commands with invalid
+    /// arguments, are returned unchanged.
+    pub fn with_absolute_expiration(&self) -> Self {
+        let rewritten_command = match self.name.as_str() {
+            "EXPIRE" => ExpireArguments::parse(self.arguments.clone(), false)
+                .ok()
+                .map(|arguments| ("PEXPIREAT", arguments.to_pexpireat_arguments())),
+            "PEXPIRE" => ExpireArguments::parse(self.arguments.clone(), true)
+                .ok()
+                .map(|arguments| ("PEXPIREAT", arguments.to_pexpireat_arguments())),
+            "SET" if self.arguments.len() > 2 => SetArguments::parse(self.arguments.clone())
+                .ok()
+                .map(|arguments| ("SET", arguments.to_absolute_expiration_arguments())),
+            _ => None,
+        };
+
+        let Some((name, arguments)) = rewritten_command else {
+            return self.clone();
+        };
+
+        let input = RespValue::Array(
+            std::iter::once(name.to_string())
+                .chain(arguments.iter().cloned())
+                .map(RespValue::BulkString)
+                .collect(),
+        );
+
+        Self {
+            name: name.to_string(),
+            arguments,
+            input,
         }
     }
 
@@ -130,7 +520,7 @@ impl CommandHandler {
         client_address: &str,
         state: Arc<Mutex<State>>,
     ) -> Result<Option<String>, CommandError> {
-        let transaction_commands = Vec::from(["MULTI", "EXEC", "DISCARD"]);
+        let transaction_commands = Vec::from(["MULTI", "EXEC", "DISCARD", "RESET", "QUIT"]);
 
         if transaction_commands.contains(&self.name.as_str()) {
             return Ok(None);
@@ -142,6 +532,12 @@ impl CommandHandler {
             return Ok(None);
         };
 
+        if SUBSCRIBE_COMMANDS.contains(&self.name.as_str()) {
+            return Err(CommandError::CommandNotAllowedInTransaction(
+                self.name.clone(),
+            ));
+        }
+
         match self.validate_command_arguments() {
             Some(err) => return Err(err),
             None => {
@@ -155,19 +551,27 @@ impl CommandHandler {
     pub async fn handle_pub_sub_commands(
         &self,
         client_address: &str,
-        writer: Arc<RwLock<OwnedWriteHalf>>,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
         server: Arc<RwLock<RedisServer>>,
+        state: Arc<Mutex<State>>,
     ) -> Result<Option<CommandResult>, CommandError> {
+        // Subscribe commands run here, ahead of the transaction queue that rejects them
+        if SUBSCRIBE_COMMANDS.contains(&self.name.as_str()) {
+            self.queue_command_if_in_transaction(client_address, state)
+                .await?;
+        }
+
         let is_in_subscribed_mode = is_in_subscribe_mode(client_address, Arc::clone(&server)).await;
+        let is_resp3_client = is_resp3_client(client_address, Arc::clone(&server)).await;
 
         match self.name.as_str() {
             "SUBSCRIBE" => {
                 let command_result =
-                    subscribe(client_address, writer, server, self.arguments.clone()).await?;
+                    subscribe(client_address, sender, server, self.arguments.clone()).await?;
                 Ok(Some(command_result))
             }
             "PING" => {
-                if is_in_subscribed_mode {
+                if is_in_subscribed_mode && !is_resp3_client {
                     let command_result = subscribe_ping(self.arguments.clone())?;
                     Ok(Some(command_result))
                 } else {
@@ -178,6 +582,26 @@ impl CommandHandler {
                 let command_result = publish(server, self.arguments.clone()).await?;
                 Ok(Some(command_result))
             }
+            "SSUBSCRIBE" => {
+                let command_result =
+                    ssubscribe(client_address, sender, server, self.arguments.clone()).await?;
+                Ok(Some(command_result))
+            }
+            "SUNSUBSCRIBE" => {
+                let command_result =
+                    sunsubscribe(client_address, server, self.arguments.clone()).await;
+                Ok(Some(command_result))
+            }
+            "SPUBLISH" => {
+                let command_result = spublish(server, self.arguments.clone()).await?;
+                Ok(Some(command_result))
+            }
+            // Invalidation messages are pushed through the connection's outbound queue
+            "CLIENT TRACKING" => {
+                let command_result =
+                    client_tracking(client_address, sender, server, self.arguments.clone()).await?;
+                Ok(Some(command_result))
+            }
             _ => Ok(None),
         }
     }
@@ -195,9 +619,15 @@ impl CommandHandler {
             "SSUBSCRIBE",
             "SUNSUBSCRIBE",
             "PING",
-            "RESET",
             "QUIT",
+            "RESET",
         ]);
+
+        // RESP3 clients can interleave regular commands with pub/sub messages
+        if is_resp3_client(client_address, Arc::clone(&server)).await {
+            return Ok(());
+        }
+
         let is_in_subscribed_mode = is_in_subscribe_mode(client_address, server).await;
 
         match is_in_subscribed_mode {
@@ -220,17 +650,121 @@ impl CommandHandler {
         server: Arc<RwLock<RedisServer>>,
         store: Arc<Mutex<KeyValueStore>>,
         state: Arc<Mutex<State>>,
+        advances_replication_offset: bool,
+        rewrite_guard: &mut RewriteGuard,
+    ) -> Result<CommandResult, CommandError> {
+        self.remove_expired_keys(Arc::clone(&server), Arc::clone(&store))
+            .await;
+
+        let command_result = self
+            .execute_command(
+                client_address,
+                Arc::clone(&server),
+                Arc::clone(&store),
+                state,
+                advances_replication_offset,
+                rewrite_guard,
+            )
+            .await;
+
+        self.update_accessed_keys(client_address, server, store)
+            .await;
+
+        command_result
+    }
+
+    /// Keys the command looks up, together with the ones it writes to if it is a write command
+    async fn accessed_keys(&self, server: &Arc<RwLock<RedisServer>>) -> Vec<String> {
+        let mut keys = read_keys(&self.name, &self.arguments);
+        if server
+            .read()
+            .await
+            .write_commands
+            .contains(&self.name.as_str())
+        {
+            keys.extend(written_keys(&self.name, &self.arguments));
+        }
+
+        keys
+    }
+
+    /// Expires the keys the command accesses before it runs, so that no command reads,
+    /// adds to or keeps the expiration of a value that has expired
+    async fn remove_expired_keys(
+        &self,
+        server: Arc<RwLock<RedisServer>>,
+        store: Arc<Mutex<KeyValueStore>>,
+    ) {
+        let keys = self.accessed_keys(&server).await;
+
+        if keys.is_empty() {
+            return;
+        }
+
+        let number_of_expired_keys = {
+            let mut store_guard = store.lock().await;
+            keys.iter()
+                .filter(|key| remove_expired_key(&mut store_guard, key))
+                .count()
+        };
+
+        server
+            .read()
+            .await
+            .stats
+            .record_expired_keys(number_of_expired_keys);
+    }
+
+    /// Records that the command accessed the keys it read or wrote, and drops what the
+    /// server remembers about the ones it deleted, emptied or found expired, so a key
+    /// created again under the same name starts afresh
+    async fn update_accessed_keys(
+        &self,
+        client_address: &str,
+        server: Arc<RwLock<RedisServer>>,
+        store: Arc<Mutex<KeyValueStore>>,
+    ) {
+        let keys = self.accessed_keys(&server).await;
+
+        if keys.is_empty() {
+            return;
+        }
+
+        let (existing_keys, removed_keys): (Vec<String>, Vec<String>) = {
+            let store_guard = store.lock().await;
+            keys.into_iter().partition(|key| {
+                store_guard
+                    .get(key)
+                    .is_some_and(|value| !is_value_expired(value))
+            })
+        };
+
+        let mut server_guard = server.write().await;
+        for key in removed_keys {
+            server_guard.forget_key(&key);
+        }
+        for key in existing_keys {
+            server_guard.touch_key(client_address, &key);
+        }
+    }
+
+    async fn execute_command(
+        &self,
+        client_address: &str,
+        server: Arc<RwLock<RedisServer>>,
+        store: Arc<Mutex<KeyValueStore>>,
+        state: Arc<Mutex<State>>,
+        advances_replication_offset: bool,
+        rewrite_guard: &mut RewriteGuard,
     ) -> Result<CommandResult, CommandError> {
         match self.name.as_str() {
             "PING" => ping(self.arguments.clone()),
             "ECHO" => echo(self.arguments.clone()),
-            "GET" => get(store, self.arguments.clone()).await,
+            "GET" => get(server, store, self.arguments.clone()).await,
             "SET" => {
-                match set(store, self.arguments.clone()).await {
+                match set(Arc::clone(&server), store, self.arguments.clone()).await {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -239,11 +773,9 @@ impl CommandHandler {
                 };
             }
             "RPUSH" => {
-                match rpush(store, state, self.arguments.clone()).await {
+                match rpush(Arc::clone(&server), store, state, self.arguments.clone()).await {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -252,11 +784,9 @@ impl CommandHandler {
                 };
             }
             "LPUSH" => {
-                match lpush(store, state, self.arguments.clone()).await {
+                match lpush(Arc::clone(&server), store, state, self.arguments.clone()).await {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -269,9 +799,7 @@ impl CommandHandler {
             "LPOP" => {
                 match lpop(store, self.arguments.clone()).await {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -279,12 +807,27 @@ impl CommandHandler {
                     Err(err) => return Err(err),
                 };
             }
+            "LMPOP" => match lmpop(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
             "BLPOP" => {
-                match blpop(client_address, store, state, self.arguments.clone()).await {
+                match blpop(
+                    client_address,
+                    store,
+                    state,
+                    self.arguments.clone(),
+                    rewrite_guard,
+                )
+                .await
+                {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -293,12 +836,16 @@ impl CommandHandler {
                 };
             }
             "TYPE" => type_command(store, self.arguments.clone()).await,
+            "OBJECT ENCODING" => {
+                object_encoding(Arc::clone(&server), store, self.arguments.clone()).await
+            }
+            "OBJECT IDLETIME" => {
+                object_idletime(Arc::clone(&server), store, self.arguments.clone()).await
+            }
             "XADD" => {
-                match xadd(store, state, self.arguments.clone()).await {
+                match xadd(Arc::clone(&server), store, state, self.arguments.clone()).await {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -308,12 +855,38 @@ impl CommandHandler {
             }
             "XRANGE" => xrange(store, self.arguments.clone()).await,
             "XREAD" => xread(client_address, store, state, self.arguments.clone()).await,
+            "XSETID" => match xsetid(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "XLEN" => xlen(store, self.arguments.clone()).await,
+            "XDEL" => match xdel(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "XTRIM" => match xtrim(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
             "INCR" => {
-                match incr(store, self.arguments.clone()).await {
+                match incr(Arc::clone(&server), store, self.arguments.clone()).await {
                     Ok(response) => {
-                        let mut server_guard = server.write().await;
-                        server_guard
-                            .update_replication_offset(self.input.clone())
+                        self.update_replication_offset(&server, advances_replication_offset)
                             .await;
 
                         return Ok(response);
@@ -324,15 +897,411 @@ impl CommandHandler {
             "MULTI" => multi(client_address, state, self.arguments.clone()).await,
             "EXEC" => exec(client_address, state, self.arguments.clone()).await,
             "DISCARD" => discard(client_address, state, self.arguments.clone()).await,
-            "INFO" => info(Arc::clone(&server), self.arguments.clone()).await,
+            "INFO" => info(Arc::clone(&server), store, self.arguments.clone()).await,
             "REPLCONF" => {
                 replconf(client_address, Arc::clone(&server), self.arguments.clone()).await
             }
             "PSYNC" => psync(Arc::clone(&server), self.arguments.clone()).await,
             "WAIT" => wait(Arc::clone(&server), self.arguments.clone()).await,
-            "CONFIG GET" => config_get(Arc::clone(&server), self.arguments.clone()).await,
+            "CONFIG GET" => {
+                config_get(client_address, Arc::clone(&server), self.arguments.clone()).await
+            }
             "KEYS" => keys(store, self.arguments.clone()).await,
-            _ => Err(CommandError::InvalidCommand),
+            "HELLO" => hello(client_address, Arc::clone(&server), self.arguments.clone()).await,
+            "RESET" => reset(client_address, server, state, self.arguments.clone()).await,
+            "QUIT" => quit(self.arguments.clone()),
+            "DEBUG SET-ACTIVE-EXPIRE" => {
+                debug_set_active_expire(Arc::clone(&server), self.arguments.clone()).await
+            }
+            "DEBUG SET-STREAM-MAX-ENTRIES" => {
+                debug_set_stream_max_entries(Arc::clone(&server), self.arguments.clone()).await
+            }
+            "DEBUG CHANGE-REPL-ID" => {
+                debug_change_repl_id(Arc::clone(&server), self.arguments.clone()).await
+            }
+            "DEBUG OBJECT" => {
+                debug_object(Arc::clone(&server), store, self.arguments.clone()).await
+            }
+            "DEBUG LISTPACK" => debug_listpack(store, self.arguments.clone()).await,
+            "DEBUG LISTPACK-ENTRIES" => debug_listpack_entries(self.arguments.clone()),
+            "DEBUG RELOAD" => debug_reload(server, store, self.arguments.clone()).await,
+            "DEBUG STREAM" => debug_stream(server, store, self.arguments.clone()).await,
+            "DEBUG STRINGMATCH-LEN" => debug_stringmatch_len(self.arguments.clone()),
+            "DEBUG SLEEP" | "DEBUG DEBUG" => debug_sleep(self.arguments.clone()).await,
+            "FAILOVER" => failover(self.arguments.clone()),
+            "DBSIZE" => dbsize(store, self.arguments.clone()).await,
+            "LOLWUT" => lolwut(self.arguments.clone()),
+            "COMMAND LIST" => command_list(self.arguments.clone()),
+            "HSET" => match hset(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "HGET" => hget(store, self.arguments.clone()).await,
+            "HGETALL" => {
+                hgetall(
+                    client_address,
+                    Arc::clone(&server),
+                    store,
+                    self.arguments.clone(),
+                )
+                .await
+            }
+            "HMGET" => hmget(store, self.arguments.clone()).await,
+            "HSETNX" => match hsetnx(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "HSTRLEN" => hstrlen(store, self.arguments.clone()).await,
+            "HRANDFIELD" => hrandfield(store, self.arguments.clone()).await,
+            "HSCAN" => hscan(store, self.arguments.clone()).await,
+            "MEMORY USAGE" => memory_usage(store, self.arguments.clone()).await,
+            "MEMORY DOCTOR" => memory_doctor(store, self.arguments.clone()).await,
+            "MEMORY STATS" => {
+                memory_stats(
+                    client_address,
+                    Arc::clone(&server),
+                    store,
+                    self.arguments.clone(),
+                )
+                .await
+            }
+            "ZADD" => match zadd(
+                client_address,
+                Arc::clone(&server),
+                store,
+                state,
+                self.arguments.clone(),
+            )
+            .await
+            {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "ZSCORE" => zscore(client_address, server, store, self.arguments.clone()).await,
+            "ZMSCORE" => zmscore(client_address, server, store, self.arguments.clone()).await,
+            "ZRANDMEMBER" => zrandmember(store, self.arguments.clone()).await,
+            "ZRANGE" => zrange(store, self.arguments.clone()).await,
+            "ZREVRANGE" => zrevrange(store, self.arguments.clone()).await,
+            "ZRANK" => zrank(store, self.arguments.clone()).await,
+            "ZREVRANK" => zrevrank(store, self.arguments.clone()).await,
+            "ZCARD" => zcard(store, self.arguments.clone()).await,
+            "ZINCRBY" => match zincrby(
+                client_address,
+                Arc::clone(&server),
+                store,
+                state,
+                self.arguments.clone(),
+            )
+            .await
+            {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "PFADD" => match pfadd(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "PFCOUNT" => pfcount(store, self.arguments.clone()).await,
+            "PFMERGE" => match pfmerge(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "BITFIELD" => match bitfield(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "GETSET" => match getset(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "GETDEL" => match getdel(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "APPEND" => match append(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "SETRANGE" => {
+                match setrange(Arc::clone(&server), store, self.arguments.clone()).await {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "CLIENT INFO" => {
+                client_info(client_address, Arc::clone(&server), self.arguments.clone()).await
+            }
+            "CLIENT NO-EVICT" => {
+                client_no_evict(client_address, Arc::clone(&server), self.arguments.clone()).await
+            }
+            "CLIENT NO-TOUCH" => {
+                client_no_touch(client_address, Arc::clone(&server), self.arguments.clone()).await
+            }
+            "SLOWLOG GET" => slowlog_get(Arc::clone(&server), self.arguments.clone()).await,
+            "SLOWLOG LEN" => slowlog_len(Arc::clone(&server), self.arguments.clone()).await,
+            "SLOWLOG RESET" => slowlog_reset(Arc::clone(&server), self.arguments.clone()).await,
+            "LATENCY LATEST" => latency_latest(Arc::clone(&server), self.arguments.clone()).await,
+            "LATENCY HISTORY" => latency_history(Arc::clone(&server), self.arguments.clone()).await,
+            "LATENCY RESET" => latency_reset(Arc::clone(&server), self.arguments.clone()).await,
+            "SADD" => match sadd(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "SINTERCARD" => sintercard(store, self.arguments.clone()).await,
+            "SSCAN" => sscan(store, self.arguments.clone()).await,
+            "SMOVE" => match smove(Arc::clone(&server), store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "SINTERSTORE" => {
+                match sinterstore(Arc::clone(&server), store, self.arguments.clone()).await {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "SUNIONSTORE" => {
+                match sunionstore(Arc::clone(&server), store, self.arguments.clone()).await {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "SDIFFSTORE" => {
+                match sdiffstore(Arc::clone(&server), store, self.arguments.clone()).await {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "ZMPOP" => match zmpop(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "ZPOPMIN" => match zpopmin(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "ZPOPMAX" => match zpopmax(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "ZREM" => match zrem(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "ZREMRANGEBYRANK" => match zremrangebyrank(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "ZREMRANGEBYSCORE" => match zremrangebyscore(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "BZPOPMIN" => {
+                match bzpopmin(
+                    client_address,
+                    store,
+                    state,
+                    self.arguments.clone(),
+                    rewrite_guard,
+                )
+                .await
+                {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "BZPOPMAX" => {
+                match bzpopmax(
+                    client_address,
+                    store,
+                    state,
+                    self.arguments.clone(),
+                    rewrite_guard,
+                )
+                .await
+                {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "SORT" => match sort(Arc::clone(&server), store, state, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "SORT_RO" => sort_ro(store, self.arguments.clone()).await,
+            "CONFIG HELP" | "CLIENT HELP" | "DEBUG HELP" | "OBJECT HELP" | "SLOWLOG HELP"
+            | "LATENCY HELP" | "MEMORY HELP" => help(&self.name, self.arguments.clone()),
+            "SAVE" => save(server, store, self.arguments.clone()).await,
+            "BGSAVE" => bgsave(server, store, self.arguments.clone()).await,
+            "LASTSAVE" => lastsave(server, self.arguments.clone()).await,
+            "WAITAOF" => waitaof(Arc::clone(&server), self.arguments.clone()).await,
+            "EXPIRE" => match expire(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "PEXPIRE" => match pexpire(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "PEXPIREAT" => match pexpireat(store, self.arguments.clone()).await {
+                Ok(response) => {
+                    self.update_replication_offset(&server, advances_replication_offset)
+                        .await;
+
+                    Ok(response)
+                }
+                Err(err) => Err(err),
+            },
+            "BGREWRITEAOF" => bgrewriteaof(server, store, self.arguments.clone()).await,
+            "GEOADD" => {
+                match geoadd(Arc::clone(&server), store, state, self.arguments.clone()).await {
+                    Ok(response) => {
+                        self.update_replication_offset(&server, advances_replication_offset)
+                            .await;
+
+                        Ok(response)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            "GEOPOS" => geopos(store, self.arguments.clone()).await,
+            "GEODIST" => geodist(client_address, server, store, self.arguments.clone()).await,
+            "GEOSEARCH" => geosearch(store, self.arguments.clone()).await,
+            _ => Err(self.unknown_command_error()),
         }
     }
 
@@ -353,26 +1322,122 @@ impl CommandHandler {
             return Ok(CommandResult::Response(response));
         }
 
-        let command_result = self
+        // The rewritten command is the one run and propagated, while the latency is
+        // recorded under the command the client sent
+        let command = self.with_absolute_expiration();
+
+        server.read().await.stats.record_command();
+
+        let rewrite_lock = server.read().await.aof_rewrite_lock(command.name.as_str());
+        let mut rewrite_guard = RewriteGuard::acquire(rewrite_lock).await;
+
+        let started_at = Instant::now();
+        let command_result = command
+            .handle_command(
+                client_address,
+                Arc::clone(&server),
+                Arc::clone(&store),
+                Arc::clone(&state),
+                true,
+                &mut rewrite_guard,
+            )
+            .await;
+        self.record_command_latency(client_address, Arc::clone(&server), started_at.elapsed())
+            .await;
+        let command_result = command_result?;
+
+        command
+            .update_tracked_keys(client_address, Arc::clone(&server))
+            .await;
+
+        {
+            let server_guard = server.read().await;
+            server_guard
+                .should_replicate_write_command(command.input.clone(), command.name.as_str());
+            server_guard
+                .should_append_write_command(command.input.clone(), command.name.as_str())
+                .await;
+        }
+
+        Ok(command_result)
+    }
+
+    /// Runs a command queued in a transaction on the master. Its propagation to replicas
+    /// is left to the caller, which sends the transaction's writes wrapped in MULTI/EXEC,
+    /// so a successful write is added to `write_commands` as it was run.
+    pub async fn handle_transaction_command_for_master_server(
+        &self,
+        client_address: &str,
+        server: Arc<RwLock<RedisServer>>,
+        store: Arc<Mutex<KeyValueStore>>,
+        state: Arc<Mutex<State>>,
+        write_commands: &mut Vec<RespValue>,
+    ) -> Result<CommandResult, CommandError> {
+        server.read().await.stats.record_command();
+
+        // Relative expirations are computed when EXEC runs the command, not when it is queued
+        let command = self.with_absolute_expiration();
+
+        let rewrite_lock = server.read().await.aof_rewrite_lock(command.name.as_str());
+        let mut rewrite_guard = RewriteGuard::acquire(rewrite_lock).await;
+
+        let command_result = command
             .handle_command(
                 client_address,
                 Arc::clone(&server),
                 Arc::clone(&store),
                 Arc::clone(&state),
+                true,
+                &mut rewrite_guard,
             )
             .await?;
 
+        command
+            .update_tracked_keys(client_address, Arc::clone(&server))
+            .await;
+
         {
             let server_guard = server.read().await;
             server_guard
-                .should_replicate_write_command(self.input.clone(), self.name.as_str())
-                .await
-                .unwrap();
+                .should_append_write_command(command.input.clone(), command.name.as_str())
+                .await;
+
+            if server_guard.write_commands.contains(&command.name.as_str()) {
+                write_commands.push(command.input);
+            }
         }
 
         Ok(command_result)
     }
 
+    /// Moves the replication offset past a write command, unless it is a replica's local
+    /// write, which is not part of the stream the replica acknowledges to its master
+    async fn update_replication_offset(
+        &self,
+        server: &Arc<RwLock<RedisServer>>,
+        advances_replication_offset: bool,
+    ) {
+        if advances_replication_offset {
+            server
+                .write()
+                .await
+                .update_replication_offset(self.input.clone())
+                .await;
+        }
+    }
+
+    /// Remembers the keys read by a connection with CLIENT TRACKING on, and tells the
+    /// connections tracking the keys a write command modified that they changed
+    async fn update_tracked_keys(&self, client_address: &str, server: Arc<RwLock<RedisServer>>) {
+        let mut server_guard = server.write().await;
+
+        if server_guard.write_commands.contains(&self.name.as_str()) {
+            server_guard.invalidate_keys(written_keys(&self.name, &self.arguments));
+        } else {
+            server_guard.track_keys(client_address, read_keys(&self.name, &self.arguments));
+        }
+    }
+
     pub async fn handle_command_for_replica_master_connection(
         &self,
         client_address: &str,
@@ -380,22 +1445,38 @@ impl CommandHandler {
         store: Arc<Mutex<KeyValueStore>>,
         state: Arc<Mutex<State>>,
     ) -> Result<CommandResult, CommandError> {
-        if let Some(response) = self
+        // The master does not expect a reply to a command it replicates, so nor to a queued one
+        if self
             .queue_command_if_in_transaction(client_address, Arc::clone(&state))
             .await?
+            .is_some()
         {
-            return Ok(CommandResult::Response(response));
+            return Ok(CommandResult::NoResponse);
         }
 
+        server.read().await.stats.record_command();
+
+        let rewrite_lock = server.read().await.aof_rewrite_lock(self.name.as_str());
+        let mut rewrite_guard = RewriteGuard::acquire(rewrite_lock).await;
+
         let command_result = self
             .handle_command(
                 client_address,
                 Arc::clone(&server),
                 Arc::clone(&store),
                 Arc::clone(&state),
+                true,
+                &mut rewrite_guard,
             )
             .await?;
 
+        {
+            let server_guard = server.read().await;
+            server_guard
+                .should_append_write_command(self.input.clone(), self.name.as_str())
+                .await;
+        }
+
         // Hack so that codecrafters test runs successfully
         if self.name.as_str() == "PING" || self.name.as_str() == "REPLCONF" {
             let mut server_guard = server.write().await;
@@ -415,6 +1496,7 @@ impl CommandHandler {
             }
             CommandResult::Batch(commands) => Ok(CommandResult::Batch(commands)),
             CommandResult::Sync(response) => Ok(CommandResult::Sync(response)),
+            CommandResult::Quit(_) => Ok(CommandResult::NoResponse),
         }
     }
 
@@ -428,18 +1510,183 @@ impl CommandHandler {
         self.throw_error_if_in_subscribed_mode(client_address, Arc::clone(&server))
             .await?;
 
-        match self.name.as_str() {
+        server.read().await.stats.record_command();
+
+        self.remove_expired_keys(Arc::clone(&server), Arc::clone(&store))
+            .await;
+
+        let monitored_server = Arc::clone(&server);
+        let monitored_store = Arc::clone(&store);
+        let started_at = Instant::now();
+
+        let command_result = match self.name.as_str() {
             "PING" => ping(self.arguments.clone()),
             "ECHO" => echo(self.arguments.clone()),
-            "GET" => get(store, self.arguments.clone()).await,
+            "GET" => get(server, store, self.arguments.clone()).await,
             "LRANGE" => lrange(store, self.arguments.clone()).await,
             "LLEN" => llen(store, self.arguments.clone()).await,
             "TYPE" => type_command(store, self.arguments.clone()).await,
+            "OBJECT ENCODING" => {
+                object_encoding(Arc::clone(&server), store, self.arguments.clone()).await
+            }
+            "OBJECT IDLETIME" => object_idletime(server, store, self.arguments.clone()).await,
             "XRANGE" => xrange(store, self.arguments.clone()).await,
             "XREAD" => xread(client_address, store, state, self.arguments.clone()).await,
-            "INFO" => info(server, self.arguments.clone()).await,
-            _ => Err(CommandError::ReplicaReadOnlyCommands),
+            "XLEN" => xlen(store, self.arguments.clone()).await,
+            "INFO" => info(server, store, self.arguments.clone()).await,
+            "CONFIG GET" => config_get(client_address, server, self.arguments.clone()).await,
+            "HELLO" => hello(client_address, server, self.arguments.clone()).await,
+            "RESET" => reset(client_address, server, state, self.arguments.clone()).await,
+            "QUIT" => quit(self.arguments.clone()),
+            "DEBUG SET-ACTIVE-EXPIRE" => {
+                debug_set_active_expire(server, self.arguments.clone()).await
+            }
+            "DEBUG SET-STREAM-MAX-ENTRIES" => {
+                debug_set_stream_max_entries(Arc::clone(&server), self.arguments.clone()).await
+            }
+            "DEBUG CHANGE-REPL-ID" => debug_change_repl_id(server, self.arguments.clone()).await,
+            "DEBUG OBJECT" => debug_object(server, store, self.arguments.clone()).await,
+            "DEBUG LISTPACK" => debug_listpack(store, self.arguments.clone()).await,
+            "DEBUG LISTPACK-ENTRIES" => debug_listpack_entries(self.arguments.clone()),
+            "DEBUG RELOAD" => debug_reload(server, store, self.arguments.clone()).await,
+            "DEBUG STREAM" => debug_stream(server, store, self.arguments.clone()).await,
+            "DEBUG STRINGMATCH-LEN" => debug_stringmatch_len(self.arguments.clone()),
+            "DEBUG SLEEP" | "DEBUG DEBUG" => debug_sleep(self.arguments.clone()).await,
+            "FAILOVER" => failover(self.arguments.clone()),
+            "DBSIZE" => dbsize(store, self.arguments.clone()).await,
+            "LOLWUT" => lolwut(self.arguments.clone()),
+            "COMMAND LIST" => command_list(self.arguments.clone()),
+            "HGET" => hget(store, self.arguments.clone()).await,
+            "HGETALL" => hgetall(client_address, server, store, self.arguments.clone()).await,
+            "HMGET" => hmget(store, self.arguments.clone()).await,
+            "HSTRLEN" => hstrlen(store, self.arguments.clone()).await,
+            "HRANDFIELD" => hrandfield(store, self.arguments.clone()).await,
+            "HSCAN" => hscan(store, self.arguments.clone()).await,
+            "MEMORY USAGE" => memory_usage(store, self.arguments.clone()).await,
+            "MEMORY DOCTOR" => memory_doctor(store, self.arguments.clone()).await,
+            "MEMORY STATS" => {
+                memory_stats(client_address, server, store, self.arguments.clone()).await
+            }
+            "ZSCORE" => zscore(client_address, server, store, self.arguments.clone()).await,
+            "ZMSCORE" => zmscore(client_address, server, store, self.arguments.clone()).await,
+            "ZRANDMEMBER" => zrandmember(store, self.arguments.clone()).await,
+            "ZRANGE" => zrange(store, self.arguments.clone()).await,
+            "ZREVRANGE" => zrevrange(store, self.arguments.clone()).await,
+            "ZRANK" => zrank(store, self.arguments.clone()).await,
+            "ZREVRANK" => zrevrank(store, self.arguments.clone()).await,
+            "ZCARD" => zcard(store, self.arguments.clone()).await,
+            "PFCOUNT" => pfcount(store, self.arguments.clone()).await,
+            "CLIENT INFO" => client_info(client_address, server, self.arguments.clone()).await,
+            "CLIENT NO-EVICT" => {
+                client_no_evict(client_address, server, self.arguments.clone()).await
+            }
+            "CLIENT NO-TOUCH" => {
+                client_no_touch(client_address, server, self.arguments.clone()).await
+            }
+            "SLOWLOG GET" => slowlog_get(server, self.arguments.clone()).await,
+            "SLOWLOG LEN" => slowlog_len(server, self.arguments.clone()).await,
+            "SLOWLOG RESET" => slowlog_reset(server, self.arguments.clone()).await,
+            "LATENCY LATEST" => latency_latest(server, self.arguments.clone()).await,
+            "LATENCY HISTORY" => latency_history(server, self.arguments.clone()).await,
+            "LATENCY RESET" => latency_reset(server, self.arguments.clone()).await,
+            "SINTERCARD" => sintercard(store, self.arguments.clone()).await,
+            "SSCAN" => sscan(store, self.arguments.clone()).await,
+            "SORT_RO" => sort_ro(store, self.arguments.clone()).await,
+            "CONFIG HELP" | "CLIENT HELP" | "DEBUG HELP" | "OBJECT HELP" | "SLOWLOG HELP"
+            | "LATENCY HELP" | "MEMORY HELP" => help(&self.name, self.arguments.clone()),
+            "SAVE" => save(server, store, self.arguments.clone()).await,
+            "BGSAVE" => bgsave(server, store, self.arguments.clone()).await,
+            "LASTSAVE" => lastsave(server, self.arguments.clone()).await,
+            "WAITAOF" => waitaof(Arc::clone(&server), self.arguments.clone()).await,
+            "BGREWRITEAOF" => bgrewriteaof(server, store, self.arguments.clone()).await,
+            "GEOPOS" => geopos(store, self.arguments.clone()).await,
+            "GEODIST" => geodist(client_address, server, store, self.arguments.clone()).await,
+            "GEOSEARCH" => geosearch(store, self.arguments.clone()).await,
+            _ => {
+                let is_local_write_allowed = {
+                    let server_guard = server.read().await;
+                    !server_guard.replica_read_only
+                        && server_guard.write_commands.contains(&self.name.as_str())
+                };
+
+                if is_local_write_allowed {
+                    // Local writes of a replica are not appended, so rewrites need not wait for them
+                    self.execute_command(
+                        client_address,
+                        Arc::clone(&server),
+                        store,
+                        state,
+                        false,
+                        &mut RewriteGuard::default(),
+                    )
+                    .await
+                } else {
+                    Err(CommandError::ReplicaReadOnlyCommands)
+                }
+            }
+        };
+
+        self.record_command_latency(
+            client_address,
+            Arc::clone(&monitored_server),
+            started_at.elapsed(),
+        )
+        .await;
+        self.update_accessed_keys(client_address, monitored_server, monitored_store)
+            .await;
+
+        command_result
+    }
+
+    /// Adds the command to the slow log when it ran for longer than `slowlog-log-slower-than`
+    /// and records a `command` latency spike when it reached `latency-monitor-threshold`
+    async fn record_command_latency(
+        &self,
+        client_address: &str,
+        server: Arc<RwLock<RedisServer>>,
+        duration: Duration,
+    ) {
+        if BLOCKING_COMMANDS.contains(&self.name.as_str()) {
+            return;
+        }
+
+        let (is_slow, is_latency_spike) = {
+            let server_guard = server.read().await;
+            (
+                SlowLog::is_slow(duration, server_guard.slowlog_log_slower_than),
+                LatencyMonitor::is_spike(duration, server_guard.latency_monitor_threshold),
+            )
+        };
+
+        if !is_slow && !is_latency_spike {
+            return;
+        }
+
+        let mut server_guard = server.write().await;
+
+        if is_latency_spike {
+            server_guard.latency_monitor.record("command", duration);
         }
+
+        if !is_slow {
+            return;
+        }
+
+        let RespValue::Array(elements) = &self.input else {
+            return;
+        };
+
+        let arguments = elements
+            .iter()
+            .filter_map(|element| match element {
+                RespValue::BulkString(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect();
+
+        server_guard
+            .slowlog
+            .push(duration, arguments, client_address);
     }
 }
 
@@ -447,7 +1694,11 @@ async fn is_in_subscribe_mode(client_address: &str, server: Arc<RwLock<RedisServ
     let server_guard = server.read().await;
     let mut is_subscribed = false;
 
-    for channel in server_guard.pub_sub_channels.values() {
+    for channel in server_guard
+        .pub_sub_channels
+        .values()
+        .chain(server_guard.shard_pub_sub_channels.values())
+    {
         if channel.contains_key(client_address) {
             is_subscribed = true;
         }
@@ -455,3 +1706,8 @@ async fn is_in_subscribe_mode(client_address: &str, server: Arc<RwLock<RedisServ
 
     is_subscribed
 }
+
+async fn is_resp3_client(client_address: &str, server: Arc<RwLock<RedisServer>>) -> bool {
+    let server_guard = server.read().await;
+    server_guard.resp3_clients.contains(client_address)
+}
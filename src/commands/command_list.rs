@@ -0,0 +1,107 @@
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::COMMAND_TABLE,
+        command_handler::CommandResult, glob_utils::compile_glob_pattern,
+        keyword_utils::normalize_keyword,
+    },
+    resp::RespValue,
+};
+
+enum CommandListFilter {
+    Module,
+    AclCategory,
+    Pattern(String),
+}
+
+pub struct CommandListArguments {
+    filter: Option<CommandListFilter>,
+}
+
+impl CommandListArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        match arguments.len() {
+            0 => Ok(Self { filter: None }),
+            3 => {
+                if normalize_keyword(&arguments[0]) != "FILTERBY" {
+                    return Err(CommandError::InvalidCommandListCommand);
+                }
+
+                let filter = match normalize_keyword(&arguments[1]).as_str() {
+                    "MODULE" => CommandListFilter::Module,
+                    "ACLCAT" => CommandListFilter::AclCategory,
+                    "PATTERN" => CommandListFilter::Pattern(arguments[2].clone()),
+                    _ => return Err(CommandError::InvalidCommandListCommand),
+                };
+
+                Ok(Self {
+                    filter: Some(filter),
+                })
+            }
+            _ => Err(CommandError::InvalidCommandListCommand),
+        }
+    }
+}
+
+/// Lists the lowercase names of the supported commands, optionally keeping only the
+/// ones whose name matches a glob pattern
+pub fn command_list(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    let command_list_arguments = CommandListArguments::parse(arguments)?;
+
+    let names = COMMAND_TABLE.iter().map(|(name, _)| name.to_lowercase());
+
+    let names = match command_list_arguments.filter {
+        None => names.collect::<Vec<String>>(),
+        // Modules and ACLs are not supported, so no command belongs to one
+        Some(CommandListFilter::Module) | Some(CommandListFilter::AclCategory) => Vec::new(),
+        // Redis matches the pattern case-insensitively
+        Some(CommandListFilter::Pattern(pattern)) => {
+            let matcher = compile_glob_pattern(&pattern.to_lowercase())?;
+            names.filter(|name| matcher.is_match(name)).collect()
+        }
+    };
+
+    Ok(CommandResult::Response(
+        RespValue::encode_array_from_strings(names),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandListArguments;
+    use crate::commands::CommandError;
+
+    fn to_strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_command_list_arguments() {
+        let test_cases = vec![
+            (vec![], Ok(())),
+            (vec!["FILTERBY", "pattern", "x*"], Ok(())),
+            (vec!["filterby", "MODULE", "json"], Ok(())),
+            (vec!["FILTERBY", "ACLCAT", "read"], Ok(())),
+            (
+                vec!["FILTERBY", "PATTERN"],
+                Err(CommandError::InvalidCommandListCommand),
+            ),
+            (
+                vec!["FILTERBY", "NAME", "x*"],
+                Err(CommandError::InvalidCommandListCommand),
+            ),
+            (
+                vec!["FILTER", "PATTERN", "x*"],
+                Err(CommandError::InvalidCommandListCommand),
+            ),
+        ];
+
+        for (arguments, expected) in test_cases {
+            assert_eq!(
+                CommandListArguments::parse(to_strings(&arguments)).map(|_| ()),
+                expected,
+                "parsing {:?}",
+                arguments
+            );
+        }
+    }
+}
@@ -24,30 +24,214 @@ impl ConfigGetArguments {
     }
 }
 
+/// Replies with the requested parameters and their values, as a map for RESP3 clients
+/// and as a flat array of alternating names and values for RESP2 clients
 pub async fn config_get(
+    client_address: &str,
     server: Arc<RwLock<RedisServer>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
     let config_get_arguments = ConfigGetArguments::parse(arguments)?;
-    let mut response = Vec::new();
+    let mut entries = Vec::new();
 
     for arg in config_get_arguments.parameters {
         match arg.as_str() {
             "dir" => {
                 let server_guard = server.read().await;
                 let dir = server_guard.rdb_directory.clone();
-                response.push(RespValue::BulkString("dir".to_string()));
-                response.push(RespValue::BulkString(dir));
+                entries.push((
+                    RespValue::BulkString("dir".to_string()),
+                    RespValue::BulkString(dir),
+                ));
             }
             "dbfilename" => {
                 let server_guard = server.read().await;
                 let file = server_guard.rdb_filename.clone();
-                response.push(RespValue::BulkString("dbfilename".to_string()));
-                response.push(RespValue::BulkString(file));
+                entries.push((
+                    RespValue::BulkString("dbfilename".to_string()),
+                    RespValue::BulkString(file),
+                ));
+            }
+            "save" => {
+                let server_guard = server.read().await;
+                let save_points = server_guard
+                    .save_points
+                    .iter()
+                    .map(|save_point| format!("{} {}", save_point.seconds, save_point.changes))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                entries.push((
+                    RespValue::BulkString("save".to_string()),
+                    RespValue::BulkString(save_points),
+                ));
+            }
+            "appendonly" => {
+                let server_guard = server.read().await;
+                let appendonly = if server_guard.appendonly { "yes" } else { "no" };
+                entries.push((
+                    RespValue::BulkString("appendonly".to_string()),
+                    RespValue::BulkString(appendonly.to_string()),
+                ));
+            }
+            "replica-read-only" => {
+                let server_guard = server.read().await;
+                let replica_read_only = if server_guard.replica_read_only {
+                    "yes"
+                } else {
+                    "no"
+                };
+                entries.push((
+                    RespValue::BulkString("replica-read-only".to_string()),
+                    RespValue::BulkString(replica_read_only.to_string()),
+                ));
+            }
+            "appendfsync" => {
+                let server_guard = server.read().await;
+                let appendfsync = server_guard.appendfsync.as_string().to_string();
+                entries.push((
+                    RespValue::BulkString("appendfsync".to_string()),
+                    RespValue::BulkString(appendfsync),
+                ));
+            }
+            "list-max-listpack-size" => {
+                let server_guard = server.read().await;
+                let list_max_listpack_size = server_guard.list_max_listpack_size.to_string();
+                entries.push((
+                    RespValue::BulkString("list-max-listpack-size".to_string()),
+                    RespValue::BulkString(list_max_listpack_size),
+                ));
+            }
+            "set-max-intset-entries" => {
+                let server_guard = server.read().await;
+                let set_max_intset_entries = server_guard
+                    .set_encoding_limits
+                    .max_intset_entries
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("set-max-intset-entries".to_string()),
+                    RespValue::BulkString(set_max_intset_entries),
+                ));
+            }
+            "set-max-listpack-entries" => {
+                let server_guard = server.read().await;
+                let set_max_listpack_entries = server_guard
+                    .set_encoding_limits
+                    .max_listpack_entries
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("set-max-listpack-entries".to_string()),
+                    RespValue::BulkString(set_max_listpack_entries),
+                ));
+            }
+            "set-max-listpack-value" => {
+                let server_guard = server.read().await;
+                let set_max_listpack_value = server_guard
+                    .set_encoding_limits
+                    .max_listpack_value
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("set-max-listpack-value".to_string()),
+                    RespValue::BulkString(set_max_listpack_value),
+                ));
+            }
+            "hash-max-listpack-entries" => {
+                let server_guard = server.read().await;
+                let hash_max_listpack_entries = server_guard
+                    .hash_encoding_limits
+                    .max_listpack_entries
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("hash-max-listpack-entries".to_string()),
+                    RespValue::BulkString(hash_max_listpack_entries),
+                ));
+            }
+            "hash-max-listpack-value" => {
+                let server_guard = server.read().await;
+                let hash_max_listpack_value = server_guard
+                    .hash_encoding_limits
+                    .max_listpack_value
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("hash-max-listpack-value".to_string()),
+                    RespValue::BulkString(hash_max_listpack_value),
+                ));
+            }
+            "zset-max-listpack-entries" => {
+                let server_guard = server.read().await;
+                let zset_max_listpack_entries = server_guard
+                    .zset_encoding_limits
+                    .max_listpack_entries
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("zset-max-listpack-entries".to_string()),
+                    RespValue::BulkString(zset_max_listpack_entries),
+                ));
+            }
+            "zset-max-listpack-value" => {
+                let server_guard = server.read().await;
+                let zset_max_listpack_value = server_guard
+                    .zset_encoding_limits
+                    .max_listpack_value
+                    .to_string();
+                entries.push((
+                    RespValue::BulkString("zset-max-listpack-value".to_string()),
+                    RespValue::BulkString(zset_max_listpack_value),
+                ));
+            }
+            "stream-node-max-entries" => {
+                let server_guard = server.read().await;
+                let stream_node_max_entries = server_guard.stream_node_max_entries.to_string();
+                entries.push((
+                    RespValue::BulkString("stream-node-max-entries".to_string()),
+                    RespValue::BulkString(stream_node_max_entries),
+                ));
+            }
+            "slowlog-log-slower-than" => {
+                let server_guard = server.read().await;
+                let slowlog_log_slower_than = server_guard.slowlog_log_slower_than.to_string();
+                entries.push((
+                    RespValue::BulkString("slowlog-log-slower-than".to_string()),
+                    RespValue::BulkString(slowlog_log_slower_than),
+                ));
+            }
+            "latency-monitor-threshold" => {
+                let server_guard = server.read().await;
+                let latency_monitor_threshold = server_guard.latency_monitor_threshold.to_string();
+                entries.push((
+                    RespValue::BulkString("latency-monitor-threshold".to_string()),
+                    RespValue::BulkString(latency_monitor_threshold),
+                ));
+            }
+            "tcp-keepalive" => {
+                let server_guard = server.read().await;
+                let tcp_keepalive = server_guard.tcp_keepalive.to_string();
+                entries.push((
+                    RespValue::BulkString("tcp-keepalive".to_string()),
+                    RespValue::BulkString(tcp_keepalive),
+                ));
+            }
+            "proto-max-bulk-len" => {
+                let server_guard = server.read().await;
+                let proto_max_bulk_len = server_guard.proto_max_bulk_len.to_string();
+                entries.push((
+                    RespValue::BulkString("proto-max-bulk-len".to_string()),
+                    RespValue::BulkString(proto_max_bulk_len),
+                ));
             }
             _ => return Err(CommandError::InvalidConfigGetCommandArgument),
         }
     }
 
+    let is_resp3_client = server.read().await.resp3_clients.contains(client_address);
+
+    if is_resp3_client {
+        return Ok(CommandResult::Response(RespValue::Map(entries).encode()));
+    }
+
+    let response = entries
+        .into_iter()
+        .flat_map(|(name, value)| [name, value])
+        .collect::<Vec<RespValue>>();
+
     Ok(CommandResult::Response(RespValue::Array(response).encode()))
 }
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+};
+
+pub struct DbsizeArguments;
+
+impl DbsizeArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidDbsizeCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn dbsize(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    DbsizeArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(store_guard.len() as i64).encode(),
+    ))
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::{RedisServer, generate_replication_id},
+};
+
+pub struct DebugChangeReplIdArguments;
+
+impl DebugChangeReplIdArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+/// Gives the server a new replication ID, so replicas can no longer partially resync from it
+pub async fn debug_change_repl_id(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    DebugChangeReplIdArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    server_guard.repl_id = generate_replication_id();
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        CommandError, command_handler::CommandResult, is_value_expired, object::value_encoding,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    list::listpack_size,
+    resp::RespValue,
+    sorted_set::format_score,
+};
+
+pub struct DebugListpackArguments {
+    key: String,
+}
+
+impl DebugListpackArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+/// Dumps the listpack holding a small list, hash, set or sorted set: its size in bytes
+/// followed by one line per entry. Hash fields and sorted set members are each followed
+/// by their value or score, as they are stored side by side in the listpack.
+pub async fn debug_listpack(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugListpackArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get(&debug_arguments.key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return Err(CommandError::NoSuchKey);
+    };
+
+    if value_encoding(&value.data) != "listpack" {
+        return Err(CommandError::NotListpackEncoded);
+    }
+
+    let entries: Vec<String> = match &value.data {
+        DataType::Array(list) => list.iter().cloned().collect(),
+        DataType::Hash(hash) => hash
+            .iter()
+            .flat_map(|(field, value)| [field.clone(), value.clone()])
+            .collect(),
+        DataType::Set(set) => set.iter().cloned().collect(),
+        DataType::SortedSet(sorted_set) => sorted_set
+            .iter()
+            .flat_map(|(member, score)| [member.clone(), format_score(score)])
+            .collect(),
+        DataType::String(_) | DataType::Stream(_) => {
+            return Err(CommandError::NotListpackEncoded);
+        }
+    };
+
+    let mut description = format!(
+        "{{total_bytes {}}} {{num_elements {}}}",
+        listpack_size(entries.iter().map(|entry| entry.as_str())),
+        entries.len()
+    );
+
+    for (index, entry) in entries.iter().enumerate() {
+        description.push_str(&format!("\n{{element {}}} {}", index, entry));
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::BulkString(description).encode(),
+    ))
+}
+
+/// Only checks its arguments, as the listpack entry encodings it would validate in
+/// Redis are not kept in memory here
+pub fn debug_listpack_entries(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    if !arguments.is_empty() {
+        return Err(CommandError::InvalidDebugCommand);
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
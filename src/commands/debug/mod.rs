@@ -0,0 +1,19 @@
+mod change_repl_id;
+mod listpack;
+mod object;
+mod reload;
+mod set_active_expire;
+mod set_stream_max_entries;
+mod sleep;
+mod stream;
+mod stringmatch_len;
+
+pub use change_repl_id::{DebugChangeReplIdArguments, debug_change_repl_id};
+pub use listpack::{DebugListpackArguments, debug_listpack, debug_listpack_entries};
+pub use object::{DebugObjectArguments, debug_object};
+pub use reload::{DebugReloadArguments, debug_reload};
+pub use set_active_expire::{DebugSetActiveExpireArguments, debug_set_active_expire};
+pub use set_stream_max_entries::{DebugSetStreamMaxEntriesArguments, debug_set_stream_max_entries};
+pub use sleep::{DebugSleepArguments, debug_sleep};
+pub use stream::{DebugStreamArguments, debug_stream};
+pub use stringmatch_len::{DebugStringmatchLenArguments, debug_stringmatch_len};
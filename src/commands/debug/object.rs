@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        CommandError, command_handler::CommandResult, is_value_expired, object::key_encoding,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    rdb::serialized_length,
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct DebugObjectArguments {
+    key: String,
+}
+
+impl DebugObjectArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+/// Describes how a value is stored: its encoding and serialized length, plus the
+/// quicklist layout for lists and the number of radix tree nodes for streams
+pub async fn debug_object(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugObjectArguments::parse(arguments)?;
+    let (list_max_listpack_size, stream_node_max_entries, is_modified_in_place) = {
+        let server_guard = server.read().await;
+        (
+            server_guard.list_max_listpack_size,
+            server_guard.stream_node_max_entries,
+            server_guard.raw_string_keys.contains(&debug_arguments.key),
+        )
+    };
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get(&debug_arguments.key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return Err(CommandError::NoSuchKey);
+    };
+
+    let mut description = format!(
+        "Value at:{:p} refcount:1 encoding:{} serializedlength:{}",
+        value,
+        key_encoding(&value.data, is_modified_in_place),
+        serialized_length(&value.data)
+    );
+
+    match &value.data {
+        DataType::Array(list) => {
+            let node_sizes = list.quicklist_node_sizes(list_max_listpack_size);
+
+            description.push_str(&format!(
+                " ql_nodes:{} ql_avg_node:{:.2} ql_listpack_max:{} ql_compressed:0 ql_uncompressed_size:{}",
+                node_sizes.len(),
+                list.len() as f64 / node_sizes.len() as f64,
+                list_max_listpack_size,
+                node_sizes.iter().sum::<usize>()
+            ));
+        }
+        DataType::Stream(stream) => {
+            description.push_str(&format!(
+                " radix-tree-keys:{}",
+                stream.radix_tree_nodes(stream_node_max_entries)
+            ));
+        }
+        _ => {}
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString(description).encode(),
+    ))
+}
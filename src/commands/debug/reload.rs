@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    key_value_store::KeyValueStore,
+    rdb::reload_rdb_file,
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct DebugReloadArguments;
+
+impl DebugReloadArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+/// Saves the dataset to the RDB file and loads it back, replacing the keys in memory
+pub async fn debug_reload(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    DebugReloadArguments::parse(arguments)?;
+
+    {
+        let server_guard = server.read().await;
+
+        if server_guard.is_background_save_in_progress {
+            return Err(CommandError::BackgroundSaveInProgress);
+        }
+    }
+
+    if let Err(e) = reload_rdb_file(server, store).await {
+        eprintln!("Failed to reload RDB file: {}", e);
+        return Err(CommandError::RdbReloadFailed);
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct DebugSetActiveExpireArguments {
+    enabled: bool,
+}
+
+impl DebugSetActiveExpireArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        let enabled = match arguments[0].as_str() {
+            "0" => false,
+            "1" => true,
+            _ => return Err(CommandError::InvalidDebugCommandArgument),
+        };
+
+        Ok(Self { enabled })
+    }
+}
+
+pub async fn debug_set_active_expire(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugSetActiveExpireArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    server_guard.active_expire_enabled = debug_arguments.enabled;
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
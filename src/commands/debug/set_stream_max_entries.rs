@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct DebugSetStreamMaxEntriesArguments {
+    node_max_entries: usize,
+}
+
+impl DebugSetStreamMaxEntriesArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        let node_max_entries = arguments[0]
+            .parse::<usize>()
+            .map_err(|_| CommandError::InvalidDebugCommandArgument)?;
+
+        Ok(Self { node_max_entries })
+    }
+}
+
+/// Changes `stream-node-max-entries` at runtime. Setting it to 1 makes approximate
+/// (`~`) trimming remove exactly as many entries as exact trimming, which keeps
+/// `MAXLEN ~` tests deterministic.
+pub async fn debug_set_stream_max_entries(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugSetStreamMaxEntriesArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    server_guard.stream_node_max_entries = debug_arguments.node_max_entries;
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
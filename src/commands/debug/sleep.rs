@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+};
+
+pub struct DebugSleepArguments {
+    duration: Duration,
+}
+
+impl DebugSleepArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        let duration = arguments[0]
+            .parse::<f64>()
+            .ok()
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .ok_or(CommandError::InvalidDebugCommandArgument)?;
+
+        Ok(Self { duration })
+    }
+}
+
+/// Waits for the given number of seconds, which may be fractional, before replying.
+/// No lock is held while waiting so other connections keep being served.
+/// Serves both DEBUG SLEEP and DEBUG DEBUG, the name some test suites use for it.
+pub async fn debug_sleep(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugSleepArguments::parse(arguments)?;
+
+    tokio::time::sleep(debug_arguments.duration).await;
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
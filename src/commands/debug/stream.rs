@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult, is_value_expired},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+    stream::format_stream_id,
+};
+
+pub struct DebugStreamArguments {
+    key: String,
+}
+
+impl DebugStreamArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+/// Describes a stream's internals: its number of entries, the IDs of its first entry and
+/// of the last generated one, and the number of radix tree nodes holding the entries
+pub async fn debug_stream(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugStreamArguments::parse(arguments)?;
+    let stream_node_max_entries = server.read().await.stream_node_max_entries;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get(&debug_arguments.key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return Err(CommandError::NoSuchKey);
+    };
+
+    let DataType::Stream(ref stream) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let description = format!(
+        "entries:{} first-id:{} last-id:{} radix-tree-keys:{} stream-node-max-entries:{}",
+        stream.len(),
+        format_stream_id(stream.first_entry_id().unwrap_or_default()),
+        format_stream_id(stream.last_id()),
+        stream.radix_tree_nodes(stream_node_max_entries),
+        stream_node_max_entries
+    );
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString(description).encode(),
+    ))
+}
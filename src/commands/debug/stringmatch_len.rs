@@ -0,0 +1,35 @@
+use crate::{
+    commands::{CommandError, command_handler::CommandResult, glob_utils::compile_glob_pattern},
+    resp::RespValue,
+};
+
+pub struct DebugStringmatchLenArguments {
+    pattern: String,
+    string: String,
+}
+
+impl DebugStringmatchLenArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidDebugCommand);
+        }
+
+        Ok(Self {
+            pattern: arguments[0].clone(),
+            string: arguments[1].clone(),
+        })
+    }
+}
+
+/// Replies 1 if the string matches the glob-style pattern and 0 otherwise, using the
+/// same matcher as KEYS
+pub fn debug_stringmatch_len(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    let debug_arguments = DebugStringmatchLenArguments::parse(arguments)?;
+
+    let glob = compile_glob_pattern(&debug_arguments.pattern)?;
+    let is_match = glob.is_match(&debug_arguments.string);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(is_match as i64).encode(),
+    ))
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use jiff::{SignedDuration, Timestamp};
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, pexpireat::expire_key_at,
+    },
+    key_value_store::KeyValueStore,
+};
+
+pub struct ExpireArguments {
+    key: String,
+    expiration: Timestamp,
+}
+
+impl ExpireArguments {
+    /// Parses EXPIRE when `is_in_milliseconds` is false and PEXPIRE otherwise
+    pub fn parse(arguments: Vec<String>, is_in_milliseconds: bool) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return if is_in_milliseconds {
+                Err(CommandError::InvalidPexpireCommand)
+            } else {
+                Err(CommandError::InvalidExpireCommand)
+            };
+        }
+
+        let multiplier = if is_in_milliseconds { 1 } else { 1000 };
+
+        // Negative times are accepted and expire the key right away
+        let expiration = arguments[1]
+            .parse::<i64>()
+            .ok()
+            .and_then(|time| time.checked_mul(multiplier))
+            .and_then(|milliseconds| {
+                Timestamp::now()
+                    .checked_add(SignedDuration::from_millis(milliseconds))
+                    .ok()
+            })
+            .ok_or(CommandError::InvalidExpireTime)?;
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            expiration,
+        })
+    }
+
+    /// Arguments of the PEXPIREAT command that sets the same expiration
+    pub fn to_pexpireat_arguments(&self) -> Vec<String> {
+        vec![
+            self.key.clone(),
+            self.expiration.as_millisecond().to_string(),
+        ]
+    }
+}
+
+pub async fn expire(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    expire_with_unit(store, arguments, false).await
+}
+
+pub async fn pexpire(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    expire_with_unit(store, arguments, true).await
+}
+
+async fn expire_with_unit(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+    is_in_milliseconds: bool,
+) -> Result<CommandResult, CommandError> {
+    let expire_arguments = ExpireArguments::parse(arguments, is_in_milliseconds)?;
+
+    Ok(CommandResult::Response(
+        expire_key_at(store, &expire_arguments.key, expire_arguments.expiration).await,
+    ))
+}
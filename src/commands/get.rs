@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
 use jiff::Timestamp;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{
     commands::{command_error::CommandError, command_handler::CommandResult},
     key_value_store::{DataType, KeyValueStore, Value},
     resp::RespValue,
+    server::RedisServer,
 };
 
 pub struct GetArguments {
@@ -26,23 +27,30 @@ impl GetArguments {
 }
 
 pub async fn get(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
     let get_arguments = GetArguments::parse(arguments)?;
+    let stats = Arc::clone(&server.read().await.stats);
 
     let mut store_guard = store.lock().await;
     let stored_data = store_guard.get(&get_arguments.key);
 
     let Some(value) = stored_data else {
+        stats.record_keyspace_lookup(false);
         return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
     };
 
     if is_value_expired(&value) {
         store_guard.remove(&get_arguments.key);
+        stats.record_expired_keys(1);
+        stats.record_keyspace_lookup(false);
         return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
     }
 
+    stats.record_keyspace_lookup(true);
+
     match value.data {
         DataType::String(ref s) => Ok(CommandResult::Response(
             RespValue::BulkString(s.clone()).encode(),
@@ -51,7 +59,17 @@ pub async fn get(
     }
 }
 
-fn is_value_expired(value: &Value) -> bool {
+/// Removes the key when its value has expired and returns whether it did
+pub fn remove_expired_key(store: &mut KeyValueStore, key: &str) -> bool {
+    if store.get(key).is_some_and(is_value_expired) {
+        store.remove(key);
+        return true;
+    }
+
+    false
+}
+
+pub fn is_value_expired(value: &Value) -> bool {
     if let Some(expiration) = value.expiration {
         if Timestamp::now() > expiration {
             return true;
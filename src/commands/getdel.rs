@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct GetdelArguments {
+    key: String,
+}
+
+impl GetdelArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidGetDelCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+/// Replies with the string stored at the key and deletes the key, or null if it does not exist
+pub async fn getdel(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let getdel_arguments = GetdelArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let value = match store_guard.get(&getdel_arguments.key) {
+        Some(value) if !is_value_expired(value) => {
+            let DataType::String(ref value) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            value.clone()
+        }
+        _ => return Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+    };
+
+    store_guard.remove(&getdel_arguments.key);
+    drop(store_guard);
+
+    // A string set again under the same key starts out with its regular encoding
+    server
+        .write()
+        .await
+        .raw_string_keys
+        .remove(&getdel_arguments.key);
+
+    Ok(CommandResult::Response(
+        RespValue::BulkString(value).encode(),
+    ))
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct GetsetArguments {
+    key: String,
+    value: String,
+}
+
+impl GetsetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidGetSetCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            value: arguments[1].clone(),
+        })
+    }
+}
+
+pub async fn getset(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let getset_arguments = GetsetArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let previous_value = match store_guard.get(&getset_arguments.key) {
+        Some(value) if !is_value_expired(value) => {
+            let DataType::String(ref previous_value) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            Some(previous_value.clone())
+        }
+        _ => None,
+    };
+
+    // Like SET without KEEPTTL, the new value does not keep the previous expiration
+    store_guard.insert(
+        getset_arguments.key.clone(),
+        Value {
+            data: DataType::String(getset_arguments.value),
+            expiration: None,
+        },
+    );
+    drop(store_guard);
+
+    server
+        .write()
+        .await
+        .raw_string_keys
+        .remove(&getset_arguments.key);
+
+    match previous_value {
+        Some(previous_value) => Ok(CommandResult::Response(
+            RespValue::BulkString(previous_value).encode(),
+        )),
+        None => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+    }
+}
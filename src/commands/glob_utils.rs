@@ -0,0 +1,43 @@
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::commands::CommandError;
+
+/// Compiles a glob-style pattern as used by KEYS. A backslash escapes the
+/// character that follows it, so metacharacters can be matched literally.
+pub fn compile_glob_pattern(pattern: &str) -> Result<GlobMatcher, CommandError> {
+    let glob = GlobBuilder::new(pattern)
+        .backslash_escape(true)
+        .build()
+        .map_err(|e| CommandError::InvalidGlobPattern(e.to_string()))?;
+
+    Ok(glob.compile_matcher())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile_glob_pattern;
+
+    #[test]
+    fn test_compile_glob_pattern() {
+        let test_cases = vec![
+            ("*", "mango", true),
+            ("m?ngo", "mango", true),
+            ("m[aeiou]ngo", "mango", true),
+            ("m[^a]ngo", "mango", false),
+            ("m\\*ngo", "m*ngo", true),
+            ("m\\*ngo", "mango", false),
+        ];
+
+        for (pattern, string, expected) in test_cases {
+            assert_eq!(
+                compile_glob_pattern(pattern).unwrap().is_match(string),
+                expected,
+                "matching {} against {}",
+                string,
+                pattern
+            );
+        }
+
+        assert!(compile_glob_pattern("[a-").is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct HgetArguments {
+    key: String,
+    field: String,
+}
+
+impl HgetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidHGetCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            field: arguments[1].clone(),
+        })
+    }
+}
+
+pub async fn hget(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hget_arguments = HgetArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get(&hget_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    let DataType::Hash(ref hash) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    match hash.get(&hget_arguments.field) {
+        Some(field_value) => Ok(CommandResult::Response(
+            RespValue::BulkString(field_value.clone()).encode(),
+        )),
+        None => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+    }
+}
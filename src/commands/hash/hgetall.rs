@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct HgetallArguments {
+    key: String,
+}
+
+impl HgetallArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidHGetAllCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+/// Replies with every field and value of the hash, as a map for RESP3 clients
+/// and as a flat array of alternating fields and values for RESP2 clients
+pub async fn hgetall(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hgetall_arguments = HgetallArguments::parse(arguments)?;
+    let is_resp3_client = server.read().await.resp3_clients.contains(client_address);
+
+    let store_guard = store.lock().await;
+
+    let entries = match store_guard.get(&hgetall_arguments.key) {
+        Some(value) => {
+            let DataType::Hash(ref hash) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            hash.iter()
+                .map(|(field, value)| {
+                    (
+                        RespValue::BulkString(field.clone()),
+                        RespValue::BulkString(value.clone()),
+                    )
+                })
+                .collect::<Vec<(RespValue, RespValue)>>()
+        }
+        None => Vec::new(),
+    };
+
+    if is_resp3_client {
+        return Ok(CommandResult::Response(RespValue::Map(entries).encode()));
+    }
+
+    let response = entries
+        .into_iter()
+        .flat_map(|(field, value)| [field, value])
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct HmgetArguments {
+    key: String,
+    fields: Vec<String>,
+}
+
+impl HmgetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidHMGetCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            fields: arguments[1..].to_vec(),
+        })
+    }
+}
+
+pub async fn hmget(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hmget_arguments = HmgetArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let hash = match store_guard.get(&hmget_arguments.key) {
+        Some(value) => {
+            let DataType::Hash(ref hash) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            Some(hash)
+        }
+        None => None,
+    };
+
+    let response = hmget_arguments
+        .fields
+        .iter()
+        .map(|field| match hash.and_then(|hash| hash.get(field)) {
+            Some(field_value) => RespValue::BulkString(field_value.clone()),
+            None => RespValue::NullBulkString,
+        })
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use rand::seq::{IndexedRandom, IteratorRandom};
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct HrandfieldArguments {
+    key: String,
+    // A positive count asks for distinct fields and a negative one allows repeats
+    count: Option<i64>,
+    with_values: bool,
+}
+
+impl HrandfieldArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() || arguments.len() > 3 {
+            return Err(CommandError::InvalidHRandFieldCommand);
+        }
+
+        let count = match arguments.get(1) {
+            Some(count) => Some(
+                count
+                    .parse::<i64>()
+                    .map_err(|_| CommandError::InvalidHRandFieldCount)?,
+            ),
+            None => None,
+        };
+
+        let with_values = match arguments.get(2) {
+            Some(option) if is_keyword(option, "WITHVALUES") => true,
+            Some(_) => return Err(CommandError::InvalidHRandFieldCommand),
+            None => false,
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            count,
+            with_values,
+        })
+    }
+}
+
+pub async fn hrandfield(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hrandfield_arguments = HrandfieldArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let hash = match store_guard.get(&hrandfield_arguments.key) {
+        Some(value) => {
+            let DataType::Hash(ref hash) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            Some(hash)
+        }
+        None => None,
+    };
+
+    let mut rng = rand::rng();
+
+    let Some(count) = hrandfield_arguments.count else {
+        let field = hash.and_then(|hash| hash.keys().choose(&mut rng));
+
+        return match field {
+            Some(field) => Ok(CommandResult::Response(
+                RespValue::BulkString(field.clone()).encode(),
+            )),
+            None => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+        };
+    };
+
+    let entries: Vec<(&String, &String)> =
+        hash.map(|hash| hash.iter().collect()).unwrap_or_default();
+
+    let chosen_entries: Vec<&(&String, &String)> = if count >= 0 {
+        entries.iter().choose_multiple(&mut rng, count as usize)
+    } else {
+        (0..count.unsigned_abs())
+            .filter_map(|_| entries.choose(&mut rng))
+            .collect()
+    };
+
+    let response = chosen_entries
+        .into_iter()
+        .flat_map(|(field, value)| {
+            let mut items = vec![RespValue::BulkString(field.to_string())];
+
+            if hrandfield_arguments.with_values {
+                items.push(RespValue::BulkString(value.to_string()));
+            }
+
+            items
+        })
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
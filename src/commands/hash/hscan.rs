@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        scan_utils::{ScanOptions, parse_scan_options, scan_elements},
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct HscanArguments {
+    key: String,
+    options: ScanOptions,
+}
+
+impl HscanArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidHScanCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            options: parse_scan_options(&arguments[1..], true)?,
+        })
+    }
+}
+
+/// Replies with the next cursor and the fields visited from the given cursor, each
+/// followed by its value unless NOVALUES is set
+pub async fn hscan(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hscan_arguments = HscanArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let entries: Vec<(&String, &String)> = match store_guard.get(&hscan_arguments.key) {
+        Some(value) => {
+            let DataType::Hash(ref hash) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            hash.iter().collect()
+        }
+        None => Vec::new(),
+    };
+
+    let (next_cursor, entries) = scan_elements(entries, &hscan_arguments.options);
+
+    let items = entries
+        .into_iter()
+        .flat_map(|(field, value)| {
+            let mut items = vec![RespValue::BulkString(field.clone())];
+
+            if !hscan_arguments.options.no_values {
+                items.push(RespValue::BulkString(value.clone()));
+            }
+
+            items
+        })
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(
+        RespValue::Array(vec![
+            RespValue::BulkString(next_cursor.to_string()),
+            RespValue::Array(items),
+        ])
+        .encode(),
+    ))
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    hash::Hash,
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct HsetArguments {
+    key: String,
+    fields: Vec<(String, String)>,
+}
+
+impl HsetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 3 || arguments.len().is_multiple_of(2) {
+            return Err(CommandError::InvalidHSetCommand);
+        }
+
+        let fields = arguments[1..]
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect::<Vec<(String, String)>>();
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            fields,
+        })
+    }
+}
+
+pub async fn hset(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hset_arguments = HsetArguments::parse(arguments)?;
+    let hash_encoding_limits = server.read().await.hash_encoding_limits;
+
+    let mut store_guard = store.lock().await;
+
+    let value = store_guard
+        .entry(hset_arguments.key)
+        .or_insert_with(|| Value {
+            data: DataType::Hash(Hash::new()),
+            expiration: None,
+        });
+
+    let DataType::Hash(ref mut hash) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let mut added_fields_count = 0;
+
+    for (field, field_value) in &hset_arguments.fields {
+        if hash.insert(field.clone(), field_value.clone()).is_none() {
+            added_fields_count += 1;
+        }
+    }
+
+    hash.update_encoding(
+        hset_arguments
+            .fields
+            .iter()
+            .map(|(field, field_value)| (field.as_str(), field_value.as_str())),
+        &hash_encoding_limits,
+    );
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(added_fields_count).encode(),
+    ))
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    hash::Hash,
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct HsetnxArguments {
+    key: String,
+    field: String,
+    value: String,
+}
+
+impl HsetnxArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidHSetNxCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            field: arguments[1].clone(),
+            value: arguments[2].clone(),
+        })
+    }
+}
+
+pub async fn hsetnx(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hsetnx_arguments = HsetnxArguments::parse(arguments)?;
+    let hash_encoding_limits = server.read().await.hash_encoding_limits;
+
+    let mut store_guard = store.lock().await;
+
+    let value = store_guard
+        .entry(hsetnx_arguments.key)
+        .or_insert_with(|| Value {
+            data: DataType::Hash(Hash::new()),
+            expiration: None,
+        });
+
+    let DataType::Hash(ref mut hash) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    if hash.contains_key(&hsetnx_arguments.field) {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    }
+
+    hash.insert(
+        hsetnx_arguments.field.clone(),
+        hsetnx_arguments.value.clone(),
+    );
+    hash.update_encoding(
+        [(
+            hsetnx_arguments.field.as_str(),
+            hsetnx_arguments.value.as_str(),
+        )],
+        &hash_encoding_limits,
+    );
+
+    Ok(CommandResult::Response(RespValue::Integer(1).encode()))
+}
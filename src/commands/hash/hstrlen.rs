@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct HstrlenArguments {
+    key: String,
+    field: String,
+}
+
+impl HstrlenArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidHStrLenCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            field: arguments[1].clone(),
+        })
+    }
+}
+
+pub async fn hstrlen(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hstrlen_arguments = HstrlenArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get(&hstrlen_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    };
+
+    let DataType::Hash(ref hash) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let length = hash
+        .get(&hstrlen_arguments.field)
+        .map(|field_value| field_value.len())
+        .unwrap_or(0);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(length as i64).encode(),
+    ))
+}
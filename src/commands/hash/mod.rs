@@ -0,0 +1,17 @@
+mod hget;
+mod hgetall;
+mod hmget;
+mod hrandfield;
+mod hscan;
+mod hset;
+mod hsetnx;
+mod hstrlen;
+
+pub use hget::{HgetArguments, hget};
+pub use hgetall::{HgetallArguments, hgetall};
+pub use hmget::{HmgetArguments, hmget};
+pub use hrandfield::{HrandfieldArguments, hrandfield};
+pub use hscan::{HscanArguments, hscan};
+pub use hset::{HsetArguments, hset};
+pub use hsetnx::{HsetnxArguments, hsetnx};
+pub use hstrlen::{HstrlenArguments, hstrlen};
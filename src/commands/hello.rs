@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+    },
+    resp::RespValue,
+    server::{REDIS_VERSION, RedisRole, RedisServer},
+};
+
+pub struct HelloArguments {
+    protocol_version: Option<u32>,
+    credentials: Option<(String, String)>,
+}
+
+impl HelloArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        let Some(protocol_version) = arguments.first() else {
+            return Ok(Self {
+                protocol_version: None,
+                credentials: None,
+            });
+        };
+
+        let protocol_version = protocol_version
+            .parse::<u32>()
+            .map_err(|_| CommandError::InvalidHelloCommand)?;
+
+        if protocol_version != 2 && protocol_version != 3 {
+            return Err(CommandError::UnsupportedProtocolVersion);
+        }
+
+        let credentials = match &arguments[1..] {
+            [] => None,
+            [option, username, password] if is_keyword(option, "AUTH") => {
+                Some((username.clone(), password.clone()))
+            }
+            _ => return Err(CommandError::InvalidHelloCommand),
+        };
+
+        Ok(Self {
+            protocol_version: Some(protocol_version),
+            credentials,
+        })
+    }
+}
+
+pub async fn hello(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let hello_arguments = HelloArguments::parse(arguments)?;
+    let mut server_guard = server.write().await;
+
+    // A failed authentication leaves the connection on its current protocol
+    if let Some((username, password)) = &hello_arguments.credentials {
+        if username != "default" || !server_guard.is_default_user_password(password) {
+            return Err(CommandError::WrongPassword);
+        }
+
+        server_guard
+            .register_client(client_address)
+            .is_authenticated = true;
+    }
+
+    match hello_arguments.protocol_version {
+        Some(3) => {
+            server_guard
+                .resp3_clients
+                .insert(client_address.to_string());
+        }
+        Some(_) => {
+            server_guard.resp3_clients.remove(client_address);
+        }
+        None => (),
+    }
+
+    let is_resp3_client = server_guard.resp3_clients.contains(client_address);
+    let role = match server_guard.role {
+        RedisRole::Master => "master",
+        RedisRole::Replica(_) => "replica",
+    };
+
+    let entries = vec![
+        (
+            RespValue::BulkString("server".to_string()),
+            RespValue::BulkString("redis".to_string()),
+        ),
+        (
+            RespValue::BulkString("version".to_string()),
+            RespValue::BulkString(REDIS_VERSION.to_string()),
+        ),
+        (
+            RespValue::BulkString("proto".to_string()),
+            RespValue::Integer(if is_resp3_client { 3 } else { 2 }),
+        ),
+        (
+            RespValue::BulkString("mode".to_string()),
+            RespValue::BulkString("standalone".to_string()),
+        ),
+        (
+            RespValue::BulkString("role".to_string()),
+            RespValue::BulkString(role.to_string()),
+        ),
+        (
+            RespValue::BulkString("modules".to_string()),
+            RespValue::Array(Vec::new()),
+        ),
+    ];
+
+    if is_resp3_client {
+        return Ok(CommandResult::Response(RespValue::Map(entries).encode()));
+    }
+
+    let flattened_entries = entries
+        .into_iter()
+        .flat_map(|(key, value)| [key, value])
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(
+        RespValue::Array(flattened_entries).encode(),
+    ))
+}
@@ -0,0 +1,194 @@
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    resp::RespValue,
+};
+
+const CLIENT_SUBCOMMANDS: &[(&str, &str)] = &[
+    (
+        "INFO",
+        "Return information about the current client connection.",
+    ),
+    (
+        "NO-EVICT (ON|OFF)",
+        "Protect the current client connection from eviction in case of OOM, when turned on.",
+    ),
+    (
+        "NO-TOUCH (ON|OFF)",
+        "Will not touch LRU/LFU stats when this mode is on.",
+    ),
+    (
+        "TRACKING (ON|OFF) [REDIRECT <id>]",
+        "Control server assisted client side caching.",
+    ),
+];
+
+const CONFIG_SUBCOMMANDS: &[(&str, &str)] = &[(
+    "GET <pattern>",
+    "Return parameters matching the glob-like <pattern> and their values.",
+)];
+
+const LATENCY_SUBCOMMANDS: &[(&str, &str)] = &[
+    (
+        "HISTORY <event>",
+        "Return time-latency samples for the <event> class.",
+    ),
+    (
+        "LATEST",
+        "Return the latest latency samples for all events.",
+    ),
+    (
+        "RESET [<event> ...]",
+        "Reset latency data of one or more <event> classes. (default: reset all data for all event classes)",
+    ),
+];
+
+const MEMORY_SUBCOMMANDS: &[(&str, &str)] = &[
+    ("DOCTOR", "Return memory problems reports."),
+    (
+        "STATS",
+        "Return information about the memory usage of the server.",
+    ),
+    (
+        "USAGE <key> [SAMPLES <count>]",
+        "Return memory in bytes used by <key> and its value. Nested values are sampled up to <count> times (default: 5, 0 means sample all).",
+    ),
+];
+
+const OBJECT_SUBCOMMANDS: &[(&str, &str)] = &[
+    (
+        "ENCODING <key>",
+        "Return the kind of internal representation used in order to store the value associated with a <key>.",
+    ),
+    (
+        "IDLETIME <key>",
+        "Return the idle time of the <key>, that is the approximated number of seconds elapsed since the last access to the key.",
+    ),
+];
+
+const DEBUG_SUBCOMMANDS: &[(&str, &str)] = &[
+    (
+        "CHANGE-REPL-ID",
+        "Change the replication IDs of the instance. Dangerous, should be used only for testing the replication subsystem.",
+    ),
+    (
+        "DEBUG <seconds>",
+        "Stop the server for <seconds> before replying, like SLEEP. Decimals allowed.",
+    ),
+    (
+        "LISTPACK <key>",
+        "Show low level info about the listpack encoding of <key>.",
+    ),
+    (
+        "LISTPACK-ENTRIES",
+        "Check the integrity of listpack entries. Always replies OK.",
+    ),
+    (
+        "OBJECT <key>",
+        "Show low level info about the <key> and associated value.",
+    ),
+    (
+        "RELOAD",
+        "Save the RDB on disk and reload it back to memory.",
+    ),
+    (
+        "SET-ACTIVE-EXPIRE <0|1>",
+        "Setting it to 0 disables expiring keys in background when they are not accessed.",
+    ),
+    (
+        "SET-STREAM-MAX-ENTRIES <count>",
+        "Set the number of entries per stream node that approximate (~) trimming removes at once. 1 makes it exact.",
+    ),
+    (
+        "SLEEP <seconds>",
+        "Stop the server for <seconds>. Decimals allowed.",
+    ),
+    (
+        "STREAM <key>",
+        "Show the number of entries, first and last IDs and radix tree size of the stream at <key>.",
+    ),
+    (
+        "STRINGMATCH-LEN <pattern> <string>",
+        "Return 1 if <string> matches the glob-style <pattern>, 0 otherwise.",
+    ),
+];
+
+const SLOWLOG_SUBCOMMANDS: &[(&str, &str)] = &[
+    (
+        "GET [<count>]",
+        "Return top <count> entries from the slowlog (default: 10, -1 mean all).",
+    ),
+    ("LEN", "Return the length of the slowlog."),
+    ("RESET", "Reset the slowlog."),
+];
+
+pub struct HelpArguments;
+
+impl HelpArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidHelpCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+/// Replies to `<FAMILY> HELP`, where `command_name` is the full command name (e.g. `CONFIG HELP`)
+pub fn help(command_name: &str, arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    HelpArguments::parse(arguments)?;
+
+    let family = command_name.split(' ').next().unwrap_or_default();
+
+    let subcommands = match family {
+        "CLIENT" => CLIENT_SUBCOMMANDS,
+        "CONFIG" => CONFIG_SUBCOMMANDS,
+        "DEBUG" => DEBUG_SUBCOMMANDS,
+        "LATENCY" => LATENCY_SUBCOMMANDS,
+        "MEMORY" => MEMORY_SUBCOMMANDS,
+        "OBJECT" => OBJECT_SUBCOMMANDS,
+        "SLOWLOG" => SLOWLOG_SUBCOMMANDS,
+        _ => return Err(CommandError::InvalidHelpCommand),
+    };
+
+    Ok(CommandResult::Response(
+        RespValue::encode_array_from_strings(help_lines(family, subcommands)),
+    ))
+}
+
+/// Builds the help text for a subcommand family: a usage line followed by
+/// each subcommand and its indented description, ending with HELP itself.
+pub fn help_lines(family: &str, subcommands: &[(&str, &str)]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{} <subcommand> [<arg> [value] [opt] ...]. Subcommands are:",
+        family
+    )];
+
+    for (usage, description) in subcommands
+        .iter()
+        .chain([("HELP", "Print this help.")].iter())
+    {
+        lines.push(usage.to_string());
+        lines.push(format!("    {}", description));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::help_lines;
+
+    #[test]
+    fn test_help_lines() {
+        assert_eq!(
+            help_lines("OBJECT", &[("FREQ <key>", "Return the access frequency.")]),
+            vec![
+                "OBJECT <subcommand> [<arg> [value] [opt] ...]. Subcommands are:".to_string(),
+                "FREQ <key>".to_string(),
+                "    Return the access frequency.".to_string(),
+                "HELP".to_string(),
+                "    Print this help.".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,22 @@
+mod pfadd;
+mod pfcount;
+mod pfmerge;
+
+pub use pfadd::{PfaddArguments, pfadd};
+pub use pfcount::{PfcountArguments, pfcount};
+pub use pfmerge::{PfmergeArguments, pfmerge};
+
+use crate::{
+    commands::command_error::CommandError,
+    hyperloglog::HyperLogLog,
+    key_value_store::{DataType, Value},
+};
+
+/// HyperLogLogs are stored as strings, so any other data type or string encoding is rejected
+fn hyperloglog_from_value(value: &Value) -> Result<HyperLogLog, CommandError> {
+    let DataType::String(ref encoded) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    HyperLogLog::from_encoded(encoded).ok_or(CommandError::InvalidHyperLogLogValue)
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult,
+        hyperloglog::hyperloglog_from_value,
+    },
+    hyperloglog::HyperLogLog,
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+};
+
+pub struct PfaddArguments {
+    key: String,
+    elements: Vec<String>,
+}
+
+impl PfaddArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return Err(CommandError::InvalidPfAddCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            elements: arguments[1..].to_vec(),
+        })
+    }
+}
+
+pub async fn pfadd(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let pfadd_arguments = PfaddArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let (mut hyperloglog, mut was_updated) = match store_guard.get(&pfadd_arguments.key) {
+        Some(value) => (hyperloglog_from_value(value)?, false),
+        None => (HyperLogLog::new(), true),
+    };
+
+    for element in &pfadd_arguments.elements {
+        if hyperloglog.add(element.as_bytes()) {
+            was_updated = true;
+        }
+    }
+
+    if was_updated {
+        let expiration = store_guard
+            .get(&pfadd_arguments.key)
+            .and_then(|value| value.expiration);
+
+        store_guard.insert(
+            pfadd_arguments.key,
+            Value {
+                data: DataType::String(hyperloglog.encode()),
+                expiration,
+            },
+        );
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(was_updated as i64).encode(),
+    ))
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult,
+        hyperloglog::hyperloglog_from_value,
+    },
+    hyperloglog::HyperLogLog,
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+};
+
+pub struct PfcountArguments {
+    keys: Vec<String>,
+}
+
+impl PfcountArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return Err(CommandError::InvalidPfCountCommand);
+        }
+
+        Ok(Self { keys: arguments })
+    }
+}
+
+pub async fn pfcount(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let pfcount_arguments = PfcountArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    // Counting multiple keys estimates the cardinality of their union
+    let mut merged_hyperloglog = HyperLogLog::new();
+
+    for key in &pfcount_arguments.keys {
+        if let Some(value) = store_guard.get(key) {
+            merged_hyperloglog.merge(&hyperloglog_from_value(value)?);
+        }
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(merged_hyperloglog.count() as i64).encode(),
+    ))
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult,
+        hyperloglog::hyperloglog_from_value,
+    },
+    hyperloglog::HyperLogLog,
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+};
+
+pub struct PfmergeArguments {
+    destination_key: String,
+    source_keys: Vec<String>,
+}
+
+impl PfmergeArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidPfMergeCommand);
+        }
+
+        Ok(Self {
+            destination_key: arguments[0].clone(),
+            source_keys: arguments[1..].to_vec(),
+        })
+    }
+}
+
+pub async fn pfmerge(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let pfmerge_arguments = PfmergeArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    // The destination is part of the union as well, so merging into an existing HLL keeps its elements
+    let (mut merged_hyperloglog, expiration) =
+        match store_guard.get(&pfmerge_arguments.destination_key) {
+            Some(value) => (hyperloglog_from_value(value)?, value.expiration),
+            None => (HyperLogLog::new(), None),
+        };
+
+    for key in &pfmerge_arguments.source_keys {
+        if let Some(value) = store_guard.get(key) {
+            merged_hyperloglog.merge(&hyperloglog_from_value(value)?);
+        }
+    }
+
+    store_guard.insert(
+        pfmerge_arguments.destination_key,
+        Value {
+            data: DataType::String(merged_hyperloglog.encode()),
+            expiration,
+        },
+    );
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{
     commands::{CommandError, command_handler::CommandResult},
     key_value_store::{DataType, KeyValueStore, Value},
     resp::RespValue,
+    server::RedisServer,
 };
 
 pub struct IncrArguments {
@@ -25,36 +26,58 @@ impl IncrArguments {
 }
 
 pub async fn incr(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
     let incr_arguments = IncrArguments::parse(arguments)?;
 
-    let mut store_guard = store.lock().await;
+    let incremented_int = {
+        let mut store_guard = store.lock().await;
 
-    let Some(value) = store_guard.get_mut(&incr_arguments.key) else {
-        store_guard.insert(
-            incr_arguments.key,
-            Value {
-                data: DataType::String("1".to_string()),
-                expiration: None,
+        match store_guard.get_mut(&incr_arguments.key) {
+            Some(value) => match value.data {
+                DataType::String(ref mut stored_data) => {
+                    let incremented_int = parse_canonical_integer(stored_data)
+                        .and_then(|int| int.checked_add(1))
+                        .ok_or(CommandError::InvalidIncrValue)?;
+                    *stored_data = incremented_int.to_string();
+
+                    incremented_int
+                }
+                _ => return Err(CommandError::WrongType),
             },
-        );
-        return Ok(CommandResult::Response(RespValue::Integer(1).encode()));
-    };
+            None => {
+                store_guard.insert(
+                    incr_arguments.key.clone(),
+                    Value {
+                        data: DataType::String("1".to_string()),
+                        expiration: None,
+                    },
+                );
 
-    match value.data {
-        DataType::String(ref mut stored_data) => {
-            let int = stored_data
-                .parse::<i64>()
-                .map_err(|_| CommandError::InvalidIncrValue)?;
-            let incremented_int = int + 1;
-            *stored_data = incremented_int.to_string();
-
-            Ok(CommandResult::Response(
-                RespValue::Integer(incremented_int).encode(),
-            ))
+                1
+            }
         }
-        _ => return Err(CommandError::InvalidDataTypeForKey),
-    }
+    };
+
+    // The incremented value replaces the string, so it is no longer raw
+    server
+        .write()
+        .await
+        .raw_string_keys
+        .remove(&incr_arguments.key);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(incremented_int).encode(),
+    ))
+}
+
+/// Parses a base-10 integer the way Redis does: without whitespace, a `+` sign or
+/// leading zeros, so only strings that round trip through an i64 unchanged are accepted
+fn parse_canonical_integer(string: &str) -> Option<i64> {
+    string
+        .parse::<i64>()
+        .ok()
+        .filter(|int| int.to_string() == string)
 }
@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{
-    commands::{CommandError, command_handler::CommandResult},
+    commands::{
+        CommandError, command_handler::CommandResult, is_value_expired,
+        keyword_utils::normalize_keyword,
+    },
+    key_value_store::KeyValueStore,
     resp::RespValue,
     server::RedisServer,
 };
@@ -11,6 +15,9 @@ use crate::{
 enum InfoSection {
     DEFAULT,
     REPLICATION,
+    STATS,
+    PERSISTENCE,
+    KEYSPACE,
 }
 
 pub struct InfoArguments {
@@ -29,8 +36,11 @@ impl InfoArguments {
             });
         }
 
-        let section = match arguments[0].as_str() {
-            "replication" => InfoSection::REPLICATION,
+        let section = match normalize_keyword(&arguments[0]).as_str() {
+            "REPLICATION" => InfoSection::REPLICATION,
+            "STATS" => InfoSection::STATS,
+            "PERSISTENCE" => InfoSection::PERSISTENCE,
+            "KEYSPACE" => InfoSection::KEYSPACE,
             _ => return Err(CommandError::InvalidInfoSection),
         };
 
@@ -40,10 +50,13 @@ impl InfoArguments {
 
 pub async fn info(
     server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
     let info_arguments = InfoArguments::parse(arguments)?;
 
+    let keyspace = keyspace_info(store).await;
+
     let server_guard = server.read().await;
     let server_role = server_guard.role.as_string();
 
@@ -51,13 +64,54 @@ pub async fn info(
 
     if server_role == "master" {
         replication.push(format!("role:{}", server_role));
-        replication.push(format!("connected_slaves:{}", 0));
+        replication.push(format!(
+            "connected_slaves:{}",
+            server_guard
+                .replicas
+                .as_ref()
+                .map_or(0, |replicas| replicas.len())
+        ));
         replication.push(format!("master_replid:{}", server_guard.repl_id));
         replication.push(format!("master_repl_offset:{}", server_guard.repl_offset));
     } else {
         replication.push(format!("role:{}", server_role));
     }
 
+    let stats = [
+        format!(
+            "total_connections_received:{}",
+            server_guard.stats.total_connections_received()
+        ),
+        format!(
+            "total_commands_processed:{}",
+            server_guard.stats.total_commands_processed()
+        ),
+        format!("keyspace_hits:{}", server_guard.stats.keyspace_hits()),
+        format!("keyspace_misses:{}", server_guard.stats.keyspace_misses()),
+        format!("expired_keys:{}", server_guard.stats.expired_keys()),
+    ];
+
+    // Commands are only served once the RDB or AOF file has been loaded
+    let persistence = [
+        "loading:0".to_string(),
+        format!(
+            "rdb_changes_since_last_save:{}",
+            server_guard.stats.changes_since_last_save()
+        ),
+        format!(
+            "rdb_last_save_time:{}",
+            server_guard.last_save_time.as_second()
+        ),
+        format!("aof_enabled:{}", server_guard.appendonly as u8),
+        format!(
+            "aof_last_bgrewrite_status:{}",
+            match server_guard.is_last_aof_rewrite_ok {
+                true => "ok",
+                false => "err",
+            }
+        ),
+    ];
+
     match info_arguments.section {
         InfoSection::DEFAULT => Ok(CommandResult::Response(
             RespValue::BulkString(replication.join("\r\n")).encode(),
@@ -65,5 +119,32 @@ pub async fn info(
         InfoSection::REPLICATION => Ok(CommandResult::Response(
             RespValue::BulkString(replication.join("\r\n")).encode(),
         )),
+        InfoSection::STATS => Ok(CommandResult::Response(
+            RespValue::BulkString(stats.join("\r\n")).encode(),
+        )),
+        InfoSection::PERSISTENCE => Ok(CommandResult::Response(
+            RespValue::BulkString(persistence.join("\r\n")).encode(),
+        )),
+        InfoSection::KEYSPACE => Ok(CommandResult::Response(
+            RespValue::BulkString(keyspace.join("\r\n")).encode(),
+        )),
+    }
+}
+
+/// One `dbN:keys=X,expires=Y,avg_ttl=0` line per non-empty database. The store
+/// only holds database 0, so there is at most one line.
+async fn keyspace_info(store: Arc<Mutex<KeyValueStore>>) -> Vec<String> {
+    let store_guard = store.lock().await;
+
+    let (keys, expires) = store_guard
+        .values()
+        .filter(|value| !is_value_expired(value))
+        .fold((0, 0), |(keys, expires), value| {
+            (keys + 1, expires + value.expiration.is_some() as usize)
+        });
+
+    match keys {
+        0 => Vec::new(),
+        _ => vec![format!("db0:keys={},expires={},avg_ttl=0", keys, expires)],
     }
 }
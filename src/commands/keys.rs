@@ -1,10 +1,9 @@
 use std::sync::Arc;
 
-use globset::Glob;
 use tokio::sync::Mutex;
 
 use crate::{
-    commands::{CommandError, CommandResult},
+    commands::{CommandError, CommandResult, glob_utils::compile_glob_pattern},
     key_value_store::KeyValueStore,
     resp::RespValue,
 };
@@ -32,9 +31,7 @@ pub async fn keys(
     let keys_arguments = KeysArguments::parse(arguments)?;
     let store_guard = store.lock().await;
 
-    let glob = Glob::new(&keys_arguments.key)
-        .map_err(|e| CommandError::InvalidGlobPattern(e.to_string()))?
-        .compile_matcher();
+    let glob = compile_glob_pattern(&keys_arguments.key)?;
     let mut response = Vec::new();
 
     for key in store_guard.keys() {
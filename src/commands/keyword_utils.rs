@@ -0,0 +1,45 @@
+/// Keyword options such as `PX`, `WITHSCORES` or `STREAMS` are case-insensitive in Redis,
+/// so arguments are uppercased before being matched against them
+pub fn normalize_keyword(argument: &str) -> String {
+    argument.to_ascii_uppercase()
+}
+
+/// Whether the argument is the given uppercase keyword, written in any case
+pub fn is_keyword(argument: &str, keyword: &str) -> bool {
+    argument.eq_ignore_ascii_case(keyword)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_keyword, normalize_keyword};
+
+    #[test]
+    fn test_normalize_keyword() {
+        let test_cases = vec![
+            ("px", "PX"),
+            ("WithScores", "WITHSCORES"),
+            ("STREAMS", "STREAMS"),
+            ("listening-port", "LISTENING-PORT"),
+        ];
+
+        for (argument, expected_keyword) in test_cases {
+            assert_eq!(normalize_keyword(argument), expected_keyword);
+        }
+    }
+
+    #[test]
+    fn test_is_keyword() {
+        let test_cases = vec![
+            ("limit", "LIMIT", true),
+            ("LiMiT", "LIMIT", true),
+            ("LIMIT", "LIMIT", true),
+            ("LIMITS", "LIMIT", false),
+            // Only ASCII letters are folded, so look-alike characters never match
+            ("ſamples", "SAMPLES", false),
+        ];
+
+        for (argument, keyword, expected_result) in test_cases {
+            assert_eq!(is_keyword(argument, keyword), expected_result);
+        }
+    }
+}
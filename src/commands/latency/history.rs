@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct LatencyHistoryArguments {
+    event: String,
+}
+
+impl LatencyHistoryArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidLatencyCommand);
+        }
+
+        Ok(Self {
+            event: arguments[0].clone(),
+        })
+    }
+}
+
+/// Replies with the spikes of an event from the oldest to the newest one, each one being
+/// an array of its unix time and its latency in milliseconds
+pub async fn latency_history(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let latency_history_arguments = LatencyHistoryArguments::parse(arguments)?;
+
+    let server_guard = server.read().await;
+    let samples = server_guard
+        .latency_monitor
+        .history(&latency_history_arguments.event)
+        .into_iter()
+        .map(|sample| {
+            RespValue::Array(vec![
+                RespValue::Integer(sample.timestamp),
+                RespValue::Integer(sample.latency_ms as i64),
+            ])
+        })
+        .collect();
+
+    Ok(CommandResult::Response(RespValue::Array(samples).encode()))
+}
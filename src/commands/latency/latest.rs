@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct LatencyLatestArguments;
+
+impl LatencyLatestArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidLatencyCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+/// Replies with an array per event holding its name, the unix time and latency in
+/// milliseconds of its latest spike, and its all-time maximum latency
+pub async fn latency_latest(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    LatencyLatestArguments::parse(arguments)?;
+
+    let server_guard = server.read().await;
+    let events = server_guard
+        .latency_monitor
+        .latest()
+        .into_iter()
+        .filter_map(|(name, event)| {
+            let latest = event.latest()?;
+
+            Some(RespValue::Array(vec![
+                RespValue::BulkString(name.to_string()),
+                RespValue::Integer(latest.timestamp),
+                RespValue::Integer(latest.latency_ms as i64),
+                RespValue::Integer(event.max_latency_ms() as i64),
+            ]))
+        })
+        .collect();
+
+    Ok(CommandResult::Response(RespValue::Array(events).encode()))
+}
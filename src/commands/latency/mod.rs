@@ -0,0 +1,7 @@
+mod history;
+mod latest;
+mod reset;
+
+pub use history::{LatencyHistoryArguments, latency_history};
+pub use latest::{LatencyLatestArguments, latency_latest};
+pub use reset::{LatencyResetArguments, latency_reset};
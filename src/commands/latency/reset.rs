@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct LatencyResetArguments {
+    // Every event is reset when empty
+    events: Vec<String>,
+}
+
+impl LatencyResetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        Ok(Self { events: arguments })
+    }
+}
+
+/// Drops the spikes of the given events, or of every event, replying with the number
+/// of events that were reset
+pub async fn latency_reset(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let latency_reset_arguments = LatencyResetArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    let number_of_events = server_guard
+        .latency_monitor
+        .reset(&latency_reset_arguments.events);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(number_of_events as i64).encode(),
+    ))
+}
@@ -37,11 +37,11 @@ pub async fn llen(
         return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
     };
 
-    if let DataType::Array(ref list) = value.data {
-        return Ok(CommandResult::Response(
-            RespValue::Integer(list.len() as i64).encode(),
-        ));
-    } else {
-        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
-    }
+    let DataType::Array(ref list) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(list.len() as i64).encode(),
+    ))
 }
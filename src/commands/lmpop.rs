@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+        keyword_utils::normalize_keyword,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct LmpopArguments {
+    keys: Vec<String>,
+    from_left: bool,
+    count: usize,
+}
+
+impl LmpopArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 3 {
+            return Err(CommandError::InvalidLMPopCommand);
+        }
+
+        let number_of_keys = match arguments[0].parse::<i64>() {
+            Ok(number_of_keys) if number_of_keys > 0 => number_of_keys as usize,
+            _ => return Err(CommandError::InvalidLMPopNumberOfKeys),
+        };
+
+        let Some(keys) = arguments.get(1..1 + number_of_keys) else {
+            return Err(CommandError::LMPopNumberOfKeysMismatch);
+        };
+
+        let Some(direction) = arguments.get(1 + number_of_keys) else {
+            return Err(CommandError::InvalidLMPopCommand);
+        };
+
+        let from_left = match normalize_keyword(direction).as_str() {
+            "LEFT" => true,
+            "RIGHT" => false,
+            _ => return Err(CommandError::InvalidLMPopCommand),
+        };
+
+        let count = match &arguments[2 + number_of_keys..] {
+            [] => 1,
+            [option, count] if is_keyword(option, "COUNT") => match count.parse::<i64>() {
+                Ok(count) if count > 0 => count as usize,
+                _ => return Err(CommandError::InvalidLMPopCount),
+            },
+            _ => return Err(CommandError::InvalidLMPopCommand),
+        };
+
+        Ok(Self {
+            keys: keys.to_vec(),
+            from_left,
+            count,
+        })
+    }
+}
+
+/// Pops up to `count` elements from the first of the keys holding a non-empty list,
+/// replying with that key and the popped elements, or with a null array when every
+/// list is empty
+pub async fn lmpop(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let lmpop_arguments = LmpopArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    for key in &lmpop_arguments.keys {
+        let Some(value) = store_guard.get_mut(key) else {
+            continue;
+        };
+
+        let DataType::Array(ref mut list) = value.data else {
+            return Err(CommandError::WrongType);
+        };
+
+        let count = lmpop_arguments.count.min(list.len());
+        let popped_elements = match lmpop_arguments.from_left {
+            true => list.drain(..count).collect::<Vec<String>>(),
+            false => {
+                let start = list.len() - count;
+                list.drain(start..).rev().collect::<Vec<String>>()
+            }
+        };
+
+        if list.is_empty() {
+            store_guard.remove(key);
+        }
+
+        return Ok(CommandResult::Response(
+            RespValue::Array(vec![
+                RespValue::BulkString(key.clone()),
+                RespValue::Array(
+                    popped_elements
+                        .into_iter()
+                        .map(RespValue::BulkString)
+                        .collect(),
+                ),
+            ])
+            .encode(),
+        ));
+    }
+
+    Ok(CommandResult::Response(RespValue::NullArray.encode()))
+}
@@ -0,0 +1,51 @@
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+    },
+    resp::RespValue,
+    server::REDIS_VERSION,
+};
+
+pub struct LolwutArguments {
+    version: Option<u32>,
+}
+
+impl LolwutArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        match arguments.len() {
+            0 => Ok(Self { version: None }),
+            2 => {
+                if !is_keyword(&arguments[0], "VERSION") {
+                    return Err(CommandError::InvalidLolwutCommandOption);
+                }
+
+                let version = arguments[1]
+                    .parse::<u32>()
+                    .map_err(|_| CommandError::InvalidLolwutCommandArgument)?;
+
+                Ok(Self {
+                    version: Some(version),
+                })
+            }
+            _ => Err(CommandError::InvalidLolwutCommand),
+        }
+    }
+}
+
+pub fn lolwut(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    let lolwut_arguments = LolwutArguments::parse(arguments)?;
+
+    let banner = match lolwut_arguments.version {
+        Some(version) => format!("LOLWUT version {}\n", version),
+        None => String::new(),
+    };
+
+    let output = format!(
+        "{} _____          _ _\n|  __ \\        | (_)\n| |__) |___  __| |_ ___\n|  _  // _ \\/ _` | / __|\n| | \\ \\  __/ (_| | \\__ \\\n|_|  \\_\\___|\\__,_|_|___/\n\nRedis ver. {}\n",
+        banner, REDIS_VERSION
+    );
+
+    Ok(CommandResult::Response(
+        RespValue::BulkString(output).encode(),
+    ))
+}
@@ -10,7 +10,7 @@ use crate::{
 
 pub struct LpopArguments {
     key: String,
-    count: usize,
+    count: Option<usize>,
 }
 
 impl LpopArguments {
@@ -19,11 +19,12 @@ impl LpopArguments {
             return Err(CommandError::InvalidLPopCommand);
         }
 
-        let count = if let Some(val) = arguments.get(1) {
-            val.parse::<usize>()
-                .map_err(|_| CommandError::InvalidLPopCommandArgument)?
-        } else {
-            1
+        let count = match arguments.get(1) {
+            Some(val) => Some(
+                val.parse::<usize>()
+                    .map_err(|_| CommandError::InvalidLPopCommandArgument)?,
+            ),
+            None => None,
         };
 
         Ok(Self {
@@ -41,29 +42,44 @@ pub async fn lpop(
 
     let mut store_guard = store.lock().await;
 
+    // Without a count a single bulk string is returned, with a count the reply is always an array
+    let empty_response = match lpop_arguments.count {
+        Some(_) => RespValue::Array(Vec::new()).encode(),
+        None => RespValue::NullBulkString.encode(),
+    };
+
     let Some(value) = store_guard.get_mut(&lpop_arguments.key) else {
-        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+        return Ok(CommandResult::Response(empty_response));
     };
 
     let DataType::Array(ref mut list) = value.data else {
-        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+        return Err(CommandError::WrongType);
     };
 
-    let mut vec = Vec::new();
-
-    for _ in 0..lpop_arguments.count {
-        if let Some(removed) = list.pop_front() {
-            vec.push(removed);
+    let removed = match lpop_arguments.count {
+        Some(count) => {
+            let count = count.min(list.len());
+            list.drain(..count).collect::<Vec<String>>()
         }
+        None => list.pop_front().into_iter().collect::<Vec<String>>(),
+    };
+
+    // Redis never keeps an empty list around
+    if list.is_empty() {
+        store_guard.remove(&lpop_arguments.key);
+    }
+
+    if removed.is_empty() {
+        return Ok(CommandResult::Response(empty_response));
     }
 
-    match vec.len() {
-        0 => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
-        1 => Ok(CommandResult::Response(
-            RespValue::BulkString(vec[0].clone()).encode(),
-        )),
-        _ => Ok(CommandResult::Response(
-            RespValue::encode_array_from_strings(vec),
-        )),
+    if lpop_arguments.count.is_none() {
+        return Ok(CommandResult::Response(
+            RespValue::BulkString(removed[0].clone()).encode(),
+        ));
     }
+
+    Ok(CommandResult::Response(
+        RespValue::encode_array_from_strings(removed),
+    ))
 }
@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::sync::Arc;
 
 use tokio::sync::Mutex;
 
@@ -51,13 +51,11 @@ pub async fn lrange(
     };
 
     let DataType::Array(ref list) = value.data else {
-        return Ok(CommandResult::Response(
-            RespValue::Array(Vec::new()).encode(),
-        ));
+        return Err(CommandError::WrongType);
     };
 
     let Ok((start, end)) = validate_range_indexes(
-        list,
+        list.len(),
         lrange_arguments.start_index,
         lrange_arguments.end_index,
     ) else {
@@ -82,12 +80,12 @@ pub async fn lrange(
     }
 }
 
-fn validate_range_indexes(
-    list: &VecDeque<String>,
+pub fn validate_range_indexes(
+    len: usize,
     start_index: isize,
     end_index: isize,
-) -> Result<(usize, usize), &str> {
-    let len = list.len() as isize;
+) -> Result<(usize, usize), &'static str> {
+    let len = len as isize;
 
     if len == 0 {
         return Err("List is empty");
@@ -125,7 +123,7 @@ mod tests {
 
     #[test]
     fn test_validate_indexes() {
-        let list = VecDeque::from([
+        let list: VecDeque<String> = VecDeque::from([
             "grape".into(),
             "apple".into(),
             "pineapple".into(),
@@ -159,7 +157,7 @@ mod tests {
 
         for (start_index, end_index, expected) in test_cases {
             assert_eq!(
-                validate_range_indexes(&list, start_index, end_index),
+                validate_range_indexes(list.len(), start_index, end_index),
                 expected,
                 "validating start index {} and end index {}",
                 start_index,
@@ -168,9 +166,6 @@ mod tests {
         }
 
         // Validation for empty list
-        assert_eq!(
-            validate_range_indexes(&VecDeque::new(), 0, 2),
-            Err("List is empty")
-        );
+        assert_eq!(validate_range_indexes(0, 0, 2), Err("List is empty"));
     }
 }
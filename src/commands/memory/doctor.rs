@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        memory::{stats::KeyspaceMemory, usage::value_size},
+    },
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+};
+
+// Below this size Redis considers the instance too empty to diagnose
+const MEMORY_DOCTOR_MIN_DATASET_BYTES: usize = 5 * 1024 * 1024;
+// Share of the dataset above which a single key is reported as a big key
+const BIG_KEY_DATASET_RATIO: usize = 4;
+
+pub struct MemoryDoctorArguments;
+
+impl MemoryDoctorArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidMemoryCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+/// Replies with a human readable report of the memory issues found in the keyspace
+pub async fn memory_doctor(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    MemoryDoctorArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+    let memory = KeyspaceMemory::measure(&store_guard);
+
+    if memory.total_bytes() < MEMORY_DOCTOR_MIN_DATASET_BYTES {
+        return Ok(CommandResult::Response(
+            RespValue::BulkString("Hi Sam, this instance is empty or is using very little memory, my issues detector can't be used in these conditions. Please, leave for your mission on Earth and fill it with some data. The new Sam and I will be back to our programming as soon as I finished rebooting.".to_string()).encode(),
+        ));
+    }
+
+    let mut big_keys = store_guard
+        .iter()
+        .filter(|(_, value)| {
+            value_size(&value.data, 0) * BIG_KEY_DATASET_RATIO > memory.dataset_bytes
+        })
+        .map(|(key, _)| key.as_str())
+        .collect::<Vec<&str>>();
+    big_keys.sort();
+
+    if big_keys.is_empty() {
+        return Ok(CommandResult::Response(
+            RespValue::BulkString("Hi Sam, I can't find any memory issue in your instance. I can only account for what occurs on this base.".to_string()).encode(),
+        ));
+    }
+
+    let report = format!(
+        "Sam, I detected a few issues in this Redis instance memory implants:\n\n * Big keys: {} key(s) hold more than a quarter of the dataset each: {}. Consider splitting them into smaller keys.\n\nI'm here to keep you safe, Sam. I want to help you.\n",
+        big_keys.len(),
+        big_keys.join(", ")
+    );
+
+    Ok(CommandResult::Response(
+        RespValue::BulkString(report).encode(),
+    ))
+}
@@ -0,0 +1,7 @@
+mod doctor;
+mod stats;
+mod usage;
+
+pub use doctor::{MemoryDoctorArguments, memory_doctor};
+pub use stats::{MemoryStatsArguments, memory_stats};
+pub use usage::{MemoryUsageArguments, memory_usage};
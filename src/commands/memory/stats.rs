@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        memory::usage::{key_size, value_size},
+    },
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct MemoryStatsArguments;
+
+impl MemoryStatsArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidMemoryCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+/// Approximate memory used by the keyspace, measuring every element of every value
+#[derive(Debug, PartialEq)]
+pub struct KeyspaceMemory {
+    pub keys_count: usize,
+    pub overhead_bytes: usize,
+    pub dataset_bytes: usize,
+}
+
+impl KeyspaceMemory {
+    pub fn measure(store: &KeyValueStore) -> Self {
+        store.iter().fold(
+            Self {
+                keys_count: 0,
+                overhead_bytes: 0,
+                dataset_bytes: 0,
+            },
+            |memory, (key, value)| Self {
+                keys_count: memory.keys_count + 1,
+                overhead_bytes: memory.overhead_bytes + key_size(key),
+                dataset_bytes: memory.dataset_bytes + value_size(&value.data, 0),
+            },
+        )
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.overhead_bytes + self.dataset_bytes
+    }
+
+    pub fn bytes_per_key(&self) -> usize {
+        match self.keys_count {
+            0 => 0,
+            keys_count => self.total_bytes() / keys_count,
+        }
+    }
+}
+
+/// Replies with a map of approximate memory statistics, flattened into an array of
+/// names and values for RESP2 clients
+pub async fn memory_stats(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    MemoryStatsArguments::parse(arguments)?;
+
+    let memory = {
+        let store_guard = store.lock().await;
+        KeyspaceMemory::measure(&store_guard)
+    };
+
+    let entries = [
+        ("total.allocated", memory.total_bytes()),
+        ("overhead.total", memory.overhead_bytes),
+        ("keys.count", memory.keys_count),
+        ("keys.bytes-per-key", memory.bytes_per_key()),
+        ("dataset.bytes", memory.dataset_bytes),
+    ]
+    .into_iter()
+    .map(|(name, value)| {
+        (
+            RespValue::BulkString(name.to_string()),
+            RespValue::Integer(value as i64),
+        )
+    })
+    .collect::<Vec<(RespValue, RespValue)>>();
+
+    let is_resp3_client = server.read().await.resp3_clients.contains(client_address);
+
+    if is_resp3_client {
+        return Ok(CommandResult::Response(RespValue::Map(entries).encode()));
+    }
+
+    let flattened_entries = entries
+        .into_iter()
+        .flat_map(|(key, value)| [key, value])
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(
+        RespValue::Array(flattened_entries).encode(),
+    ))
+}
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, is_value_expired,
+        keyword_utils::is_keyword,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    stream::StreamEntry,
+};
+
+// Number of nested values sampled by default, like Redis does
+const DEFAULT_MEMORY_USAGE_SAMPLES: usize = 5;
+// Approximate sizes of the structures Redis allocates besides the raw data:
+// the keyspace dictionary entry holding the key, the object header of the value,
+// the header of each string and the node or dictionary entry of each element
+const KEY_OVERHEAD: usize = 24;
+const OBJECT_OVERHEAD: usize = 16;
+const STRING_OVERHEAD: usize = 3;
+const ELEMENT_OVERHEAD: usize = 24;
+const SCORE_SIZE: usize = 8;
+// Stream entries are keyed in a radix tree by their ID packed as two 64-bit integers,
+// milliseconds and sequence number, and each field and value carries a length prefix
+const STREAM_ID_SIZE: usize = 16;
+const STREAM_FIELD_OVERHEAD: usize = 2;
+
+pub struct MemoryUsageArguments {
+    key: String,
+    // Every nested value is measured when 0
+    samples: usize,
+}
+
+impl MemoryUsageArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 && arguments.len() != 3 {
+            return Err(CommandError::InvalidMemoryCommand);
+        }
+
+        let samples = match arguments.get(1) {
+            Some(option) if is_keyword(option, "SAMPLES") => arguments[2]
+                .parse::<usize>()
+                .map_err(|_| CommandError::InvalidMemoryUsageSamples)?,
+            Some(_) => return Err(CommandError::InvalidMemoryCommand),
+            None => DEFAULT_MEMORY_USAGE_SAMPLES,
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            samples,
+        })
+    }
+}
+
+/// Replies with an estimate of the bytes used by the key and its value, or null if the
+/// key does not exist
+pub async fn memory_usage(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let memory_usage_arguments = MemoryUsageArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get(&memory_usage_arguments.key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    let usage = key_size(&memory_usage_arguments.key)
+        + value_size(&value.data, memory_usage_arguments.samples);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(usage as i64).encode(),
+    ))
+}
+
+/// Estimates the bytes the keyspace uses to hold a key, without its value
+pub fn key_size(key: &str) -> usize {
+    KEY_OVERHEAD + string_size(key)
+}
+
+/// Estimates the bytes used by a value. The elements of a collection are sized by
+/// averaging the first `samples` of them, or all of them when `samples` is 0.
+pub fn value_size(data: &DataType, samples: usize) -> usize {
+    let elements_size = match data {
+        DataType::String(string) => string_size(string),
+        DataType::Array(list) => sampled_size(
+            list.iter().map(|item| string_size(item)),
+            list.len(),
+            samples,
+        ),
+        DataType::Stream(stream) => sampled_size(
+            stream.values().map(stream_entry_size),
+            stream.len(),
+            samples,
+        ),
+        DataType::Hash(hash) => sampled_size(
+            hash.iter()
+                .map(|(field, value)| string_size(field) + string_size(value)),
+            hash.len(),
+            samples,
+        ),
+        DataType::SortedSet(sorted_set) => sampled_size(
+            sorted_set
+                .iter()
+                .map(|(member, _)| string_size(member) + SCORE_SIZE),
+            sorted_set.len(),
+            samples,
+        ),
+        DataType::Set(set) => sampled_size(
+            set.iter().map(|member| string_size(member)),
+            set.len(),
+            samples,
+        ),
+    };
+
+    OBJECT_OVERHEAD + elements_size
+}
+
+fn string_size(string: &str) -> usize {
+    STRING_OVERHEAD + string.len()
+}
+
+fn stream_entry_size(fields: &StreamEntry) -> usize {
+    STREAM_ID_SIZE
+        + fields
+            .iter()
+            .map(|(field, value)| 2 * STREAM_FIELD_OVERHEAD + field.len() + value.len())
+            .sum::<usize>()
+}
+
+/// Extrapolates the size of a collection from the average size of its sampled elements
+fn sampled_size(
+    element_sizes: impl Iterator<Item = usize>,
+    number_of_elements: usize,
+    samples: usize,
+) -> usize {
+    if number_of_elements == 0 {
+        return 0;
+    }
+
+    let samples = match samples {
+        0 => number_of_elements,
+        samples => samples.min(number_of_elements),
+    };
+    let sampled_size: usize = element_sizes
+        .take(samples)
+        .map(|size| size + ELEMENT_OVERHEAD)
+        .sum();
+
+    sampled_size * number_of_elements / samples
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{sampled_size, stream_entry_size};
+
+    #[test]
+    fn test_sampled_size() {
+        let test_cases = vec![
+            (vec![], 5, 0),
+            (vec![10, 20], 0, 78),
+            (vec![10, 20], 5, 78),
+            (vec![10, 20, 30, 40], 2, 156),
+            (vec![10, 20, 30, 40], 0, 196),
+        ];
+
+        for (element_sizes, samples, expected_size) in test_cases {
+            let number_of_elements = element_sizes.len();
+
+            assert_eq!(
+                sampled_size(element_sizes.into_iter(), number_of_elements, samples),
+                expected_size
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_entry_size() {
+        let test_cases = vec![
+            (vec![], 16),
+            (vec![("temperature", "36")], 33),
+            (vec![("temperature", "36"), ("humidity", "95")], 47),
+        ];
+
+        for (fields, expected_size) in test_cases {
+            let fields = fields
+                .into_iter()
+                .map(|(field, value)| (field.to_string(), value.to_string()))
+                .collect::<BTreeMap<String, String>>();
+
+            assert_eq!(stream_entry_size(&fields), expected_size);
+        }
+    }
+}
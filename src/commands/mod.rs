@@ -1,30 +1,67 @@
+mod append;
+mod arity;
+mod bitfield;
 mod blpop;
+mod client;
 mod command_error;
 mod command_handler;
+mod command_list;
 mod config_get;
+mod dbsize;
+mod debug;
 mod echo;
+mod expire;
 mod get;
+mod getdel;
+mod getset;
+mod glob_utils;
+mod hash;
+mod hello;
+mod help;
+mod hyperloglog;
 mod incr;
 mod info;
 mod keys;
+mod keyword_utils;
+mod latency;
 mod llen;
+mod lmpop;
+mod lolwut;
 mod lpop;
 mod lrange;
+mod memory;
+mod object;
+mod persistence;
+mod pexpireat;
 mod ping;
 mod pub_sub;
+mod quit;
 mod replication;
+mod reset;
 mod rpush_and_lpush;
+mod scan_utils;
 mod set;
+mod setrange;
+mod sets;
+mod slowlog;
+mod sort;
+mod sorted_set;
 mod stream_utils;
 mod transactions;
 mod type_command;
 mod xadd;
+mod xdel;
+mod xlen;
 mod xrange;
 mod xread;
+mod xsetid;
+mod xtrim;
 
 pub use command_error::CommandError;
 pub use command_handler::{CommandHandler, CommandResult};
-pub use stream_utils::validate_stream_id;
+pub use get::{is_value_expired, remove_expired_key};
+pub use persistence::start_background_save;
+pub use stream_utils::{StreamTrimStrategy, parse_stream_trim_strategy, validate_stream_id};
 pub use transactions::{
     run_transaction_commands_for_master_server, run_transaction_commands_for_replica_server,
 };
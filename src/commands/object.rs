@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    key_value_store::{DataType, KeyValueStore, is_integer_encodable},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+// Longest string Redis stores in the same allocation as its object header
+const EMBSTR_MAX_LENGTH: usize = 44;
+
+pub struct ObjectEncodingArguments {
+    key: String,
+}
+
+impl ObjectEncodingArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidObjectEncodingCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+pub async fn object_encoding(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let object_encoding_arguments = ObjectEncodingArguments::parse(arguments)?;
+    let is_modified_in_place = server
+        .read()
+        .await
+        .raw_string_keys
+        .contains(&object_encoding_arguments.key);
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get(&object_encoding_arguments.key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    let encoding = key_encoding(&value.data, is_modified_in_place);
+
+    Ok(CommandResult::Response(
+        RespValue::BulkString(encoding.to_string()).encode(),
+    ))
+}
+
+pub struct ObjectIdletimeArguments {
+    key: String,
+}
+
+impl ObjectIdletimeArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidObjectIdletimeCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+/// Replies with the seconds elapsed since the key was last accessed, without touching it
+pub async fn object_idletime(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let object_idletime_arguments = ObjectIdletimeArguments::parse(arguments)?;
+
+    let key_exists = store
+        .lock()
+        .await
+        .get(&object_idletime_arguments.key)
+        .is_some_and(|value| !is_value_expired(value));
+
+    if !key_exists {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    }
+
+    let idle_time = server
+        .read()
+        .await
+        .key_idle_time(&object_idletime_arguments.key);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(idle_time as i64).encode(),
+    ))
+}
+
+/// Like `value_encoding`, except that strings modified in place by APPEND or
+/// SETRANGE stay raw whatever their contents, as Redis never converts them back
+pub fn key_encoding(data: &DataType, is_modified_in_place: bool) -> &str {
+    match data {
+        DataType::String(_) if is_modified_in_place => "raw",
+        data => value_encoding(data),
+    }
+}
+
+/// Name of the internal representation Redis would use for the value
+pub fn value_encoding(data: &DataType) -> &str {
+    match data {
+        DataType::String(string) => string_encoding(string),
+        DataType::Array(list) => list.encoding(),
+        DataType::Stream(_) => "stream",
+        DataType::Hash(hash) => hash.encoding(),
+        DataType::SortedSet(sorted_set) => sorted_set.encoding(),
+        DataType::Set(set) => set.encoding(),
+    }
+}
+
+fn string_encoding(string: &str) -> &'static str {
+    if is_integer_encodable(string) {
+        "int"
+    } else if string.len() <= EMBSTR_MAX_LENGTH {
+        "embstr"
+    } else {
+        "raw"
+    }
+}
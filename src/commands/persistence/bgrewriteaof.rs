@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    aof::rewrite_append_only_file,
+    commands::{CommandError, command_handler::CommandResult},
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct BgrewriteaofArguments;
+
+impl BgrewriteaofArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidBgRewriteAofCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn bgrewriteaof(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    BgrewriteaofArguments::parse(arguments)?;
+
+    {
+        let mut server_guard = server.write().await;
+
+        if server_guard.is_aof_rewrite_in_progress {
+            return Err(CommandError::AofRewriteInProgress);
+        }
+
+        server_guard.is_aof_rewrite_in_progress = true;
+    }
+
+    tokio::spawn(async move {
+        let result = rewrite_append_only_file(Arc::clone(&server), store).await;
+
+        let mut server_guard = server.write().await;
+        server_guard.is_aof_rewrite_in_progress = false;
+        server_guard.is_last_aof_rewrite_ok = result.is_ok();
+
+        if let Err(e) = result {
+            eprintln!("Background append only file rewriting failed: {}", e);
+        }
+    });
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("Background append only file rewriting started".to_string())
+            .encode(),
+    ))
+}
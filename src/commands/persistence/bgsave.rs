@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use jiff::Timestamp;
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    key_value_store::KeyValueStore,
+    rdb::save_rdb_file,
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct BgsaveArguments;
+
+impl BgsaveArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidBgSaveCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn bgsave(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    BgsaveArguments::parse(arguments)?;
+
+    start_background_save(server, store).await?;
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("Background saving started".to_string()).encode(),
+    ))
+}
+
+/// Saves the RDB file in a background task, shared by BGSAVE and the save points
+pub async fn start_background_save(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+) -> Result<(), CommandError> {
+    let stats = {
+        let mut server_guard = server.write().await;
+
+        if server_guard.is_background_save_in_progress {
+            return Err(CommandError::BackgroundSaveInProgress);
+        }
+
+        server_guard.is_background_save_in_progress = true;
+        Arc::clone(&server_guard.stats)
+    };
+
+    tokio::spawn(async move {
+        // Writes made while the file is being written are left for the next save
+        let changes_to_save = stats.changes_since_last_save();
+        let result = save_rdb_file(Arc::clone(&server), store).await;
+
+        let mut server_guard = server.write().await;
+        server_guard.is_background_save_in_progress = false;
+
+        match result {
+            Ok(()) => {
+                server_guard.last_save_time = Timestamp::now();
+                stats.record_saved_changes(changes_to_save);
+            }
+            Err(e) => eprintln!("Background saving failed: {}", e),
+        }
+    });
+
+    Ok(())
+}
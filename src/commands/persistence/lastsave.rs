@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct LastsaveArguments;
+
+impl LastsaveArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidLastSaveCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn lastsave(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    LastsaveArguments::parse(arguments)?;
+
+    let server_guard = server.read().await;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(server_guard.last_save_time.as_second()).encode(),
+    ))
+}
@@ -0,0 +1,9 @@
+mod bgrewriteaof;
+mod bgsave;
+mod lastsave;
+mod save;
+
+pub use bgrewriteaof::{BgrewriteaofArguments, bgrewriteaof};
+pub use bgsave::{BgsaveArguments, bgsave, start_background_save};
+pub use lastsave::{LastsaveArguments, lastsave};
+pub use save::{SaveArguments, save};
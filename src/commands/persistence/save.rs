@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use jiff::Timestamp;
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    key_value_store::KeyValueStore,
+    rdb::save_rdb_file,
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct SaveArguments;
+
+impl SaveArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidSaveCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn save(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    SaveArguments::parse(arguments)?;
+
+    let stats = {
+        let server_guard = server.read().await;
+
+        if server_guard.is_background_save_in_progress {
+            return Err(CommandError::BackgroundSaveInProgress);
+        }
+
+        Arc::clone(&server_guard.stats)
+    };
+
+    let changes_to_save = stats.changes_since_last_save();
+
+    if let Err(e) = save_rdb_file(Arc::clone(&server), store).await {
+        eprintln!("Failed to save RDB file: {}", e);
+        return Err(CommandError::RdbSaveFailed);
+    }
+
+    let mut server_guard = server.write().await;
+    server_guard.last_save_time = Timestamp::now();
+    stats.record_saved_changes(changes_to_save);
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use jiff::Timestamp;
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+};
+
+pub struct PexpireatArguments {
+    key: String,
+    expiration: Timestamp,
+}
+
+impl PexpireatArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidPexpireatCommand);
+        }
+
+        let expiration = arguments[1]
+            .parse::<i64>()
+            .ok()
+            .and_then(|milliseconds| Timestamp::from_millisecond(milliseconds).ok())
+            .ok_or(CommandError::InvalidPexpireatTimestamp)?;
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            expiration,
+        })
+    }
+}
+
+pub async fn pexpireat(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let pexpireat_arguments = PexpireatArguments::parse(arguments)?;
+
+    Ok(CommandResult::Response(
+        expire_key_at(
+            store,
+            &pexpireat_arguments.key,
+            pexpireat_arguments.expiration,
+        )
+        .await,
+    ))
+}
+
+/// Sets the expiration of a live key, replying with 1, or with 0 when the key does not exist
+pub async fn expire_key_at(
+    store: Arc<Mutex<KeyValueStore>>,
+    key: &str,
+    expiration: Timestamp,
+) -> String {
+    let mut store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get_mut(key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return RespValue::Integer(0).encode();
+    };
+
+    // A timestamp in the past deletes the key right away
+    if expiration <= Timestamp::now() {
+        store_guard.remove(key);
+    } else {
+        value.expiration = Some(expiration);
+    }
+
+    RespValue::Integer(1).encode()
+}
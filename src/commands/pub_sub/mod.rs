@@ -1,7 +1,24 @@
 mod ping;
 mod publish;
+mod spublish;
+mod ssubscribe;
 mod subscribe;
+mod sunsubscribe;
 
 pub use ping::subscribe_ping;
 pub use publish::publish;
+pub use spublish::spublish;
+pub use ssubscribe::ssubscribe;
 pub use subscribe::subscribe;
+pub use sunsubscribe::sunsubscribe;
+
+use crate::resp::RespValue;
+
+/// Wraps a pub/sub message or confirmation in the frame the client negotiated:
+/// a push for RESP3 clients and an array for RESP2 ones
+pub fn pub_sub_frame(is_resp3_client: bool, elements: Vec<RespValue>) -> RespValue {
+    match is_resp3_client {
+        true => RespValue::Push(elements),
+        false => RespValue::Array(elements),
+    }
+}
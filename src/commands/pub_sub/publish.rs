@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
-use tokio::{io::AsyncWriteExt, sync::RwLock};
+use tokio::sync::RwLock;
 
 use crate::{
-    commands::{command_error::CommandError, command_handler::CommandResult},
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, pub_sub::pub_sub_frame,
+    },
     resp::RespValue,
     server::RedisServer,
 };
@@ -39,23 +41,23 @@ pub async fn publish(
         .pub_sub_channels
         .get(&publish_arguments.channel)
     {
-        let message = RespValue::Array(vec![
+        let message = vec![
             RespValue::BulkString("message".to_string()),
             RespValue::BulkString(publish_arguments.channel),
             RespValue::BulkString(publish_arguments.message.clone()),
-        ]);
-
-        for subscriber in channel.values() {
-            let mut subscriber_guard = subscriber.write().await;
-            subscriber_guard
-                .write_all(message.encode().as_bytes())
-                .await
-                .map_err(|_| CommandError::IoError)?;
-            subscriber_guard
-                .flush()
-                .await
-                .map_err(|_| CommandError::IoError)?;
-            count += 1;
+        ];
+
+        // Messages are queued to each subscriber's connection so a slow subscriber
+        // never holds up PUBLISH or the other subscribers
+        for (client_address, subscriber) in channel {
+            let is_resp3_client = server_guard.resp3_clients.contains(client_address);
+            let bytes = pub_sub_frame(is_resp3_client, message.clone())
+                .encode()
+                .into_bytes();
+
+            if subscriber.send(bytes).is_ok() {
+                count += 1;
+            }
         }
     }
 
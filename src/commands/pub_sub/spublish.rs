@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, pub_sub::pub_sub_frame,
+    },
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct SpublishArguments {
+    pub channel: String,
+    pub message: String,
+}
+
+impl SpublishArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidSpublishCommand);
+        }
+
+        Ok(Self {
+            channel: arguments[0].clone(),
+            message: arguments[1].clone(),
+        })
+    }
+}
+
+pub async fn spublish(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let spublish_arguments = SpublishArguments::parse(arguments)?;
+    let server_guard = server.read().await;
+
+    let mut count = 0;
+
+    if let Some(channel) = server_guard
+        .shard_pub_sub_channels
+        .get(&spublish_arguments.channel)
+    {
+        let message = vec![
+            RespValue::BulkString("smessage".to_string()),
+            RespValue::BulkString(spublish_arguments.channel),
+            RespValue::BulkString(spublish_arguments.message),
+        ];
+
+        for (client_address, subscriber) in channel {
+            let is_resp3_client = server_guard.resp3_clients.contains(client_address);
+            let bytes = pub_sub_frame(is_resp3_client, message.clone())
+                .encode()
+                .into_bytes();
+
+            if subscriber.send(bytes).is_ok() {
+                count += 1;
+            }
+        }
+    }
+
+    Ok(CommandResult::Response(RespValue::Integer(count).encode()))
+}
@@ -0,0 +1,75 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::{RwLock, mpsc};
+
+use crate::{
+    commands::{CommandError, CommandResult, pub_sub::pub_sub_frame},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct SsubscribeArguments {
+    pub channels: Vec<String>,
+}
+
+impl SsubscribeArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return Err(CommandError::InvalidSsubscribeCommand);
+        }
+
+        Ok(Self {
+            channels: arguments,
+        })
+    }
+}
+
+/// Subscribes the client to each shard channel, replying with one confirmation per channel
+pub async fn ssubscribe(
+    client_address: &str,
+    sender: mpsc::UnboundedSender<Vec<u8>>,
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let ssubscribe_arguments = SsubscribeArguments::parse(arguments)?;
+    let mut server_guard = server.write().await;
+    let is_resp3_client = server_guard.resp3_clients.contains(client_address);
+
+    let mut response = String::new();
+
+    for channel in ssubscribe_arguments.channels {
+        server_guard
+            .shard_pub_sub_channels
+            .entry(channel.clone())
+            .or_insert_with(HashMap::new)
+            .entry(client_address.to_string())
+            .or_insert_with(|| sender.clone());
+
+        let count =
+            count_shard_channels_for_client(client_address, &server_guard.shard_pub_sub_channels);
+
+        response.push_str(
+            &pub_sub_frame(
+                is_resp3_client,
+                vec![
+                    RespValue::BulkString("ssubscribe".to_string()),
+                    RespValue::BulkString(channel),
+                    RespValue::Integer(count),
+                ],
+            )
+            .encode(),
+        );
+    }
+
+    Ok(CommandResult::Response(response))
+}
+
+pub fn count_shard_channels_for_client(
+    client_address: &str,
+    channels: &HashMap<String, HashMap<String, mpsc::UnboundedSender<Vec<u8>>>>,
+) -> i64 {
+    channels
+        .values()
+        .filter(|channel| channel.contains_key(client_address))
+        .count() as i64
+}
@@ -1,9 +1,9 @@
 use std::{collections::HashMap, sync::Arc};
 
-use tokio::{net::tcp::OwnedWriteHalf, sync::RwLock};
+use tokio::sync::{RwLock, mpsc};
 
 use crate::{
-    commands::{CommandError, CommandResult},
+    commands::{CommandError, CommandResult, pub_sub::pub_sub_frame},
     resp::RespValue,
     server::RedisServer,
 };
@@ -26,7 +26,7 @@ impl SubscribeArguments {
 
 pub async fn subscribe(
     client_address: &str,
-    writer: Arc<RwLock<OwnedWriteHalf>>,
+    sender: mpsc::UnboundedSender<Vec<u8>>,
     server: Arc<RwLock<RedisServer>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
@@ -42,38 +42,41 @@ pub async fn subscribe(
         return Ok(find_number_of_subscribed_channels_for_client(
             client_address,
             &subscribe_arguments.channel,
-            &server_guard.pub_sub_channels,
+            &server_guard,
         ));
     }
 
-    channel_map.insert(client_address.to_string(), writer);
+    channel_map.insert(client_address.to_string(), sender);
 
     Ok(find_number_of_subscribed_channels_for_client(
         client_address,
         &subscribe_arguments.channel,
-        &server_guard.pub_sub_channels,
+        &server_guard,
     ))
 }
 
 pub fn find_number_of_subscribed_channels_for_client(
     client_address: &str,
     channel_name: &str,
-    channels: &HashMap<String, HashMap<String, Arc<RwLock<OwnedWriteHalf>>>>,
+    server: &RedisServer,
 ) -> CommandResult {
     let mut count = 0;
 
-    for channel in channels.values() {
+    for channel in server.pub_sub_channels.values() {
         if channel.contains_key(client_address) {
             count += 1;
         }
     }
 
     CommandResult::Response(
-        RespValue::Array(vec![
-            RespValue::BulkString("subscribe".to_string()),
-            RespValue::BulkString(channel_name.to_string()),
-            RespValue::Integer(count),
-        ])
+        pub_sub_frame(
+            server.resp3_clients.contains(client_address),
+            vec![
+                RespValue::BulkString("subscribe".to_string()),
+                RespValue::BulkString(channel_name.to_string()),
+                RespValue::Integer(count),
+            ],
+        )
         .encode(),
     )
 }
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{
+        CommandResult,
+        pub_sub::{pub_sub_frame, ssubscribe::count_shard_channels_for_client},
+    },
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct SunsubscribeArguments {
+    pub channels: Vec<String>,
+}
+
+impl SunsubscribeArguments {
+    pub fn parse(arguments: Vec<String>) -> Self {
+        Self {
+            channels: arguments,
+        }
+    }
+}
+
+/// Unsubscribes the client from the given shard channels, or from all of them
+/// when none are given, replying with one confirmation per channel
+pub async fn sunsubscribe(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> CommandResult {
+    let sunsubscribe_arguments = SunsubscribeArguments::parse(arguments);
+    let mut server_guard = server.write().await;
+    let is_resp3_client = server_guard.resp3_clients.contains(client_address);
+
+    let channels = match sunsubscribe_arguments.channels.is_empty() {
+        true => {
+            let mut channels = server_guard
+                .shard_pub_sub_channels
+                .iter()
+                .filter(|(_, subscribers)| subscribers.contains_key(client_address))
+                .map(|(channel, _)| channel.clone())
+                .collect::<Vec<String>>();
+            channels.sort();
+            channels
+        }
+        false => sunsubscribe_arguments.channels,
+    };
+
+    if channels.is_empty() {
+        return CommandResult::Response(
+            pub_sub_frame(
+                is_resp3_client,
+                vec![
+                    RespValue::BulkString("sunsubscribe".to_string()),
+                    RespValue::NullBulkString,
+                    RespValue::Integer(0),
+                ],
+            )
+            .encode(),
+        );
+    }
+
+    let mut response = String::new();
+
+    for channel in channels {
+        if let Some(subscribers) = server_guard.shard_pub_sub_channels.get_mut(&channel) {
+            subscribers.remove(client_address);
+
+            if subscribers.is_empty() {
+                server_guard.shard_pub_sub_channels.remove(&channel);
+            }
+        }
+
+        let count =
+            count_shard_channels_for_client(client_address, &server_guard.shard_pub_sub_channels);
+
+        response.push_str(
+            &pub_sub_frame(
+                is_resp3_client,
+                vec![
+                    RespValue::BulkString("sunsubscribe".to_string()),
+                    RespValue::BulkString(channel),
+                    RespValue::Integer(count),
+                ],
+            )
+            .encode(),
+        );
+    }
+
+    CommandResult::Response(response)
+}
@@ -0,0 +1,24 @@
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    resp::RespValue,
+};
+
+pub struct QuitArguments;
+
+impl QuitArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidQuitCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub fn quit(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    QuitArguments::parse(arguments)?;
+
+    Ok(CommandResult::Quit(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -0,0 +1,25 @@
+use crate::{
+    commands::{CommandError, command_handler::CommandResult, keyword_utils::is_keyword},
+    resp::RespValue,
+};
+
+pub struct FailoverArguments;
+
+impl FailoverArguments {
+    /// Only `FAILOVER ABORT` is accepted, since manual failover isn't supported
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        match arguments.as_slice() {
+            [abort] if is_keyword(abort, "ABORT") => Ok(Self),
+            _ => Err(CommandError::FailoverNotSupported),
+        }
+    }
+}
+
+/// There is never a failover in progress, so aborting one always succeeds
+pub fn failover(arguments: Vec<String>) -> Result<CommandResult, CommandError> {
+    FailoverArguments::parse(arguments)?;
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -1,7 +1,11 @@
+mod failover;
 mod psync;
 mod replconf;
 mod wait;
+mod waitaof;
 
+pub use failover::{FailoverArguments, failover};
 pub use psync::{PsyncArguments, psync};
 pub use replconf::{ReplconfArguments, replconf};
 pub use wait::{WaitArguments, wait};
+pub use waitaof::{WaitaofArguments, waitaof};
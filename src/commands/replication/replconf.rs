@@ -3,7 +3,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::{
-    commands::{CommandError, command_handler::CommandResult},
+    commands::{CommandError, command_handler::CommandResult, keyword_utils::normalize_keyword},
     resp::RespValue,
     server::RedisServer,
 };
@@ -25,29 +25,29 @@ impl ReplconfArguments {
             return Err(CommandError::InvalidReplconfCommand);
         }
 
-        let configuration = match arguments[0].to_lowercase().as_str() {
-            "listening-port" => {
+        let configuration = match normalize_keyword(&arguments[0]).as_str() {
+            "LISTENING-PORT" => {
                 arguments[1]
                     .parse::<u32>()
                     .map_err(|_| CommandError::InvalidReplconfCommand)?;
 
                 ReplconfConfiguration::ListeningPort
             }
-            "capa" => {
+            "CAPA" => {
                 if arguments[1] != "psync2" {
                     return Err(CommandError::InvalidReplconfCommand);
                 }
 
                 ReplconfConfiguration::Capabilities
             }
-            "getack" => {
+            "GETACK" => {
                 if arguments[1] != "*" {
                     return Err(CommandError::InvalidReplconfCommand);
                 }
 
                 ReplconfConfiguration::GetAck
             }
-            "ack" => {
+            "ACK" => {
                 let offset = arguments[1]
                     .parse::<usize>()
                     .map_err(|_| CommandError::InvalidReplconfCommand)?;
@@ -91,9 +91,11 @@ pub async fn replconf(
         ReplconfConfiguration::Ack(offset) => {
             let mut server_guard = server.write().await;
 
+            // Acks arrive on the replica's own connection while writes keep being propagated,
+            // so a late ack for an older offset must not move the replica back
             if let Some(ref mut replicas) = server_guard.replicas {
                 if let Some(replica) = replicas.get_mut(client_address) {
-                    replica.offset = offset;
+                    replica.offset = replica.offset.max(offset);
                 }
             }
 
@@ -1,6 +1,5 @@
 use std::{sync::Arc, time::Duration};
 
-use tokio::io::AsyncWriteExt;
 use tokio::{sync::RwLock, time::timeout};
 
 use crate::commands::command_handler::CommandResult;
@@ -43,32 +42,43 @@ pub async fn wait(
 ) -> Result<CommandResult, CommandError> {
     let wait_arguments = WaitArguments::parse(arguments)?;
 
-    let response = match wait_arguments.timeout_ms {
+    // Return number of replicas that processed commands before timeout or until it matches given argument
+    let response = wait_for_replicas(
+        server,
+        wait_arguments.number_of_replicas,
+        wait_arguments.timeout_ms,
+    )
+    .await?;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(response as i64).encode(),
+    ))
+}
+
+/// Waits until `number_of_replicas` replicas acknowledged the current replication offset
+/// or the timeout elapses, returning how many replicas are in sync. Waiting for no
+/// replicas returns the current count straight away, even without any replicas.
+pub async fn wait_for_replicas(
+    server: Arc<RwLock<RedisServer>>,
+    number_of_replicas: usize,
+    timeout_ms: Option<Duration>,
+) -> Result<usize, CommandError> {
+    if number_of_replicas == 0 {
+        return get_synced_replica_count(server).await;
+    }
+
+    match timeout_ms {
         Some(duration) => match timeout(
             duration,
-            check_if_replica_processed_commands(
-                Arc::clone(&server),
-                wait_arguments.number_of_replicas,
-            ),
+            check_if_replica_processed_commands(Arc::clone(&server), number_of_replicas),
         )
         .await
         {
             Ok(replicas) => replicas,
             Err(_) => get_synced_replica_count(Arc::clone(&server)).await,
         },
-        None => {
-            check_if_replica_processed_commands(
-                Arc::clone(&server),
-                wait_arguments.number_of_replicas,
-            )
-            .await
-        }
-    }?;
-    // Return number of replicas that processed commands before timeout or until it matches given argument
-
-    Ok(CommandResult::Response(
-        RespValue::Integer(response as i64).encode(),
-    ))
+        None => check_if_replica_processed_commands(Arc::clone(&server), number_of_replicas).await,
+    }
 }
 
 async fn check_if_replica_processed_commands(
@@ -118,27 +128,22 @@ async fn send_getack_to_unsynced_replicas(
         .iter()
         .filter(|(_, replica)| replica.offset < server_guard.repl_offset);
 
+    let getack_command = RespValue::Array(vec![
+        RespValue::BulkString("REPLCONF".to_string()),
+        RespValue::BulkString("GETACK".to_string()),
+        RespValue::BulkString("*".to_string()),
+    ])
+    .encode();
+
+    // GETACK goes through the same queue as the replication stream so it arrives after it
     for (_, replica) in replicas_to_check {
-        let mut replica_writer_guard = replica.writer.write().await;
-
-        if let Err(_) = replica_writer_guard
-            .write_all(
-                RespValue::Array(vec![
-                    RespValue::BulkString("REPLCONF".to_string()),
-                    RespValue::BulkString("GETACK".to_string()),
-                    RespValue::BulkString("*".to_string()),
-                ])
-                .encode()
-                .as_bytes(),
-            )
-            .await
+        if replica
+            .sender
+            .send(getack_command.clone().into_bytes())
+            .is_err()
         {
             return Err(CommandError::InvalidWaitCommand);
         }
-
-        if let Err(_) = replica_writer_guard.flush().await {
-            return Err(CommandError::InvalidWaitCommand);
-        }
     }
 
     Ok(())
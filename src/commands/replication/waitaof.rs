@@ -0,0 +1,79 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::RwLock;
+
+use crate::commands::command_handler::CommandResult;
+use crate::{
+    commands::{CommandError, replication::wait::wait_for_replicas},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct WaitaofArguments {
+    pub number_of_local: usize,
+    pub number_of_replicas: usize,
+    pub timeout_ms: Option<Duration>,
+}
+
+impl WaitaofArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidWaitaofCommand);
+        }
+
+        let number_of_local = arguments[0]
+            .parse::<usize>()
+            .map_err(|_| CommandError::InvalidWaitaofCommandArgument)?;
+
+        let number_of_replicas = arguments[1]
+            .parse::<usize>()
+            .map_err(|_| CommandError::InvalidWaitaofCommandArgument)?;
+
+        let timeout = arguments[2]
+            .parse::<u64>()
+            .map_err(|_| CommandError::InvalidWaitaofCommandArgument)?;
+
+        let timeout_ms = match timeout {
+            0 => None,
+            _ => Some(Duration::from_millis(timeout)),
+        };
+
+        Ok(Self {
+            number_of_local,
+            number_of_replicas,
+            timeout_ms,
+        })
+    }
+}
+
+/// There is no AOF yet, so nothing is ever fsynced locally and replicas are counted
+/// once they acknowledged the current replication offset, the same way WAIT does
+pub async fn waitaof(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let waitaof_arguments = WaitaofArguments::parse(arguments)?;
+
+    if server.read().await.replicas.is_none() {
+        return Err(CommandError::InvalidWaitaofCommandForReplica);
+    }
+
+    if waitaof_arguments.number_of_local > 0 {
+        return Err(CommandError::WaitaofAppendOnlyDisabled);
+    }
+
+    let replicas = wait_for_replicas(
+        server,
+        waitaof_arguments.number_of_replicas,
+        waitaof_arguments.timeout_ms,
+    )
+    .await?;
+
+    Ok(CommandResult::Response(
+        RespValue::Array(vec![
+            RespValue::Integer(0),
+            RespValue::Integer(replicas as i64),
+        ])
+        .encode(),
+    ))
+}
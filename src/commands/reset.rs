@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+    state::State,
+};
+
+pub struct ResetArguments;
+
+impl ResetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidResetCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn reset(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    ResetArguments::parse(arguments)?;
+
+    {
+        let mut state_guard = state.lock().await;
+        let _ = state_guard.remove_transaction(client_address.to_string());
+    }
+
+    let mut server_guard = server.write().await;
+
+    server_guard.unsubscribe_client_from_all_channels(client_address);
+    server_guard.resp3_clients.remove(client_address);
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("RESET".to_string()).encode(),
+    ))
+}
@@ -1,11 +1,13 @@
 use std::{collections::VecDeque, sync::Arc};
 
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{
     commands::{command_error::CommandError, command_handler::CommandResult},
     key_value_store::{DataType, KeyValueStore, Value},
+    list::List,
     resp::RespValue,
+    server::RedisServer,
     state::State,
 };
 
@@ -32,28 +34,32 @@ impl PushArrayOperations {
 }
 
 pub async fn rpush(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
-    return push_array_operations(store, state, arguments, false).await;
+    push_array_operations(server, store, state, arguments, false).await
 }
 
 pub async fn lpush(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
-    return push_array_operations(store, state, arguments, true).await;
+    push_array_operations(server, store, state, arguments, true).await
 }
 
 async fn push_array_operations(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
     arguments: Vec<String>,
     should_prepend: bool,
 ) -> Result<CommandResult, CommandError> {
     let push_array_arguments = PushArrayOperations::parse(arguments, should_prepend)?;
+    let list_max_listpack_size = server.read().await.list_max_listpack_size;
 
     let (pushed_values_count, was_empty_before) = {
         let mut store_guard = store.lock().await;
@@ -61,17 +67,19 @@ async fn push_array_operations(
         match store_guard.get_mut(&push_array_arguments.key) {
             Some(value) => {
                 let DataType::Array(ref mut list) = value.data else {
-                    return Err(CommandError::InvalidDataTypeForKey);
+                    return Err(CommandError::WrongType);
                 };
 
                 let was_empty_before = list.is_empty();
                 add_values_to_list(list, &push_array_arguments.values, should_prepend);
+                list.update_encoding(list_max_listpack_size);
 
                 (list.len(), was_empty_before)
             }
             None => {
-                let mut list = VecDeque::new();
+                let mut list = List::new();
                 add_values_to_list(&mut list, &push_array_arguments.values, should_prepend);
+                list.update_encoding(list_max_listpack_size);
 
                 let list_length = list.len();
                 store_guard.insert(
@@ -0,0 +1,125 @@
+use globset::GlobMatcher;
+
+use crate::commands::{CommandError, glob_utils::compile_glob_pattern, keyword_utils::is_keyword};
+
+const DEFAULT_SCAN_COUNT: usize = 10;
+
+/// Options shared by HSCAN and SSCAN. The cursor is the position in the sorted fields
+/// or members the next call resumes from, where 0 both starts and ends an iteration.
+pub struct ScanOptions {
+    pub cursor: usize,
+    pub pattern: Option<GlobMatcher>,
+    pub count: usize,
+    pub no_values: bool,
+}
+
+/// Parses `cursor [MATCH pattern] [COUNT count]`, also accepting NOVALUES when
+/// `allows_no_values` is set
+pub fn parse_scan_options(
+    arguments: &[String],
+    allows_no_values: bool,
+) -> Result<ScanOptions, CommandError> {
+    let Some(cursor) = arguments.first() else {
+        return Err(CommandError::ScanSyntaxError);
+    };
+
+    let mut options = ScanOptions {
+        cursor: cursor
+            .parse::<usize>()
+            .map_err(|_| CommandError::InvalidScanCursor)?,
+        pattern: None,
+        count: DEFAULT_SCAN_COUNT,
+        no_values: false,
+    };
+
+    let mut remaining_arguments = arguments[1..].iter();
+
+    while let Some(option) = remaining_arguments.next() {
+        if is_keyword(option, "MATCH") {
+            let pattern = remaining_arguments
+                .next()
+                .ok_or(CommandError::ScanSyntaxError)?;
+            options.pattern = Some(compile_glob_pattern(pattern)?);
+        } else if is_keyword(option, "COUNT") {
+            let count = remaining_arguments
+                .next()
+                .ok_or(CommandError::ScanSyntaxError)?
+                .parse::<i64>()
+                .map_err(|_| CommandError::InvalidScanCount)?;
+
+            if count < 1 {
+                return Err(CommandError::ScanSyntaxError);
+            }
+
+            options.count = count as usize;
+        } else if allows_no_values && is_keyword(option, "NOVALUES") {
+            options.no_values = true;
+        } else {
+            return Err(CommandError::ScanSyntaxError);
+        }
+    }
+
+    Ok(options)
+}
+
+/// Visits the next `count` elements after the cursor in sorted order and returns the
+/// cursor to resume from, 0 once the last element was visited, along with the visited
+/// elements matching the pattern. Each element is sorted and matched by its name.
+pub fn scan_elements<'a, T>(
+    mut elements: Vec<(&'a String, T)>,
+    options: &ScanOptions,
+) -> (usize, Vec<(&'a String, T)>) {
+    elements.sort_unstable_by_key(|(name, _)| *name);
+
+    let start = options.cursor.min(elements.len());
+    let end = start.saturating_add(options.count).min(elements.len());
+    let next_cursor = if end == elements.len() { 0 } else { end };
+
+    let matching_elements = elements
+        .drain(start..end)
+        .filter(|(name, _)| {
+            options
+                .pattern
+                .as_ref()
+                .is_none_or(|pattern| pattern.is_match(name))
+        })
+        .collect();
+
+    (next_cursor, matching_elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_scan_options, scan_elements};
+
+    fn to_strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_scan_elements() {
+        let names = to_strings(&["kiwi", "apple", "mango", "grape", "melon"]);
+        let elements = || names.iter().map(|name| (name, ())).collect::<Vec<_>>();
+
+        let test_cases = vec![
+            (vec!["0", "COUNT", "2"], 2, vec!["apple", "grape"]),
+            (vec!["2", "COUNT", "2"], 4, vec!["kiwi", "mango"]),
+            (vec!["4", "COUNT", "2"], 0, vec!["melon"]),
+            (vec!["0", "MATCH", "m*"], 0, vec!["mango", "melon"]),
+            (vec!["0", "COUNT", "3", "MATCH", "m*"], 3, vec![]),
+            (vec!["10"], 0, vec![]),
+        ];
+
+        for (arguments, expected_cursor, expected_names) in test_cases {
+            let options = parse_scan_options(&to_strings(&arguments), false).unwrap();
+            let (next_cursor, visited) = scan_elements(elements(), &options);
+            let visited = visited
+                .into_iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<&str>>();
+
+            assert_eq!(next_cursor, expected_cursor, "scanning {:?}", arguments);
+            assert_eq!(visited, expected_names, "scanning {:?}", arguments);
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use std::{sync::Arc, time::Duration};
 
 use jiff::Timestamp;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{
-    commands::{command_error::CommandError, command_handler::CommandResult},
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+    },
     key_value_store::{DataType, KeyValueStore, Value},
     resp::RespValue,
+    server::RedisServer,
 };
 
 pub struct SetArguments {
@@ -24,18 +27,28 @@ impl SetArguments {
         let mut expiration: Option<Timestamp> = None;
 
         if arguments.len() == 4 {
-            if arguments[2].to_lowercase() != "px" {
+            let timestamp = if is_keyword(&arguments[2], "PX") {
+                arguments[3]
+                    .parse::<u64>()
+                    .ok()
+                    .and_then(|expiration_time| {
+                        Timestamp::now()
+                            .checked_add(Duration::from_millis(expiration_time))
+                            .ok()
+                    })
+                    .ok_or(CommandError::InvalidSetCommandExpiration)?
+            } else if is_keyword(&arguments[2], "PXAT") {
+                arguments[3]
+                    .parse::<u64>()
+                    .ok()
+                    .and_then(|milliseconds| i64::try_from(milliseconds).ok())
+                    .and_then(|milliseconds| Timestamp::from_millisecond(milliseconds).ok())
+                    .ok_or(CommandError::InvalidSetCommandExpiration)?
+            } else {
                 return Err(CommandError::InvalidSetCommandArgument);
-            }
+            };
 
-            if let Ok(expiration_time) = arguments[3].parse::<u64>() {
-                let timestamp = Timestamp::now()
-                    .checked_add(Duration::from_millis(expiration_time))
-                    .map_err(|_| CommandError::InvalidSetCommandExpiration)?;
-                expiration = Some(timestamp);
-            } else {
-                return Err(CommandError::InvalidSetCommandExpiration);
-            }
+            expiration = Some(timestamp);
         }
 
         Ok(Self {
@@ -44,14 +57,36 @@ impl SetArguments {
             expiration,
         })
     }
+
+    /// Arguments of the same SET with its expiration given as an absolute `PXAT` timestamp
+    pub fn to_absolute_expiration_arguments(&self) -> Vec<String> {
+        let mut arguments = vec![self.key.clone(), self.value.clone()];
+
+        if let Some(expiration) = self.expiration {
+            arguments.push("PXAT".to_string());
+            arguments.push(expiration.as_millisecond().to_string());
+        }
+
+        arguments
+    }
 }
 
 pub async fn set(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
     let set_arguments = SetArguments::parse(arguments)?;
 
+    // A new value starts out as just accessed, even for CLIENT NO-TOUCH connections
+    {
+        let mut server_guard = server.write().await;
+        server_guard
+            .key_access_times
+            .insert(set_arguments.key.clone(), Timestamp::now());
+        server_guard.raw_string_keys.remove(&set_arguments.key);
+    }
+
     let mut store_guard = store.lock().await;
     store_guard.insert(
         set_arguments.key,
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        bitfield::{bytes_to_string, string_to_bytes},
+        command_error::CommandError,
+        command_handler::CommandResult,
+        is_value_expired,
+    },
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+// Redis' default proto-max-bulk-len, the largest string a key can hold
+const STRING_MAX_LENGTH: usize = 512 * 1024 * 1024;
+
+pub struct SetrangeArguments {
+    key: String,
+    offset: usize,
+    value: String,
+}
+
+impl SetrangeArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidSetRangeCommand);
+        }
+
+        let offset = arguments[1]
+            .parse::<i64>()
+            .map_err(|_| CommandError::InvalidSetRangeOffset)?;
+
+        if offset < 0 {
+            return Err(CommandError::SetRangeOffsetOutOfRange);
+        }
+
+        let offset = offset as usize;
+
+        if offset.saturating_add(arguments[2].len()) > STRING_MAX_LENGTH {
+            return Err(CommandError::StringExceedsMaximumSize);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            offset,
+            value: arguments[2].clone(),
+        })
+    }
+}
+
+/// Overwrites the string at the key starting at the offset, padding it with zero bytes
+/// when it is shorter, and replies with the new length of the string
+pub async fn setrange(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let setrange_arguments = SetrangeArguments::parse(arguments)?;
+
+    let length = {
+        let mut store_guard = store.lock().await;
+
+        let (mut bytes, expiration) = match store_guard.get(&setrange_arguments.key) {
+            Some(value) if !is_value_expired(value) => {
+                let DataType::String(ref string) = value.data else {
+                    return Err(CommandError::WrongType);
+                };
+
+                (string_to_bytes(string), value.expiration)
+            }
+            _ => (Vec::new(), None),
+        };
+
+        // An empty value leaves the string untouched and does not create missing keys
+        if setrange_arguments.value.is_empty() {
+            return Ok(CommandResult::Response(
+                RespValue::Integer(bytes.len() as i64).encode(),
+            ));
+        }
+
+        let value_bytes = string_to_bytes(&setrange_arguments.value);
+        let end = setrange_arguments.offset + value_bytes.len();
+
+        if bytes.len() < end {
+            bytes.resize(end, 0);
+        }
+
+        bytes[setrange_arguments.offset..end].copy_from_slice(&value_bytes);
+
+        store_guard.insert(
+            setrange_arguments.key.clone(),
+            Value {
+                data: DataType::String(bytes_to_string(&bytes)),
+                expiration,
+            },
+        );
+
+        bytes.len()
+    };
+
+    server
+        .write()
+        .await
+        .raw_string_keys
+        .insert(setrange_arguments.key);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(length as i64).encode(),
+    ))
+}
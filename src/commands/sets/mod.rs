@@ -0,0 +1,91 @@
+mod sadd;
+mod sintercard;
+mod smove;
+mod sscan;
+mod store_operations;
+
+pub use sadd::{SaddArguments, sadd};
+pub use sintercard::{SintercardArguments, sintercard};
+pub use smove::{SmoveArguments, smove};
+pub use sscan::{SscanArguments, sscan};
+pub use store_operations::{SetOperation, SetStoreArguments, sdiffstore, sinterstore, sunionstore};
+
+use std::collections::HashSet;
+
+use crate::{
+    commands::command_error::CommandError,
+    key_value_store::{DataType, KeyValueStore},
+};
+
+/// Looks up the set stored at a key, returning None for a missing key
+fn get_set<'a>(
+    store: &'a KeyValueStore,
+    key: &str,
+) -> Result<Option<&'a HashSet<String>>, CommandError> {
+    match store.get(key) {
+        Some(value) => match value.data {
+            DataType::Set(ref set) => Ok(Some(set)),
+            _ => Err(CommandError::WrongType),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Collects the sets stored at the given keys, where missing keys are treated as empty sets
+fn get_sets<'a>(
+    store: &'a KeyValueStore,
+    keys: &[String],
+) -> Result<Vec<Option<&'a HashSet<String>>>, CommandError> {
+    keys.iter().map(|key| get_set(store, key)).collect()
+}
+
+/// Members present in every set. Iteration starts from the smallest set and stops once `limit`
+/// members are found, if a limit is given.
+fn intersect_sets(sets: &[Option<&HashSet<String>>], limit: Option<usize>) -> HashSet<String> {
+    let mut intersection = HashSet::new();
+
+    let Some(sets) = sets
+        .iter()
+        .copied()
+        .collect::<Option<Vec<&HashSet<String>>>>()
+    else {
+        return intersection;
+    };
+
+    let Some(smallest_set) = sets.iter().min_by_key(|set| set.len()) else {
+        return intersection;
+    };
+
+    for member in smallest_set.iter() {
+        if limit.is_some_and(|limit| intersection.len() >= limit) {
+            break;
+        }
+
+        if sets.iter().all(|set| set.contains(member)) {
+            intersection.insert(member.clone());
+        }
+    }
+
+    intersection
+}
+
+/// Members present in at least one of the sets
+fn union_sets(sets: &[Option<&HashSet<String>>]) -> HashSet<String> {
+    sets.iter()
+        .flatten()
+        .flat_map(|set| set.iter().cloned())
+        .collect()
+}
+
+/// Members of the first set that are not present in any of the other sets
+fn diff_sets(sets: &[Option<&HashSet<String>>]) -> HashSet<String> {
+    let Some(Some(first_set)) = sets.first() else {
+        return HashSet::new();
+    };
+
+    first_set
+        .iter()
+        .filter(|member| sets[1..].iter().flatten().all(|set| !set.contains(*member)))
+        .cloned()
+        .collect()
+}
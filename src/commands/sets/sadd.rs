@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+    set::Set,
+};
+
+pub struct SaddArguments {
+    key: String,
+    members: Vec<String>,
+}
+
+impl SaddArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidSAddCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            members: arguments[1..].to_vec(),
+        })
+    }
+}
+
+pub async fn sadd(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let sadd_arguments = SaddArguments::parse(arguments)?;
+    let set_encoding_limits = server.read().await.set_encoding_limits;
+
+    let mut store_guard = store.lock().await;
+
+    let value = store_guard
+        .entry(sadd_arguments.key)
+        .or_insert_with(|| Value {
+            data: DataType::Set(Set::new()),
+            expiration: None,
+        });
+
+    let DataType::Set(ref mut set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let mut added_members_count = 0;
+
+    let mut added_members = Vec::new();
+
+    for member in &sadd_arguments.members {
+        if set.insert(member.clone()) {
+            added_members.push(member.as_str());
+            added_members_count += 1;
+        }
+    }
+
+    set.update_encoding(added_members, &set_encoding_limits);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(added_members_count).encode(),
+    ))
+}
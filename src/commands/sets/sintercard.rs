@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        keyword_utils::is_keyword,
+        sets::{get_sets, intersect_sets},
+    },
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+};
+
+pub struct SintercardArguments {
+    keys: Vec<String>,
+    limit: Option<usize>,
+}
+
+impl SintercardArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidSInterCardCommand);
+        }
+
+        let number_of_keys = match arguments[0].parse::<i64>() {
+            Ok(number_of_keys) if number_of_keys > 0 => number_of_keys as usize,
+            _ => return Err(CommandError::InvalidSInterCardNumberOfKeys),
+        };
+
+        let Some(keys) = arguments.get(1..1 + number_of_keys) else {
+            return Err(CommandError::SInterCardNumberOfKeysMismatch);
+        };
+
+        let limit = match &arguments[1 + number_of_keys..] {
+            [] => None,
+            [option, limit] if is_keyword(option, "LIMIT") => {
+                let limit = limit
+                    .parse::<i64>()
+                    .map_err(|_| CommandError::InvalidSInterCardLimit)?;
+
+                // A limit of 0 means that the whole intersection is counted
+                match limit {
+                    0 => None,
+                    limit if limit > 0 => Some(limit as usize),
+                    _ => return Err(CommandError::InvalidSInterCardLimit),
+                }
+            }
+            _ => return Err(CommandError::InvalidSInterCardCommand),
+        };
+
+        Ok(Self {
+            keys: keys.to_vec(),
+            limit,
+        })
+    }
+}
+
+pub async fn sintercard(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let sintercard_arguments = SintercardArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let sets = get_sets(&store_guard, &sintercard_arguments.keys)?;
+    let intersection = intersect_sets(&sets, sintercard_arguments.limit);
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(intersection.len() as i64).encode(),
+    ))
+}
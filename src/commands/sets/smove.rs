@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, sets::get_set},
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+    set::Set,
+};
+
+pub struct SmoveArguments {
+    source: String,
+    destination: String,
+    member: String,
+}
+
+impl SmoveArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidSMoveCommand);
+        }
+
+        Ok(Self {
+            source: arguments[0].clone(),
+            destination: arguments[1].clone(),
+            member: arguments[2].clone(),
+        })
+    }
+}
+
+pub async fn smove(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let smove_arguments = SmoveArguments::parse(arguments)?;
+    let set_encoding_limits = server.read().await.set_encoding_limits;
+
+    let mut store_guard = store.lock().await;
+
+    // Both keys are type checked before anything is modified so the move stays atomic
+    let is_member_in_source = get_set(&store_guard, &smove_arguments.source)?
+        .is_some_and(|set| set.contains(&smove_arguments.member));
+    get_set(&store_guard, &smove_arguments.destination)?;
+
+    if !is_member_in_source {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    }
+
+    if smove_arguments.source == smove_arguments.destination {
+        return Ok(CommandResult::Response(RespValue::Integer(1).encode()));
+    }
+
+    if let Some(Value {
+        data: DataType::Set(source_set),
+        ..
+    }) = store_guard.get_mut(&smove_arguments.source)
+    {
+        source_set.remove(&smove_arguments.member);
+
+        if source_set.is_empty() {
+            store_guard.remove(&smove_arguments.source);
+        }
+    }
+
+    let destination_value = store_guard
+        .entry(smove_arguments.destination)
+        .or_insert_with(|| Value {
+            data: DataType::Set(Set::new()),
+            expiration: None,
+        });
+
+    if let DataType::Set(ref mut destination_set) = destination_value.data
+        && destination_set.insert(smove_arguments.member.clone())
+    {
+        destination_set.update_encoding([smove_arguments.member.as_str()], &set_encoding_limits);
+    }
+
+    Ok(CommandResult::Response(RespValue::Integer(1).encode()))
+}
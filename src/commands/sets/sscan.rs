@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        scan_utils::{ScanOptions, parse_scan_options, scan_elements},
+        sets::get_set,
+    },
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+};
+
+pub struct SscanArguments {
+    key: String,
+    options: ScanOptions,
+}
+
+impl SscanArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidSScanCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            options: parse_scan_options(&arguments[1..], false)?,
+        })
+    }
+}
+
+/// Replies with the next cursor and the members visited from the given cursor
+pub async fn sscan(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let sscan_arguments = SscanArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let members: Vec<(&String, ())> = get_set(&store_guard, &sscan_arguments.key)?
+        .map(|set| set.iter().map(|member| (member, ())).collect())
+        .unwrap_or_default();
+
+    let (next_cursor, members) = scan_elements(members, &sscan_arguments.options);
+
+    let items = members
+        .into_iter()
+        .map(|(member, _)| RespValue::BulkString(member.clone()))
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(
+        RespValue::Array(vec![
+            RespValue::BulkString(next_cursor.to_string()),
+            RespValue::Array(items),
+        ])
+        .encode(),
+    ))
+}
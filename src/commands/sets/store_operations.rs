@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        sets::{diff_sets, get_sets, intersect_sets, union_sets},
+    },
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+    set::Set,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SetOperation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+pub struct SetStoreArguments {
+    destination: String,
+    keys: Vec<String>,
+}
+
+impl SetStoreArguments {
+    pub fn parse(arguments: Vec<String>, operation: SetOperation) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return match operation {
+                SetOperation::Intersection => Err(CommandError::InvalidSInterStoreCommand),
+                SetOperation::Union => Err(CommandError::InvalidSUnionStoreCommand),
+                SetOperation::Difference => Err(CommandError::InvalidSDiffStoreCommand),
+            };
+        }
+
+        Ok(Self {
+            destination: arguments[0].clone(),
+            keys: arguments[1..].to_vec(),
+        })
+    }
+}
+
+pub async fn sinterstore(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    store_set_operation(server, store, arguments, SetOperation::Intersection).await
+}
+
+pub async fn sunionstore(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    store_set_operation(server, store, arguments, SetOperation::Union).await
+}
+
+pub async fn sdiffstore(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    store_set_operation(server, store, arguments, SetOperation::Difference).await
+}
+
+async fn store_set_operation(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+    operation: SetOperation,
+) -> Result<CommandResult, CommandError> {
+    let set_store_arguments = SetStoreArguments::parse(arguments, operation)?;
+    let set_encoding_limits = server.read().await.set_encoding_limits;
+
+    let mut store_guard = store.lock().await;
+
+    let result = {
+        let sets = get_sets(&store_guard, &set_store_arguments.keys)?;
+
+        match operation {
+            SetOperation::Intersection => intersect_sets(&sets, None),
+            SetOperation::Union => union_sets(&sets),
+            SetOperation::Difference => diff_sets(&sets),
+        }
+    };
+
+    let cardinality = result.len();
+
+    // The destination is overwritten whatever its type was, and deleted for an empty result
+    if result.is_empty() {
+        store_guard.remove(&set_store_arguments.destination);
+    } else {
+        store_guard.insert(
+            set_store_arguments.destination,
+            Value {
+                data: DataType::Set(Set::with_encoding_limits(result, &set_encoding_limits)),
+                expiration: None,
+            },
+        );
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(cardinality as i64).encode(),
+    ))
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+    slowlog::SlowLogEntry,
+};
+
+const DEFAULT_SLOWLOG_GET_COUNT: usize = 10;
+
+pub struct SlowlogGetArguments {
+    // None returns every entry in the log
+    count: Option<usize>,
+}
+
+impl SlowlogGetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() > 1 {
+            return Err(CommandError::InvalidSlowlogCommand);
+        }
+
+        let Some(count) = arguments.first() else {
+            return Ok(Self {
+                count: Some(DEFAULT_SLOWLOG_GET_COUNT),
+            });
+        };
+
+        let count = match count.parse::<i64>() {
+            Ok(-1) => None,
+            Ok(count) if count >= 0 => Some(count as usize),
+            _ => return Err(CommandError::InvalidSlowlogCount),
+        };
+
+        Ok(Self { count })
+    }
+}
+
+/// Replies with the newest slow log entries, each one being an array of its id, the unix
+/// time it was logged at, its duration in microseconds, its arguments, the client address
+/// and the client name
+pub async fn slowlog_get(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let slowlog_get_arguments = SlowlogGetArguments::parse(arguments)?;
+
+    let server_guard = server.read().await;
+    let entries = server_guard
+        .slowlog
+        .get(slowlog_get_arguments.count)
+        .into_iter()
+        .map(encode_entry)
+        .collect();
+
+    Ok(CommandResult::Response(RespValue::Array(entries).encode()))
+}
+
+fn encode_entry(entry: &SlowLogEntry) -> RespValue {
+    RespValue::Array(vec![
+        RespValue::Integer(entry.id as i64),
+        RespValue::Integer(entry.timestamp.as_second()),
+        RespValue::Integer(entry.duration_micros as i64),
+        RespValue::Array(
+            entry
+                .arguments
+                .iter()
+                .map(|argument| RespValue::BulkString(argument.clone()))
+                .collect(),
+        ),
+        RespValue::BulkString(entry.client_address.clone()),
+        RespValue::BulkString(String::new()),
+    ])
+}
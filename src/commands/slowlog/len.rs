@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct SlowlogLenArguments;
+
+impl SlowlogLenArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidSlowlogCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn slowlog_len(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    SlowlogLenArguments::parse(arguments)?;
+
+    let server_guard = server.read().await;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(server_guard.slowlog.get(None).len() as i64).encode(),
+    ))
+}
@@ -0,0 +1,7 @@
+mod get;
+mod len;
+mod reset;
+
+pub use get::{SlowlogGetArguments, slowlog_get};
+pub use len::{SlowlogLenArguments, slowlog_len};
+pub use reset::{SlowlogResetArguments, slowlog_reset};
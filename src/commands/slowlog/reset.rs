@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    commands::{CommandError, command_handler::CommandResult},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct SlowlogResetArguments;
+
+impl SlowlogResetArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if !arguments.is_empty() {
+            return Err(CommandError::InvalidSlowlogCommand);
+        }
+
+        Ok(Self)
+    }
+}
+
+pub async fn slowlog_reset(
+    server: Arc<RwLock<RedisServer>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    SlowlogResetArguments::parse(arguments)?;
+
+    let mut server_guard = server.write().await;
+    server_guard.slowlog.reset();
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
@@ -0,0 +1,335 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, is_value_expired,
+        keyword_utils::normalize_keyword,
+    },
+    key_value_store::{DataType, KeyValueStore, Value},
+    list::List,
+    resp::RespValue,
+    server::RedisServer,
+    sorted_set::parse_score,
+    state::State,
+};
+
+pub struct SortArguments {
+    key: String,
+    by_pattern: Option<String>,
+    get_patterns: Vec<String>,
+    limit: Option<(i64, i64)>,
+    is_descending: bool,
+    is_alpha: bool,
+    destination: Option<String>,
+}
+
+impl SortArguments {
+    pub fn parse(arguments: Vec<String>, is_read_only: bool) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return match is_read_only {
+                true => Err(CommandError::InvalidSortRoCommand),
+                false => Err(CommandError::InvalidSortCommand),
+            };
+        }
+
+        let mut sort_arguments = Self {
+            key: arguments[0].clone(),
+            by_pattern: None,
+            get_patterns: Vec::new(),
+            limit: None,
+            is_descending: false,
+            is_alpha: false,
+            destination: None,
+        };
+
+        let mut index = 1;
+
+        while index < arguments.len() {
+            let remaining = arguments.len() - index - 1;
+
+            match normalize_keyword(&arguments[index]).as_str() {
+                "ASC" => sort_arguments.is_descending = false,
+                "DESC" => sort_arguments.is_descending = true,
+                "ALPHA" => sort_arguments.is_alpha = true,
+                "LIMIT" if remaining >= 2 => {
+                    let offset = arguments[index + 1]
+                        .parse::<i64>()
+                        .map_err(|_| CommandError::InvalidSortLimit)?;
+                    let count = arguments[index + 2]
+                        .parse::<i64>()
+                        .map_err(|_| CommandError::InvalidSortLimit)?;
+
+                    sort_arguments.limit = Some((offset, count));
+                    index += 2;
+                }
+                "BY" if remaining >= 1 => {
+                    sort_arguments.by_pattern = Some(arguments[index + 1].clone());
+                    index += 1;
+                }
+                "GET" if remaining >= 1 => {
+                    sort_arguments
+                        .get_patterns
+                        .push(arguments[index + 1].clone());
+                    index += 1;
+                }
+                // SORT_RO never writes, so STORE is only accepted by SORT
+                "STORE" if remaining >= 1 && !is_read_only => {
+                    sort_arguments.destination = Some(arguments[index + 1].clone());
+                    index += 1;
+                }
+                _ => return Err(CommandError::SortSyntaxError),
+            }
+
+            index += 1;
+        }
+
+        Ok(sort_arguments)
+    }
+}
+
+pub async fn sort(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let sort_arguments = SortArguments::parse(arguments, false)?;
+
+    let Some(ref destination) = sort_arguments.destination else {
+        let store_guard = store.lock().await;
+        let result = sort_result(&store_guard, &sort_arguments)?;
+
+        return Ok(CommandResult::Response(encode_sort_result(result)));
+    };
+
+    let list_max_listpack_size = server.read().await.list_max_listpack_size;
+
+    let stored_length = {
+        let mut store_guard = store.lock().await;
+        let result = sort_result(&store_guard, &sort_arguments)?;
+
+        // Missing GET lookups are stored as empty strings since lists cannot hold nil
+        let mut list = result
+            .into_iter()
+            .map(|value| value.unwrap_or_default())
+            .collect::<List>();
+        list.update_encoding(list_max_listpack_size);
+        let stored_length = list.len();
+
+        if list.is_empty() {
+            store_guard.remove(destination);
+        } else {
+            store_guard.insert(
+                destination.clone(),
+                Value {
+                    data: DataType::Array(list),
+                    expiration: None,
+                },
+            );
+        }
+
+        stored_length
+    };
+
+    if stored_length > 0 {
+        let mut state_guard = state.lock().await;
+        state_guard.send_to_blpop_subscriber(destination, true);
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(stored_length as i64).encode(),
+    ))
+}
+
+pub async fn sort_ro(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let sort_arguments = SortArguments::parse(arguments, true)?;
+
+    let store_guard = store.lock().await;
+    let result = sort_result(&store_guard, &sort_arguments)?;
+
+    Ok(CommandResult::Response(encode_sort_result(result)))
+}
+
+fn encode_sort_result(result: Vec<Option<String>>) -> String {
+    let response = result
+        .into_iter()
+        .map(|value| match value {
+            Some(value) => RespValue::BulkString(value),
+            None => RespValue::NullBulkString,
+        })
+        .collect();
+
+    RespValue::Array(response).encode()
+}
+
+/// Returns the sorted elements, or the GET lookups for them, within the LIMIT window
+fn sort_result(
+    store: &KeyValueStore,
+    sort_arguments: &SortArguments,
+) -> Result<Vec<Option<String>>, CommandError> {
+    let mut elements = match store.get(&sort_arguments.key) {
+        Some(value) if !is_value_expired(value) => match &value.data {
+            DataType::Array(list) => list.iter().cloned().collect::<Vec<String>>(),
+            DataType::Set(set) => set.iter().cloned().collect(),
+            DataType::SortedSet(sorted_set) => sorted_set
+                .iter()
+                .map(|(member, _)| member.clone())
+                .collect(),
+            _ => return Err(CommandError::WrongType),
+        },
+        _ => Vec::new(),
+    };
+
+    // A BY pattern without `*` cannot refer to per-element keys, so the elements are left unsorted
+    let should_sort = match &sort_arguments.by_pattern {
+        Some(pattern) => pattern.contains('*'),
+        None => true,
+    };
+
+    if should_sort {
+        elements = sort_elements(store, sort_arguments, elements)?;
+    }
+
+    let (start, end) = limit_window(sort_arguments.limit, elements.len());
+
+    if sort_arguments.get_patterns.is_empty() {
+        return Ok(elements[start..end].iter().cloned().map(Some).collect());
+    }
+
+    let mut result = Vec::new();
+
+    for element in &elements[start..end] {
+        for pattern in &sort_arguments.get_patterns {
+            result.push(lookup_pattern(store, pattern, element));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Sorts the elements by their own value or by the value the BY pattern resolves to
+fn sort_elements(
+    store: &KeyValueStore,
+    sort_arguments: &SortArguments,
+    elements: Vec<String>,
+) -> Result<Vec<String>, CommandError> {
+    let weight_of = |element: &String| match &sort_arguments.by_pattern {
+        Some(pattern) => lookup_pattern(store, pattern, element),
+        None => Some(element.clone()),
+    };
+
+    let sorted_elements = if sort_arguments.is_alpha {
+        let mut weighted = elements
+            .into_iter()
+            .map(|element| (weight_of(&element), element))
+            .collect::<Vec<(Option<String>, String)>>();
+
+        weighted.sort_by(|a, b| {
+            let ordering = a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1));
+            apply_direction(ordering, sort_arguments.is_descending)
+        });
+
+        weighted.into_iter().map(|(_, element)| element).collect()
+    } else {
+        let mut weighted = Vec::with_capacity(elements.len());
+
+        for element in elements {
+            // Elements whose BY key does not exist are weighted as 0
+            let weight = match weight_of(&element) {
+                Some(weight) => parse_score(&weight).ok_or(CommandError::SortScoreNotANumber)?,
+                None => 0.0,
+            };
+
+            weighted.push((weight, element));
+        }
+
+        weighted.sort_by(|a, b| {
+            let ordering = a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1));
+            apply_direction(ordering, sort_arguments.is_descending)
+        });
+
+        weighted.into_iter().map(|(_, element)| element).collect()
+    };
+
+    Ok(sorted_elements)
+}
+
+fn apply_direction(ordering: Ordering, is_descending: bool) -> Ordering {
+    if is_descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+/// Converts LIMIT offset and count into a window over a list of the given length
+fn limit_window(limit: Option<(i64, i64)>, len: usize) -> (usize, usize) {
+    let Some((offset, count)) = limit else {
+        return (0, len);
+    };
+
+    let start = (offset.max(0) as usize).min(len);
+    let end = if count < 0 {
+        len
+    } else {
+        start.saturating_add(count as usize).min(len)
+    };
+
+    (start, end)
+}
+
+/// Resolves a BY or GET pattern for an element.
+///
+/// `#` stands for the element itself, the first `*` is replaced by the element
+/// and a `->field` suffix reads a field from a hash instead of a string value.
+fn lookup_pattern(store: &KeyValueStore, pattern: &str, element: &str) -> Option<String> {
+    if pattern == "#" {
+        return Some(element.to_string());
+    }
+
+    let star_index = pattern.find('*')?;
+
+    let (key_pattern, field) = match pattern[star_index + 1..].find("->") {
+        Some(arrow_index) if star_index + 1 + arrow_index + 2 < pattern.len() => {
+            let arrow_index = star_index + 1 + arrow_index;
+            (&pattern[..arrow_index], Some(&pattern[arrow_index + 2..]))
+        }
+        _ => (pattern, None),
+    };
+
+    let key = key_pattern.replacen('*', element, 1);
+
+    let value = store.get(&key).filter(|value| !is_value_expired(value))?;
+
+    match (&value.data, field) {
+        (DataType::String(string), None) => Some(string.clone()),
+        (DataType::Hash(hash), Some(field)) => hash.get(field).cloned(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::limit_window;
+
+    #[test]
+    fn test_limit_window() {
+        let test_cases = vec![
+            (None, 5, (0, 5)),
+            (Some((0, 2)), 5, (0, 2)),
+            (Some((3, 10)), 5, (3, 5)),
+            (Some((7, 2)), 5, (5, 5)),
+            (Some((-1, 2)), 5, (0, 2)),
+            (Some((1, -1)), 5, (1, 5)),
+        ];
+
+        for (limit, len, expected) in test_cases {
+            assert_eq!(limit_window(limit, len), expected);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    sync::{Mutex, mpsc},
+    time::Instant,
+};
+
+use crate::{
+    aof::RewriteGuard,
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, sorted_set::zpop::pop_members,
+    },
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+    sorted_set::format_score,
+    state::{SortedSetSubscriber, State},
+};
+
+pub struct BzpopArguments {
+    keys: Vec<String>,
+    block_duration_secs: f64,
+}
+
+impl BzpopArguments {
+    pub fn parse(arguments: Vec<String>, is_max: bool) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return if is_max {
+                Err(CommandError::InvalidBZPopMaxCommand)
+            } else {
+                Err(CommandError::InvalidBZPopMinCommand)
+            };
+        }
+
+        let block_duration_secs = arguments[arguments.len() - 1]
+            .parse::<f64>()
+            .ok()
+            .filter(|duration| duration.is_finite() && *duration >= 0.0)
+            .ok_or(CommandError::InvalidBZPopTimeout)?;
+
+        Ok(Self {
+            keys: arguments[..arguments.len() - 1].to_vec(),
+            block_duration_secs,
+        })
+    }
+}
+
+pub async fn bzpopmin(
+    client_address: &str,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+    rewrite_guard: &mut RewriteGuard,
+) -> Result<CommandResult, CommandError> {
+    bzpop(
+        client_address,
+        store,
+        state,
+        arguments,
+        false,
+        rewrite_guard,
+    )
+    .await
+}
+
+pub async fn bzpopmax(
+    client_address: &str,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+    rewrite_guard: &mut RewriteGuard,
+) -> Result<CommandResult, CommandError> {
+    bzpop(client_address, store, state, arguments, true, rewrite_guard).await
+}
+
+async fn bzpop(
+    client_address: &str,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+    is_max: bool,
+    rewrite_guard: &mut RewriteGuard,
+) -> Result<CommandResult, CommandError> {
+    let bzpop_arguments = BzpopArguments::parse(arguments, is_max)?;
+
+    // A timeout of 0 blocks forever
+    let deadline = match bzpop_arguments.block_duration_secs {
+        0.0 => None,
+        duration => Some(Instant::now() + Duration::from_secs_f64(duration)),
+    };
+
+    loop {
+        if let Some(response) =
+            pop_from_first_non_empty_key(Arc::clone(&store), &bzpop_arguments.keys, is_max).await?
+        {
+            return Ok(CommandResult::Response(response));
+        }
+
+        let (sender, mut receiver) = mpsc::channel(1);
+
+        {
+            let mut state_guard = state.lock().await;
+
+            for key in &bzpop_arguments.keys {
+                state_guard.add_sorted_set_subscriber(
+                    key.clone(),
+                    SortedSetSubscriber {
+                        client_address: client_address.to_string(),
+                        sender: sender.clone(),
+                    },
+                );
+            }
+        }
+
+        rewrite_guard.release();
+        let notification = match deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline, receiver.recv())
+                .await
+                .ok()
+                .flatten(),
+            None => receiver.recv().await,
+        };
+        rewrite_guard.reacquire().await;
+
+        {
+            let mut state_guard = state.lock().await;
+
+            for key in &bzpop_arguments.keys {
+                state_guard.remove_sorted_set_subscriber(key, client_address);
+            }
+        }
+
+        // Another client may have popped the new members first, in which case we keep waiting
+        if notification.is_none() {
+            return Ok(CommandResult::Response(RespValue::NullArray.encode()));
+        }
+    }
+}
+
+/// Checks the keys from left to right and pops a single member from the first non empty one
+async fn pop_from_first_non_empty_key(
+    store: Arc<Mutex<KeyValueStore>>,
+    keys: &[String],
+    is_max: bool,
+) -> Result<Option<String>, CommandError> {
+    let mut store_guard = store.lock().await;
+
+    for key in keys {
+        if let Some((member, score)) = pop_members(&mut store_guard, key, 1, is_max)?.pop() {
+            return Ok(Some(RespValue::encode_array_from_strings(vec![
+                key.clone(),
+                member,
+                format_score(score),
+            ])));
+        }
+    }
+
+    Ok(None)
+}
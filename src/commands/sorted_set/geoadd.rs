@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        command_error::CommandError,
+        command_handler::CommandResult,
+        keyword_utils::normalize_keyword,
+        sorted_set::{ZaddArguments, apply_zadd},
+    },
+    geohash,
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+    server::RedisServer,
+    state::State,
+};
+
+pub struct GeoaddArguments {
+    key: String,
+    only_new: bool,
+    only_existing: bool,
+    count_changed: bool,
+    members: Vec<(f64, String)>,
+}
+
+impl GeoaddArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 4 {
+            return Err(CommandError::InvalidGeoAddCommand);
+        }
+
+        let (mut only_new, mut only_existing, mut count_changed) = (false, false, false);
+        let mut index = 1;
+
+        while index < arguments.len() {
+            match normalize_keyword(&arguments[index]).as_str() {
+                "NX" => only_new = true,
+                "XX" => only_existing = true,
+                "CH" => count_changed = true,
+                _ => break,
+            }
+
+            index += 1;
+        }
+
+        if only_new && only_existing {
+            return Err(CommandError::IncompatibleZAddNxXxOptions);
+        }
+
+        let coordinates = &arguments[index..];
+
+        if coordinates.is_empty() || !coordinates.len().is_multiple_of(3) {
+            return Err(CommandError::InvalidGeoAddCommand);
+        }
+
+        let mut members = Vec::with_capacity(coordinates.len() / 3);
+
+        for triplet in coordinates.chunks(3) {
+            let longitude = triplet[0]
+                .parse::<f64>()
+                .map_err(|_| CommandError::InvalidSortedSetScore)?;
+            let latitude = triplet[1]
+                .parse::<f64>()
+                .map_err(|_| CommandError::InvalidSortedSetScore)?;
+
+            if !geohash::is_valid_coordinate(longitude, latitude) {
+                return Err(CommandError::InvalidGeoCoordinates(format!(
+                    "{:.6},{:.6}",
+                    longitude, latitude
+                )));
+            }
+
+            let score = geohash::encode(longitude, latitude) as f64;
+            members.push((score, triplet[2].clone()));
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            only_new,
+            only_existing,
+            count_changed,
+            members,
+        })
+    }
+}
+
+/// Stores each member in a sorted set with its geohash as the score
+pub async fn geoadd(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let geoadd_arguments = GeoaddArguments::parse(arguments)?;
+
+    let zadd_arguments = ZaddArguments::new(
+        geoadd_arguments.key,
+        geoadd_arguments.only_new,
+        geoadd_arguments.only_existing,
+        geoadd_arguments.count_changed,
+        geoadd_arguments.members,
+    );
+
+    let response = apply_zadd(server, store, state, &zadd_arguments).await?;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(response).encode(),
+    ))
+}
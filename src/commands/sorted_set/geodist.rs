@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    geohash,
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct GeodistArguments {
+    key: String,
+    first_member: String,
+    second_member: String,
+    unit_in_meters: f64,
+}
+
+impl GeodistArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 && arguments.len() != 4 {
+            return Err(CommandError::InvalidGeoDistCommand);
+        }
+
+        let unit_in_meters = match arguments.get(3) {
+            Some(unit) => {
+                geohash::parse_distance_unit(unit).ok_or(CommandError::UnsupportedGeoUnit)?
+            }
+            None => 1.0,
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            first_member: arguments[1].clone(),
+            second_member: arguments[2].clone(),
+            unit_in_meters,
+        })
+    }
+}
+
+/// Replies with the distance between two members in the requested unit,
+/// or a null bulk string if either member does not exist
+pub async fn geodist(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let geodist_arguments = GeodistArguments::parse(arguments)?;
+    let is_resp3_client = server.read().await.resp3_clients.contains(client_address);
+
+    let store_guard = store.lock().await;
+
+    let sorted_set = match store_guard.get(&geodist_arguments.key) {
+        Some(value) if !is_value_expired(value) => match &value.data {
+            DataType::SortedSet(sorted_set) => sorted_set,
+            _ => return Err(CommandError::WrongType),
+        },
+        _ => return Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+    };
+
+    let (Some(first_score), Some(second_score)) = (
+        sorted_set.score(&geodist_arguments.first_member),
+        sorted_set.score(&geodist_arguments.second_member),
+    ) else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    let (first_longitude, first_latitude) = geohash::decode(first_score as u64);
+    let (second_longitude, second_latitude) = geohash::decode(second_score as u64);
+
+    let distance = geohash::distance(
+        first_longitude,
+        first_latitude,
+        second_longitude,
+        second_latitude,
+    ) / geodist_arguments.unit_in_meters;
+
+    // Distances are rounded to 4 decimals in both protocols
+    let distance = format!("{:.4}", distance);
+
+    match is_resp3_client {
+        true => Ok(CommandResult::Response(
+            RespValue::Double(distance.parse().unwrap_or_default()).encode(),
+        )),
+        false => Ok(CommandResult::Response(
+            RespValue::BulkString(distance).encode(),
+        )),
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, is_value_expired},
+    geohash,
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct GeoposArguments {
+    key: String,
+    members: Vec<String>,
+}
+
+impl GeoposArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return Err(CommandError::InvalidGeoPosCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            members: arguments[1..].to_vec(),
+        })
+    }
+}
+
+/// Replies with the `[longitude, latitude]` decoded from each member's geohash score,
+/// or a null array for members that do not exist
+pub async fn geopos(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let geopos_arguments = GeoposArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let sorted_set = match store_guard.get(&geopos_arguments.key) {
+        Some(value) if !is_value_expired(value) => match &value.data {
+            DataType::SortedSet(sorted_set) => Some(sorted_set),
+            _ => return Err(CommandError::WrongType),
+        },
+        _ => None,
+    };
+
+    let positions = geopos_arguments
+        .members
+        .iter()
+        .map(
+            |member| match sorted_set.and_then(|sorted_set| sorted_set.score(member)) {
+                Some(score) => {
+                    let (longitude, latitude) = geohash::decode(score as u64);
+
+                    RespValue::Array(vec![
+                        RespValue::BulkString(longitude.to_string()),
+                        RespValue::BulkString(latitude.to_string()),
+                    ])
+                }
+                None => RespValue::NullArray,
+            },
+        )
+        .collect();
+
+    Ok(CommandResult::Response(
+        RespValue::Array(positions).encode(),
+    ))
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, is_value_expired,
+        keyword_utils::normalize_keyword,
+    },
+    geohash,
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+enum GeosearchOrigin {
+    Member(String),
+    Coordinates(f64, f64),
+}
+
+#[derive(PartialEq)]
+enum GeosearchOrder {
+    Ascending,
+    Descending,
+}
+
+pub struct GeosearchArguments {
+    key: String,
+    origin: GeosearchOrigin,
+    radius_in_meters: f64,
+    order: Option<GeosearchOrder>,
+    count: Option<usize>,
+}
+
+impl GeosearchArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() {
+            return Err(CommandError::InvalidGeoSearchCommand);
+        }
+
+        let mut origin = None;
+        let mut radius_in_meters = None;
+        let mut order = None;
+        let mut count = None;
+        let mut index = 1;
+
+        while index < arguments.len() {
+            let remaining = arguments.len() - index - 1;
+
+            match normalize_keyword(&arguments[index]).as_str() {
+                "FROMMEMBER" if remaining >= 1 && origin.is_none() => {
+                    origin = Some(GeosearchOrigin::Member(arguments[index + 1].clone()));
+                    index += 1;
+                }
+                "FROMLONLAT" if remaining >= 2 && origin.is_none() => {
+                    let longitude = arguments[index + 1]
+                        .parse::<f64>()
+                        .map_err(|_| CommandError::InvalidSortedSetScore)?;
+                    let latitude = arguments[index + 2]
+                        .parse::<f64>()
+                        .map_err(|_| CommandError::InvalidSortedSetScore)?;
+
+                    if !geohash::is_valid_coordinate(longitude, latitude) {
+                        return Err(CommandError::InvalidGeoCoordinates(format!(
+                            "{:.6},{:.6}",
+                            longitude, latitude
+                        )));
+                    }
+
+                    origin = Some(GeosearchOrigin::Coordinates(longitude, latitude));
+                    index += 2;
+                }
+                "BYRADIUS" if remaining >= 2 && radius_in_meters.is_none() => {
+                    let radius = arguments[index + 1]
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|radius| *radius >= 0.0)
+                        .ok_or(CommandError::InvalidGeoSearchRadius)?;
+                    let unit_in_meters = geohash::parse_distance_unit(&arguments[index + 2])
+                        .ok_or(CommandError::UnsupportedGeoUnit)?;
+
+                    radius_in_meters = Some(radius * unit_in_meters);
+                    index += 2;
+                }
+                "ASC" => order = Some(GeosearchOrder::Ascending),
+                "DESC" => order = Some(GeosearchOrder::Descending),
+                "COUNT" if remaining >= 1 => {
+                    let value = arguments[index + 1]
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|count| *count > 0)
+                        .ok_or(CommandError::InvalidGeoSearchCount)?;
+
+                    count = Some(value);
+                    index += 1;
+                }
+                _ => return Err(CommandError::InvalidGeoSearchCommand),
+            }
+
+            index += 1;
+        }
+
+        let (Some(origin), Some(radius_in_meters)) = (origin, radius_in_meters) else {
+            return Err(CommandError::InvalidGeoSearchCommand);
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            origin,
+            radius_in_meters,
+            order,
+            count,
+        })
+    }
+}
+
+/// Replies with the members within the radius of a member or of a longitude and latitude.
+///
+/// Members come back in sorted set order unless ASC or DESC is given. COUNT without
+/// an order keeps the nearest members, so results are then sorted ascending.
+pub async fn geosearch(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let geosearch_arguments = GeosearchArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let sorted_set = match store_guard.get(&geosearch_arguments.key) {
+        Some(value) if !is_value_expired(value) => match &value.data {
+            DataType::SortedSet(sorted_set) => sorted_set,
+            _ => return Err(CommandError::WrongType),
+        },
+        _ => return Ok(CommandResult::Response(RespValue::Array(vec![]).encode())),
+    };
+
+    let (origin_longitude, origin_latitude) = match &geosearch_arguments.origin {
+        GeosearchOrigin::Member(member) => {
+            let score = sorted_set
+                .score(member)
+                .ok_or(CommandError::GeoMemberNotFound)?;
+            geohash::decode(score as u64)
+        }
+        GeosearchOrigin::Coordinates(longitude, latitude) => (*longitude, *latitude),
+    };
+
+    let mut members = sorted_set
+        .iter()
+        .filter_map(|(member, score)| {
+            let (longitude, latitude) = geohash::decode(score as u64);
+            let distance =
+                geohash::distance(origin_longitude, origin_latitude, longitude, latitude);
+
+            (distance <= geosearch_arguments.radius_in_meters).then_some((distance, member))
+        })
+        .collect::<Vec<(f64, &String)>>();
+
+    let order = match (&geosearch_arguments.order, geosearch_arguments.count) {
+        (Some(order), _) => Some(order),
+        (None, Some(_)) => Some(&GeosearchOrder::Ascending),
+        (None, None) => None,
+    };
+
+    if let Some(order) = order {
+        members.sort_by(|a, b| {
+            let ordering = a.0.total_cmp(&b.0);
+
+            match order {
+                GeosearchOrder::Ascending => ordering,
+                GeosearchOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    if let Some(count) = geosearch_arguments.count {
+        members.truncate(count);
+    }
+
+    let response = members
+        .into_iter()
+        .map(|(_, member)| RespValue::BulkString(member.clone()))
+        .collect();
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
@@ -0,0 +1,37 @@
+mod bzpop;
+mod geoadd;
+mod geodist;
+mod geopos;
+mod geosearch;
+mod zadd;
+mod zcard;
+mod zincrby;
+mod zmpop;
+mod zmscore;
+mod zpop;
+mod zrandmember;
+mod zrange;
+mod zrank;
+mod zrem;
+mod zremrange;
+mod zscore;
+
+pub use bzpop::{BzpopArguments, bzpopmax, bzpopmin};
+pub use geoadd::{GeoaddArguments, geoadd};
+pub use geodist::{GeodistArguments, geodist};
+pub use geopos::{GeoposArguments, geopos};
+pub use geosearch::{GeosearchArguments, geosearch};
+pub use zadd::{ZaddArguments, apply_zadd, zadd};
+pub use zcard::{ZcardArguments, zcard};
+pub use zincrby::{ZincrbyArguments, zincrby};
+pub use zmpop::{ZmpopArguments, zmpop};
+pub use zmscore::{ZmscoreArguments, zmscore};
+pub use zpop::{ZpopArguments, zpopmax, zpopmin};
+pub use zrandmember::{ZrandmemberArguments, zrandmember};
+pub use zrange::{ZrangeArguments, zrange, zrevrange};
+pub use zrank::{ZrankArguments, zrank, zrevrank};
+pub use zrem::{ZremArguments, zrem};
+pub use zremrange::{
+    ZremrangebyrankArguments, ZremrangebyscoreArguments, zremrangebyrank, zremrangebyscore,
+};
+pub use zscore::{ZscoreArguments, zscore};
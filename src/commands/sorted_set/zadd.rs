@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult,
+        keyword_utils::normalize_keyword, sorted_set::zincrby::increment_score,
+    },
+    key_value_store::{DataType, KeyValueStore, Value},
+    resp::RespValue,
+    server::RedisServer,
+    sorted_set::{SortedSet, SortedSetEncodingLimits, encode_score, parse_score},
+    state::State,
+};
+
+#[derive(Debug, PartialEq)]
+enum ZaddCondition {
+    OnlyNew,
+    OnlyExisting,
+}
+
+#[derive(Debug, PartialEq)]
+enum ZaddComparison {
+    GreaterThan,
+    LessThan,
+}
+
+pub struct ZaddArguments {
+    key: String,
+    condition: Option<ZaddCondition>,
+    comparison: Option<ZaddComparison>,
+    count_changed: bool,
+    // With INCR the single score is added to the member's score, like ZINCRBY
+    increment: bool,
+    members: Vec<(f64, String)>,
+}
+
+impl ZaddArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 3 {
+            return Err(CommandError::InvalidZAddCommand);
+        }
+
+        let (mut nx, mut xx, mut gt, mut lt, mut count_changed, mut increment) =
+            (false, false, false, false, false, false);
+        let mut index = 1;
+
+        while index < arguments.len() {
+            match normalize_keyword(&arguments[index]).as_str() {
+                "NX" => nx = true,
+                "XX" => xx = true,
+                "GT" => gt = true,
+                "LT" => lt = true,
+                "CH" => count_changed = true,
+                "INCR" => increment = true,
+                _ => break,
+            }
+
+            index += 1;
+        }
+
+        if nx && xx {
+            return Err(CommandError::IncompatibleZAddNxXxOptions);
+        }
+
+        if (gt && lt) || (nx && (gt || lt)) {
+            return Err(CommandError::IncompatibleZAddGtLtNxOptions);
+        }
+
+        let condition = match (nx, xx) {
+            (true, _) => Some(ZaddCondition::OnlyNew),
+            (_, true) => Some(ZaddCondition::OnlyExisting),
+            _ => None,
+        };
+
+        let comparison = match (gt, lt) {
+            (true, _) => Some(ZaddComparison::GreaterThan),
+            (_, true) => Some(ZaddComparison::LessThan),
+            _ => None,
+        };
+
+        let score_member_pairs = &arguments[index..];
+
+        if score_member_pairs.is_empty() || !score_member_pairs.len().is_multiple_of(2) {
+            return Err(CommandError::InvalidZAddCommand);
+        }
+
+        if increment && score_member_pairs.len() > 2 {
+            return Err(CommandError::InvalidZAddIncrPairs);
+        }
+
+        let mut members = Vec::with_capacity(score_member_pairs.len() / 2);
+
+        for pair in score_member_pairs.chunks(2) {
+            let score = parse_score(&pair[0]).ok_or(CommandError::InvalidSortedSetScore)?;
+            members.push((score, pair[1].clone()));
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            condition,
+            comparison,
+            count_changed,
+            increment,
+            members,
+        })
+    }
+
+    /// Builds the arguments for commands that store their members in a sorted set, like GEOADD
+    pub fn new(
+        key: String,
+        only_new: bool,
+        only_existing: bool,
+        count_changed: bool,
+        members: Vec<(f64, String)>,
+    ) -> Self {
+        let condition = match (only_new, only_existing) {
+            (true, _) => Some(ZaddCondition::OnlyNew),
+            (_, true) => Some(ZaddCondition::OnlyExisting),
+            _ => None,
+        };
+
+        Self {
+            key,
+            condition,
+            comparison: None,
+            count_changed,
+            increment: false,
+            members,
+        }
+    }
+}
+
+pub async fn zadd(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zadd_arguments = ZaddArguments::parse(arguments)?;
+
+    if zadd_arguments.increment {
+        return zadd_increment(client_address, server, store, state, &zadd_arguments).await;
+    }
+
+    let response = apply_zadd(server, store, state, &zadd_arguments).await?;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(response).encode(),
+    ))
+}
+
+/// Runs ZADD with INCR, which replies with the new score like ZINCRBY, or with null
+/// when the NX/XX/GT/LT options prevent the update
+async fn zadd_increment(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    zadd_arguments: &ZaddArguments,
+) -> Result<CommandResult, CommandError> {
+    let (is_resp3_client, zset_encoding_limits) = {
+        let server_guard = server.read().await;
+        (
+            server_guard.resp3_clients.contains(client_address),
+            server_guard.zset_encoding_limits,
+        )
+    };
+
+    let (increment, member) = &zadd_arguments.members[0];
+
+    let result = {
+        let mut store_guard = store.lock().await;
+
+        increment_score(
+            &mut store_guard,
+            &zadd_arguments.key,
+            member,
+            *increment,
+            &zset_encoding_limits,
+            |current_score, new_score| {
+                let is_condition_met = !matches!(
+                    (&zadd_arguments.condition, current_score),
+                    (Some(ZaddCondition::OnlyNew), Some(_))
+                        | (Some(ZaddCondition::OnlyExisting), None)
+                );
+
+                let is_comparison_met = match (&zadd_arguments.comparison, current_score) {
+                    (Some(ZaddComparison::GreaterThan), Some(current_score)) => {
+                        new_score > current_score
+                    }
+                    (Some(ZaddComparison::LessThan), Some(current_score)) => {
+                        new_score < current_score
+                    }
+                    _ => true,
+                };
+
+                is_condition_met && is_comparison_met
+            },
+        )?
+    };
+
+    let Some((new_score, is_new_member)) = result else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    if is_new_member {
+        let mut state_guard = state.lock().await;
+        state_guard.send_to_sorted_set_subscriber(&zadd_arguments.key, true);
+    }
+
+    Ok(CommandResult::Response(
+        encode_score(new_score, is_resp3_client).encode(),
+    ))
+}
+
+/// Adds the members and returns the ZADD reply count, which includes updated members with CH
+pub async fn apply_zadd(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    zadd_arguments: &ZaddArguments,
+) -> Result<i64, CommandError> {
+    let zset_encoding_limits = server.read().await.zset_encoding_limits;
+
+    let (added_members_count, changed_members_count) = {
+        let mut store_guard = store.lock().await;
+        add_members(&mut store_guard, zadd_arguments, &zset_encoding_limits)?
+    };
+
+    // New members can unblock BZPOPMIN/BZPOPMAX clients waiting on this key
+    if added_members_count > 0 {
+        let mut state_guard = state.lock().await;
+        state_guard.send_to_sorted_set_subscriber(&zadd_arguments.key, true);
+    }
+
+    if zadd_arguments.count_changed {
+        Ok(added_members_count + changed_members_count)
+    } else {
+        Ok(added_members_count)
+    }
+}
+
+/// Applies the ZADD conditions and returns the number of added and of updated members
+fn add_members(
+    store: &mut KeyValueStore,
+    zadd_arguments: &ZaddArguments,
+    zset_encoding_limits: &SortedSetEncodingLimits,
+) -> Result<(i64, i64), CommandError> {
+    let value = match store.get_mut(&zadd_arguments.key) {
+        Some(value) => value,
+        None => {
+            // Nothing can be added to a missing key when only existing members may be updated
+            if zadd_arguments.condition == Some(ZaddCondition::OnlyExisting) {
+                return Ok((0, 0));
+            }
+
+            store.entry(zadd_arguments.key.clone()).or_insert(Value {
+                data: DataType::SortedSet(SortedSet::new()),
+                expiration: None,
+            })
+        }
+    };
+
+    let DataType::SortedSet(ref mut sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let mut added_members = Vec::new();
+    let mut changed_members_count = 0;
+
+    for (score, member) in &zadd_arguments.members {
+        match sorted_set.score(member) {
+            Some(current_score) => {
+                if zadd_arguments.condition == Some(ZaddCondition::OnlyNew) {
+                    continue;
+                }
+
+                let should_update = match zadd_arguments.comparison {
+                    Some(ZaddComparison::GreaterThan) => *score > current_score,
+                    Some(ZaddComparison::LessThan) => *score < current_score,
+                    None => true,
+                };
+
+                if should_update && *score != current_score {
+                    sorted_set.insert(member.clone(), *score);
+                    changed_members_count += 1;
+                }
+            }
+            None => {
+                if zadd_arguments.condition == Some(ZaddCondition::OnlyExisting) {
+                    continue;
+                }
+
+                sorted_set.insert(member.clone(), *score);
+                added_members.push(member.as_str());
+            }
+        }
+    }
+
+    let added_members_count = added_members.len() as i64;
+
+    if sorted_set.is_empty() {
+        store.remove(&zadd_arguments.key);
+    } else {
+        sorted_set.update_encoding(added_members, zset_encoding_limits);
+    }
+
+    Ok((added_members_count, changed_members_count))
+}
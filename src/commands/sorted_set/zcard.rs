@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct ZcardArguments {
+    key: String,
+}
+
+impl ZcardArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 1 {
+            return Err(CommandError::InvalidZCardCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+        })
+    }
+}
+
+pub async fn zcard(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zcard_arguments = ZcardArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get(&zcard_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    };
+
+    let DataType::SortedSet(ref sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(sorted_set.len() as i64).encode(),
+    ))
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore, Value},
+    server::RedisServer,
+    sorted_set::{SortedSet, SortedSetEncodingLimits, encode_score, parse_score},
+    state::State,
+};
+
+pub struct ZincrbyArguments {
+    key: String,
+    increment: f64,
+    member: String,
+}
+
+impl ZincrbyArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidZIncrByCommand);
+        }
+
+        let increment = parse_score(&arguments[1]).ok_or(CommandError::InvalidSortedSetScore)?;
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            increment,
+            member: arguments[2].clone(),
+        })
+    }
+}
+
+pub async fn zincrby(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zincrby_arguments = ZincrbyArguments::parse(arguments)?;
+    let (is_resp3_client, zset_encoding_limits) = {
+        let server_guard = server.read().await;
+        (
+            server_guard.resp3_clients.contains(client_address),
+            server_guard.zset_encoding_limits,
+        )
+    };
+
+    let (new_score, is_new_member) = {
+        let mut store_guard = store.lock().await;
+
+        increment_score(
+            &mut store_guard,
+            &zincrby_arguments.key,
+            &zincrby_arguments.member,
+            zincrby_arguments.increment,
+            &zset_encoding_limits,
+            |_, _| true,
+        )?
+        .expect("ZINCRBY always updates the score")
+    };
+
+    if is_new_member {
+        let mut state_guard = state.lock().await;
+        state_guard.send_to_sorted_set_subscriber(&zincrby_arguments.key, true);
+    }
+
+    Ok(CommandResult::Response(
+        encode_score(new_score, is_resp3_client).encode(),
+    ))
+}
+
+/// Adds the increment to the score of the member, counting a missing member as scored 0, and
+/// returns the new score and whether the member was added. `should_update` is given the current
+/// and the new score and can veto the update, in which case nothing is written and None is returned.
+pub fn increment_score(
+    store: &mut KeyValueStore,
+    key: &str,
+    member: &str,
+    increment: f64,
+    zset_encoding_limits: &SortedSetEncodingLimits,
+    should_update: impl FnOnce(Option<f64>, f64) -> bool,
+) -> Result<Option<(f64, bool)>, CommandError> {
+    let current_score = match store.get(key) {
+        Some(value) => {
+            let DataType::SortedSet(ref sorted_set) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            sorted_set.score(member)
+        }
+        None => None,
+    };
+
+    let new_score = current_score.unwrap_or(0.0) + increment;
+
+    // Adding opposite infinities (e.g. inf + -inf) does not produce a valid score
+    if new_score.is_nan() {
+        return Err(CommandError::SortedSetScoreNaN);
+    }
+
+    if !should_update(current_score, new_score) {
+        return Ok(None);
+    }
+
+    let value = store.entry(key.to_string()).or_insert_with(|| Value {
+        data: DataType::SortedSet(SortedSet::new()),
+        expiration: None,
+    });
+
+    let DataType::SortedSet(ref mut sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let is_new_member = sorted_set.insert(member.to_string(), new_score);
+    if is_new_member {
+        sorted_set.update_encoding([member], zset_encoding_limits);
+    }
+
+    Ok(Some((new_score, is_new_member)))
+}
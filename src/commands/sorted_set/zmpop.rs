@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+        keyword_utils::normalize_keyword, sorted_set::zpop::pop_members,
+    },
+    key_value_store::KeyValueStore,
+    resp::RespValue,
+    sorted_set::format_score,
+};
+
+pub struct ZmpopArguments {
+    keys: Vec<String>,
+    is_max: bool,
+    count: usize,
+}
+
+impl ZmpopArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 3 {
+            return Err(CommandError::InvalidZMPopCommand);
+        }
+
+        let number_of_keys = match arguments[0].parse::<i64>() {
+            Ok(number_of_keys) if number_of_keys > 0 => number_of_keys as usize,
+            _ => return Err(CommandError::InvalidZMPopNumberOfKeys),
+        };
+
+        let Some(keys) = arguments.get(1..1 + number_of_keys) else {
+            return Err(CommandError::ZMPopNumberOfKeysMismatch);
+        };
+
+        let Some(direction) = arguments.get(1 + number_of_keys) else {
+            return Err(CommandError::InvalidZMPopCommand);
+        };
+
+        let is_max = match normalize_keyword(direction).as_str() {
+            "MIN" => false,
+            "MAX" => true,
+            _ => return Err(CommandError::InvalidZMPopCommand),
+        };
+
+        let count = match &arguments[2 + number_of_keys..] {
+            [] => 1,
+            [option, count] if is_keyword(option, "COUNT") => match count.parse::<i64>() {
+                Ok(count) if count > 0 => count as usize,
+                _ => return Err(CommandError::InvalidZMPopCount),
+            },
+            _ => return Err(CommandError::InvalidZMPopCommand),
+        };
+
+        Ok(Self {
+            keys: keys.to_vec(),
+            is_max,
+            count,
+        })
+    }
+}
+
+/// Pops up to `count` members from the first of the keys holding a non-empty sorted set,
+/// replying with that key and the popped member-score pairs, or with a null array when
+/// every sorted set is empty
+pub async fn zmpop(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zmpop_arguments = ZmpopArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    for key in &zmpop_arguments.keys {
+        let popped_members = pop_members(
+            &mut store_guard,
+            key,
+            zmpop_arguments.count,
+            zmpop_arguments.is_max,
+        )?;
+
+        if popped_members.is_empty() {
+            continue;
+        }
+
+        let popped_members = popped_members
+            .into_iter()
+            .map(|(member, score)| {
+                RespValue::Array(vec![
+                    RespValue::BulkString(member),
+                    RespValue::BulkString(format_score(score)),
+                ])
+            })
+            .collect();
+
+        return Ok(CommandResult::Response(
+            RespValue::Array(vec![
+                RespValue::BulkString(key.clone()),
+                RespValue::Array(popped_members),
+            ])
+            .encode(),
+        ));
+    }
+
+    Ok(CommandResult::Response(RespValue::NullArray.encode()))
+}
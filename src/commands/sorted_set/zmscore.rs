@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+    sorted_set::encode_score,
+};
+
+pub struct ZmscoreArguments {
+    key: String,
+    members: Vec<String>,
+}
+
+impl ZmscoreArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidZMScoreCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            members: arguments[1..].to_vec(),
+        })
+    }
+}
+
+/// Replies with the score of every member, or null for members that are not in the sorted set
+pub async fn zmscore(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zmscore_arguments = ZmscoreArguments::parse(arguments)?;
+    let is_resp3_client = server.read().await.resp3_clients.contains(client_address);
+
+    let store_guard = store.lock().await;
+
+    let sorted_set = match store_guard.get(&zmscore_arguments.key) {
+        Some(value) => {
+            let DataType::SortedSet(ref sorted_set) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            Some(sorted_set)
+        }
+        None => None,
+    };
+
+    let response = zmscore_arguments
+        .members
+        .iter()
+        .map(
+            |member| match sorted_set.and_then(|sorted_set| sorted_set.score(member)) {
+                Some(score) => encode_score(score, is_resp3_client),
+                None => RespValue::NullBulkString,
+            },
+        )
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
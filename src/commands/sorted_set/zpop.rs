@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    sorted_set::format_score,
+};
+
+pub struct ZpopArguments {
+    key: String,
+    count: usize,
+}
+
+impl ZpopArguments {
+    pub fn parse(arguments: Vec<String>, is_max: bool) -> Result<Self, CommandError> {
+        if arguments.is_empty() || arguments.len() > 2 {
+            return if is_max {
+                Err(CommandError::InvalidZPopMaxCommand)
+            } else {
+                Err(CommandError::InvalidZPopMinCommand)
+            };
+        }
+
+        let count = match arguments.get(1) {
+            Some(count) => count
+                .parse::<usize>()
+                .map_err(|_| CommandError::InvalidZPopCount)?,
+            None => 1,
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            count,
+        })
+    }
+}
+
+pub async fn zpopmin(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    zpop(store, arguments, false).await
+}
+
+pub async fn zpopmax(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    zpop(store, arguments, true).await
+}
+
+async fn zpop(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+    is_max: bool,
+) -> Result<CommandResult, CommandError> {
+    let zpop_arguments = ZpopArguments::parse(arguments, is_max)?;
+
+    let mut store_guard = store.lock().await;
+
+    let popped_members = pop_members(
+        &mut store_guard,
+        &zpop_arguments.key,
+        zpop_arguments.count,
+        is_max,
+    )?;
+
+    let mut response = Vec::with_capacity(popped_members.len() * 2);
+
+    for (member, score) in popped_members {
+        response.push(member);
+        response.push(format_score(score));
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::encode_array_from_strings(response),
+    ))
+}
+
+/// Pops up to `count` members from the lowest or highest end of the sorted set at `key`,
+/// deleting the key once it is empty
+pub fn pop_members(
+    store: &mut KeyValueStore,
+    key: &str,
+    count: usize,
+    is_max: bool,
+) -> Result<Vec<(String, f64)>, CommandError> {
+    let Some(value) = store.get_mut(key) else {
+        return Ok(Vec::new());
+    };
+
+    let DataType::SortedSet(ref mut sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let mut popped_members = Vec::new();
+
+    while popped_members.len() < count {
+        let popped_member = if is_max {
+            sorted_set.pop_max()
+        } else {
+            sorted_set.pop_min()
+        };
+
+        match popped_member {
+            Some(popped_member) => popped_members.push(popped_member),
+            None => break,
+        }
+    }
+
+    if sorted_set.is_empty() {
+        store.remove(key);
+    }
+
+    Ok(popped_members)
+}
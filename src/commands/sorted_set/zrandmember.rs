@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use rand::seq::{IndexedRandom, IteratorRandom};
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    sorted_set::format_score,
+};
+
+pub struct ZrandmemberArguments {
+    key: String,
+    // A positive count asks for distinct members and a negative one allows repeats
+    count: Option<i64>,
+    with_scores: bool,
+}
+
+impl ZrandmemberArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.is_empty() || arguments.len() > 3 {
+            return Err(CommandError::InvalidZRandMemberCommand);
+        }
+
+        let count = match arguments.get(1) {
+            Some(count) => Some(
+                count
+                    .parse::<i64>()
+                    .map_err(|_| CommandError::InvalidZRandMemberCount)?,
+            ),
+            None => None,
+        };
+
+        let with_scores = match arguments.get(2) {
+            Some(option) if is_keyword(option, "WITHSCORES") => true,
+            Some(_) => return Err(CommandError::InvalidZRandMemberCommand),
+            None => false,
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            count,
+            with_scores,
+        })
+    }
+}
+
+pub async fn zrandmember(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zrandmember_arguments = ZrandmemberArguments::parse(arguments)?;
+
+    let store_guard = store.lock().await;
+
+    let sorted_set = match store_guard.get(&zrandmember_arguments.key) {
+        Some(value) => {
+            let DataType::SortedSet(ref sorted_set) = value.data else {
+                return Err(CommandError::WrongType);
+            };
+
+            Some(sorted_set)
+        }
+        None => None,
+    };
+
+    let mut rng = rand::rng();
+
+    let Some(count) = zrandmember_arguments.count else {
+        let member = sorted_set.and_then(|sorted_set| sorted_set.iter().choose(&mut rng));
+
+        return match member {
+            Some((member, _)) => Ok(CommandResult::Response(
+                RespValue::BulkString(member.clone()).encode(),
+            )),
+            None => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+        };
+    };
+
+    let entries: Vec<(&String, f64)> = sorted_set
+        .map(|sorted_set| sorted_set.iter().collect())
+        .unwrap_or_default();
+
+    let chosen_entries: Vec<&(&String, f64)> = if count >= 0 {
+        entries.iter().choose_multiple(&mut rng, count as usize)
+    } else {
+        (0..count.unsigned_abs())
+            .filter_map(|_| entries.choose(&mut rng))
+            .collect()
+    };
+
+    let response = chosen_entries
+        .into_iter()
+        .flat_map(|(member, score)| {
+            let mut items = vec![RespValue::BulkString(member.to_string())];
+
+            if zrandmember_arguments.with_scores {
+                items.push(RespValue::BulkString(format_score(*score)));
+            }
+
+            items
+        })
+        .collect::<Vec<RespValue>>();
+
+    Ok(CommandResult::Response(RespValue::Array(response).encode()))
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, keyword_utils::is_keyword,
+        lrange::validate_range_indexes,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    sorted_set::format_score,
+};
+
+pub struct ZrangeArguments {
+    key: String,
+    start_index: isize,
+    end_index: isize,
+    with_scores: bool,
+}
+
+impl ZrangeArguments {
+    pub fn parse(arguments: Vec<String>, is_reverse: bool) -> Result<Self, CommandError> {
+        let invalid_command_error = if is_reverse {
+            CommandError::InvalidZRevRangeCommand
+        } else {
+            CommandError::InvalidZRangeCommand
+        };
+
+        if arguments.len() != 3 && arguments.len() != 4 {
+            return Err(invalid_command_error);
+        }
+
+        let with_scores = match arguments.get(3) {
+            Some(option) if is_keyword(option, "WITHSCORES") => true,
+            Some(_) => return Err(invalid_command_error),
+            None => false,
+        };
+
+        let Ok(start_index) = arguments[1].parse::<isize>() else {
+            return Err(CommandError::InvalidZRangeCommandArgument);
+        };
+
+        let Ok(end_index) = arguments[2].parse::<isize>() else {
+            return Err(CommandError::InvalidZRangeCommandArgument);
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            start_index,
+            end_index,
+            with_scores,
+        })
+    }
+}
+
+pub async fn zrange(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    zrange_by_rank(store, arguments, false).await
+}
+
+pub async fn zrevrange(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    zrange_by_rank(store, arguments, true).await
+}
+
+async fn zrange_by_rank(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+    is_reverse: bool,
+) -> Result<CommandResult, CommandError> {
+    let zrange_arguments = ZrangeArguments::parse(arguments, is_reverse)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get(&zrange_arguments.key) else {
+        return Ok(CommandResult::Response(
+            RespValue::Array(Vec::new()).encode(),
+        ));
+    };
+
+    let DataType::SortedSet(ref sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let Ok((start, end)) = validate_range_indexes(
+        sorted_set.len(),
+        zrange_arguments.start_index,
+        zrange_arguments.end_index,
+    ) else {
+        return Ok(CommandResult::Response(
+            RespValue::Array(Vec::new()).encode(),
+        ));
+    };
+
+    let members: Box<dyn Iterator<Item = (&String, f64)>> = if is_reverse {
+        Box::new(sorted_set.iter().rev())
+    } else {
+        Box::new(sorted_set.iter())
+    };
+
+    let mut response = Vec::new();
+
+    for (member, score) in members.skip(start).take(end - start + 1) {
+        response.push(member.clone());
+
+        if zrange_arguments.with_scores {
+            response.push(format_score(score));
+        }
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::encode_array_from_strings(response),
+    ))
+}
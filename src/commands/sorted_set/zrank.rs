@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct ZrankArguments {
+    key: String,
+    member: String,
+}
+
+impl ZrankArguments {
+    pub fn parse(arguments: Vec<String>, is_reverse: bool) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return if is_reverse {
+                Err(CommandError::InvalidZRevRankCommand)
+            } else {
+                Err(CommandError::InvalidZRankCommand)
+            };
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            member: arguments[1].clone(),
+        })
+    }
+}
+
+pub async fn zrank(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    member_rank(store, arguments, false).await
+}
+
+pub async fn zrevrank(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    member_rank(store, arguments, true).await
+}
+
+async fn member_rank(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+    is_reverse: bool,
+) -> Result<CommandResult, CommandError> {
+    let zrank_arguments = ZrankArguments::parse(arguments, is_reverse)?;
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get(&zrank_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    let DataType::SortedSet(ref sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    match sorted_set.rank(&zrank_arguments.member) {
+        Some(rank) => {
+            let rank = if is_reverse {
+                sorted_set.len() - 1 - rank
+            } else {
+                rank
+            };
+
+            Ok(CommandResult::Response(
+                RespValue::Integer(rank as i64).encode(),
+            ))
+        }
+        None => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct ZremArguments {
+    key: String,
+    members: Vec<String>,
+}
+
+impl ZremArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidZRemCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            members: arguments[1..].to_vec(),
+        })
+    }
+}
+
+pub async fn zrem(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zrem_arguments = ZremArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get_mut(&zrem_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    };
+
+    let DataType::SortedSet(ref mut sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let removed_members_count = zrem_arguments
+        .members
+        .iter()
+        .filter(|member| sorted_set.remove(member))
+        .count();
+
+    if sorted_set.is_empty() {
+        store_guard.remove(&zrem_arguments.key);
+    }
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(removed_members_count as i64).encode(),
+    ))
+}
@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, lrange::validate_range_indexes,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    sorted_set::{ScoreBound, SortedSet, is_score_in_range},
+};
+
+pub struct ZremrangebyrankArguments {
+    key: String,
+    start_index: isize,
+    end_index: isize,
+}
+
+impl ZremrangebyrankArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidZRemRangeByRankCommand);
+        }
+
+        let Ok(start_index) = arguments[1].parse::<isize>() else {
+            return Err(CommandError::InvalidZRangeCommandArgument);
+        };
+
+        let Ok(end_index) = arguments[2].parse::<isize>() else {
+            return Err(CommandError::InvalidZRangeCommandArgument);
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            start_index,
+            end_index,
+        })
+    }
+}
+
+pub struct ZremrangebyscoreArguments {
+    key: String,
+    min: ScoreBound,
+    max: ScoreBound,
+}
+
+impl ZremrangebyscoreArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 3 {
+            return Err(CommandError::InvalidZRemRangeByScoreCommand);
+        }
+
+        let (Some(min), Some(max)) = (
+            ScoreBound::parse(&arguments[1]),
+            ScoreBound::parse(&arguments[2]),
+        ) else {
+            return Err(CommandError::InvalidSortedSetScoreRange);
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            min,
+            max,
+        })
+    }
+}
+
+/// Removes the members whose zero based rank, counted from the lowest score, falls
+/// between the start and stop indexes. Negative indexes count from the highest score.
+pub async fn zremrangebyrank(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zremrangebyrank_arguments = ZremrangebyrankArguments::parse(arguments)?;
+
+    let removed_members_count =
+        remove_members(store, &zremrangebyrank_arguments.key, |sorted_set| {
+            let Ok((start, end)) = validate_range_indexes(
+                sorted_set.len(),
+                zremrangebyrank_arguments.start_index,
+                zremrangebyrank_arguments.end_index,
+            ) else {
+                return Vec::new();
+            };
+
+            sorted_set
+                .iter()
+                .skip(start)
+                .take(end - start + 1)
+                .map(|(member, _)| member.clone())
+                .collect()
+        })
+        .await?;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(removed_members_count as i64).encode(),
+    ))
+}
+
+/// Removes the members whose score lies between min and max
+pub async fn zremrangebyscore(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zremrangebyscore_arguments = ZremrangebyscoreArguments::parse(arguments)?;
+
+    let removed_members_count =
+        remove_members(store, &zremrangebyscore_arguments.key, |sorted_set| {
+            sorted_set
+                .iter()
+                .filter(|(_, score)| {
+                    is_score_in_range(
+                        *score,
+                        &zremrangebyscore_arguments.min,
+                        &zremrangebyscore_arguments.max,
+                    )
+                })
+                .map(|(member, _)| member.clone())
+                .collect()
+        })
+        .await?;
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(removed_members_count as i64).encode(),
+    ))
+}
+
+/// Removes the members picked by `select_members` from the sorted set at `key`,
+/// deleting the key once it is empty, and returns how many were removed
+async fn remove_members(
+    store: Arc<Mutex<KeyValueStore>>,
+    key: &str,
+    select_members: impl FnOnce(&SortedSet) -> Vec<String>,
+) -> Result<usize, CommandError> {
+    let mut store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get_mut(key) else {
+        return Ok(0);
+    };
+
+    let DataType::SortedSet(ref mut sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let members = select_members(sorted_set);
+
+    for member in &members {
+        sorted_set.remove(member);
+    }
+
+    if sorted_set.is_empty() {
+        store_guard.remove(key);
+    }
+
+    Ok(members.len())
+}
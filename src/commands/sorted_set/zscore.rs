@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+    sorted_set::encode_score,
+};
+
+pub struct ZscoreArguments {
+    key: String,
+    member: String,
+}
+
+impl ZscoreArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidZScoreCommand);
+        }
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            member: arguments[1].clone(),
+        })
+    }
+}
+
+pub async fn zscore(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let zscore_arguments = ZscoreArguments::parse(arguments)?;
+    let is_resp3_client = server.read().await.resp3_clients.contains(client_address);
+
+    let store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get(&zscore_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::NullBulkString.encode()));
+    };
+
+    let DataType::SortedSet(ref sorted_set) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    match sorted_set.score(&zscore_arguments.member) {
+        Some(score) => Ok(CommandResult::Response(
+            encode_score(score, is_resp3_client).encode(),
+        )),
+        None => Ok(CommandResult::Response(RespValue::NullBulkString.encode())),
+    }
+}
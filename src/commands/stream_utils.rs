@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use crate::resp::RespValue;
+use crate::{commands::keyword_utils::is_keyword, resp::RespValue};
 
 pub fn validate_stream_id(
     stream_id: &str,
@@ -31,6 +31,46 @@ pub fn validate_stream_id(
     return Ok((timestamp, Some(sequence)));
 }
 
+/// A `MAXLEN [=|~] <threshold>` trimming strategy of XADD and XTRIM
+#[derive(Debug, PartialEq)]
+pub struct StreamTrimStrategy {
+    pub max_len: usize,
+    pub is_approximate: bool,
+}
+
+/// Parses a trimming strategy at the start of `arguments`. Returns `None` when the
+/// arguments do not start with `MAXLEN`, and otherwise the strategy along with how
+/// many arguments it spans.
+pub fn parse_stream_trim_strategy(
+    arguments: &[String],
+) -> Option<Result<(StreamTrimStrategy, usize), String>> {
+    if !is_keyword(arguments.first()?, "MAXLEN") {
+        return None;
+    }
+
+    let (is_approximate, threshold_index) = match arguments.get(1).map(|arg| arg.as_str()) {
+        Some("~") => (true, 2),
+        Some("=") => (false, 2),
+        _ => (false, 1),
+    };
+
+    let Some(threshold) = arguments.get(threshold_index) else {
+        return Some(Err("syntax error".to_string()));
+    };
+
+    let Ok(max_len) = threshold.parse::<usize>() else {
+        return Some(Err("value is not an integer or out of range".to_string()));
+    };
+
+    Some(Ok((
+        StreamTrimStrategy {
+            max_len,
+            is_approximate,
+        },
+        threshold_index + 1,
+    )))
+}
+
 pub fn parse_stream_entries_to_resp(
     entries: Vec<(&String, &BTreeMap<String, String>)>,
 ) -> RespValue {
@@ -58,7 +98,10 @@ pub fn parse_stream_entries_to_resp(
 mod tests {
     use crate::resp::RespValue;
 
-    use super::{parse_stream_entries_to_resp, validate_stream_id};
+    use super::{
+        StreamTrimStrategy, parse_stream_entries_to_resp, parse_stream_trim_strategy,
+        validate_stream_id,
+    };
 
     #[test]
     fn test_validate_stream_id() {
@@ -103,6 +146,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_stream_trim_strategy() {
+        let test_cases = vec![
+            (vec!["1-1", "field", "value"], None),
+            (
+                vec!["MAXLEN", "3", "*"],
+                Some(Ok((
+                    StreamTrimStrategy {
+                        max_len: 3,
+                        is_approximate: false,
+                    },
+                    2,
+                ))),
+            ),
+            (
+                vec!["maxlen", "~", "3"],
+                Some(Ok((
+                    StreamTrimStrategy {
+                        max_len: 3,
+                        is_approximate: true,
+                    },
+                    3,
+                ))),
+            ),
+            (
+                vec!["MAXLEN", "=", "0"],
+                Some(Ok((
+                    StreamTrimStrategy {
+                        max_len: 0,
+                        is_approximate: false,
+                    },
+                    3,
+                ))),
+            ),
+            (vec!["MAXLEN", "~"], Some(Err("syntax error".to_string()))),
+            (
+                vec!["MAXLEN", "-1"],
+                Some(Err("value is not an integer or out of range".to_string())),
+            ),
+        ];
+
+        for (arguments, expected_result) in test_cases {
+            let arguments = arguments
+                .iter()
+                .map(|arg| arg.to_string())
+                .collect::<Vec<String>>();
+            assert_eq!(
+                parse_stream_trim_strategy(&arguments),
+                expected_result,
+                "parsing trim strategy {:?}",
+                arguments
+            );
+        }
+    }
+
     #[test]
     fn test_parse_stream_entries_to_resp() {
         use std::collections::BTreeMap;
@@ -54,30 +54,31 @@ pub async fn run_transaction_commands_for_master_server(
     let mut responses = Vec::with_capacity(commands.len() + 1);
     responses.push(format!("*{}\r\n", commands.len()));
 
+    let mut write_commands = Vec::new();
+
     for cmd in commands {
         match cmd
-            .handle_command_for_master_server(
+            .handle_transaction_command_for_master_server(
                 &client_address,
                 Arc::clone(&server),
                 Arc::clone(&store),
                 Arc::clone(&state),
+                &mut write_commands,
             )
             .await
         {
-            Ok(resp) => {
-                match resp {
-                    CommandResult::Response(data) => {
-                        responses.push(data);
-                    }
-                    _ => (), // ignore other command results
-                }
+            Ok(CommandResult::Response(data)) => {
+                responses.push(data);
             }
+            Ok(_) => (), // ignore other command results
             Err(e) => {
                 responses.push(e.as_string());
             }
         }
     }
 
+    server.read().await.replicate_transaction(write_commands);
+
     Ok(responses.join(""))
 }
 
@@ -92,6 +93,8 @@ pub async fn run_transaction_commands_for_replica_server(
     responses.push(format!("*{}\r\n", commands.len()));
 
     for cmd in commands {
+        let cmd = cmd.with_absolute_expiration();
+
         match cmd
             .handle_command_for_replica_master_connection(
                 &client_address,
@@ -38,21 +38,16 @@ pub async fn type_command(
         ));
     };
 
-    match value.data {
-        DataType::String(_) => {
-            return Ok(CommandResult::Response(
-                RespValue::SimpleString("string".to_string()).encode(),
-            ));
-        }
-        DataType::Array(_) => {
-            return Ok(CommandResult::Response(
-                RespValue::SimpleString("list".to_string()).encode(),
-            ));
-        }
-        DataType::Stream(_) => {
-            return Ok(CommandResult::Response(
-                RespValue::SimpleString("stream".to_string()).encode(),
-            ));
-        }
-    }
+    let type_name = match value.data {
+        DataType::String(_) => "string",
+        DataType::Array(_) => "list",
+        DataType::Stream(_) => "stream",
+        DataType::Hash(_) => "hash",
+        DataType::SortedSet(_) => "zset",
+        DataType::Set(_) => "set",
+    };
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString(type_name.to_string()).encode(),
+    ))
 }
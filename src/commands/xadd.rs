@@ -4,17 +4,23 @@ use std::{
     time::{SystemTime, SystemTimeError},
 };
 
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{
-    commands::{command_error::CommandError, command_handler::CommandResult, validate_stream_id},
+    commands::{
+        StreamTrimStrategy, command_error::CommandError, command_handler::CommandResult,
+        parse_stream_trim_strategy,
+    },
     key_value_store::{DataType, KeyValueStore, Value},
     resp::RespValue,
+    server::RedisServer,
     state::State,
+    stream::{Stream, format_stream_id},
 };
 
 pub struct XaddArguments {
     key: String,
+    trim_strategy: Option<StreamTrimStrategy>,
     stream_id: String,
     entries: BTreeMap<String, String>,
 }
@@ -25,14 +31,23 @@ impl XaddArguments {
             return Err(CommandError::InvalidXAddCommand);
         }
 
-        if arguments[2..].len() % 2 != 0 {
+        let (trim_strategy, id_index) = match parse_stream_trim_strategy(&arguments[1..]) {
+            Some(Ok((trim_strategy, trim_arguments_len))) => {
+                (Some(trim_strategy), 1 + trim_arguments_len)
+            }
+            Some(Err(e)) => return Err(CommandError::InvalidStreamTrimStrategy(e)),
+            None => (None, 1),
+        };
+
+        if arguments.len() < id_index + 3 || !arguments[id_index + 1..].len().is_multiple_of(2) {
             return Err(CommandError::InvalidXAddCommand);
         }
 
         Ok(Self {
             key: arguments[0].clone(),
-            stream_id: arguments[1].clone(),
-            entries: arguments[2..]
+            trim_strategy,
+            stream_id: arguments[id_index].clone(),
+            entries: arguments[id_index + 1..]
                 .chunks(2)
                 .map(|chunk| (chunk[0].clone(), chunk[1].clone()))
                 .collect::<BTreeMap<String, String>>(),
@@ -41,11 +56,13 @@ impl XaddArguments {
 }
 
 pub async fn xadd(
+    server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
     arguments: Vec<String>,
 ) -> Result<CommandResult, CommandError> {
     let xadd_arguments = XaddArguments::parse(arguments)?;
+    let stream_node_max_entries = server.read().await.stream_node_max_entries;
 
     let validated_stream_id = validate_stream_id_against_store(
         Arc::clone(&store),
@@ -54,34 +71,33 @@ pub async fn xadd(
     )
     .await
     .map_err(|e| match e.as_str() {
-        "Invalid data type for key" => CommandError::InvalidDataTypeForKey,
+        "Invalid data type for key" => CommandError::WrongType,
         _ => CommandError::InvalidStreamId(e),
     })?;
 
     let mut store_guard = store.lock().await;
 
-    match store_guard.get_mut(&xadd_arguments.key) {
-        Some(value) => {
-            let DataType::Stream(ref mut stream) = value.data else {
-                return Err(CommandError::InvalidDataTypeForKey);
-            };
+    let value = store_guard
+        .entry(xadd_arguments.key.clone())
+        .or_insert_with(|| Value {
+            data: DataType::Stream(Stream::default()),
+            expiration: None,
+        });
 
-            stream.insert(validated_stream_id.clone(), xadd_arguments.entries);
-        }
-        None => {
-            store_guard.insert(
-                xadd_arguments.key.clone(),
-                Value {
-                    data: DataType::Stream(BTreeMap::from([(
-                        validated_stream_id.clone(),
-                        xadd_arguments.entries,
-                    )])),
-                    expiration: None,
-                },
-            );
-        }
+    let DataType::Stream(ref mut stream) = value.data else {
+        return Err(CommandError::WrongType);
     };
 
+    stream.insert(validated_stream_id.clone(), xadd_arguments.entries);
+
+    if let Some(trim_strategy) = xadd_arguments.trim_strategy {
+        stream.trim_to_max_len(
+            trim_strategy.max_len,
+            trim_strategy.is_approximate,
+            stream_node_max_entries,
+        );
+    }
+
     let mut state_guard = state.lock().await;
     state_guard.send_to_xread_subscribers(&xadd_arguments.key, &validated_stream_id, true)?;
 
@@ -98,9 +114,14 @@ async fn validate_stream_id_against_store(
     if stream_id == "*" {
         let timestamp = get_timestamp_in_milliseconds()
             .map_err(|_| "System time is before unix epoch".to_string())?;
-        let sequence = get_next_sequence_for_timestamp(store, key, timestamp).await?;
+        let last_id = get_last_id(store, key).await?;
 
-        return Ok(format!("{}-{}", timestamp, sequence));
+        // The clock can lag behind the last ID, e.g. after XSETID moved it into the future
+        if timestamp <= last_id.0 {
+            return Ok(format_stream_id((last_id.0, last_id.1 + 1)));
+        }
+
+        return Ok(format_stream_id((timestamp, 0)));
     }
 
     let (timestamp, sequence_part) = parse_stream_id_parts(stream_id)?;
@@ -113,7 +134,7 @@ async fn validate_stream_id_against_store(
 
     let sequence = sequence_part
         .parse::<u128>()
-        .map_err(|_| "The ID specified in XADD must be greater than 0-0".to_string())?;
+        .map_err(|_| "Invalid stream ID specified as stream command argument".to_string())?;
 
     if timestamp == 0 && sequence == 0 {
         return Err("The ID specified in XADD must be greater than 0-0".to_string());
@@ -128,12 +149,12 @@ fn parse_stream_id_parts(stream_id: &str) -> Result<(u128, &str), String> {
     let parts = stream_id.split('-').collect::<Vec<&str>>();
 
     if parts.len() != 2 || parts[0].is_empty() || parts[1].is_empty() {
-        return Err("Invalid stream ID format".to_string());
+        return Err("Invalid stream ID specified as stream command argument".to_string());
     }
 
     let timestamp = parts[0]
         .parse::<u128>()
-        .map_err(|_| "The ID specified in XADD must be greater than 0-0")?;
+        .map_err(|_| "Invalid stream ID specified as stream command argument".to_string())?;
 
     Ok((timestamp, parts[1]))
 }
@@ -156,38 +177,31 @@ async fn validate_against_existing_entries(
     Ok(())
 }
 
-async fn get_next_sequence_for_timestamp(
-    store: Arc<Mutex<KeyValueStore>>,
-    key: &str,
-    timestamp: u128,
-) -> Result<u128, String> {
+/// Last ID generated for the stream at `key`, which is 0-0 when the stream does not exist
+async fn get_last_id(store: Arc<Mutex<KeyValueStore>>, key: &str) -> Result<(u128, u128), String> {
     let store_guard = store.lock().await;
 
     let Some(value) = store_guard.get(key) else {
-        if timestamp == 0 {
-            return Ok(1);
-        } else {
-            return Ok(0);
-        }
+        return Ok((0, 0));
     };
 
     let DataType::Stream(ref stream) = value.data else {
         return Err("Invalid data type for key".to_string());
     };
 
-    let Some(max_stream_id) = stream.keys().max() else {
-        return Ok(0);
-    };
-
-    let (max_timestamp, max_sequence) = validate_stream_id(max_stream_id, true)?;
+    Ok(stream.last_id())
+}
 
-    let Some(sequence) = max_sequence else {
-        return Err("Invalid stream ID format".to_string());
-    };
+async fn get_next_sequence_for_timestamp(
+    store: Arc<Mutex<KeyValueStore>>,
+    key: &str,
+    timestamp: u128,
+) -> Result<u128, String> {
+    let (last_timestamp, last_sequence) = get_last_id(store, key).await?;
 
-    if timestamp == max_timestamp {
-        Ok(sequence + 1)
-    } else if timestamp > max_timestamp {
+    if timestamp == last_timestamp {
+        Ok(last_sequence + 1)
+    } else if timestamp > last_timestamp {
         Ok(0)
     } else {
         Err(
@@ -211,7 +225,10 @@ mod tests {
 
     use tokio::sync::Mutex;
 
-    use crate::key_value_store::{DataType, KeyValueStore, Value};
+    use crate::{
+        key_value_store::{DataType, KeyValueStore, Value},
+        stream::Stream,
+    };
 
     use super::{
         get_next_sequence_for_timestamp, get_timestamp_in_milliseconds, parse_stream_id_parts,
@@ -234,15 +251,33 @@ mod tests {
                 "999999999999999999999-123",
                 Ok((999999999999999999999, "123")),
             ),
-            ("invalid", Err("Invalid stream ID format".to_string())),
-            ("", Err("Invalid stream ID format".to_string())),
-            ("123", Err("Invalid stream ID format".to_string())),
-            ("123-456-789", Err("Invalid stream ID format".to_string())),
-            ("-123", Err("Invalid stream ID format".to_string())),
-            ("123-", Err("Invalid stream ID format".to_string())),
+            (
+                "invalid",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
+            (
+                "",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
+            (
+                "123",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
+            (
+                "123-456-789",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
+            (
+                "-123",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
+            (
+                "123-",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
             (
                 "invalid-123",
-                Err("The ID specified in XADD must be greater than 0-0".to_string()),
+                Err("Invalid stream ID specified as stream command argument".to_string()),
             ),
         ];
 
@@ -261,7 +296,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream1".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "0-1".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -276,7 +311,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream1".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "0-1".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -291,7 +326,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream2".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -306,7 +341,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream2".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -321,7 +356,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream2".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -369,7 +404,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream1".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -385,7 +420,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream1".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -404,7 +439,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream1".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -423,7 +458,7 @@ mod tests {
                 KeyValueStore::from([(
                     "stream1".to_string(),
                     Value {
-                        data: DataType::Stream(BTreeMap::from([(
+                        data: DataType::Stream(Stream::from([(
                             "1234-5".to_string(),
                             BTreeMap::new(),
                         )])),
@@ -454,7 +489,7 @@ mod tests {
             (
                 "fruits".to_string(),
                 Value {
-                    data: DataType::Stream(BTreeMap::from([
+                    data: DataType::Stream(Stream::from([
                         (
                             "0-0".to_string(),
                             BTreeMap::from([("apple".to_string(), "mango".to_string())]),
@@ -470,7 +505,7 @@ mod tests {
             (
                 "sensor".to_string(),
                 Value {
-                    data: DataType::Stream(BTreeMap::from([(
+                    data: DataType::Stream(Stream::from([(
                         "1526919030474-0".to_string(),
                         BTreeMap::from([("temperature".to_string(), "37".to_string())]),
                     )])),
@@ -490,23 +525,27 @@ mod tests {
             (
                 "key",
                 "stream_id",
-                Err("Invalid stream ID format".to_string()),
+                Err("Invalid stream ID specified as stream command argument".to_string()),
             ),
             (
                 "key",
                 "invalid",
-                Err("Invalid stream ID format".to_string()),
+                Err("Invalid stream ID specified as stream command argument".to_string()),
+            ),
+            (
+                "key",
+                "-1-1",
+                Err("Invalid stream ID specified as stream command argument".to_string()),
             ),
-            ("key", "-1-1", Err("Invalid stream ID format".to_string())),
             (
                 "key",
                 "invalid-1",
-                Err("The ID specified in XADD must be greater than 0-0".to_string()),
+                Err("Invalid stream ID specified as stream command argument".to_string()),
             ),
             (
                 "key",
                 "1-invalid",
-                Err("The ID specified in XADD must be greater than 0-0".to_string()),
+                Err("Invalid stream ID specified as stream command argument".to_string()),
             ),
             (
                 "key",
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{command_error::CommandError, command_handler::CommandResult, validate_stream_id},
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    stream::format_stream_id,
+};
+
+pub struct XdelArguments {
+    key: String,
+    stream_ids: Vec<String>,
+}
+
+impl XdelArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 2 {
+            return Err(CommandError::InvalidXDelCommand);
+        }
+
+        // IDs are normalized so that `5` and `5-0` remove the same entry
+        let stream_ids = arguments[1..]
+            .iter()
+            .map(|stream_id| {
+                validate_stream_id(stream_id, false)
+                    .map(|(timestamp, sequence)| {
+                        format_stream_id((timestamp, sequence.unwrap_or(0)))
+                    })
+                    .map_err(|_| {
+                        CommandError::InvalidStreamId(
+                            "Invalid stream ID specified as stream command argument".to_string(),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<String>, CommandError>>()?;
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            stream_ids,
+        })
+    }
+}
+
+/// Removes entries from a stream and replies with how many existed. The stream keeps its
+/// last generated ID, and is not deleted when it becomes empty, as in Redis.
+pub async fn xdel(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let xdel_arguments = XdelArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get_mut(&xdel_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    };
+
+    let DataType::Stream(ref mut stream) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let deleted = xdel_arguments
+        .stream_ids
+        .iter()
+        .filter(|stream_id| stream.remove(*stream_id).is_some())
+        .count();
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(deleted as i64).encode(),
+    ))
+}
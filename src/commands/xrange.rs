@@ -7,8 +7,9 @@ use crate::{
         command_handler::CommandResult,
         stream_utils::{parse_stream_entries_to_resp, validate_stream_id},
     },
-    key_value_store::{DataType, KeyValueStore, Stream},
+    key_value_store::{DataType, KeyValueStore},
     resp::RespValue,
+    stream::StreamEntry,
 };
 
 pub struct XrangeArguments {
@@ -48,7 +49,7 @@ pub async fn xrange(
     };
 
     let DataType::Stream(ref stream) = value.data else {
-        return Err(CommandError::InvalidDataTypeForKey);
+        return Err(CommandError::WrongType);
     };
 
     let Some(start_stream_id) =
@@ -76,14 +77,14 @@ pub async fn xrange(
                 None
             }
         })
-        .collect::<Vec<(&String, &Stream)>>();
+        .collect::<Vec<(&String, &StreamEntry)>>();
 
     let resp_value = parse_stream_entries_to_resp(entries);
     return Ok(CommandResult::Response(resp_value.encode()));
 }
 
 fn validate_start_stream_id(
-    stream: &BTreeMap<String, Stream>,
+    stream: &BTreeMap<String, StreamEntry>,
     start_stream_id: &str,
 ) -> Result<Option<(u128, Option<u128>)>, CommandError> {
     match start_stream_id {
@@ -107,7 +108,7 @@ fn validate_start_stream_id(
 }
 
 fn validate_end_stream_id(
-    stream: &BTreeMap<String, Stream>,
+    stream: &BTreeMap<String, StreamEntry>,
     end_stream_id: &str,
 ) -> Result<Option<(u128, Option<u128>)>, CommandError> {
     match end_stream_id {
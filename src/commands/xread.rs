@@ -5,15 +5,18 @@ use crate::{
     commands::{
         command_error::CommandError,
         command_handler::CommandResult,
+        keyword_utils::normalize_keyword,
         stream_utils::{parse_stream_entries_to_resp, validate_stream_id},
     },
-    key_value_store::{DataType, KeyValueStore, Stream},
+    key_value_store::{DataType, KeyValueStore},
     resp::RespValue,
     state::{State, XreadSubscriber},
+    stream::StreamEntry,
 };
 
 pub struct XreadArguments {
     blocking_duration: Option<u64>,
+    count: Option<usize>,
     key_stream_pairs: Vec<(String, String)>,
 }
 
@@ -23,27 +26,51 @@ impl XreadArguments {
             return Err(CommandError::InvalidXReadCommand);
         }
 
-        let (blocking_duration, start_data_index) = match arguments[0].to_lowercase().as_str() {
-            "block" => {
-                let duration_ms = arguments[1]
-                    .parse::<u64>()
-                    .map_err(|_| CommandError::InvalidXReadBlockDuration)?;
-
-                if arguments[2].to_lowercase() != "streams" {
+        let mut blocking_duration = None;
+        let mut count = None;
+        let mut start_data_index = 0;
+
+        // BLOCK and COUNT may come in any order before STREAMS
+        loop {
+            let Some(option) = arguments.get(start_data_index) else {
+                return Err(CommandError::InvalidXReadCommand);
+            };
+
+            match normalize_keyword(option).as_str() {
+                "BLOCK" => {
+                    let duration_ms = arguments
+                        .get(start_data_index + 1)
+                        .ok_or(CommandError::InvalidXReadCommand)?
+                        .parse::<u64>()
+                        .map_err(|_| CommandError::InvalidXReadBlockDuration)?;
+
+                    blocking_duration = Some(duration_ms);
+                    start_data_index += 2;
+                }
+                "COUNT" => {
+                    let max_entries = arguments
+                        .get(start_data_index + 1)
+                        .ok_or(CommandError::InvalidXReadCommand)?
+                        .parse::<usize>()
+                        .map_err(|_| CommandError::InvalidXReadCount)?;
+
+                    // A count of 0 returns every entry, as if no count was given
+                    count = Some(max_entries).filter(|count| *count > 0);
+                    start_data_index += 2;
+                }
+                "STREAMS" => {
+                    start_data_index += 1;
+                    break;
+                }
+                _ => {
                     return Err(CommandError::InvalidXReadOption);
                 }
-
-                (Some(duration_ms), 3)
-            }
-            "streams" => (None, 1),
-            _ => {
-                return Err(CommandError::InvalidXReadOption);
             }
-        };
+        }
 
         let data = arguments[start_data_index..].to_vec();
 
-        if data.len() % 2 != 0 {
+        if data.is_empty() || !data.len().is_multiple_of(2) {
             return Err(CommandError::InvalidXReadCommand);
         }
 
@@ -53,17 +80,34 @@ impl XreadArguments {
 
         for i in 0..split_index {
             let key = data[i].clone();
-            let stream_id = data[split_index + i].clone();
+            let stream_id = parse_start_stream_id(&data[split_index + i])?;
             key_stream_pairs.push((key, stream_id));
         }
 
         Ok(Self {
             blocking_duration,
+            count,
             key_stream_pairs,
         })
     }
 }
 
+/// Keeps the special `$` ID and fills in sequence 0 for a bare timestamp, rejecting
+/// anything else that is not a full stream ID, such as the `-` and `+` range bounds
+fn parse_start_stream_id(stream_id: &str) -> Result<String, CommandError> {
+    if stream_id == "$" {
+        return Ok(stream_id.to_string());
+    }
+
+    let (timestamp, sequence) = validate_stream_id(stream_id, false).map_err(|_| {
+        CommandError::InvalidStreamId(
+            "Invalid stream ID specified as stream command argument".to_string(),
+        )
+    })?;
+
+    Ok(format!("{}-{}", timestamp, sequence.unwrap_or(0)))
+}
+
 pub async fn xread(
     client_address: &str,
     store: Arc<Mutex<KeyValueStore>>,
@@ -84,13 +128,18 @@ pub async fn xread(
         };
 
     let Some(blocking_duration_ms) = xread_arguments.blocking_duration else {
-        match read_streams(store, parsed_stream_ids).await {
+        match read_streams(store, parsed_stream_ids, xread_arguments.count).await {
             Ok(response) => return Ok(CommandResult::Response(response)),
             Err(e) => return Err(e),
         }
     };
 
-    let direct_call_response = read_streams(Arc::clone(&store), parsed_stream_ids.clone()).await?;
+    let direct_call_response = read_streams(
+        Arc::clone(&store),
+        parsed_stream_ids.clone(),
+        xread_arguments.count,
+    )
+    .await?;
 
     if direct_call_response != RespValue::Array(Vec::new()).encode() {
         return Ok(CommandResult::Response(direct_call_response));
@@ -109,7 +158,7 @@ pub async fn xread(
     remove_subscribers(state, &parsed_stream_ids, &client_address).await;
 
     match result {
-        Some(_) => match read_streams(store, parsed_stream_ids).await {
+        Some(_) => match read_streams(store, parsed_stream_ids, xread_arguments.count).await {
             Ok(response) => return Ok(CommandResult::Response(response)),
             Err(e) => return Err(e),
         },
@@ -148,7 +197,7 @@ async fn resolve_special_id(
     };
 
     let DataType::Stream(ref stream) = value.data else {
-        return Err(CommandError::InvalidDataTypeForKey);
+        return Err(CommandError::WrongType);
     };
 
     let Some(last_stream_id) = stream.keys().last().cloned() else {
@@ -202,9 +251,11 @@ async fn wait_for_data(
     }
 }
 
+/// Reads the entries after each start ID, keeping at most `count` entries per stream
 async fn read_streams(
     store: Arc<Mutex<KeyValueStore>>,
     key_stream_id_pairs: Vec<(String, String)>,
+    count: Option<usize>,
 ) -> Result<String, CommandError> {
     let store_guard = store.lock().await;
     let mut result_streams = Vec::new();
@@ -215,7 +266,7 @@ async fn read_streams(
         };
 
         let DataType::Stream(stream) = &value.data else {
-            return Err(CommandError::InvalidDataTypeForKey);
+            return Err(CommandError::WrongType);
         };
 
         let start_stream_id =
@@ -231,7 +282,8 @@ async fn read_streams(
                     None
                 }
             })
-            .collect::<Vec<(&String, &Stream)>>();
+            .take(count.unwrap_or(usize::MAX))
+            .collect::<Vec<(&String, &StreamEntry)>>();
 
         if !matching_entries.is_empty() {
             let entries_resp = parse_stream_entries_to_resp(matching_entries);
@@ -260,10 +312,12 @@ pub fn is_xread_stream_id_after(
     false
 }
 
+/// A start ID without a sequence matches every sequence of its timestamp
 fn is_sequence_after(sequence: &Option<u128>, start_sequence: &Option<u128>) -> bool {
     match (sequence, start_sequence) {
         (Some(s), Some(start)) => s > start,
-        _ => false,
+        (_, None) => true,
+        (None, Some(_)) => false,
     }
 }
 
@@ -276,11 +330,12 @@ mod tests {
         commands::command_error::CommandError,
         key_value_store::{DataType, KeyValueStore, Value},
         state::State,
+        stream::Stream,
     };
 
     use super::{
-        add_subscribers, is_sequence_after, is_xread_stream_id_after, parse_stream_ids,
-        read_streams, remove_subscribers, resolve_special_id, wait_for_data,
+        add_subscribers, is_sequence_after, is_xread_stream_id_after, parse_start_stream_id,
+        parse_stream_ids, read_streams, remove_subscribers, resolve_special_id, wait_for_data,
     };
 
     #[test]
@@ -289,9 +344,10 @@ mod tests {
             (Some(5), Some(3), true),
             (Some(3), Some(3), false),
             (Some(2), Some(3), false),
-            (Some(5), None, false),
+            (Some(5), None, true),
+            (Some(0), None, true),
             (None, Some(3), false),
-            (None, None, false),
+            (None, None, true),
         ];
 
         for (sequence, start_sequence, expected) in test_cases {
@@ -313,9 +369,12 @@ mod tests {
             ((1000, Some(5)), (1000, Some(3)), true),
             ((1000, Some(3)), (1000, Some(5)), false),
             ((1000, Some(3)), (1000, Some(3)), false),
-            ((1000, Some(1)), (1000, None), false),
+            ((1000, Some(1)), (1000, None), true),
+            ((1000, Some(0)), (1000, None), true),
+            ((1001, Some(0)), (1000, None), true),
+            ((999, Some(5)), (1000, None), false),
             ((1000, None), (1000, Some(1)), false),
-            ((1000, None), (1000, None), false),
+            ((1000, None), (1000, None), true),
             ((2000, Some(0)), (1000, Some(999)), true),
             ((999, Some(999)), (1000, Some(0)), false),
         ];
@@ -331,6 +390,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_start_stream_id() {
+        let test_cases = vec![
+            ("$", Some("$")),
+            ("0", Some("0-0")),
+            ("1526919030404", Some("1526919030404-0")),
+            ("1526919030404-3", Some("1526919030404-3")),
+            ("+", None),
+            ("-", None),
+            ("1526919030404-", None),
+            ("mango", None),
+        ];
+
+        for (stream_id, expected) in test_cases {
+            let expected = match expected {
+                Some(parsed_stream_id) => Ok(parsed_stream_id.to_string()),
+                None => Err(CommandError::InvalidStreamId(
+                    "Invalid stream ID specified as stream command argument".to_string(),
+                )),
+            };
+
+            assert_eq!(
+                parse_start_stream_id(stream_id),
+                expected,
+                "parsing stream id {}",
+                stream_id
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_parse_stream_ids() {
         let mut store = KeyValueStore::new();
@@ -343,7 +432,7 @@ mod tests {
         store.insert(
             "mystream".to_string(),
             Value {
-                data: DataType::Stream(stream),
+                data: DataType::Stream(Stream::from(stream)),
                 expiration: None,
             },
         );
@@ -397,14 +486,14 @@ mod tests {
         store.insert(
             "mystream".to_string(),
             Value {
-                data: DataType::Stream(stream),
+                data: DataType::Stream(Stream::from(stream)),
                 expiration: None,
             },
         );
         store.insert(
             "empty_stream".to_string(),
             Value {
-                data: DataType::Stream(BTreeMap::new()),
+                data: DataType::Stream(Stream::default()),
                 expiration: None,
             },
         );
@@ -422,7 +511,7 @@ mod tests {
             ("mystream", Ok("3000-10".to_string())),
             ("empty_stream", Err(CommandError::DataNotFound)),
             ("nonexistent", Err(CommandError::DataNotFound)),
-            ("not_a_stream", Err(CommandError::InvalidDataTypeForKey)),
+            ("not_a_stream", Err(CommandError::WrongType)),
         ];
 
         for (key, expected_result) in test_cases {
@@ -565,7 +654,7 @@ mod tests {
         store.insert(
             "mystream".to_string(),
             Value {
-                data: DataType::Stream(stream),
+                data: DataType::Stream(Stream::from(stream)),
                 expiration: None,
             },
         );
@@ -598,7 +687,7 @@ mod tests {
             ),
             (
                 vec![("not_a_stream".to_string(), "0-0".to_string())],
-                Err(CommandError::InvalidDataTypeForKey),
+                Err(CommandError::WrongType),
             ),
             (
                 vec![("mystream".to_string(), "invalid-id".to_string())],
@@ -608,7 +697,7 @@ mod tests {
 
         for (input, expected_result) in test_cases {
             assert_eq!(
-                read_streams(Arc::clone(&key_value_store), input.clone()).await,
+                read_streams(Arc::clone(&key_value_store), input.clone(), None).await,
                 expected_result,
                 "Failed for input: {:?}",
                 input
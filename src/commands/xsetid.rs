@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::{
+    commands::{
+        command_error::CommandError, command_handler::CommandResult, is_value_expired,
+        validate_stream_id,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+};
+
+pub struct XsetidArguments {
+    key: String,
+    last_id: (u128, u128),
+}
+
+impl XsetidArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() != 2 {
+            return Err(CommandError::InvalidXSetIdCommand);
+        }
+
+        // A missing sequence number defaults to 0, as in Redis
+        let (timestamp, sequence) = validate_stream_id(&arguments[1], false).map_err(|_| {
+            CommandError::InvalidStreamId(
+                "Invalid stream ID specified as stream command argument".to_string(),
+            )
+        })?;
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            last_id: (timestamp, sequence.unwrap_or(0)),
+        })
+    }
+}
+
+pub async fn xsetid(
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let xsetid_arguments = XsetidArguments::parse(arguments)?;
+
+    let mut store_guard = store.lock().await;
+
+    let Some(value) = store_guard
+        .get_mut(&xsetid_arguments.key)
+        .filter(|value| !is_value_expired(value))
+    else {
+        return Err(CommandError::NoSuchKey);
+    };
+
+    let DataType::Stream(ref mut stream) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    if stream
+        .top_entry_id()
+        .is_some_and(|top_entry_id| xsetid_arguments.last_id < top_entry_id)
+    {
+        return Err(CommandError::InvalidStreamId(
+            "The ID specified in XSETID is smaller than the target stream top item".to_string(),
+        ));
+    }
+
+    stream.set_last_id(xsetid_arguments.last_id);
+
+    Ok(CommandResult::Response(
+        RespValue::SimpleString("OK".to_string()).encode(),
+    ))
+}
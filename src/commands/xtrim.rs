@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::{
+        StreamTrimStrategy, command_error::CommandError, command_handler::CommandResult,
+        parse_stream_trim_strategy,
+    },
+    key_value_store::{DataType, KeyValueStore},
+    resp::RespValue,
+    server::RedisServer,
+};
+
+pub struct XtrimArguments {
+    key: String,
+    trim_strategy: StreamTrimStrategy,
+}
+
+impl XtrimArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<Self, CommandError> {
+        if arguments.len() < 3 {
+            return Err(CommandError::InvalidXTrimCommand);
+        }
+
+        let trim_strategy = match parse_stream_trim_strategy(&arguments[1..]) {
+            Some(Ok((trim_strategy, trim_arguments_len)))
+                if trim_arguments_len == arguments.len() - 1 =>
+            {
+                trim_strategy
+            }
+            Some(Err(e)) => return Err(CommandError::InvalidStreamTrimStrategy(e)),
+            _ => return Err(CommandError::InvalidXTrimCommand),
+        };
+
+        Ok(Self {
+            key: arguments[0].clone(),
+            trim_strategy,
+        })
+    }
+}
+
+/// Trims a stream down to its `MAXLEN` and replies with how many entries were removed
+pub async fn xtrim(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    arguments: Vec<String>,
+) -> Result<CommandResult, CommandError> {
+    let xtrim_arguments = XtrimArguments::parse(arguments)?;
+    let stream_node_max_entries = server.read().await.stream_node_max_entries;
+
+    let mut store_guard = store.lock().await;
+
+    let Some(value) = store_guard.get_mut(&xtrim_arguments.key) else {
+        return Ok(CommandResult::Response(RespValue::Integer(0).encode()));
+    };
+
+    let DataType::Stream(ref mut stream) = value.data else {
+        return Err(CommandError::WrongType);
+    };
+
+    let removed = stream.trim_to_max_len(
+        xtrim_arguments.trim_strategy.max_len,
+        xtrim_arguments.trim_strategy.is_approximate,
+        stream_node_max_entries,
+    );
+
+    Ok(CommandResult::Response(
+        RespValue::Integer(removed as i64).encode(),
+    ))
+}
@@ -1,48 +1,53 @@
 use std::sync::Arc;
 
-use tokio::io::AsyncWriteExt;
-use tokio::net::tcp::OwnedWriteHalf;
-use tokio::{
-    net::TcpStream,
-    sync::{Mutex, RwLock},
-};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{Mutex, RwLock, mpsc};
+use tokio::task::JoinHandle;
 
 use crate::commands::{
     CommandHandler, CommandResult, run_transaction_commands_for_master_server,
     run_transaction_commands_for_replica_server,
 };
 use crate::rdb::stream_rdb_file;
-use crate::resp::RespValue;
+use crate::resp::{RespError, RespValue};
 use crate::{
-    input::{CommandReadError, read_and_parse_resp},
+    input::{CommandReadError, RequestReader, read_and_parse_resp},
     key_value_store::KeyValueStore,
-    server::RedisServer,
+    server::{RedisServer, spawn_outbound_queue},
     state::State,
 };
 
-pub async fn handle_master_to_client_connection(
-    stream: TcpStream,
+/// Serves a client of a master server over any byte stream, such as a TCP
+/// connection or an in-memory duplex stream
+pub async fn handle_master_to_client_connection<S>(
+    stream: S,
     server: Arc<RwLock<RedisServer>>,
     client_address: String,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
-) {
-    let mut buffer = [0; 1024];
-
-    let (mut reader, writer) = stream.into_split();
+) where
+    S: AsyncRead + AsyncWrite + Send + Sync + 'static,
+{
+    let (reader, writer) = tokio::io::split(stream);
     let writer = Arc::new(RwLock::new(writer));
+    let outbound_sender = spawn_outbound_queue(Arc::clone(&writer));
+    let proto_max_bulk_len = server.read().await.proto_max_bulk_len;
+    let (request_reader, mut requests) = spawn_request_reader(reader, proto_max_bulk_len);
 
-    loop {
-        let parsed_input = match read_and_parse_resp(&mut reader, &mut buffer).await {
+    {
+        let mut server_guard = server.write().await;
+        server_guard.register_client(&client_address);
+    }
+
+    'connection: loop {
+        let Some(parsed_input) = requests.recv().await else {
+            break;
+        };
+
+        let parsed_input = match parsed_input {
             Ok(cmd) => cmd,
             Err(e) => match e {
                 CommandReadError::ConnectionClosed => {
-                    let mut server_guard = server.write().await;
-
-                    if let Some(replicas) = &mut server_guard.replicas {
-                        replicas.remove(&client_address);
-                    }
-
                     break;
                 }
                 _ => {
@@ -57,7 +62,28 @@ pub async fn handle_master_to_client_connection(
             },
         };
 
-        for input in parsed_input {
+        for request in parsed_input {
+            let input = match request {
+                Ok(input) => input,
+                Err(e) if e.is_recoverable() => {
+                    if let Err(e) =
+                        thread_safe_write_to_stream(Arc::clone(&writer), e.as_string().as_bytes())
+                            .await
+                    {
+                        eprintln!("Error writing to stream: {}", e);
+                    }
+                    continue;
+                }
+                Err(e) => {
+                    if let Err(e) =
+                        close_client_connection(Arc::clone(&writer), e.as_string().as_bytes()).await
+                    {
+                        eprintln!("Error writing to stream: {}", e);
+                    }
+                    break 'connection;
+                }
+            };
+
             let command_handler = match CommandHandler::new(input) {
                 Ok(handler) => handler,
                 Err(e) => {
@@ -72,7 +98,12 @@ pub async fn handle_master_to_client_connection(
             };
 
             match command_handler
-                .handle_pub_sub_commands(&client_address, Arc::clone(&writer), Arc::clone(&server))
+                .handle_pub_sub_commands(
+                    &client_address,
+                    outbound_sender.clone(),
+                    Arc::clone(&server),
+                    Arc::clone(&state),
+                )
                 .await
             {
                 Ok(Some(command_result)) => match command_result {
@@ -151,14 +182,26 @@ pub async fn handle_master_to_client_connection(
                         continue;
                     }
 
-                    if let Err(e) =
-                        stream_rdb_file(&client_address, Arc::clone(&writer), Arc::clone(&server))
-                            .await
+                    if let Err(e) = stream_rdb_file(
+                        &client_address,
+                        Arc::clone(&writer),
+                        Arc::clone(&server),
+                        Arc::clone(&store),
+                    )
+                    .await
                     {
                         eprintln!("Error writing to stream: {}", e);
                     }
                     continue;
                 }
+                CommandResult::Quit(response) => {
+                    if let Err(e) =
+                        close_client_connection(Arc::clone(&writer), response.as_bytes()).await
+                    {
+                        eprintln!("Error writing to stream: {}", e);
+                    }
+                    break 'connection;
+                }
                 CommandResult::Batch(commands) => match run_transaction_commands_for_master_server(
                     &client_address,
                     Arc::clone(&server),
@@ -192,15 +235,20 @@ pub async fn handle_master_to_client_connection(
             }
         }
     }
+
+    request_reader.abort();
+    cleanup_client_connection(&client_address, server, state).await;
 }
 
-pub async fn handle_master_to_replica_connection(
+pub async fn handle_master_to_replica_connection<S>(
     master_address: &str,
-    stream: &mut TcpStream,
+    stream: &mut S,
     server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
-) {
+) where
+    S: AsyncReadExt + AsyncWriteExt + Unpin,
+{
     let mut buffer = [0; 1024];
 
     loop {
@@ -259,6 +307,7 @@ pub async fn handle_master_to_replica_connection(
                     }
                     continue;
                 }
+                CommandResult::Quit(_) => (),
                 CommandResult::Batch(commands) => {
                     if let Err(e) = run_transaction_commands_for_replica_server(
                         &master_address,
@@ -278,20 +327,33 @@ pub async fn handle_master_to_replica_connection(
     }
 }
 
-pub async fn handle_replica_to_client_connection(
-    stream: TcpStream,
+/// Serves a client of a replica server over any byte stream
+pub async fn handle_replica_to_client_connection<S>(
+    stream: S,
     server: Arc<RwLock<RedisServer>>,
     client_address: String,
     store: Arc<Mutex<KeyValueStore>>,
     state: Arc<Mutex<State>>,
-) {
-    let mut buffer = [0; 1024];
-
-    let (mut reader, writer) = stream.into_split();
+) where
+    S: AsyncRead + AsyncWrite + Send + Sync + 'static,
+{
+    let (reader, writer) = tokio::io::split(stream);
     let writer = Arc::new(RwLock::new(writer));
+    let outbound_sender = spawn_outbound_queue(Arc::clone(&writer));
+    let proto_max_bulk_len = server.read().await.proto_max_bulk_len;
+    let (request_reader, mut requests) = spawn_request_reader(reader, proto_max_bulk_len);
 
-    loop {
-        let parsed_input = match read_and_parse_resp(&mut reader, &mut buffer).await {
+    {
+        let mut server_guard = server.write().await;
+        server_guard.register_client(&client_address);
+    }
+
+    'connection: loop {
+        let Some(parsed_input) = requests.recv().await else {
+            break;
+        };
+
+        let parsed_input = match parsed_input {
             Ok(cmd) => cmd,
             Err(e) => match e {
                 CommandReadError::ConnectionClosed => {
@@ -309,7 +371,28 @@ pub async fn handle_replica_to_client_connection(
             },
         };
 
-        for input in parsed_input {
+        for request in parsed_input {
+            let input = match request {
+                Ok(input) => input,
+                Err(e) if e.is_recoverable() => {
+                    if let Err(e) =
+                        thread_safe_write_to_stream(Arc::clone(&writer), e.as_string().as_bytes())
+                            .await
+                    {
+                        eprintln!("Error writing to stream: {}", e);
+                    }
+                    continue;
+                }
+                Err(e) => {
+                    if let Err(e) =
+                        close_client_connection(Arc::clone(&writer), e.as_string().as_bytes()).await
+                    {
+                        eprintln!("Error writing to stream: {}", e);
+                    }
+                    break 'connection;
+                }
+            };
+
             let command_handler = match CommandHandler::new(input) {
                 Ok(handler) => handler,
                 Err(e) => {
@@ -324,7 +407,12 @@ pub async fn handle_replica_to_client_connection(
             };
 
             match command_handler
-                .handle_pub_sub_commands(&client_address, Arc::clone(&writer), Arc::clone(&server))
+                .handle_pub_sub_commands(
+                    &client_address,
+                    outbound_sender.clone(),
+                    Arc::clone(&server),
+                    Arc::clone(&state),
+                )
                 .await
             {
                 Ok(Some(command_result)) => match command_result {
@@ -408,6 +496,14 @@ pub async fn handle_replica_to_client_connection(
                     }
                     continue;
                 }
+                CommandResult::Quit(response) => {
+                    if let Err(e) =
+                        close_client_connection(Arc::clone(&writer), response.as_bytes()).await
+                    {
+                        eprintln!("Error writing to stream: {}", e);
+                    }
+                    break 'connection;
+                }
                 CommandResult::Batch(_) => {
                     let error_msg = RespValue::Error(
                         "ERR transactions should not be handled by replica server".to_string(),
@@ -424,12 +520,90 @@ pub async fn handle_replica_to_client_connection(
             }
         }
     }
+
+    request_reader.abort();
+    cleanup_client_connection(&client_address, server, state).await;
 }
 
-async fn thread_safe_write_to_stream(
-    writer: Arc<RwLock<OwnedWriteHalf>>,
+type ReadRequests = Result<Vec<Result<RespValue, RespError>>, CommandReadError>;
+
+// Number of reads from the socket that may wait in a client's request queue
+const REQUEST_QUEUE_CAPACITY: usize = 16;
+
+/// Spawns the task that reads a client's requests and queues them in the order they arrived.
+///
+/// Requests pipelined behind a blocking command wait in the queue until it completes,
+/// so every reply is written in the same order as its request. Once the queue is full
+/// the task stops reading, which leaves the client to TCP backpressure.
+fn spawn_request_reader<R>(
+    reader: R,
+    max_bulk_length: usize,
+) -> (JoinHandle<()>, mpsc::Receiver<ReadRequests>)
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(REQUEST_QUEUE_CAPACITY);
+
+    let handle = tokio::spawn(async move {
+        let mut request_reader = RequestReader::new(reader, max_bulk_length);
+
+        loop {
+            let requests = request_reader.read_requests().await;
+            let connection_closed = requests == Err(CommandReadError::ConnectionClosed);
+
+            if sender.send(requests).await.is_err() || connection_closed {
+                break;
+            }
+        }
+    });
+
+    (handle, receiver)
+}
+
+async fn cleanup_client_connection(
+    client_address: &str,
+    server: Arc<RwLock<RedisServer>>,
+    state: Arc<Mutex<State>>,
+) {
+    {
+        let mut state_guard = state.lock().await;
+        let _ = state_guard.remove_transaction(client_address.to_string());
+    }
+
+    let mut server_guard = server.write().await;
+
+    if let Some(replicas) = &mut server_guard.replicas {
+        replicas.remove(client_address);
+    }
+
+    server_guard.stop_tracking(client_address);
+    server_guard.unsubscribe_client_from_all_channels(client_address);
+    server_guard.resp3_clients.remove(client_address);
+    server_guard.clients.remove(client_address);
+}
+
+async fn close_client_connection<W>(
+    writer: Arc<RwLock<W>>,
+    response: &[u8],
+) -> tokio::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut writer_guard = writer.write().await;
+    writer_guard.write_all(response).await?;
+    writer_guard.flush().await?;
+    writer_guard.shutdown().await?;
+
+    Ok(())
+}
+
+async fn thread_safe_write_to_stream<W>(
+    writer: Arc<RwLock<W>>,
     response: &[u8],
-) -> tokio::io::Result<()> {
+) -> tokio::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
     let mut writer_guard = writer.write().await;
     writer_guard.write_all(response).await?;
     writer_guard.flush().await?;
@@ -0,0 +1,149 @@
+pub const GEO_LONGITUDE_MIN: f64 = -180.0;
+pub const GEO_LONGITUDE_MAX: f64 = 180.0;
+// Latitudes are limited to the range where EPSG:3857 web mercator projections are defined
+pub const GEO_LATITUDE_MIN: f64 = -85.05112878;
+pub const GEO_LATITUDE_MAX: f64 = 85.05112878;
+const GEO_STEP: u32 = 26;
+// Earth radius used by Redis, as defined by the WGS84 ellipsoid's semi-major axis for distances
+const EARTH_RADIUS_IN_METERS: f64 = 6372797.560856;
+
+/// Returns whether the coordinates can be encoded as a geohash
+pub fn is_valid_coordinate(longitude: f64, latitude: f64) -> bool {
+    (GEO_LONGITUDE_MIN..=GEO_LONGITUDE_MAX).contains(&longitude)
+        && (GEO_LATITUDE_MIN..=GEO_LATITUDE_MAX).contains(&latitude)
+}
+
+/// Encodes the coordinates as a 52-bit geohash, interleaving 26 latitude bits in the
+/// even positions with 26 longitude bits in the odd positions like Redis does.
+/// The result fits exactly in the mantissa of a sorted set score.
+pub fn encode(longitude: f64, latitude: f64) -> u64 {
+    let latitude_offset = (latitude - GEO_LATITUDE_MIN) / (GEO_LATITUDE_MAX - GEO_LATITUDE_MIN);
+    let longitude_offset =
+        (longitude - GEO_LONGITUDE_MIN) / (GEO_LONGITUDE_MAX - GEO_LONGITUDE_MIN);
+
+    let cells = (1u64 << GEO_STEP) as f64;
+    let max_cell = (1u64 << GEO_STEP) - 1;
+
+    // The upper bound of each range would otherwise map to one cell past the grid
+    let latitude_cell = ((latitude_offset * cells) as u64).min(max_cell);
+    let longitude_cell = ((longitude_offset * cells) as u64).min(max_cell);
+
+    interleave(latitude_cell, longitude_cell)
+}
+
+/// Decodes a geohash into the longitude and latitude of the center of its cell
+pub fn decode(hash: u64) -> (f64, f64) {
+    let (latitude_cell, longitude_cell) = deinterleave(hash);
+    let cells = (1u64 << GEO_STEP) as f64;
+
+    let latitude_scale = GEO_LATITUDE_MAX - GEO_LATITUDE_MIN;
+    let longitude_scale = GEO_LONGITUDE_MAX - GEO_LONGITUDE_MIN;
+
+    let latitude_min = GEO_LATITUDE_MIN + (latitude_cell as f64 / cells) * latitude_scale;
+    let latitude_max = GEO_LATITUDE_MIN + ((latitude_cell + 1) as f64 / cells) * latitude_scale;
+    let longitude_min = GEO_LONGITUDE_MIN + (longitude_cell as f64 / cells) * longitude_scale;
+    let longitude_max = GEO_LONGITUDE_MIN + ((longitude_cell + 1) as f64 / cells) * longitude_scale;
+
+    let longitude =
+        ((longitude_min + longitude_max) / 2.0).clamp(GEO_LONGITUDE_MIN, GEO_LONGITUDE_MAX);
+    let latitude = ((latitude_min + latitude_max) / 2.0).clamp(GEO_LATITUDE_MIN, GEO_LATITUDE_MAX);
+
+    (longitude, latitude)
+}
+
+/// Great circle distance in meters between two coordinates, using the haversine formula
+pub fn distance(longitude1: f64, latitude1: f64, longitude2: f64, latitude2: f64) -> f64 {
+    let latitude1 = latitude1.to_radians();
+    let latitude2 = latitude2.to_radians();
+    let u = ((latitude2 - latitude1) / 2.0).sin();
+    let v = ((longitude2.to_radians() - longitude1.to_radians()) / 2.0).sin();
+
+    2.0 * EARTH_RADIUS_IN_METERS
+        * (u * u + latitude1.cos() * latitude2.cos() * v * v)
+            .sqrt()
+            .asin()
+}
+
+/// Returns how many meters one of the given distance unit is
+pub fn parse_distance_unit(unit: &str) -> Option<f64> {
+    match unit.to_lowercase().as_str() {
+        "m" => Some(1.0),
+        "km" => Some(1000.0),
+        "mi" => Some(1609.34),
+        "ft" => Some(0.3048),
+        _ => None,
+    }
+}
+
+fn interleave(even_bits: u64, odd_bits: u64) -> u64 {
+    let mut hash = 0;
+
+    for bit in 0..GEO_STEP {
+        hash |= ((even_bits >> bit) & 1) << (2 * bit);
+        hash |= ((odd_bits >> bit) & 1) << (2 * bit + 1);
+    }
+
+    hash
+}
+
+fn deinterleave(hash: u64) -> (u64, u64) {
+    let mut even_bits = 0;
+    let mut odd_bits = 0;
+
+    for bit in 0..GEO_STEP {
+        even_bits |= ((hash >> (2 * bit)) & 1) << bit;
+        odd_bits |= ((hash >> (2 * bit + 1)) & 1) << bit;
+    }
+
+    (even_bits, odd_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, distance, encode, is_valid_coordinate};
+
+    #[test]
+    fn test_encode_matches_redis() {
+        // Scores reported by ZSCORE after GEOADD in Redis
+        let test_cases = vec![
+            (13.361389, 38.115556, 3479099956230698),
+            (15.087269, 37.502669, 3479447370796909),
+        ];
+
+        for (longitude, latitude, expected_hash) in test_cases {
+            assert_eq!(encode(longitude, latitude), expected_hash);
+        }
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let test_cases = vec![
+            (13.361389, 38.115556),
+            (-122.4194, 37.7749),
+            (180.0, 85.05112878),
+            (-180.0, -85.05112878),
+            (0.0, 0.0),
+        ];
+
+        for (longitude, latitude) in test_cases {
+            let (decoded_longitude, decoded_latitude) = decode(encode(longitude, latitude));
+
+            assert!((decoded_longitude - longitude).abs() < 1e-5);
+            assert!((decoded_latitude - latitude).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_distance() {
+        // Distance between Palermo and Catania reported by GEODIST in Redis
+        let distance = distance(13.361389, 38.115556, 15.087269, 37.502669);
+        assert!((distance - 166274.1516).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_is_valid_coordinate() {
+        assert!(is_valid_coordinate(13.361389, 38.115556));
+        assert!(!is_valid_coordinate(180.1, 0.0));
+        assert!(!is_valid_coordinate(0.0, 85.06));
+    }
+}
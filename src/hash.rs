@@ -0,0 +1,162 @@
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// Default of Redis' `hash-max-listpack-entries`
+pub const DEFAULT_HASH_MAX_LISTPACK_ENTRIES: usize = 128;
+/// Default of Redis' `hash-max-listpack-value`
+pub const DEFAULT_HASH_MAX_LISTPACK_VALUE: usize = 64;
+
+/// Thresholds past which a hash leaves the listpack encoding
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HashEncodingLimits {
+    pub max_listpack_entries: usize,
+    pub max_listpack_value: usize,
+}
+
+impl Default for HashEncodingLimits {
+    fn default() -> Self {
+        Self {
+            max_listpack_entries: DEFAULT_HASH_MAX_LISTPACK_ENTRIES,
+            max_listpack_value: DEFAULT_HASH_MAX_LISTPACK_VALUE,
+        }
+    }
+}
+
+// Ordered from the most to the least compact, as a hash only ever moves down this list
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy)]
+enum HashEncoding {
+    #[default]
+    Listpack,
+    Hashtable,
+}
+
+/// A hash is always kept in a `HashMap`, but remembers whether it has ever outgrown a
+/// listpack so OBJECT ENCODING reports `hashtable` from then on, like Redis does.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Hash {
+    fields: HashMap<String, String>,
+    encoding: HashEncoding,
+}
+
+impl Hash {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts the hash to a hashtable once it has too many fields, or a field or
+    /// value longer than the listpack allows. Only the fields just set are checked,
+    /// as the others already fitted the listpack.
+    pub fn update_encoding<'a>(
+        &mut self,
+        set_fields: impl IntoIterator<Item = (&'a str, &'a str)>,
+        limits: &HashEncodingLimits,
+    ) {
+        if self.encoding == HashEncoding::Hashtable {
+            return;
+        }
+
+        if !fits_listpack(self.fields.len(), set_fields, limits) {
+            self.encoding = HashEncoding::Hashtable;
+        }
+    }
+
+    pub fn encoding(&self) -> &str {
+        match self.encoding {
+            HashEncoding::Listpack => "listpack",
+            HashEncoding::Hashtable => "hashtable",
+        }
+    }
+}
+
+impl Deref for Hash {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.fields
+    }
+}
+
+impl DerefMut for Hash {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.fields
+    }
+}
+
+fn fits_listpack<'a>(
+    number_of_fields: usize,
+    fields: impl IntoIterator<Item = (&'a str, &'a str)>,
+    limits: &HashEncodingLimits,
+) -> bool {
+    number_of_fields <= limits.max_listpack_entries
+        && fields.into_iter().all(|(field, value)| {
+            field.len() <= limits.max_listpack_value && value.len() <= limits.max_listpack_value
+        })
+}
+
+/// Starts out in the most compact encoding that fits the fields under the default limits
+impl From<HashMap<String, String>> for Hash {
+    fn from(fields: HashMap<String, String>) -> Self {
+        let encoding = match fits_listpack(
+            fields.len(),
+            fields
+                .iter()
+                .map(|(field, value)| (field.as_str(), value.as_str())),
+            &HashEncodingLimits::default(),
+        ) {
+            true => HashEncoding::Listpack,
+            false => HashEncoding::Hashtable,
+        };
+
+        Self { fields, encoding }
+    }
+}
+
+impl FromIterator<(String, String)> for Hash {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<HashMap<String, String>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hash, HashEncodingLimits};
+
+    const LIMITS: HashEncodingLimits = HashEncodingLimits {
+        max_listpack_entries: 2,
+        max_listpack_value: 5,
+    };
+
+    #[test]
+    fn test_update_encoding_past_listpack_entries() {
+        let mut hash = Hash::from_iter([("a".to_string(), "1".to_string())]);
+        hash.insert("b".to_string(), "2".to_string());
+        hash.update_encoding([("b", "2")], &LIMITS);
+        assert_eq!(hash.encoding(), "listpack");
+
+        hash.insert("c".to_string(), "3".to_string());
+        hash.update_encoding([("c", "3")], &LIMITS);
+        assert_eq!(hash.encoding(), "hashtable");
+
+        // Removing fields does not bring the listpack back
+        hash.clear();
+        hash.update_encoding([], &LIMITS);
+        assert_eq!(hash.encoding(), "hashtable");
+    }
+
+    #[test]
+    fn test_update_encoding_past_listpack_value() {
+        let mut hash = Hash::from_iter([("fruit".to_string(), "mango".to_string())]);
+        hash.update_encoding([("fruit", "mango")], &LIMITS);
+        assert_eq!(hash.encoding(), "listpack");
+
+        hash.insert("fruit".to_string(), "banana".to_string());
+        hash.update_encoding([("fruit", "banana")], &LIMITS);
+        assert_eq!(hash.encoding(), "hashtable");
+
+        let mut hash = Hash::from_iter([("banana".to_string(), "1".to_string())]);
+        hash.update_encoding([("banana", "1")], &LIMITS);
+        assert_eq!(hash.encoding(), "hashtable");
+    }
+}
@@ -0,0 +1,200 @@
+const HLL_HEADER: &str = "HYLL";
+const HLL_PRECISION: u32 = 14;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+const HLL_REGISTER_MASK: u64 = (HLL_REGISTERS as u64) - 1;
+const HLL_HASH_SEED: u64 = 0xadc83b19;
+
+/// Dense HyperLogLog with 16384 registers. It is stored in the key value store as a string made
+/// of a magic header followed by one printable character per register, so it can live alongside
+/// plain string values like in Redis.
+#[derive(Debug, PartialEq, Clone)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    /// Decodes a string value, returning None if it is not a valid HyperLogLog encoding
+    pub fn from_encoded(value: &str) -> Option<Self> {
+        let encoded_registers = value.strip_prefix(HLL_HEADER)?.as_bytes();
+
+        if encoded_registers.len() != HLL_REGISTERS {
+            return None;
+        }
+
+        let registers = encoded_registers
+            .iter()
+            .map(|byte| byte.checked_sub(b'0').filter(|register| *register <= 64))
+            .collect::<Option<Vec<u8>>>()?;
+
+        Some(Self { registers })
+    }
+
+    pub fn encode(&self) -> String {
+        let mut encoded = String::with_capacity(HLL_HEADER.len() + HLL_REGISTERS);
+        encoded.push_str(HLL_HEADER);
+        encoded.extend(
+            self.registers
+                .iter()
+                .map(|register| (b'0' + register) as char),
+        );
+
+        encoded
+    }
+
+    /// Adds an element, returning true if one of the registers was updated
+    pub fn add(&mut self, element: &[u8]) -> bool {
+        let hash = murmur_hash_64a(element, HLL_HASH_SEED);
+        let index = (hash & HLL_REGISTER_MASK) as usize;
+
+        // Set a sentinel bit so that the run of zeros is bounded by the remaining hash bits
+        let remaining_bits = (hash >> HLL_PRECISION) | (1 << (64 - HLL_PRECISION));
+        let run_length = (remaining_bits.trailing_zeros() + 1) as u8;
+
+        if run_length > self.registers[index] {
+            self.registers[index] = run_length;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Keeps the maximum of each register, which yields the HyperLogLog of the union
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other_register) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other_register);
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        let registers_count = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / registers_count);
+
+        let mut inverse_sum = 0.0;
+        let mut empty_registers = 0;
+
+        for register in &self.registers {
+            inverse_sum += 2f64.powi(-(*register as i32));
+
+            if *register == 0 {
+                empty_registers += 1;
+            }
+        }
+
+        let estimate = alpha * registers_count * registers_count / inverse_sum;
+
+        // Linear counting gives a better estimate for small cardinalities
+        if estimate <= 2.5 * registers_count && empty_registers > 0 {
+            return (registers_count * (registers_count / empty_registers as f64).ln()).round()
+                as u64;
+        }
+
+        estimate.round() as u64
+    }
+}
+
+/// MurmurHash64A, the same hash function Redis uses for its HyperLogLog implementation
+fn murmur_hash_64a(key: &[u8], seed: u64) -> u64 {
+    const M: u64 = 0xc6a4a7935bd1e995;
+    const R: u32 = 47;
+
+    let mut hash = seed ^ (key.len() as u64).wrapping_mul(M);
+
+    let chunks = key.chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        let mut k = u64::from_le_bytes(chunk.try_into().expect("chunk has 8 bytes"));
+        k = k.wrapping_mul(M);
+        k ^= k >> R;
+        k = k.wrapping_mul(M);
+
+        hash ^= k;
+        hash = hash.wrapping_mul(M);
+    }
+
+    if !remainder.is_empty() {
+        for (index, byte) in remainder.iter().enumerate() {
+            hash ^= (*byte as u64) << (8 * index);
+        }
+
+        hash = hash.wrapping_mul(M);
+    }
+
+    hash ^= hash >> R;
+    hash = hash.wrapping_mul(M);
+    hash ^= hash >> R;
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperLogLog;
+
+    #[test]
+    fn test_hyperloglog_count_is_approximate() {
+        let mut hyperloglog = HyperLogLog::new();
+
+        for element in 0..10000 {
+            hyperloglog.add(format!("element:{}", element).as_bytes());
+        }
+
+        let count = hyperloglog.count() as f64;
+        assert!(
+            (count - 10000.0).abs() / 10000.0 < 0.03,
+            "estimated count {} is too far from 10000",
+            count
+        );
+
+        assert!(!hyperloglog.add(b"element:1"));
+        assert_eq!(hyperloglog.count() as f64, count);
+    }
+
+    #[test]
+    fn test_hyperloglog_encoding() {
+        let mut hyperloglog = HyperLogLog::new();
+        hyperloglog.add(b"mango");
+        hyperloglog.add(b"grape");
+
+        let encoded = hyperloglog.encode();
+
+        assert_eq!(HyperLogLog::from_encoded(&encoded), Some(hyperloglog));
+        assert_eq!(HyperLogLog::from_encoded("mango"), None);
+        assert_eq!(HyperLogLog::from_encoded("HYLL0000"), None);
+    }
+
+    #[test]
+    fn test_hyperloglog_merge() {
+        let mut first = HyperLogLog::new();
+        let mut second = HyperLogLog::new();
+
+        for element in 0..3000 {
+            first.add(format!("{}", element).as_bytes());
+        }
+
+        for element in 2000..5000 {
+            second.add(format!("{}", element).as_bytes());
+        }
+
+        first.merge(&second);
+
+        let count = first.count() as f64;
+        assert!(
+            (count - 5000.0).abs() / 5000.0 < 0.03,
+            "estimated count {} is too far from 5000",
+            count
+        );
+    }
+}
@@ -3,7 +3,6 @@ use std::sync::Arc;
 use regex::Regex;
 use thiserror::Error;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::TcpStream;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::commands::CommandError;
@@ -58,6 +57,136 @@ pub async fn read_and_parse_resp<R>(
     stream: &mut R,
     buffer: &mut [u8; 1024],
 ) -> Result<Vec<RespValue>, CommandReadError>
+where
+    R: AsyncReadExt + Unpin,
+{
+    let input = read_input(stream, buffer).await?;
+    let parsed_input = RespValue::parse(input)?;
+
+    Ok(parsed_input)
+}
+
+/// Reads client requests, holding on to a request that is split across reads
+/// until the rest of it arrives
+pub struct RequestReader<R> {
+    reader: R,
+    pending: Vec<u8>,
+    max_bulk_length: usize,
+}
+
+impl<R> RequestReader<R>
+where
+    R: AsyncReadExt + Unpin,
+{
+    pub fn new(reader: R, max_bulk_length: usize) -> Self {
+        Self {
+            reader,
+            pending: Vec::new(),
+            max_bulk_length,
+        }
+    }
+
+    /// Waits for at least one whole request and parses every whole request received,
+    /// keeping a per-request parse result so that the requests before a protocol
+    /// error are still executed
+    pub async fn read_requests(
+        &mut self,
+    ) -> Result<Vec<Result<RespValue, RespError>>, CommandReadError> {
+        let mut buffer = [0; 1024];
+
+        loop {
+            let number_of_bytes = match self.reader.read(&mut buffer).await {
+                Ok(n) => n,
+                Err(e) => return Err(CommandReadError::IoError(e.to_string())),
+            };
+
+            if number_of_bytes == 0 {
+                return Err(CommandReadError::ConnectionClosed);
+            }
+
+            self.pending.extend_from_slice(&buffer[..number_of_bytes]);
+
+            let complete_length = complete_requests_length(&self.pending, self.max_bulk_length);
+
+            if complete_length > 0 {
+                let complete_requests = self.pending.drain(..complete_length).collect::<Vec<u8>>();
+                let input = parse_input(&complete_requests)?;
+
+                return Ok(RespValue::parse_requests(input, self.max_bulk_length));
+            }
+        }
+    }
+}
+
+/// Returns how many bytes at the start of the input form whole lines or whole
+/// multibulk requests. A malformed multibulk header, or one announcing more than
+/// `max_bulk_length` elements or bytes, makes the whole input count as complete,
+/// so that the parser reports the protocol error right away instead of buffering.
+fn complete_requests_length(input: &[u8], max_bulk_length: usize) -> usize {
+    let mut complete_length = 0;
+
+    while let Some(line_end) = find_line_end(input, complete_length) {
+        let line = &input[complete_length..line_end];
+
+        let Some(array_length) = line.strip_prefix(b"*") else {
+            complete_length = line_end + 2;
+            continue;
+        };
+
+        let Some(array_length) =
+            parse_length(array_length).filter(|length| *length <= max_bulk_length)
+        else {
+            return input.len();
+        };
+
+        let mut cursor = line_end + 2;
+
+        for _ in 0..array_length {
+            let Some(header_end) = find_line_end(input, cursor) else {
+                return complete_length;
+            };
+
+            let Some(bulk_string_length) = input[cursor..header_end]
+                .strip_prefix(b"$")
+                .and_then(parse_length)
+                .filter(|length| *length <= max_bulk_length)
+            else {
+                return input.len();
+            };
+
+            cursor = header_end + 2 + bulk_string_length + 2;
+
+            if cursor > input.len() {
+                return complete_length;
+            }
+        }
+
+        complete_length = cursor;
+    }
+
+    complete_length
+}
+
+fn find_line_end(input: &[u8], start: usize) -> Option<usize> {
+    input[start..]
+        .windows(2)
+        .position(|window| window == b"\r\n")
+        .map(|position| start + position)
+}
+
+/// Negative lengths are treated as zero, like empty and null arrays
+fn parse_length(length: &[u8]) -> Option<usize> {
+    str::from_utf8(length)
+        .ok()?
+        .parse::<i64>()
+        .ok()
+        .map(|length| length.max(0) as usize)
+}
+
+async fn read_input<'a, R>(
+    stream: &mut R,
+    buffer: &'a mut [u8; 1024],
+) -> Result<Vec<&'a str>, CommandReadError>
 where
     R: AsyncReadExt + Unpin,
 {
@@ -70,17 +199,17 @@ where
         return Err(CommandReadError::ConnectionClosed);
     }
 
-    let input = parse_input(&buffer[..number_of_bytes])?;
-    let parsed_input = RespValue::parse(input)?;
-
-    Ok(parsed_input)
+    parse_input(&buffer[..number_of_bytes])
 }
 
-pub async fn handshake(
-    stream: &mut TcpStream,
+pub async fn handshake<S>(
+    stream: &mut S,
     server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
-) -> Result<(), CommandReadError> {
+) -> Result<(), CommandReadError>
+where
+    S: AsyncReadExt + AsyncWriteExt + Unpin,
+{
     let mut buffer: [u8; 1024] = [0; 1024];
 
     let response = send_and_handle_handshake_command(
@@ -146,11 +275,19 @@ pub async fn handshake(
             }
 
             let repl_id = parts[1];
-            let offset = parts[2];
+            let Ok(offset) = parts[2].parse::<usize>() else {
+                return Err(CommandReadError::InvalidResponseFromMaster);
+            };
 
-            if !is_valid_repl_id(repl_id) || offset != "0" {
+            if !is_valid_repl_id(repl_id) {
                 return Err(CommandReadError::InvalidResponseFromMaster);
             }
+
+            // The replica continues the master's replication stream, so the bytes it
+            // processes from now on are counted from the master's offset
+            let mut server_guard = server.write().await;
+            server_guard.repl_id = repl_id.to_string();
+            server_guard.repl_offset = offset;
         }
         _ => {
             return Err(CommandReadError::InvalidResponseFromMaster);
@@ -163,11 +300,14 @@ pub async fn handshake(
     Ok(())
 }
 
-async fn send_and_handle_handshake_command(
+async fn send_and_handle_handshake_command<S>(
     buffer: &mut [u8; 1024],
-    stream: &mut TcpStream,
+    stream: &mut S,
     command: RespValue,
-) -> Result<RespValue, CommandReadError> {
+) -> Result<RespValue, CommandReadError>
+where
+    S: AsyncReadExt + AsyncWriteExt + Unpin,
+{
     stream
         .write_all(command.encode().as_bytes())
         .await
@@ -191,10 +331,13 @@ fn is_valid_repl_id(repl_id: &str) -> bool {
     re.is_match(repl_id)
 }
 
-async fn send_and_handle_psync_command(
-    stream: &mut TcpStream,
+async fn send_and_handle_psync_command<S>(
+    stream: &mut S,
     command: RespValue,
-) -> Result<RespValue, CommandReadError> {
+) -> Result<RespValue, CommandReadError>
+where
+    S: AsyncReadExt + AsyncWriteExt + Unpin,
+{
     stream
         .write_all(command.encode().as_bytes())
         .await
@@ -241,10 +384,13 @@ async fn send_and_handle_psync_command(
     Ok(RespValue::SimpleString(fullresync_line))
 }
 
-async fn receive_rdb_file(
-    stream: &mut TcpStream,
+async fn receive_rdb_file<S>(
+    stream: &mut S,
     store: Arc<Mutex<KeyValueStore>>,
-) -> Result<(), CommandReadError> {
+) -> Result<(), CommandReadError>
+where
+    S: AsyncReadExt + Unpin,
+{
     // Read the RDB bulk string header ($<size>\r\n)
     let mut size_line = Vec::new();
     let mut byte: [u8; 1] = [0; 1];
@@ -293,15 +439,107 @@ async fn receive_rdb_file(
             .map_err(|_| CommandReadError::InvalidResponseFromMaster)?;
     }
 
-    let mut store_guard = store.lock().await;
-    store_guard.extend(rdb_parser.key_value_store);
+    let keys = rdb_parser
+        .take_default_database()
+        .map_err(|_| CommandReadError::InvalidResponseFromMaster)?;
+    store.lock().await.extend(keys);
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::parse_input;
+    use tokio::io::AsyncWriteExt;
+
+    use super::{RequestReader, complete_requests_length, parse_input};
+    use crate::resp::{DEFAULT_PROTO_MAX_BULK_LEN, RespValue};
+
+    #[test]
+    fn test_complete_requests_length() {
+        let ping = "*1\r\n$4\r\nPING\r\n";
+        let echo = "*2\r\n$4\r\nECHO\r\n$5\r\nmango\r\n";
+
+        let test_cases = vec![
+            (ping.to_string(), ping.len(), "single request"),
+            (
+                format!("{}{}", ping, echo),
+                ping.len() + echo.len(),
+                "pipelined requests",
+            ),
+            (
+                format!("{}*2\r\n$4\r\nECHO", ping),
+                ping.len(),
+                "split header",
+            ),
+            (
+                format!("{}{}", ping, &echo[..echo.len() - 3]),
+                ping.len(),
+                "split bulk string",
+            ),
+            ("*2\r\n".to_string(), 0, "missing elements"),
+            (
+                "PING\r\n*1".to_string(),
+                6,
+                "inline line followed by a partial request",
+            ),
+            ("*x\r\n*1".to_string(), 6, "invalid multibulk length"),
+            (
+                "*1\r\n+PING\r\n".to_string(),
+                11,
+                "invalid bulk string header",
+            ),
+            (
+                "*1\r\n$536870913\r\nPING".to_string(),
+                20,
+                "bulk string over the maximum length",
+            ),
+            (
+                "*536870913\r\n$4\r\nPING\r\n".to_string(),
+                22,
+                "multibulk over the maximum length",
+            ),
+        ];
+
+        for (input, expected, description) in test_cases {
+            assert_eq!(
+                complete_requests_length(input.as_bytes(), DEFAULT_PROTO_MAX_BULK_LEN),
+                expected,
+                "{}",
+                description
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_reader_waits_for_split_requests() {
+        let (mut client, server) = tokio::io::duplex(64);
+        let mut request_reader = RequestReader::new(server, DEFAULT_PROTO_MAX_BULK_LEN);
+
+        let request = "*2\r\n$4\r\nECHO\r\n$5\r\nmango\r\n*1\r\n$4\r\nPING\r\n";
+        let (first_half, second_half) = request.split_at(20);
+
+        client.write_all(first_half.as_bytes()).await.unwrap();
+
+        let reader_task = tokio::spawn(async move { request_reader.read_requests().await });
+
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!reader_task.is_finished());
+
+        client.write_all(second_half.as_bytes()).await.unwrap();
+
+        assert_eq!(
+            reader_task.await.unwrap(),
+            Ok(vec![
+                Ok(RespValue::Array(vec![
+                    RespValue::BulkString("ECHO".to_string()),
+                    RespValue::BulkString("mango".to_string()),
+                ])),
+                Ok(RespValue::Array(vec![RespValue::BulkString(
+                    "PING".to_string()
+                )])),
+            ])
+        );
+    }
 
     #[test]
     fn test_parse_input() {
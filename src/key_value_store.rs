@@ -1,14 +1,17 @@
-use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::collections::HashMap;
 
 use jiff::Timestamp;
 
-pub type Stream = BTreeMap<String, String>;
+use crate::{hash::Hash, list::List, set::Set, sorted_set::SortedSet, stream::Stream};
 
 #[derive(Debug, PartialEq)]
 pub enum DataType {
     String(String),
-    Array(VecDeque<String>),
-    Stream(BTreeMap<String, Stream>),
+    Array(List),
+    Stream(Stream),
+    Hash(Hash),
+    SortedSet(SortedSet),
+    Set(Set),
 }
 
 #[derive(Debug, PartialEq)]
@@ -18,3 +21,10 @@ pub struct Value {
 }
 
 pub type KeyValueStore = HashMap<String, Value>;
+
+/// Only strings that round trip through an i64 unchanged can be stored as integers
+pub fn is_integer_encodable(string: &str) -> bool {
+    string
+        .parse::<i64>()
+        .is_ok_and(|number| number.to_string() == string)
+}
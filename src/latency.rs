@@ -0,0 +1,190 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use jiff::Timestamp;
+
+/// Default of Redis' `latency-monitor-threshold`, which leaves the monitor disabled
+pub const DEFAULT_LATENCY_MONITOR_THRESHOLD: u64 = 0;
+// Number of samples Redis keeps for each event
+const LATENCY_HISTORY_LEN: usize = 160;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LatencySample {
+    // Unix time in seconds
+    pub timestamp: i64,
+    pub latency_ms: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LatencyEvent {
+    samples: VecDeque<LatencySample>,
+    max_latency_ms: u64,
+}
+
+impl LatencyEvent {
+    pub fn latest(&self) -> Option<&LatencySample> {
+        self.samples.back()
+    }
+
+    pub fn max_latency_ms(&self) -> u64 {
+        self.max_latency_ms
+    }
+
+    /// Samples from the oldest to the newest one
+    pub fn history(&self) -> Vec<LatencySample> {
+        self.samples.iter().copied().collect()
+    }
+
+    /// Adds a sample, merging it into the newest one when both fall in the same second
+    /// so that a burst of spikes only keeps the highest latency
+    fn add_sample(&mut self, sample: LatencySample) {
+        self.max_latency_ms = self.max_latency_ms.max(sample.latency_ms);
+
+        match self.samples.back_mut() {
+            Some(latest) if latest.timestamp == sample.timestamp => {
+                latest.latency_ms = latest.latency_ms.max(sample.latency_ms);
+                return;
+            }
+            _ => {}
+        }
+
+        self.samples.push_back(sample);
+
+        if self.samples.len() > LATENCY_HISTORY_LEN {
+            self.samples.pop_front();
+        }
+    }
+}
+
+/// Latency spikes of named events, such as `command` or `expire-cycle`, that took at
+/// least `latency-monitor-threshold` milliseconds
+#[derive(Debug, Default, Clone)]
+pub struct LatencyMonitor {
+    events: HashMap<String, LatencyEvent>,
+}
+
+impl LatencyMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether an event that lasted `latency` should be recorded. A threshold of 0
+    /// disables the monitor.
+    pub fn is_spike(latency: Duration, threshold_ms: u64) -> bool {
+        threshold_ms > 0 && latency.as_millis() >= threshold_ms as u128
+    }
+
+    pub fn record(&mut self, event: &str, latency: Duration) {
+        let sample = LatencySample {
+            timestamp: Timestamp::now().as_second(),
+            latency_ms: latency.as_millis() as u64,
+        };
+
+        self.events
+            .entry(event.to_string())
+            .or_default()
+            .add_sample(sample);
+    }
+
+    /// Every event with at least one sample, sorted by name
+    pub fn latest(&self) -> Vec<(&str, &LatencyEvent)> {
+        let mut events: Vec<(&str, &LatencyEvent)> = self
+            .events
+            .iter()
+            .map(|(name, event)| (name.as_str(), event))
+            .collect();
+        events.sort_by_key(|(name, _)| *name);
+
+        events
+    }
+
+    pub fn history(&self, event: &str) -> Vec<LatencySample> {
+        self.events
+            .get(event)
+            .map(|event| event.history())
+            .unwrap_or_default()
+    }
+
+    /// Drops the samples of the given events, or of every event when none are given,
+    /// returning how many events were reset
+    pub fn reset(&mut self, events: &[String]) -> usize {
+        if events.is_empty() {
+            let number_of_events = self.events.len();
+            self.events.clear();
+            return number_of_events;
+        }
+
+        events
+            .iter()
+            .filter(|event| self.events.remove(event.as_str()).is_some())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_spike() {
+        let test_cases = vec![
+            (Duration::from_millis(100), 100, true),
+            (Duration::from_millis(150), 100, true),
+            (Duration::from_millis(99), 100, false),
+            (Duration::from_secs(10), 0, false),
+        ];
+
+        for (latency, threshold_ms, expected_result) in test_cases {
+            assert_eq!(
+                LatencyMonitor::is_spike(latency, threshold_ms),
+                expected_result
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_sample_merges_samples_of_the_same_second() {
+        let mut event = LatencyEvent::default();
+
+        for (timestamp, latency_ms) in [(10, 50), (10, 80), (10, 20), (11, 30)] {
+            event.add_sample(LatencySample {
+                timestamp,
+                latency_ms,
+            });
+        }
+
+        assert_eq!(
+            event.history(),
+            vec![
+                LatencySample {
+                    timestamp: 10,
+                    latency_ms: 80
+                },
+                LatencySample {
+                    timestamp: 11,
+                    latency_ms: 30
+                },
+            ]
+        );
+        assert_eq!(event.max_latency_ms(), 80);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut monitor = LatencyMonitor::new();
+        monitor.record("command", Duration::from_millis(20));
+        monitor.record("expire-cycle", Duration::from_millis(30));
+
+        assert_eq!(
+            monitor.reset(&["command".to_string(), "fork".to_string()]),
+            1
+        );
+        assert!(monitor.history("command").is_empty());
+        assert_eq!(monitor.history("expire-cycle").len(), 1);
+
+        assert_eq!(monitor.reset(&[]), 1);
+        assert!(monitor.latest().is_empty());
+    }
+}
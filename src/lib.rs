@@ -1,8 +1,19 @@
+pub mod aof;
 pub mod commands;
 pub mod connection;
+pub mod geohash;
+pub mod hash;
+pub mod hyperloglog;
 pub mod input;
 pub mod key_value_store;
+pub mod latency;
+pub mod list;
 pub mod rdb;
 pub mod resp;
 pub mod server;
+pub mod set;
+pub mod slowlog;
+pub mod sorted_set;
 pub mod state;
+pub mod stream;
+pub mod tls;
@@ -0,0 +1,200 @@
+use std::{
+    collections::VecDeque,
+    ops::{Deref, DerefMut},
+};
+
+/// Default of Redis' `list-max-listpack-size`, limiting a listpack to 8 kb
+pub const DEFAULT_LIST_MAX_LISTPACK_SIZE: i64 = -2;
+// A listpack without entries is a 6 byte header followed by a 1 byte terminator
+const EMPTY_LISTPACK_SIZE: usize = 7;
+
+/// A list is always kept in a `VecDeque`, but remembers whether it has ever outgrown a
+/// listpack so OBJECT ENCODING reports `quicklist` from then on, like Redis does.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct List {
+    items: VecDeque<String>,
+    is_quicklist: bool,
+}
+
+impl List {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Switches the list to the quicklist encoding once it exceeds `max_listpack_size`.
+    /// A positive size limits the number of entries and a negative one, from -1 to -5,
+    /// limits the listpack to 4, 8, 16, 32 or 64 kb.
+    pub fn update_encoding(&mut self, max_listpack_size: i64) {
+        if !self.is_quicklist && exceeds_listpack_size(&self.items, max_listpack_size) {
+            self.is_quicklist = true;
+        }
+    }
+
+    pub fn encoding(&self) -> &str {
+        match self.is_quicklist {
+            true => "quicklist",
+            false => "listpack",
+        }
+    }
+
+    /// Sizes in bytes of the listpack nodes a quicklist holding the list would be split
+    /// into, filling each node up to `max_listpack_size` before starting the next one
+    pub fn quicklist_node_sizes(&self, max_listpack_size: i64) -> Vec<usize> {
+        let mut node_sizes = Vec::new();
+        let mut node_entries = 0;
+        let mut node_size = EMPTY_LISTPACK_SIZE;
+
+        for item in self.items.iter() {
+            let entry_size = listpack_entry_size(item.len());
+            let is_node_full = match max_listpack_size > 0 {
+                true => node_entries >= max_listpack_size as usize,
+                false => node_size + entry_size > max_listpack_bytes(max_listpack_size),
+            };
+
+            if node_entries > 0 && is_node_full {
+                node_sizes.push(node_size);
+                node_entries = 0;
+                node_size = EMPTY_LISTPACK_SIZE;
+            }
+
+            node_entries += 1;
+            node_size += entry_size;
+        }
+
+        node_sizes.push(node_size);
+        node_sizes
+    }
+}
+
+impl Deref for List {
+    type Target = VecDeque<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl DerefMut for List {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+
+impl From<VecDeque<String>> for List {
+    fn from(items: VecDeque<String>) -> Self {
+        Self {
+            items,
+            is_quicklist: false,
+        }
+    }
+}
+
+impl FromIterator<String> for List {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<VecDeque<String>>())
+    }
+}
+
+/// Returns whether `max_listpack_size` is a value Redis accepts for `list-max-listpack-size`
+pub fn is_valid_max_listpack_size(max_listpack_size: i64) -> bool {
+    max_listpack_size > 0 || (-5..=-1).contains(&max_listpack_size)
+}
+
+fn exceeds_listpack_size(items: &VecDeque<String>, max_listpack_size: i64) -> bool {
+    if max_listpack_size > 0 {
+        return items.len() > max_listpack_size as usize;
+    }
+
+    let listpack_bytes = EMPTY_LISTPACK_SIZE
+        + items
+            .iter()
+            .map(|item| listpack_entry_size(item.len()))
+            .sum::<usize>();
+
+    listpack_bytes > max_listpack_bytes(max_listpack_size)
+}
+
+/// Negative sizes from -1 to -5 limit a listpack to 4, 8, 16, 32 or 64 kb
+fn max_listpack_bytes(max_listpack_size: i64) -> usize {
+    4096 << (max_listpack_size.unsigned_abs().clamp(1, 5) - 1)
+}
+
+/// Size of a listpack entry holding a string: encoding header, data and back length.
+/// Integers would be encoded more compactly, so this slightly overestimates for them.
+/// Bytes used by a listpack holding the given entries, stored as strings
+pub fn listpack_size<'a>(entries: impl Iterator<Item = &'a str>) -> usize {
+    EMPTY_LISTPACK_SIZE
+        + entries
+            .map(|entry| listpack_entry_size(entry.len()))
+            .sum::<usize>()
+}
+
+fn listpack_entry_size(length: usize) -> usize {
+    let encoding_size = match length {
+        0..64 => 1,
+        64..4096 => 2,
+        _ => 5,
+    };
+    let entry_size = encoding_size + length;
+
+    let back_length_size = match entry_size {
+        0..128 => 1,
+        128..16384 => 2,
+        16384..2097152 => 3,
+        2097152..268435456 => 4,
+        _ => 5,
+    };
+
+    entry_size + back_length_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DEFAULT_LIST_MAX_LISTPACK_SIZE, List};
+
+    #[test]
+    fn test_update_encoding_by_number_of_entries() {
+        let mut list = List::from_iter(["a".to_string(), "b".to_string()]);
+
+        list.update_encoding(2);
+        assert_eq!(list.encoding(), "listpack");
+
+        list.push_back("c".to_string());
+        list.update_encoding(2);
+        assert_eq!(list.encoding(), "quicklist");
+
+        // Once converted the list stays a quicklist even after shrinking
+        list.clear();
+        list.update_encoding(2);
+        assert_eq!(list.encoding(), "quicklist");
+    }
+
+    #[test]
+    fn test_update_encoding_by_size() {
+        let mut list = List::from_iter(["a".repeat(4000)]);
+
+        list.update_encoding(DEFAULT_LIST_MAX_LISTPACK_SIZE);
+        assert_eq!(list.encoding(), "listpack");
+
+        list.update_encoding(-1);
+        assert_eq!(list.encoding(), "listpack");
+
+        list.push_back("a".repeat(100));
+        list.update_encoding(-1);
+        assert_eq!(list.encoding(), "quicklist");
+    }
+
+    #[test]
+    fn test_quicklist_node_sizes() {
+        let list = List::from_iter((0..5).map(|i| i.to_string()));
+
+        // Every single character entry takes 1 byte of encoding, 1 of data and 1 of back length
+        assert_eq!(list.quicklist_node_sizes(2), vec![13, 13, 10]);
+        assert_eq!(list.quicklist_node_sizes(-1), vec![22]);
+        assert_eq!(List::new().quicklist_node_sizes(2), vec![7]);
+
+        let list = List::from_iter(["a".repeat(3000), "b".repeat(3000), "c".repeat(3000)]);
+        assert_eq!(list.quicklist_node_sizes(-1).len(), 3);
+        assert_eq!(list.quicklist_node_sizes(-2).len(), 2);
+    }
+}
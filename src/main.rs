@@ -1,13 +1,24 @@
 use crate::server::RedisServer;
 
+mod aof;
 mod commands;
 mod connection;
+mod geohash;
+mod hash;
+mod hyperloglog;
 mod input;
 mod key_value_store;
+mod latency;
+mod list;
 mod rdb;
 mod resp;
 mod server;
+mod set;
+mod slowlog;
+mod sorted_set;
 mod state;
+mod stream;
+mod tls;
 
 #[tokio::main]
 async fn main() {
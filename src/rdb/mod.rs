@@ -3,6 +3,10 @@ mod get_slice;
 mod opcode;
 mod rdb_file_operations;
 mod rdb_parser;
+mod rdb_writer;
+mod save_point;
 
-pub use rdb_file_operations::{parse_rdb_file, stream_rdb_file};
+pub use rdb_file_operations::{parse_rdb_file, reload_rdb_file, save_rdb_file, stream_rdb_file};
 pub use rdb_parser::RdbParser;
+pub use rdb_writer::{encode_rdb, serialized_length};
+pub use save_point::{SavePoint, parse_save_points};
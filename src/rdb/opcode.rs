@@ -1,6 +1,13 @@
-use crate::rdb::{
-    encoding::{parse_length_encoded_integer, parse_value},
-    get_slice::get_buffer_slice,
+use std::collections::BTreeMap;
+
+use crate::{
+    key_value_store::DataType,
+    list::List,
+    rdb::{
+        encoding::{parse_length_encoded_integer, parse_value},
+        get_slice::get_buffer_slice,
+    },
+    stream::{Stream, parse_stream_id},
 };
 
 const METADATA_OPCODE: u8 = 0xFA;
@@ -10,7 +17,7 @@ const EXPIRATION_SECONDS_OPCODE: u8 = 0xFD;
 const EXPIRATION_MILLISECONDS_OPCODE: u8 = 0xFC;
 const END_OF_FILE_OPCODE: u8 = 0xFF;
 const STRING_VALUE_TYPE: u8 = 0x00; // 0
-// const LIST_VALUE_TYPE: u8 = 0x01; // 1
+const LIST_VALUE_TYPE: u8 = 0x01; // 1
 // const SET_VALUE_TYPE: u8 = 0x02; // 2
 // const SORTED_SET_VALUE_TYPE: u8 = 0x03; // 3
 // const HASH_VALUE_TYPE: u8 = 0x04; // 4
@@ -20,6 +27,7 @@ const STRING_VALUE_TYPE: u8 = 0x00; // 0
 // const SORTED_SET_ZIPLIST_VALUE_TYPE: u8 = 0x0C; // 12
 // const HASHMAP_ZIPLIST_VALUE_TYPE: u8 = 0x0D; // 13
 // const LIST_QUICKLIST_VALUE_TYPE: u8 = 0x0E; // 14
+const STREAM_VALUE_TYPE: u8 = 0x15; // 21
 
 pub enum OpCodeResponse {
     Metadata {
@@ -35,12 +43,12 @@ pub enum OpCodeResponse {
     },
     ExpirationSeconds {
         key: String,
-        value: String,
+        value: DataType,
         expiration: i64,
     },
     ExpirationMilliseconds {
         key: String,
-        value: String,
+        value: DataType,
         expiration: i64,
     },
     EndOfFile {
@@ -48,7 +56,7 @@ pub enum OpCodeResponse {
     },
     KeyValuePair {
         key: String,
-        value: String,
+        value: DataType,
     },
 }
 
@@ -157,7 +165,65 @@ pub fn parse_opcode(bytes: &[u8], cursor: usize) -> tokio::io::Result<(OpCodeRes
             let (value, value_cursor) = parse_value(bytes, temp_cursor)?;
             temp_cursor += value_cursor;
 
-            Ok(OpCodeResponse::KeyValuePair { key, value })
+            Ok(OpCodeResponse::KeyValuePair {
+                key,
+                value: DataType::String(value),
+            })
+        }
+        LIST_VALUE_TYPE => {
+            let (key, key_cursor) = parse_value(bytes, temp_cursor)?;
+            temp_cursor += key_cursor;
+            let (items, items_cursor) = parse_values(bytes, temp_cursor)?;
+            temp_cursor += items_cursor;
+
+            Ok(OpCodeResponse::KeyValuePair {
+                key,
+                value: DataType::Array(List::from_iter(items)),
+            })
+        }
+        STREAM_VALUE_TYPE => {
+            let (key, key_cursor) = parse_value(bytes, temp_cursor)?;
+            temp_cursor += key_cursor;
+            let (number_of_entries, number_of_entries_cursor) = parse_length(bytes, temp_cursor)?;
+            temp_cursor += number_of_entries_cursor;
+
+            let mut stream = BTreeMap::new();
+
+            for _ in 0..number_of_entries {
+                let (stream_id, stream_id_cursor) = parse_value(bytes, temp_cursor)?;
+                temp_cursor += stream_id_cursor;
+                let (number_of_fields, number_of_fields_cursor) = parse_length(bytes, temp_cursor)?;
+                temp_cursor += number_of_fields_cursor;
+
+                let mut entry = BTreeMap::new();
+
+                for _ in 0..number_of_fields {
+                    let (field, field_cursor) = parse_value(bytes, temp_cursor)?;
+                    temp_cursor += field_cursor;
+                    let (value, value_cursor) = parse_value(bytes, temp_cursor)?;
+                    temp_cursor += value_cursor;
+
+                    entry.insert(field, value);
+                }
+
+                stream.insert(stream_id, entry);
+            }
+
+            let (last_id, last_id_cursor) = parse_value(bytes, temp_cursor)?;
+            temp_cursor += last_id_cursor;
+
+            let mut stream = Stream::from(stream);
+            stream.set_last_id(parse_stream_id(&last_id).ok_or_else(|| {
+                tokio::io::Error::new(
+                    tokio::io::ErrorKind::InvalidData,
+                    format!("Invalid stream last ID: {}", last_id),
+                )
+            })?);
+
+            Ok(OpCodeResponse::KeyValuePair {
+                key,
+                value: DataType::Stream(stream),
+            })
         }
         opcode => Err(tokio::io::Error::new(
             tokio::io::ErrorKind::InvalidData,
@@ -170,6 +236,36 @@ pub fn parse_opcode(bytes: &[u8], cursor: usize) -> tokio::io::Result<(OpCodeRes
     Ok((response, bytes_read))
 }
 
+fn parse_length(bytes: &[u8], cursor: usize) -> tokio::io::Result<(usize, usize)> {
+    let (length, length_cursor) = parse_length_encoded_integer(bytes, cursor)?;
+
+    let length = length.parse::<usize>().map_err(|e| {
+        tokio::io::Error::new(
+            tokio::io::ErrorKind::InvalidData,
+            format!("Invalid length: {}", e),
+        )
+    })?;
+
+    Ok((length, length_cursor))
+}
+
+/// Parses a length followed by that many strings, the layout of a list
+fn parse_values(bytes: &[u8], cursor: usize) -> tokio::io::Result<(Vec<String>, usize)> {
+    let mut temp_cursor = cursor;
+    let (length, length_cursor) = parse_length(bytes, temp_cursor)?;
+    temp_cursor += length_cursor;
+
+    let mut values = Vec::with_capacity(length);
+
+    for _ in 0..length {
+        let (value, value_cursor) = parse_value(bytes, temp_cursor)?;
+        temp_cursor += value_cursor;
+        values.push(value);
+    }
+
+    Ok((values, temp_cursor - cursor))
+}
+
 pub struct MagicStringResponse {
     pub number_of_read_bytes: usize,
     pub magic_string: String,
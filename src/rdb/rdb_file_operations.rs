@@ -1,8 +1,7 @@
 use std::path::Path;
 use std::sync::Arc;
 
-use tokio::io::AsyncWriteExt;
-use tokio::net::tcp::OwnedWriteHalf;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tokio::sync::Mutex;
 use tokio::{
     fs::File,
@@ -11,59 +10,83 @@ use tokio::{
 };
 
 use crate::key_value_store::KeyValueStore;
-use crate::rdb::RdbParser;
-use crate::server::RedisServer;
+use crate::rdb::{RdbParser, encode_rdb};
+use crate::server::{RedisServer, register_replica};
 
-pub async fn stream_rdb_file(
+pub async fn stream_rdb_file<W>(
     client_address: &str,
-    writer: Arc<RwLock<OwnedWriteHalf>>,
+    writer: Arc<RwLock<W>>,
     server: Arc<RwLock<RedisServer>>,
-) -> tokio::io::Result<()> {
-    // Stream RDB file instead of loading it all into memory
-    let file = {
+    store: Arc<Mutex<KeyValueStore>>,
+) -> tokio::io::Result<()>
+where
+    W: AsyncWrite + Unpin + Send + Sync + 'static,
+{
+    let (file_path, is_file_up_to_date) = {
         let server_guard = server.read().await;
-        File::open(format!(
-            "{}/{}",
-            &server_guard.rdb_directory, &server_guard.rdb_filename
-        ))
-        .await?
+        (
+            Path::new(&server_guard.rdb_directory).join(&server_guard.rdb_filename),
+            server_guard.stats.changes_since_last_save() == 0,
+        )
     };
-    let file_size = file.metadata().await?.len();
-
-    // First send the bulk string header
-    let header = format!("${}\r\n", file_size);
 
     let mut writer_guard = writer.write().await;
-    writer_guard.write_all(header.as_bytes()).await?;
 
-    // Stream the file contents in chunks
-    let mut reader = BufReader::new(file);
-    let mut buffer: [u8; 4096] = [0; 4096]; // 4KB chunks
+    let file = if is_file_up_to_date {
+        match File::open(&file_path).await {
+            Ok(file) => Some(file),
+            Err(e) if e.kind() == tokio::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        }
+    } else {
+        None
+    };
+
+    match file {
+        Some(file) => {
+            // The length prefix comes from the file metadata so the payload is never held in memory
+            let file_size = file.metadata().await?.len();
+            let header = format!("${}\r\n", file_size);
+            writer_guard.write_all(header.as_bytes()).await?;
+
+            // Stream the file contents in chunks, never sending more than the announced length
+            let mut reader = BufReader::new(file.take(file_size));
+            let mut buffer: [u8; 4096] = [0; 4096]; // 4KB chunks
+            let mut sent_bytes: u64 = 0;
+
+            loop {
+                let n = reader.read(&mut buffer).await?;
+
+                if n == 0 {
+                    break;
+                }
 
-    loop {
-        match reader.read(&mut buffer).await {
-            Ok(0) => break,
-            Ok(n) => {
                 writer_guard.write_all(&buffer[..n]).await?;
+                sent_bytes += n as u64;
             }
-            Err(e) => {
-                eprintln!("Error streaming RDB file: {}", e);
-                break;
+
+            // The replica waits for as many bytes as announced, so a short payload cannot be sent
+            if sent_bytes < file_size {
+                return Err(tokio::io::Error::new(
+                    tokio::io::ErrorKind::UnexpectedEof,
+                    format!("RDB file ended after {} of {} bytes", sent_bytes, file_size),
+                ));
             }
         }
+        None => {
+            // Without an up-to-date save the snapshot is generated from the live dataset
+            let rdb = encode_rdb(&*store.lock().await);
+            let header = format!("${}\r\n", rdb.len());
+            writer_guard.write_all(header.as_bytes()).await?;
+            writer_guard.write_all(&rdb).await?;
+        }
     }
 
     writer_guard.flush().await?;
     drop(writer_guard);
 
     // Add replica to replication list after successful RDB streaming
-    let mut server_guard = server.write().await;
-    if let Some(replicas) = &mut server_guard.replicas {
-        replicas.insert(
-            client_address.to_string(),
-            crate::server::Replica { writer, offset: 0 },
-        );
-    }
+    register_replica(server, client_address, writer).await;
 
     Ok(())
 }
@@ -90,12 +113,76 @@ where
         rdb_parser.parse(buffer[..n].to_vec())?;
     }
 
+    let keys = rdb_parser.take_default_database()?;
+    store.lock().await.extend(keys);
+
+    Ok(())
+}
+
+/// Writes a snapshot of the store to the configured RDB file.
+///
+/// The snapshot is written to a temporary file first and then renamed, so a
+/// failed save never leaves a truncated RDB file behind.
+pub async fn save_rdb_file(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+) -> tokio::io::Result<()> {
+    let file_path = {
+        let server_guard = server.read().await;
+        Path::new(&server_guard.rdb_directory).join(&server_guard.rdb_filename)
+    };
+
+    let rdb_bytes = {
+        let store_guard = store.lock().await;
+        encode_rdb(&store_guard)
+    };
+
+    write_rdb_file(&file_path, &rdb_bytes).await
+}
+
+/// Saves the store to the configured RDB file and replaces it with the keys loaded back from it.
+///
+/// The store stays locked throughout so no write lands between the save and the load,
+/// and it is only replaced once the whole file has been parsed.
+pub async fn reload_rdb_file(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+) -> tokio::io::Result<()> {
+    let file_path = {
+        let server_guard = server.read().await;
+        Path::new(&server_guard.rdb_directory).join(&server_guard.rdb_filename)
+    };
+
     let mut store_guard = store.lock().await;
-    store_guard.extend(rdb_parser.key_value_store);
+    write_rdb_file(&file_path, &encode_rdb(&store_guard)).await?;
+
+    let rdb_bytes = tokio::fs::read(&file_path).await?;
+    let mut rdb_parser = RdbParser::new();
+    rdb_parser.parse(rdb_bytes)?;
+
+    // The parser stops at the first value it cannot read, so a missing checksum means a partial load
+    if rdb_parser.crc64_checksum.is_none() {
+        return Err(tokio::io::Error::new(
+            tokio::io::ErrorKind::InvalidData,
+            "RDB file ended before the end-of-file opcode",
+        ));
+    }
+
+    *store_guard = rdb_parser.take_default_database()?;
 
     Ok(())
 }
 
+async fn write_rdb_file(file_path: &Path, rdb_bytes: &[u8]) -> tokio::io::Result<()> {
+    let temp_file_path = file_path.with_file_name(format!("temp-{}.rdb", std::process::id()));
+
+    let mut file = File::create(&temp_file_path).await?;
+    file.write_all(rdb_bytes).await?;
+    file.sync_all().await?;
+
+    tokio::fs::rename(&temp_file_path, file_path).await
+}
+
 pub async fn parse_rdb_file(
     server: Arc<RwLock<RedisServer>>,
     store: Arc<Mutex<KeyValueStore>>,
@@ -3,10 +3,12 @@ use std::collections::HashMap;
 use jiff::Timestamp;
 
 use crate::{
-    key_value_store::{DataType, Value},
+    key_value_store::Value,
     rdb::opcode::{OpCodeResponse, parse_magic_string, parse_opcode},
 };
 
+const MAX_RESERVED_KEYS: usize = 1 << 16;
+
 #[derive(Debug)]
 pub struct RdbParser {
     buffer: Vec<u8>,
@@ -17,7 +19,9 @@ pub struct RdbParser {
     pub db_number: Option<String>,
     pub hash_table_size: Option<String>,
     pub expiry_hash_table_size: Option<String>,
-    pub key_value_store: HashMap<String, Value>,
+    /// Keys of each logical database, indexed by the number given in SELECTDB
+    pub databases: HashMap<usize, HashMap<String, Value>>,
+    current_database: usize,
     pub crc64_checksum: Option<Vec<u8>>,
 }
 
@@ -32,16 +36,43 @@ impl RdbParser {
             db_number: None,
             hash_table_size: None,
             expiry_hash_table_size: None,
-            key_value_store: HashMap::new(),
+            databases: HashMap::new(),
+            current_database: 0,
             crc64_checksum: None,
         }
     }
 
+    /// Takes the keys of DB 0, the server's only logical database. A file with keys in
+    /// other databases is rejected instead of having them silently dropped.
+    pub fn take_default_database(&mut self) -> tokio::io::Result<HashMap<String, Value>> {
+        if let Some(database_number) = self
+            .databases
+            .iter()
+            .find(|(database_number, keys)| **database_number != 0 && !keys.is_empty())
+            .map(|(database_number, _)| database_number)
+        {
+            return Err(tokio::io::Error::new(
+                tokio::io::ErrorKind::InvalidData,
+                format!(
+                    "RDB file has keys in database {}, but only database 0 is supported",
+                    database_number
+                ),
+            ));
+        }
+
+        Ok(self.databases.remove(&0).unwrap_or_default())
+    }
+
     pub fn parse(&mut self, buffer: Vec<u8>) -> tokio::io::Result<()> {
         self.buffer.extend_from_slice(&buffer);
 
         if self.magic_string.is_none() {
-            let magic_string_response = parse_magic_string(&self.buffer, self.cursor)?;
+            let magic_string_response = match parse_magic_string(&self.buffer, self.cursor) {
+                Ok(response) => response,
+                // Wait for the next chunk when the header itself was split
+                Err(e) if e.kind() == tokio::io::ErrorKind::UnexpectedEof => return Ok(()),
+                Err(e) => return Err(e),
+            };
             self.cursor += magic_string_response.number_of_read_bytes;
             self.magic_string = Some(magic_string_response.magic_string);
             self.redis_version = Some(magic_string_response.redis_version);
@@ -70,10 +101,27 @@ impl RdbParser {
                     db_hash_table_size,
                     expiry_hash_table_size,
                 } => {
+                    // The size comes from the file, which may be corrupt, so only part
+                    // of it is reserved up front and the rest is allocated as keys are read
+                    let number_of_keys = db_hash_table_size
+                        .parse::<usize>()
+                        .unwrap_or(0)
+                        .min(MAX_RESERVED_KEYS);
+                    self.databases
+                        .entry(self.current_database)
+                        .or_default()
+                        .reserve(number_of_keys);
+
                     self.hash_table_size = Some(db_hash_table_size);
                     self.expiry_hash_table_size = Some(expiry_hash_table_size);
                 }
                 OpCodeResponse::Database { database_number } => {
+                    self.current_database = database_number.parse::<usize>().map_err(|e| {
+                        tokio::io::Error::new(
+                            tokio::io::ErrorKind::InvalidData,
+                            format!("Invalid database number: {}", e),
+                        )
+                    })?;
                     self.db_number = Some(database_number);
                 }
                 OpCodeResponse::ExpirationSeconds {
@@ -87,10 +135,10 @@ impl RdbParser {
                             format!("Invalid expiration timestamp: {}", e),
                         )
                     })?;
-                    self.key_value_store.insert(
+                    self.insert_key(
                         key,
                         Value {
-                            data: DataType::String(value),
+                            data: value,
                             expiration: Some(expiration),
                         },
                     );
@@ -106,10 +154,10 @@ impl RdbParser {
                             format!("Invalid expiration timestamp: {}", e),
                         )
                     })?;
-                    self.key_value_store.insert(
+                    self.insert_key(
                         key,
                         Value {
-                            data: DataType::String(value),
+                            data: value,
                             expiration: Some(expiration),
                         },
                     );
@@ -118,10 +166,10 @@ impl RdbParser {
                     self.crc64_checksum = Some(crc64_checksum);
                 }
                 OpCodeResponse::KeyValuePair { key, value } => {
-                    self.key_value_store.insert(
+                    self.insert_key(
                         key,
                         Value {
-                            data: DataType::String(value),
+                            data: value,
                             expiration: None,
                         },
                     );
@@ -131,6 +179,17 @@ impl RdbParser {
             self.cursor += new_cursor;
         }
 
+        // Drop the bytes that were already parsed so a large payload is not kept in memory
+        self.buffer.drain(..self.cursor);
+        self.cursor = 0;
+
         Ok(())
     }
+
+    fn insert_key(&mut self, key: String, value: Value) {
+        self.databases
+            .entry(self.current_database)
+            .or_default()
+            .insert(key, value);
+    }
 }
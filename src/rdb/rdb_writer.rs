@@ -0,0 +1,285 @@
+use crate::{
+    commands::is_value_expired,
+    key_value_store::{DataType, KeyValueStore},
+    server::REDIS_VERSION,
+    stream::format_stream_id,
+};
+
+const MAGIC_STRING: &[u8] = b"REDIS0011";
+const METADATA_OPCODE: u8 = 0xFA;
+const RESIZE_DB_OPCODE: u8 = 0xFB;
+const EXPIRATION_MILLISECONDS_OPCODE: u8 = 0xFC;
+const DATABASE_OPCODE: u8 = 0xFE;
+const END_OF_FILE_OPCODE: u8 = 0xFF;
+const STRING_VALUE_TYPE: u8 = 0x00;
+const LIST_VALUE_TYPE: u8 = 0x01;
+const STREAM_VALUE_TYPE: u8 = 0x15;
+
+/// Serializes the store into the RDB format.
+///
+/// Only strings, lists and streams are written since they are the only types the
+/// RDB parser can load back. Expired keys are left out.
+pub fn encode_rdb(store: &KeyValueStore) -> Vec<u8> {
+    let mut bytes = MAGIC_STRING.to_vec();
+
+    for (key, value) in [("redis-ver", REDIS_VERSION), ("redis-bits", "64")] {
+        bytes.push(METADATA_OPCODE);
+        encode_string(key, &mut bytes);
+        encode_string(value, &mut bytes);
+    }
+
+    let entries = store
+        .iter()
+        .filter(|(_, value)| !is_value_expired(value))
+        .filter_map(|(key, value)| {
+            let value_type = match &value.data {
+                DataType::String(_) => STRING_VALUE_TYPE,
+                DataType::Array(_) => LIST_VALUE_TYPE,
+                DataType::Stream(_) => STREAM_VALUE_TYPE,
+                _ => return None,
+            };
+
+            Some((key, value_type, value))
+        })
+        .collect::<Vec<_>>();
+
+    let number_of_expiring_keys = entries
+        .iter()
+        .filter(|(_, _, value)| value.expiration.is_some())
+        .count();
+
+    bytes.push(DATABASE_OPCODE);
+    encode_length(0, &mut bytes);
+    bytes.push(RESIZE_DB_OPCODE);
+    encode_length(entries.len(), &mut bytes);
+    encode_length(number_of_expiring_keys, &mut bytes);
+
+    for (key, value_type, value) in entries {
+        if let Some(expiration) = value.expiration {
+            bytes.push(EXPIRATION_MILLISECONDS_OPCODE);
+            bytes.extend_from_slice(&(expiration.as_millisecond() as u64).to_le_bytes());
+        }
+
+        bytes.push(value_type);
+        encode_string(key, &mut bytes);
+        encode_value(&value.data, &mut bytes);
+    }
+
+    // A zero checksum tells readers that checksum verification is disabled
+    bytes.push(END_OF_FILE_OPCODE);
+    bytes.extend_from_slice(&[0; 8]);
+
+    bytes
+}
+
+/// Length in bytes of the value once serialized, used by DEBUG OBJECT's `serializedlength`
+pub fn serialized_length(data: &DataType) -> usize {
+    let mut bytes = Vec::new();
+    encode_value(data, &mut bytes);
+
+    bytes.len()
+}
+
+/// Serializes a value the way it follows its key in an RDB file, without its type byte.
+///
+/// Collections use the plain length-prefixed layouts instead of listpacks, and streams
+/// are written as their flattened entries rather than Redis' listpack-based layout,
+/// so their sizes are approximate and only this server can load streams back.
+fn encode_value(data: &DataType, bytes: &mut Vec<u8>) {
+    match data {
+        DataType::String(string) => encode_string(string, bytes),
+        DataType::Array(list) => {
+            encode_length(list.len(), bytes);
+            list.iter().for_each(|item| encode_string(item, bytes));
+        }
+        DataType::Set(set) => {
+            encode_length(set.len(), bytes);
+            set.iter().for_each(|member| encode_string(member, bytes));
+        }
+        DataType::Hash(hash) => {
+            encode_length(hash.len(), bytes);
+
+            for (field, value) in hash.iter() {
+                encode_string(field, bytes);
+                encode_string(value, bytes);
+            }
+        }
+        DataType::SortedSet(sorted_set) => {
+            encode_length(sorted_set.len(), bytes);
+
+            for (member, score) in sorted_set.iter() {
+                encode_string(member, bytes);
+                bytes.extend_from_slice(&score.to_le_bytes());
+            }
+        }
+        DataType::Stream(stream) => {
+            encode_length(stream.len(), bytes);
+
+            for (stream_id, fields) in stream.iter() {
+                encode_string(stream_id, bytes);
+                encode_length(fields.len(), bytes);
+
+                for (field, value) in fields {
+                    encode_string(field, bytes);
+                    encode_string(value, bytes);
+                }
+            }
+
+            // The last generated ID can be ahead of the top entry after XSETID
+            encode_string(&format_stream_id(stream.last_id()), bytes);
+        }
+    }
+}
+
+fn encode_length(length: usize, bytes: &mut Vec<u8>) {
+    if length < 1 << 6 {
+        bytes.push(length as u8);
+    } else if length < 1 << 14 {
+        bytes.push(0b0100_0000 | (length >> 8) as u8);
+        bytes.push(length as u8);
+    } else if length <= u32::MAX as usize {
+        bytes.push(0x80);
+        bytes.extend_from_slice(&(length as u32).to_be_bytes());
+    } else {
+        bytes.push(0x81);
+        bytes.extend_from_slice(&(length as u64).to_be_bytes());
+    }
+}
+
+fn encode_string(string: &str, bytes: &mut Vec<u8>) {
+    encode_length(string.len(), bytes);
+    bytes.extend_from_slice(string.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use jiff::Timestamp;
+
+    use super::{encode_length, encode_rdb};
+    use crate::{
+        key_value_store::{DataType, Value},
+        list::List,
+        rdb::RdbParser,
+        set::Set,
+        stream::Stream,
+    };
+
+    #[test]
+    fn test_encode_length() {
+        let test_cases = vec![
+            (10, vec![0x0A]),
+            (700, vec![0x42, 0xBC]),
+            (17000, vec![0x80, 0x00, 0x00, 0x42, 0x68]),
+        ];
+
+        for (length, expected) in test_cases {
+            let mut bytes = Vec::new();
+            encode_length(length, &mut bytes);
+            assert_eq!(bytes, expected, "encoding length {}", length);
+        }
+    }
+
+    #[test]
+    fn test_encode_rdb_round_trip() {
+        let expiration = "2032-01-01T00:00:00Z".parse::<Timestamp>().unwrap();
+        let mut berries = Stream::from([
+            (
+                "1-0".to_string(),
+                BTreeMap::from([
+                    ("name".to_string(), "blueberry".to_string()),
+                    ("color".to_string(), "blue".to_string()),
+                ]),
+            ),
+            (
+                "2-0".to_string(),
+                BTreeMap::from([("name".to_string(), "raspberry".to_string())]),
+            ),
+        ]);
+        berries.set_last_id((2, 7));
+        let store = HashMap::from([
+            (
+                "mango".to_string(),
+                Value {
+                    data: DataType::String("pineapple".to_string()),
+                    expiration: None,
+                },
+            ),
+            (
+                "orange".to_string(),
+                Value {
+                    data: DataType::String("x".repeat(20000)),
+                    expiration: Some(expiration),
+                },
+            ),
+            (
+                "expired".to_string(),
+                Value {
+                    data: DataType::String("gone".to_string()),
+                    expiration: Some(Timestamp::UNIX_EPOCH),
+                },
+            ),
+            (
+                "fruits".to_string(),
+                Value {
+                    data: DataType::Array(List::from_iter(["apple".to_string()])),
+                    expiration: None,
+                },
+            ),
+            (
+                "berries".to_string(),
+                Value {
+                    data: DataType::Stream(berries.clone()),
+                    expiration: None,
+                },
+            ),
+            (
+                "colors".to_string(),
+                Value {
+                    data: DataType::Set(Set::from_iter(["red".to_string()])),
+                    expiration: None,
+                },
+            ),
+        ]);
+
+        let mut rdb_parser = RdbParser::new();
+        rdb_parser.parse(encode_rdb(&store)).unwrap();
+
+        assert_eq!(
+            rdb_parser.databases[&0],
+            HashMap::from([
+                (
+                    "mango".to_string(),
+                    Value {
+                        data: DataType::String("pineapple".to_string()),
+                        expiration: None,
+                    },
+                ),
+                (
+                    "orange".to_string(),
+                    Value {
+                        data: DataType::String("x".repeat(20000)),
+                        expiration: Some(expiration),
+                    },
+                ),
+                (
+                    "fruits".to_string(),
+                    Value {
+                        data: DataType::Array(List::from_iter(["apple".to_string()])),
+                        expiration: None,
+                    },
+                ),
+                (
+                    "berries".to_string(),
+                    Value {
+                        data: DataType::Stream(berries),
+                        expiration: None,
+                    },
+                ),
+            ])
+        );
+        assert_eq!(rdb_parser.hash_table_size, Some("4".to_string()));
+        assert_eq!(rdb_parser.expiry_hash_table_size, Some("1".to_string()));
+    }
+}
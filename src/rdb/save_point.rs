@@ -0,0 +1,99 @@
+/// A `save <seconds> <changes>` point: a background save is due once at least
+/// `changes` writes happened and more than `seconds` elapsed since the last save
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SavePoint {
+    pub seconds: u64,
+    pub changes: u64,
+}
+
+impl SavePoint {
+    pub fn is_due(&self, seconds_since_last_save: u64, changes_since_last_save: u64) -> bool {
+        changes_since_last_save > 0
+            && changes_since_last_save >= self.changes
+            && seconds_since_last_save > self.seconds
+    }
+}
+
+/// Parses a `"<seconds> <changes> [<seconds> <changes> ...]"` value. An empty
+/// value is valid and disables automatic saving.
+pub fn parse_save_points(value: &str) -> Option<Vec<SavePoint>> {
+    let numbers = value
+        .split_whitespace()
+        .map(|number| number.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    if numbers.len() % 2 != 0 {
+        return None;
+    }
+
+    Some(
+        numbers
+            .chunks(2)
+            .map(|pair| SavePoint {
+                seconds: pair[0],
+                changes: pair[1],
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_save_points() {
+        let test_cases = vec![
+            ("", Some(vec![])),
+            (
+                "3600 1",
+                Some(vec![SavePoint {
+                    seconds: 3600,
+                    changes: 1,
+                }]),
+            ),
+            (
+                "3600 1 300 100",
+                Some(vec![
+                    SavePoint {
+                        seconds: 3600,
+                        changes: 1,
+                    },
+                    SavePoint {
+                        seconds: 300,
+                        changes: 100,
+                    },
+                ]),
+            ),
+            ("3600", None),
+            ("3600 1 300", None),
+            ("3600 many", None),
+            ("-1 1", None),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(parse_save_points(value), expected, "parsing {:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_save_point_is_due() {
+        let save_point = SavePoint {
+            seconds: 60,
+            changes: 10,
+        };
+
+        assert!(save_point.is_due(61, 10));
+        assert!(save_point.is_due(120, 50));
+        assert!(!save_point.is_due(60, 10));
+        assert!(!save_point.is_due(61, 9));
+
+        let save_point = SavePoint {
+            seconds: 0,
+            changes: 0,
+        };
+
+        assert!(save_point.is_due(1, 1));
+        assert!(!save_point.is_due(1, 0));
+    }
+}
@@ -2,6 +2,10 @@ use std::slice::Iter;
 
 use thiserror::Error;
 
+/// Default of Redis' `proto-max-bulk-len`: the longest bulk string, and the most
+/// elements in a multibulk request, accepted from a client
+pub const DEFAULT_PROTO_MAX_BULK_LEN: usize = 512 * 1024 * 1024;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum RespError {
     #[error("unknown RESP type")]
@@ -12,6 +16,10 @@ pub enum RespError {
     InvalidBulkString,
     #[error("invalid array")]
     InvalidArray,
+    #[error("expected '*', got '{0}'")]
+    UnexpectedRequestType(char),
+    #[error("protocol error: {0}")]
+    ProtocolError(String),
 }
 
 impl RespError {
@@ -27,8 +35,23 @@ impl RespError {
                 RespValue::Error("ERR invalid bulk string".to_string()).encode()
             }
             RespError::InvalidArray => RespValue::Error("ERR invalid array".to_string()).encode(),
+            RespError::UnexpectedRequestType(prefix) => RespValue::Error(format!(
+                "ERR Protocol error: expected '*', got '{}'",
+                prefix
+            ))
+            .encode(),
+            RespError::ProtocolError(message) => {
+                RespValue::Error(format!("ERR Protocol error: {}", message)).encode()
+            }
         }
     }
+
+    /// Whether the rest of the input can still be parsed after this error.
+    /// A malformed multibulk request leaves the stream out of sync, so the
+    /// connection has to be closed.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(self, RespError::ProtocolError(_))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -40,6 +63,10 @@ pub enum RespValue {
     Array(Vec<RespValue>),
     NullBulkString,
     NullArray,
+    Map(Vec<(RespValue, RespValue)>),
+    // Out-of-band data such as pub/sub messages, only sent to RESP3 clients
+    Push(Vec<RespValue>),
+    Double(f64),
 }
 
 impl RespValue {
@@ -55,6 +82,94 @@ impl RespValue {
         Ok(vec)
     }
 
+    /// Parses client requests, which must be arrays of bulk strings.
+    ///
+    /// Each request is parsed on its own so a stray line only fails itself,
+    /// while parsing stops at the first error that desyncs the stream.
+    pub fn parse_requests(
+        data: Vec<&str>,
+        max_bulk_length: usize,
+    ) -> Vec<Result<RespValue, RespError>> {
+        let mut data_iter = data.iter();
+        let mut requests = Vec::new();
+
+        while let Some(value) = data_iter.next() {
+            // Empty and whitespace-only lines between requests are ignored
+            if value.trim().is_empty() {
+                continue;
+            }
+
+            let request = Self::decode_request(value, &mut data_iter, max_bulk_length);
+            let is_recoverable = match &request {
+                // Like in Redis, an empty multibulk request is not a command, so it gets no reply
+                Ok(RespValue::Array(elements)) if elements.is_empty() => continue,
+                Ok(_) => true,
+                Err(e) => e.is_recoverable(),
+            };
+
+            requests.push(request);
+
+            if !is_recoverable {
+                break;
+            }
+        }
+
+        requests
+    }
+
+    fn decode_request(
+        value: &str,
+        rest_of_data: &mut Iter<'_, &str>,
+        max_bulk_length: usize,
+    ) -> Result<Self, RespError> {
+        let Some(length_str) = value.strip_prefix('*') else {
+            let prefix = value.chars().next().unwrap_or_default();
+            return Err(RespError::UnexpectedRequestType(prefix));
+        };
+
+        let array_length = length_str
+            .parse::<i32>()
+            .map_err(|_| RespError::ProtocolError("invalid multibulk length".to_string()))?;
+
+        // Null and negative length requests are treated as empty ones
+        if array_length <= 0 {
+            return Ok(RespValue::Array(Vec::new()));
+        }
+
+        // Checked before allocating room for the elements a client claims to send
+        if array_length as usize > max_bulk_length {
+            return Err(RespError::ProtocolError(
+                "invalid multibulk length".to_string(),
+            ));
+        }
+
+        let mut array_elements = Vec::with_capacity(array_length as usize);
+
+        while array_elements.len() < array_length as usize {
+            let Some(next_element) = rest_of_data.next() else {
+                return Err(RespError::ProtocolError(
+                    "unexpected end of multibulk request".to_string(),
+                ));
+            };
+
+            let Some(bulk_string_length) = next_element.strip_prefix('$') else {
+                let prefix = next_element.chars().next().unwrap_or_default();
+                return Err(RespError::ProtocolError(format!(
+                    "expected '$', got '{}'",
+                    prefix
+                )));
+            };
+
+            let bulk_string =
+                Self::decode_bulk_string(bulk_string_length, rest_of_data, max_bulk_length)
+                    .map_err(|_| RespError::ProtocolError("invalid bulk length".to_string()))?;
+
+            array_elements.push(bulk_string);
+        }
+
+        Ok(RespValue::Array(array_elements))
+    }
+
     pub fn decode(value: &str, rest_of_data: &mut Iter<'_, &str>) -> Result<Self, RespError> {
         let Some(prefix) = value.chars().next() else {
             return Err(RespError::UnknownRespType);
@@ -63,7 +178,7 @@ impl RespValue {
         let content = &value[1..];
 
         match prefix {
-            '$' => Self::decode_bulk_string(content, rest_of_data),
+            '$' => Self::decode_bulk_string(content, rest_of_data, DEFAULT_PROTO_MAX_BULK_LEN),
             '+' => Ok(RespValue::SimpleString(content.to_string())),
             '-' => Ok(RespValue::Error(content.to_string())),
             ':' => {
@@ -73,7 +188,7 @@ impl RespValue {
 
                 Ok(RespValue::Integer(integer))
             }
-            '*' => Self::decode_array(content, rest_of_data),
+            '*' => Self::decode_array(content, rest_of_data, DEFAULT_PROTO_MAX_BULK_LEN),
             _ => Err(RespError::UnknownRespType),
         }
     }
@@ -81,6 +196,7 @@ impl RespValue {
     fn decode_bulk_string(
         length_str: &str,
         rest_of_data: &mut Iter<'_, &str>,
+        max_length: usize,
     ) -> Result<RespValue, RespError> {
         let bulk_string_length = length_str
             .parse::<i32>()
@@ -92,7 +208,7 @@ impl RespValue {
         }
 
         // Negative lengths other than -1 are invalid
-        if bulk_string_length < 0 {
+        if bulk_string_length < 0 || bulk_string_length as usize > max_length {
             return Err(RespError::InvalidBulkString);
         }
 
@@ -110,6 +226,7 @@ impl RespValue {
     fn decode_array(
         length_str: &str,
         rest_of_data: &mut Iter<'_, &str>,
+        max_length: usize,
     ) -> Result<RespValue, RespError> {
         let array_length = length_str
             .parse::<i32>()
@@ -121,7 +238,7 @@ impl RespValue {
         }
 
         // Negative lengths other than -1 are invalid
-        if array_length < 0 {
+        if array_length < 0 || array_length as usize > max_length {
             return Err(RespError::InvalidArray);
         }
 
@@ -173,6 +290,26 @@ impl RespValue {
             RespValue::NullArray => {
                 format!("*-1\r\n")
             }
+            RespValue::Map(entries) => {
+                let mut encoded_entries = Vec::new();
+
+                for (key, value) in entries {
+                    encoded_entries.push(key.encode());
+                    encoded_entries.push(value.encode());
+                }
+
+                format!("%{}\r\n{}", entries.len(), encoded_entries.join(""))
+            }
+            RespValue::Push(elements) => {
+                let encoded_elements = elements
+                    .iter()
+                    .map(|element| element.encode())
+                    .collect::<String>();
+
+                format!(">{}\r\n{}", elements.len(), encoded_elements)
+            }
+            // Rust already spells infinities as `inf` and `-inf`, like RESP3 does
+            RespValue::Double(d) => format!(",{}\r\n", d),
         }
     }
 
@@ -193,7 +330,7 @@ impl RespValue {
 
 #[cfg(test)]
 mod tests {
-    use super::{RespError, RespValue};
+    use super::{DEFAULT_PROTO_MAX_BULK_LEN, RespError, RespValue};
 
     #[test]
     fn test_parse_to_resp_values() {
@@ -264,13 +401,20 @@ mod tests {
 
         for (length_str, data, expected) in test_cases {
             let mut iter = data.iter();
-            let result = RespValue::decode_bulk_string(length_str, &mut iter);
+            let result =
+                RespValue::decode_bulk_string(length_str, &mut iter, DEFAULT_PROTO_MAX_BULK_LEN);
             assert_eq!(
                 result, expected,
                 "Failed for length_str: '{}', data: {:?}",
                 length_str, data
             );
         }
+
+        let mut iter = ["hello"].iter();
+        assert_eq!(
+            RespValue::decode_bulk_string("5", &mut iter, 4),
+            Err(RespError::InvalidBulkString)
+        );
     }
 
     #[test]
@@ -317,13 +461,19 @@ mod tests {
 
         for (length_str, data, expected) in test_cases {
             let mut iter = data.iter();
-            let result = RespValue::decode_array(length_str, &mut iter);
+            let result = RespValue::decode_array(length_str, &mut iter, DEFAULT_PROTO_MAX_BULK_LEN);
             assert_eq!(
                 result, expected,
                 "Failed for length_str: '{}', data: {:?}",
                 length_str, data
             );
         }
+
+        let mut iter = [":1", ":2"].iter();
+        assert_eq!(
+            RespValue::decode_array("2", &mut iter, 1),
+            Err(RespError::InvalidArray)
+        );
     }
 
     #[test]
@@ -418,6 +568,31 @@ mod tests {
                 ]),
                 "*2\r\n*1\r\n:1\r\n*1\r\n:2\r\n",
             ),
+            (RespValue::Map(vec![]), "%0\r\n"),
+            (
+                RespValue::Map(vec![
+                    (
+                        RespValue::BulkString("proto".to_string()),
+                        RespValue::Integer(3),
+                    ),
+                    (
+                        RespValue::BulkString("mode".to_string()),
+                        RespValue::BulkString("standalone".to_string()),
+                    ),
+                ]),
+                "%2\r\n$5\r\nproto\r\n:3\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n",
+            ),
+            (
+                RespValue::Push(vec![
+                    RespValue::BulkString("message".to_string()),
+                    RespValue::BulkString("fruits".to_string()),
+                    RespValue::BulkString("mango".to_string()),
+                ]),
+                ">3\r\n$7\r\nmessage\r\n$6\r\nfruits\r\n$5\r\nmango\r\n",
+            ),
+            (RespValue::Double(2.5), ",2.5\r\n"),
+            (RespValue::Double(10.0), ",10\r\n"),
+            (RespValue::Double(f64::NEG_INFINITY), ",-inf\r\n"),
         ];
 
         for (input, expected) in test_cases {
@@ -450,4 +625,98 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_parse_requests() {
+        let set_request = RespValue::Array(vec![
+            RespValue::BulkString("SET".into()),
+            RespValue::BulkString("a".into()),
+        ]);
+        let ping_request = RespValue::Array(vec![RespValue::BulkString("PING".into())]);
+
+        let test_cases = vec![
+            (
+                vec!["*2", "$3", "SET", "$1", "a", "*1", "$4", "PING"],
+                vec![Ok(set_request.clone()), Ok(ping_request.clone())],
+            ),
+            (
+                vec!["+OK", "", "*1", "$4", "PING"],
+                vec![
+                    Err(RespError::UnexpectedRequestType('+')),
+                    Ok(ping_request.clone()),
+                ],
+            ),
+            (
+                vec!["*3", "$3", "SET", "$1", "a", "*1", "$4", "PING"],
+                vec![Err(RespError::ProtocolError(
+                    "expected '$', got '*'".to_string(),
+                ))],
+            ),
+            (
+                vec!["*3", "$3", "SET", "$1", "a"],
+                vec![Err(RespError::ProtocolError(
+                    "unexpected end of multibulk request".to_string(),
+                ))],
+            ),
+            (
+                vec!["*1", "$5", "PING", "*1", "$4", "PING"],
+                vec![Err(RespError::ProtocolError(
+                    "invalid bulk length".to_string(),
+                ))],
+            ),
+            (
+                vec!["*0", "  ", "*-1", "*1", "$4", "PING"],
+                vec![Ok(ping_request.clone())],
+            ),
+            (
+                vec!["*x", "*1", "$4", "PING"],
+                vec![Err(RespError::ProtocolError(
+                    "invalid multibulk length".to_string(),
+                ))],
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                RespValue::parse_requests(input.clone(), DEFAULT_PROTO_MAX_BULK_LEN),
+                expected,
+                "parsing requests {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_requests_over_max_bulk_length() {
+        let test_cases = vec![
+            (
+                vec!["*1", "$5", "mango", "*1", "$4", "PING"],
+                Err(RespError::ProtocolError("invalid bulk length".to_string())),
+            ),
+            (
+                vec!["*5", "$4", "PING"],
+                Err(RespError::ProtocolError(
+                    "invalid multibulk length".to_string(),
+                )),
+            ),
+            (
+                vec!["*4", "$4", "ECHO", "$4", "kiwi", "$1", "a", "$1", "b"],
+                Ok(RespValue::Array(vec![
+                    RespValue::BulkString("ECHO".into()),
+                    RespValue::BulkString("kiwi".into()),
+                    RespValue::BulkString("a".into()),
+                    RespValue::BulkString("b".into()),
+                ])),
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                RespValue::parse_requests(input.clone(), 4),
+                vec![expected],
+                "parsing requests {:?}",
+                input
+            );
+        }
+    }
 }
@@ -1,21 +1,58 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
+use jiff::Timestamp;
 use rand::distr::{Alphanumeric, SampleString};
 use regex::Regex;
+use socket2::{SockRef, TcpKeepalive};
 use thiserror::Error;
-use tokio::io::AsyncWriteExt;
-use tokio::net::tcp::OwnedWriteHalf;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio::{
     net::{TcpListener, TcpStream},
-    sync::{Mutex, RwLock},
+    sync::{Mutex, RwLock, mpsc},
 };
+use tokio_rustls::{TlsAcceptor, rustls::ServerConfig};
 
+use crate::aof::{
+    AOF_FILENAME, AppendFsync, AppendOnlyFile, load_append_only_file, rewrite_append_only_file,
+};
+use crate::commands::{is_value_expired, start_background_save};
 use crate::connection::{handle_master_to_replica_connection, handle_replica_to_client_connection};
+use crate::hash::HashEncodingLimits;
 use crate::input::handshake;
-use crate::rdb::parse_rdb_file;
-use crate::resp::RespValue;
+use crate::key_value_store::KeyValueStore;
+use crate::latency::{DEFAULT_LATENCY_MONITOR_THRESHOLD, LatencyMonitor};
+use crate::list::{DEFAULT_LIST_MAX_LISTPACK_SIZE, is_valid_max_listpack_size};
+use crate::rdb::{SavePoint, parse_rdb_file, parse_save_points};
+use crate::resp::{DEFAULT_PROTO_MAX_BULK_LEN, RespValue};
+use crate::set::SetEncodingLimits;
+use crate::slowlog::{DEFAULT_SLOWLOG_LOG_SLOWER_THAN, SlowLog};
+use crate::sorted_set::SortedSetEncodingLimits;
+use crate::stream::DEFAULT_STREAM_NODE_MAX_ENTRIES;
+use crate::tls::load_tls_config;
 use crate::{connection::handle_master_to_client_connection, state::State};
 
+pub const REDIS_VERSION: &str = "7.4.0";
+const ACTIVE_EXPIRE_CYCLE_INTERVAL: Duration = Duration::from_millis(100);
+/// Keys the active expire cycle checks per batch, the same as Redis
+const ACTIVE_EXPIRE_CYCLE_KEYS_PER_LOOP: usize = 20;
+/// The active expire cycle checks another batch while more than this share of the last one
+/// had expired
+const ACTIVE_EXPIRE_CYCLE_ACCEPTABLE_STALE_PERCENT: usize = 25;
+const SAVE_POINTS_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+/// Default of Redis' `tcp-keepalive`, in seconds
+pub const DEFAULT_TCP_KEEPALIVE: u64 = 300;
+/// Channel on which invalidations are published for CLIENT TRACKING ON REDIRECT
+pub const INVALIDATION_CHANNEL: &str = "__redis__:invalidate";
+
 #[derive(Error, Debug, PartialEq, Clone)]
 pub enum CliError {
     #[error("Invalid command line flag")]
@@ -30,6 +67,52 @@ pub enum CliError {
     InvalidRdbDirectoryPath,
     #[error("Invalid RDB file name")]
     InvalidRdbFileName,
+    #[error("Invalid bind address")]
+    InvalidBindAddress,
+    #[error("Invalid tls-port flag value")]
+    InvalidTlsPortFlagValue,
+    #[error("Invalid tls-cert-file flag value")]
+    InvalidTlsCertFileFlagValue,
+    #[error("Invalid tls-key-file flag value")]
+    InvalidTlsKeyFileFlagValue,
+    #[error("TLS port requires a certificate and a private key file")]
+    MissingTlsCertificateOrKey,
+    #[error("Invalid TLS certificate or private key")]
+    InvalidTlsCertificateOrKey,
+    #[error("Invalid appendonly flag value")]
+    InvalidAppendOnlyFlagValue,
+    #[error("Invalid save flag value")]
+    InvalidSaveFlagValue,
+    #[error("Invalid replica-read-only flag value")]
+    InvalidReplicaReadOnlyFlagValue,
+    #[error("Invalid appendfsync flag value")]
+    InvalidAppendFsyncFlagValue,
+    #[error("Invalid list-max-listpack-size flag value")]
+    InvalidListMaxListpackSizeFlagValue,
+    #[error("Invalid set-max-intset-entries flag value")]
+    InvalidSetMaxIntsetEntriesFlagValue,
+    #[error("Invalid set-max-listpack-entries flag value")]
+    InvalidSetMaxListpackEntriesFlagValue,
+    #[error("Invalid set-max-listpack-value flag value")]
+    InvalidSetMaxListpackValueFlagValue,
+    #[error("Invalid hash-max-listpack-entries flag value")]
+    InvalidHashMaxListpackEntriesFlagValue,
+    #[error("Invalid hash-max-listpack-value flag value")]
+    InvalidHashMaxListpackValueFlagValue,
+    #[error("Invalid zset-max-listpack-entries flag value")]
+    InvalidZsetMaxListpackEntriesFlagValue,
+    #[error("Invalid zset-max-listpack-value flag value")]
+    InvalidZsetMaxListpackValueFlagValue,
+    #[error("Invalid stream-node-max-entries flag value")]
+    InvalidStreamNodeMaxEntriesFlagValue,
+    #[error("Invalid slowlog-log-slower-than flag value")]
+    InvalidSlowlogLogSlowerThanFlagValue,
+    #[error("Invalid latency-monitor-threshold flag value")]
+    InvalidLatencyMonitorThresholdFlagValue,
+    #[error("Invalid tcp-keepalive flag value")]
+    InvalidTcpKeepaliveFlagValue,
+    #[error("Invalid proto-max-bulk-len flag value")]
+    InvalidProtoMaxBulkLenFlagValue,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -49,13 +132,228 @@ impl RedisRole {
 
 #[derive(Debug, Clone)]
 pub struct Replica {
-    pub writer: Arc<RwLock<OwnedWriteHalf>>,
+    pub sender: mpsc::UnboundedSender<Vec<u8>>,
     pub offset: usize,
 }
 
+/// Starts propagating writes to a replica that finished its full resynchronization.
+///
+/// A replica whose connection can no longer be written to is removed as soon as its
+/// outbound queue stops, rather than when the read side notices the closed connection,
+/// so it stops counting towards `connected_slaves` and WAIT right away.
+pub async fn register_replica<W>(
+    server: Arc<RwLock<RedisServer>>,
+    client_address: &str,
+    writer: Arc<RwLock<W>>,
+) where
+    W: AsyncWrite + Unpin + Send + Sync + 'static,
+{
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let outbound_queue = tokio::spawn(flush_outbound_queue(writer, receiver));
+
+    {
+        let mut server_guard = server.write().await;
+        if let Some(replicas) = &mut server_guard.replicas {
+            replicas.insert(client_address.to_string(), Replica { sender, offset: 0 });
+        }
+    }
+
+    let client_address = client_address.to_string();
+
+    tokio::spawn(async move {
+        let _ = outbound_queue.await;
+
+        // The queue also stops once the replica was removed and its sender dropped, and
+        // the address may have been taken by a new replica with a live queue in the meantime
+        let mut server_guard = server.write().await;
+        if let Some(replicas) = &mut server_guard.replicas
+            && replicas
+                .get(&client_address)
+                .is_some_and(|replica| replica.sender.is_closed())
+        {
+            replicas.remove(&client_address);
+        }
+    });
+}
+
+/// Spawns the task that owns queued writes to a connection and returns the queue's sender.
+///
+/// Each queued message is written whole while holding the writer lock, so it never
+/// interleaves with replies written directly to the same connection.
+pub fn spawn_outbound_queue<W>(writer: Arc<RwLock<W>>) -> mpsc::UnboundedSender<Vec<u8>>
+where
+    W: AsyncWrite + Unpin + Send + Sync + 'static,
+{
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        flush_outbound_queue(writer, receiver).await;
+    });
+
+    sender
+}
+
+/// Writes queued messages to a connection in order, flushing once per batch
+/// of queued messages rather than once per message
+async fn flush_outbound_queue<W>(
+    writer: Arc<RwLock<W>>,
+    mut receiver: mpsc::UnboundedReceiver<Vec<u8>>,
+) where
+    W: AsyncWrite + Unpin + Send + Sync + 'static,
+{
+    while let Some(bytes) = receiver.recv().await {
+        let mut writer_guard = writer.write().await;
+        let mut result = writer_guard.write_all(&bytes).await;
+
+        while result.is_ok() {
+            let Ok(bytes) = receiver.try_recv() else {
+                break;
+            };
+
+            result = writer_guard.write_all(&bytes).await;
+        }
+
+        if result.is_ok() {
+            result = writer_guard.flush().await;
+        }
+
+        if let Err(e) = result {
+            eprintln!("Error writing to stream: {}", e);
+            break;
+        }
+    }
+}
+
+/// Where the invalidation messages of a connection with CLIENT TRACKING on are sent
+#[derive(Debug, Clone)]
+pub enum InvalidationTarget {
+    /// Pushed to the tracking connection itself, which only receives them over RESP3
+    Connection(mpsc::UnboundedSender<Vec<u8>>),
+    /// Published on the invalidation channel to the connection at this address,
+    /// given by CLIENT TRACKING ON REDIRECT
+    Redirect(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub id: u64,
+    pub address: String,
+    pub connected_at: Timestamp,
+    // Set by CLIENT NO-EVICT so that client eviction, once implemented, skips this connection
+    pub no_evict: bool,
+    // Set by CLIENT NO-TOUCH so that the keys this connection reads keep their idle time
+    pub no_touch: bool,
+    // Set by CLIENT TRACKING ON so that this connection learns when the keys it read change
+    pub tracking: Option<InvalidationTarget>,
+    // Set once the connection authenticated as the default user with HELLO AUTH
+    pub is_authenticated: bool,
+}
+
+impl Client {
+    /// Single line description of the connection in the CLIENT LIST format
+    pub fn format_info(&self, is_resp3: bool) -> String {
+        let age = (Timestamp::now() - self.connected_at)
+            .total(jiff::Unit::Second)
+            .unwrap_or(0.0) as u64;
+        let mut flags = String::new();
+        if self.no_evict {
+            flags.push('e');
+        }
+        if self.no_touch {
+            flags.push('T');
+        }
+        if flags.is_empty() {
+            flags.push('N');
+        }
+
+        let protocol = if is_resp3 { 3 } else { 2 };
+
+        format!(
+            "id={} addr={} age={} flags={} db=0 resp={}\n",
+            self.id, self.address, age, flags, protocol
+        )
+    }
+}
+
+/// Counters reported by INFO stats. They are atomic so that recording an event
+/// only needs a read lock on the server.
+#[derive(Debug, Default)]
+pub struct ServerStats {
+    total_connections_received: AtomicU64,
+    total_commands_processed: AtomicU64,
+    keyspace_hits: AtomicU64,
+    keyspace_misses: AtomicU64,
+    expired_keys: AtomicU64,
+    changes_since_last_save: AtomicU64,
+}
+
+impl ServerStats {
+    pub fn record_connection(&self) {
+        self.total_connections_received
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_command(&self) {
+        self.total_commands_processed
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_keyspace_lookup(&self, is_hit: bool) {
+        let counter = if is_hit {
+            &self.keyspace_hits
+        } else {
+            &self.keyspace_misses
+        };
+
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_expired_keys(&self, number_of_keys: usize) {
+        self.expired_keys
+            .fetch_add(number_of_keys as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_change(&self) {
+        self.changes_since_last_save.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Forgets the changes persisted by a save, keeping the ones made while it ran
+    pub fn record_saved_changes(&self, number_of_changes: u64) {
+        self.changes_since_last_save
+            .fetch_sub(number_of_changes, Ordering::Relaxed);
+    }
+
+    pub fn total_connections_received(&self) -> u64 {
+        self.total_connections_received.load(Ordering::Relaxed)
+    }
+
+    pub fn total_commands_processed(&self) -> u64 {
+        self.total_commands_processed.load(Ordering::Relaxed)
+    }
+
+    pub fn keyspace_hits(&self) -> u64 {
+        self.keyspace_hits.load(Ordering::Relaxed)
+    }
+
+    pub fn keyspace_misses(&self) -> u64 {
+        self.keyspace_misses.load(Ordering::Relaxed)
+    }
+
+    pub fn expired_keys(&self) -> u64 {
+        self.expired_keys.load(Ordering::Relaxed)
+    }
+
+    pub fn changes_since_last_save(&self) -> u64 {
+        self.changes_since_last_save.load(Ordering::Relaxed)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RedisServer {
     pub port: u32,
+    pub bind_addresses: Vec<IpAddr>,
+    pub tls_port: Option<u32>,
+    pub tls_config: Option<Arc<ServerConfig>>,
     pub role: RedisRole,
     pub repl_id: String,
     pub repl_offset: usize,
@@ -63,7 +361,37 @@ pub struct RedisServer {
     pub write_commands: Vec<&'static str>,
     pub rdb_directory: String,
     pub rdb_filename: String,
-    pub pub_sub_channels: HashMap<String, HashMap<String, Arc<RwLock<OwnedWriteHalf>>>>,
+    pub pub_sub_channels: HashMap<String, HashMap<String, mpsc::UnboundedSender<Vec<u8>>>>,
+    pub shard_pub_sub_channels: HashMap<String, HashMap<String, mpsc::UnboundedSender<Vec<u8>>>>,
+    pub resp3_clients: HashSet<String>,
+    pub active_expire_enabled: bool,
+    pub clients: HashMap<String, Client>,
+    pub next_client_id: u64,
+    pub last_save_time: Timestamp,
+    pub is_background_save_in_progress: bool,
+    pub save_points: Vec<SavePoint>,
+    pub appendonly: bool,
+    pub replica_read_only: bool,
+    pub appendfsync: AppendFsync,
+    pub append_only_file: Option<AppendOnlyFile>,
+    pub is_aof_rewrite_in_progress: bool,
+    pub is_last_aof_rewrite_ok: bool,
+    pub list_max_listpack_size: i64,
+    pub set_encoding_limits: SetEncodingLimits,
+    pub zset_encoding_limits: SortedSetEncodingLimits,
+    pub hash_encoding_limits: HashEncodingLimits,
+    pub stream_node_max_entries: usize,
+    pub stats: Arc<ServerStats>,
+    pub slowlog_log_slower_than: i64,
+    pub slowlog: SlowLog,
+    pub latency_monitor_threshold: u64,
+    pub latency_monitor: LatencyMonitor,
+    pub tcp_keepalive: u64,
+    pub key_access_times: HashMap<String, Timestamp>, // key --> last time it was accessed
+    pub raw_string_keys: HashSet<String>, // keys whose strings were modified in place by APPEND or SETRANGE
+    pub tracking_table: HashMap<String, HashSet<String>>, // key --> connections with CLIENT TRACKING on that read it
+    pub requirepass: Option<String>,
+    pub proto_max_bulk_len: usize,
 }
 
 impl RedisServer {
@@ -73,6 +401,24 @@ impl RedisServer {
         let mut redis_role: Option<RedisRole> = None;
         let mut directory_path: Option<String> = None;
         let mut rdb_filename: Option<String> = None;
+        let mut bind_addresses: Option<Vec<IpAddr>> = None;
+        let mut tls_port: Option<u32> = None;
+        let mut tls_cert_file: Option<String> = None;
+        let mut tls_key_file: Option<String> = None;
+        let mut save_points: Option<Vec<SavePoint>> = None;
+        let mut appendonly: Option<bool> = None;
+        let mut replica_read_only: Option<bool> = None;
+        let mut appendfsync: Option<AppendFsync> = None;
+        let mut list_max_listpack_size: Option<i64> = None;
+        let mut set_encoding_limits = SetEncodingLimits::default();
+        let mut zset_encoding_limits = SortedSetEncodingLimits::default();
+        let mut hash_encoding_limits = HashEncodingLimits::default();
+        let mut stream_node_max_entries: Option<usize> = None;
+        let mut slowlog_log_slower_than: Option<i64> = None;
+        let mut latency_monitor_threshold: Option<u64> = None;
+        let mut tcp_keepalive: Option<u64> = None;
+        let mut requirepass: Option<String> = None;
+        let mut proto_max_bulk_len: Option<usize> = None;
 
         while let Some(arg) = iter.next() {
             match arg.as_str() {
@@ -115,6 +461,226 @@ impl RedisServer {
 
                     rdb_filename = Some(validated_filename);
                 }
+                "--bind" => {
+                    let Some(addresses) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_addresses = validate_bind_addresses(&addresses)?;
+
+                    bind_addresses = Some(validated_addresses);
+                }
+                "--tls-port" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value =
+                        validate_port_with_error(&value, CliError::InvalidTlsPortFlagValue)?;
+
+                    tls_port = Some(validated_value);
+                }
+                "--tls-cert-file" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    tls_cert_file = Some(value);
+                }
+                "--tls-key-file" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    tls_key_file = Some(value);
+                }
+                "--save" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value =
+                        parse_save_points(&value).ok_or(CliError::InvalidSaveFlagValue)?;
+
+                    // Like repeated `save` lines in redis.conf, every flag adds its points,
+                    // while an empty value clears the ones given so far
+                    let points = save_points.get_or_insert_with(Vec::new);
+
+                    if validated_value.is_empty() {
+                        points.clear();
+                    } else {
+                        points.extend(validated_value);
+                    }
+                }
+                "--appendonly" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = validate_appendonly_flag(&value)?;
+
+                    appendonly = Some(validated_value);
+                }
+                "--replica-read-only" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = match value.to_lowercase().as_str() {
+                        "yes" => true,
+                        "no" => false,
+                        _ => return Err(CliError::InvalidReplicaReadOnlyFlagValue),
+                    };
+
+                    replica_read_only = Some(validated_value);
+                }
+                "--appendfsync" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value =
+                        AppendFsync::parse(&value).ok_or(CliError::InvalidAppendFsyncFlagValue)?;
+
+                    appendfsync = Some(validated_value);
+                }
+                "--list-max-listpack-size" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = validate_list_max_listpack_size_flag(&value)?;
+
+                    list_max_listpack_size = Some(validated_value);
+                }
+                "--set-max-intset-entries" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    set_encoding_limits.max_intset_entries = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidSetMaxIntsetEntriesFlagValue)?;
+                }
+                "--set-max-listpack-entries" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    set_encoding_limits.max_listpack_entries = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidSetMaxListpackEntriesFlagValue)?;
+                }
+                "--set-max-listpack-value" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    set_encoding_limits.max_listpack_value = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidSetMaxListpackValueFlagValue)?;
+                }
+                "--zset-max-listpack-entries" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    zset_encoding_limits.max_listpack_entries = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidZsetMaxListpackEntriesFlagValue)?;
+                }
+                "--zset-max-listpack-value" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    zset_encoding_limits.max_listpack_value = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidZsetMaxListpackValueFlagValue)?;
+                }
+                "--hash-max-listpack-entries" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    hash_encoding_limits.max_listpack_entries = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidHashMaxListpackEntriesFlagValue)?;
+                }
+                "--hash-max-listpack-value" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    hash_encoding_limits.max_listpack_value = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidHashMaxListpackValueFlagValue)?;
+                }
+                "--stream-node-max-entries" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = value
+                        .parse::<usize>()
+                        .map_err(|_| CliError::InvalidStreamNodeMaxEntriesFlagValue)?;
+
+                    stream_node_max_entries = Some(validated_value);
+                }
+                "--slowlog-log-slower-than" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = value
+                        .parse::<i64>()
+                        .map_err(|_| CliError::InvalidSlowlogLogSlowerThanFlagValue)?;
+
+                    slowlog_log_slower_than = Some(validated_value);
+                }
+                "--latency-monitor-threshold" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = value
+                        .parse::<u64>()
+                        .map_err(|_| CliError::InvalidLatencyMonitorThresholdFlagValue)?;
+
+                    latency_monitor_threshold = Some(validated_value);
+                }
+                "--tcp-keepalive" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = value
+                        .parse::<u64>()
+                        .map_err(|_| CliError::InvalidTcpKeepaliveFlagValue)?;
+
+                    tcp_keepalive = Some(validated_value);
+                }
+                "--requirepass" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    // Like in redis.conf, an empty password turns authentication off
+                    requirepass = Some(value).filter(|password| !password.is_empty());
+                }
+                "--proto-max-bulk-len" => {
+                    let Some(value) = iter.next() else {
+                        return Err(CliError::InvalidCommandLineFlag);
+                    };
+
+                    let validated_value = value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|length| *length > 0)
+                        .ok_or(CliError::InvalidProtoMaxBulkLenFlagValue)?;
+
+                    proto_max_bulk_len = Some(validated_value);
+                }
                 _ => return Err(CliError::InvalidCommandLineFlag),
             }
         }
@@ -127,150 +693,775 @@ impl RedisServer {
             None
         };
 
+        // Loaded here so that a missing or invalid certificate stops the server at startup
+        let tls_config = match (tls_port, tls_cert_file, tls_key_file) {
+            (None, _, _) => None,
+            (Some(_), Some(cert_file), Some(key_file)) => {
+                Some(load_tls_config(&cert_file, &key_file)?)
+            }
+            _ => return Err(CliError::MissingTlsCertificateOrKey),
+        };
+
         Ok(RedisServer {
             port: port.unwrap_or(6379),
+            bind_addresses: bind_addresses.unwrap_or(vec![IpAddr::V4(Ipv4Addr::LOCALHOST)]),
+            tls_port,
+            tls_config,
             role,
-            repl_id: Alphanumeric.sample_string(&mut rand::rng(), 40),
+            repl_id: generate_replication_id(),
             repl_offset: 0,
             replicas,
-            write_commands: Vec::from(["SET", "RPUSH", "LPUSH", "INCR", "LPOP", "BLPOP", "XADD"]),
+            write_commands: Vec::from([
+                "SET",
+                "RPUSH",
+                "LPUSH",
+                "INCR",
+                "LPOP",
+                "LMPOP",
+                "BLPOP",
+                "XADD",
+                "XSETID",
+                "XDEL",
+                "XTRIM",
+                "HSET",
+                "HSETNX",
+                "ZADD",
+                "ZINCRBY",
+                "PFADD",
+                "PFMERGE",
+                "BITFIELD",
+                "GETSET",
+                "GETDEL",
+                "APPEND",
+                "SETRANGE",
+                "SADD",
+                "SMOVE",
+                "SINTERSTORE",
+                "SUNIONSTORE",
+                "SDIFFSTORE",
+                "ZPOPMIN",
+                "ZMPOP",
+                "ZPOPMAX",
+                "ZREM",
+                "ZREMRANGEBYRANK",
+                "ZREMRANGEBYSCORE",
+                "BZPOPMIN",
+                "BZPOPMAX",
+                "SORT",
+                "EXPIRE",
+                "PEXPIRE",
+                "PEXPIREAT",
+                "GEOADD",
+            ]),
             rdb_directory: directory_path.unwrap_or("./src".to_string()),
             rdb_filename: rdb_filename.unwrap_or("dump.rdb".to_string()),
             pub_sub_channels: HashMap::new(),
+            shard_pub_sub_channels: HashMap::new(),
+            resp3_clients: HashSet::new(),
+            active_expire_enabled: true,
+            clients: HashMap::new(),
+            next_client_id: 1,
+            last_save_time: Timestamp::now(),
+            is_background_save_in_progress: false,
+            save_points: save_points.unwrap_or_default(),
+            appendonly: appendonly.unwrap_or(false),
+            replica_read_only: replica_read_only.unwrap_or(true),
+            appendfsync: appendfsync.unwrap_or(AppendFsync::EverySec),
+            append_only_file: None,
+            is_aof_rewrite_in_progress: false,
+            is_last_aof_rewrite_ok: true,
+            list_max_listpack_size: list_max_listpack_size
+                .unwrap_or(DEFAULT_LIST_MAX_LISTPACK_SIZE),
+            set_encoding_limits,
+            zset_encoding_limits,
+            hash_encoding_limits,
+            stream_node_max_entries: stream_node_max_entries
+                .unwrap_or(DEFAULT_STREAM_NODE_MAX_ENTRIES),
+            stats: Arc::new(ServerStats::default()),
+            slowlog_log_slower_than: slowlog_log_slower_than
+                .unwrap_or(DEFAULT_SLOWLOG_LOG_SLOWER_THAN),
+            slowlog: SlowLog::default(),
+            latency_monitor_threshold: latency_monitor_threshold
+                .unwrap_or(DEFAULT_LATENCY_MONITOR_THRESHOLD),
+            latency_monitor: LatencyMonitor::new(),
+            tcp_keepalive: tcp_keepalive.unwrap_or(DEFAULT_TCP_KEEPALIVE),
+            key_access_times: HashMap::new(),
+            raw_string_keys: HashSet::new(),
+            tracking_table: HashMap::new(),
+            requirepass,
+            proto_max_bulk_len: proto_max_bulk_len.unwrap_or(DEFAULT_PROTO_MAX_BULK_LEN),
         })
     }
 
+    /// Returns the registry entry of a connection, registering it with a new id if needed
+    pub fn register_client(&mut self, client_address: &str) -> &mut Client {
+        let next_client_id = &mut self.next_client_id;
+
+        self.clients
+            .entry(client_address.to_string())
+            .or_insert_with(|| {
+                let id = *next_client_id;
+                *next_client_id += 1;
+
+                Client {
+                    id,
+                    address: client_address.to_string(),
+                    connected_at: Timestamp::now(),
+                    no_evict: false,
+                    no_touch: false,
+                    tracking: None,
+                    is_authenticated: false,
+                }
+            })
+    }
+
+    /// Checks a password for the default user, the only user there is. Without
+    /// `requirepass` the default user has no password and any password is accepted.
+    pub fn is_default_user_password(&self, password: &str) -> bool {
+        self.requirepass
+            .as_ref()
+            .is_none_or(|requirepass| requirepass == password)
+    }
+
+    /// Records that the key was just accessed, unless the connection turned on CLIENT NO-TOUCH.
+    /// A key seen for the first time starts out as just accessed either way.
+    pub fn touch_key(&mut self, client_address: &str, key: &str) {
+        if self.key_access_times.contains_key(key)
+            && self
+                .clients
+                .get(client_address)
+                .is_some_and(|client| client.no_touch)
+        {
+            return;
+        }
+
+        self.key_access_times
+            .insert(key.to_string(), Timestamp::now());
+    }
+
+    /// Seconds since the key was last accessed. Keys that were never accessed
+    /// since the server started count as just accessed.
+    pub fn key_idle_time(&self, key: &str) -> u64 {
+        self.key_access_times
+            .get(key)
+            .map(|accessed_at| {
+                (Timestamp::now() - *accessed_at)
+                    .total(jiff::Unit::Second)
+                    .unwrap_or(0.0) as u64
+            })
+            .unwrap_or(0)
+    }
+
+    /// Drops what the server remembers about a key that no longer exists
+    pub fn forget_key(&mut self, key: &str) {
+        self.raw_string_keys.remove(key);
+        self.key_access_times.remove(key);
+    }
+
+    /// Remembers that a connection with CLIENT TRACKING on read the keys
+    pub fn track_keys(&mut self, client_address: &str, keys: Vec<String>) {
+        if self
+            .clients
+            .get(client_address)
+            .is_none_or(|client| client.tracking.is_none())
+        {
+            return;
+        }
+
+        for key in keys {
+            self.tracking_table
+                .entry(key)
+                .or_default()
+                .insert(client_address.to_string());
+        }
+    }
+
+    /// Sends an `invalidate` message listing the modified keys to every connection tracking
+    /// them. The keys stop being tracked until the connection reads them again.
+    pub fn invalidate_keys(&mut self, keys: Vec<String>) {
+        let mut invalidated_keys: HashMap<String, Vec<String>> = HashMap::new();
+
+        for key in keys {
+            let Some(client_addresses) = self.tracking_table.remove(&key) else {
+                continue;
+            };
+
+            for client_address in client_addresses {
+                invalidated_keys
+                    .entry(client_address)
+                    .or_default()
+                    .push(key.clone());
+            }
+        }
+
+        for (client_address, keys) in invalidated_keys {
+            let Some(target) = self
+                .clients
+                .get(&client_address)
+                .and_then(|client| client.tracking.as_ref())
+            else {
+                continue;
+            };
+
+            let keys = RespValue::Array(keys.into_iter().map(RespValue::BulkString).collect());
+
+            match target {
+                InvalidationTarget::Connection(sender) => {
+                    if self.resp3_clients.contains(&client_address) {
+                        let message = RespValue::Push(vec![
+                            RespValue::BulkString("invalidate".to_string()),
+                            keys,
+                        ]);
+                        let _ = sender.send(message.encode().into_bytes());
+                    }
+                }
+                InvalidationTarget::Redirect(redirect_address) => {
+                    let Some(sender) = self
+                        .pub_sub_channels
+                        .get(INVALIDATION_CHANNEL)
+                        .and_then(|subscribers| subscribers.get(redirect_address))
+                    else {
+                        continue;
+                    };
+
+                    let elements = vec![
+                        RespValue::BulkString("message".to_string()),
+                        RespValue::BulkString(INVALIDATION_CHANNEL.to_string()),
+                        keys,
+                    ];
+                    let message = match self.resp3_clients.contains(redirect_address) {
+                        true => RespValue::Push(elements),
+                        false => RespValue::Array(elements),
+                    };
+                    let _ = sender.send(message.encode().into_bytes());
+                }
+            }
+        }
+    }
+
+    /// Turns CLIENT TRACKING off for the connection and forgets the keys it read
+    pub fn stop_tracking(&mut self, client_address: &str) {
+        if let Some(client) = self.clients.get_mut(client_address) {
+            client.tracking = None;
+        }
+
+        for client_addresses in self.tracking_table.values_mut() {
+            client_addresses.remove(client_address);
+        }
+        self.tracking_table
+            .retain(|_, client_addresses| !client_addresses.is_empty());
+    }
+
+    /// Removes the client from every channel and shard channel, dropping channels left without subscribers
+    pub fn unsubscribe_client_from_all_channels(&mut self, client_address: &str) {
+        for channels in [&mut self.pub_sub_channels, &mut self.shard_pub_sub_channels] {
+            for subscribers in channels.values_mut() {
+                subscribers.remove(client_address);
+            }
+            channels.retain(|_, subscribers| !subscribers.is_empty());
+        }
+    }
+
     pub async fn update_replication_offset(&mut self, input: RespValue) {
         self.repl_offset += input.encode().as_bytes().len();
     }
 
-    pub async fn should_replicate_write_command(
-        &self,
-        input: RespValue,
-        command_name: &str,
-    ) -> tokio::io::Result<()> {
-        if !self.write_commands.contains(&command_name) {
-            return Ok(());
+    pub fn should_replicate_write_command(&self, input: RespValue, command_name: &str) {
+        if !self.write_commands.contains(&command_name) {
+            return;
+        }
+
+        if let Some(ref replicas) = self.replicas {
+            let bytes = input.encode().into_bytes();
+
+            for replica in replicas.values() {
+                // A closed channel means the replica connection is gone and will be cleaned up
+                let _ = replica.sender.send(bytes.clone());
+            }
+        }
+    }
+
+    /// Propagates the write commands of a transaction wrapped in MULTI/EXEC so that
+    /// replicas apply them atomically
+    pub fn replicate_transaction(&self, inputs: Vec<RespValue>) {
+        if inputs.is_empty() {
+            return;
+        }
+
+        if let Some(ref replicas) = self.replicas {
+            let mut transaction =
+                RespValue::Array(vec![RespValue::BulkString("MULTI".to_string())]).encode();
+
+            for input in inputs {
+                transaction.push_str(&input.encode());
+            }
+
+            transaction.push_str(
+                &RespValue::Array(vec![RespValue::BulkString("EXEC".to_string())]).encode(),
+            );
+
+            let bytes = transaction.into_bytes();
+
+            for replica in replicas.values() {
+                // A closed channel means the replica connection is gone and will be cleaned up
+                let _ = replica.sender.send(bytes.clone());
+            }
+        }
+    }
+
+    /// Lock that a write command holds from before it runs until it is appended, see
+    /// `RewriteGuard`. There is none for other commands or when AOF is disabled.
+    pub fn aof_rewrite_lock(&self, command_name: &str) -> Option<Arc<RwLock<()>>> {
+        if !self.write_commands.contains(&command_name) {
+            return None;
+        }
+
+        self.append_only_file
+            .as_ref()
+            .map(|append_only_file| append_only_file.rewrite_lock())
+    }
+
+    /// Logs a write command to the append-only file when AOF is enabled. Every logged write
+    /// also counts as a change towards the save points.
+    pub async fn should_append_write_command(&self, input: RespValue, command_name: &str) {
+        if !self.write_commands.contains(&command_name) {
+            return;
+        }
+
+        self.stats.record_change();
+
+        if let Some(ref append_only_file) = self.append_only_file
+            && let Err(e) = append_only_file.append(input).await
+        {
+            eprintln!("Failed to write to append-only file: {}", e);
+        }
+    }
+
+    pub async fn run(&self) {
+        let store = Arc::new(Mutex::new(HashMap::new()));
+        let state = Arc::new(Mutex::new(State::new()));
+        let server = Arc::new(RwLock::new(self.clone()));
+
+        // The append-only file is preferred over the RDB file since it holds the latest writes
+        let is_aof_loaded = if self.appendonly {
+            match load_append_only_file(Arc::clone(&server), Arc::clone(&store), Arc::clone(&state))
+                .await
+            {
+                Ok(()) => true,
+                Err(e) if e.kind() == tokio::io::ErrorKind::NotFound => false,
+                Err(e) => {
+                    eprintln!("Failed to load append-only file: {}", e);
+                    return;
+                }
+            }
+        } else {
+            false
+        };
+
+        let is_rdb_loaded = if is_aof_loaded {
+            false
+        } else {
+            match parse_rdb_file(Arc::clone(&server), Arc::clone(&store)).await {
+                Ok(()) => true,
+                Err(e) if e.kind() == tokio::io::ErrorKind::NotFound => {
+                    eprintln!("RDB file not found, proceeding without it");
+                    false
+                }
+                Err(e) => {
+                    eprintln!("Failed to parse RDB file: {}", e);
+                    return;
+                }
+            }
+        };
+
+        // Opened after loading so that replayed commands are not appended again
+        if self.appendonly {
+            // The next restart prefers the append-only file, so it must hold the RDB dataset too
+            if is_rdb_loaded
+                && let Err(e) =
+                    rewrite_append_only_file(Arc::clone(&server), Arc::clone(&store)).await
+            {
+                eprintln!("Failed to write append-only file from RDB file: {}", e);
+                return;
+            }
+
+            let file_path = Path::new(&self.rdb_directory).join(AOF_FILENAME);
+
+            match AppendOnlyFile::open(&file_path, self.appendfsync).await {
+                Ok(append_only_file) => {
+                    let mut server_guard = server.write().await;
+                    server_guard.append_only_file = Some(append_only_file);
+                }
+                Err(e) => {
+                    eprintln!("Failed to open append-only file: {}", e);
+                    return;
+                }
+            }
+        }
+
+        let server_clone = Arc::clone(&server);
+        let store_clone = Arc::clone(&store);
+
+        tokio::spawn(async move {
+            active_expire_keys(server_clone, store_clone).await;
+        });
+
+        let server_clone = Arc::clone(&server);
+        let store_clone = Arc::clone(&store);
+
+        tokio::spawn(async move {
+            save_on_save_points(server_clone, store_clone).await;
+        });
+
+        match &self.role {
+            RedisRole::Replica((address, port)) => {
+                let master_address = format!("{}:{}", address, port);
+
+                let mut stream = match TcpStream::connect(&master_address).await {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        eprintln!("Failed to connect to replica: {}", e);
+                        return;
+                    }
+                };
+
+                let server_clone = Arc::clone(&server);
+                let store_clone = Arc::clone(&store);
+                let state_clone = Arc::clone(&state);
+
+                if let Err(e) =
+                    handshake(&mut stream, Arc::clone(&server), Arc::clone(&store)).await
+                {
+                    eprintln!("Failed to perform handshake: {}", e);
+                    return;
+                };
+
+                tokio::spawn(async move {
+                    handle_master_to_replica_connection(
+                        &master_address,
+                        &mut stream,
+                        server_clone,
+                        store_clone,
+                        state_clone,
+                    )
+                    .await;
+                });
+            }
+            _ => (),
+        }
+
+        let mut listeners = Vec::with_capacity(self.bind_addresses.len());
+
+        for address in &self.bind_addresses {
+            let socket_address = SocketAddr::new(*address, self.port as u16);
+
+            match TcpListener::bind(socket_address).await {
+                Ok(listener) => listeners.push(listener),
+                Err(e) => {
+                    eprintln!("Failed to bind TCP listener on {}: {}", socket_address, e);
+                    return;
+                }
+            }
+        }
+
+        let mut listeners = listeners
+            .into_iter()
+            .map(|listener| (listener, None))
+            .collect::<Vec<(TcpListener, Option<TlsAcceptor>)>>();
+
+        // The TLS listeners run next to the plaintext ones, on the same addresses
+        if let (Some(tls_port), Some(tls_config)) = (self.tls_port, &self.tls_config) {
+            let tls_acceptor = TlsAcceptor::from(Arc::clone(tls_config));
+
+            for address in &self.bind_addresses {
+                let socket_address = SocketAddr::new(*address, tls_port as u16);
+
+                match TcpListener::bind(socket_address).await {
+                    Ok(listener) => listeners.push((listener, Some(tls_acceptor.clone()))),
+                    Err(e) => {
+                        eprintln!("Failed to bind TLS listener on {}: {}", socket_address, e);
+                        return;
+                    }
+                }
+            }
+        }
+
+        let mut accept_tasks = Vec::with_capacity(listeners.len());
+
+        for (listener, tls_acceptor) in listeners {
+            let server_clone = Arc::clone(&server);
+            let store_clone = Arc::clone(&store);
+            let state_clone = Arc::clone(&state);
+
+            accept_tasks.push(tokio::spawn(async move {
+                accept_connections(
+                    listener,
+                    tls_acceptor,
+                    server_clone,
+                    store_clone,
+                    state_clone,
+                )
+                .await;
+            }));
+        }
+
+        for accept_task in accept_tasks {
+            let _ = accept_task.await;
+        }
+    }
+}
+
+/// Accepts client connections on a listener, completing the TLS handshake first when
+/// the listener has a `tls_acceptor`
+async fn accept_connections(
+    listener: TcpListener,
+    tls_acceptor: Option<TlsAcceptor>,
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, client_address)) => {
+                let tcp_keepalive = {
+                    let server_guard = server.read().await;
+                    server_guard.stats.record_connection();
+                    server_guard.tcp_keepalive
+                };
+
+                if let Err(e) = configure_client_socket(&stream, tcp_keepalive) {
+                    eprintln!(
+                        "Failed to configure client socket {}: {}",
+                        client_address, e
+                    );
+                }
+
+                let server_clone = Arc::clone(&server);
+                let store_clone = Arc::clone(&store);
+                let state_clone = Arc::clone(&state);
+                let tls_acceptor = tls_acceptor.clone();
+
+                tokio::spawn(async move {
+                    let client_address = client_address.to_string();
+
+                    match tls_acceptor {
+                        Some(tls_acceptor) => match tls_acceptor.accept(stream).await {
+                            Ok(tls_stream) => {
+                                handle_client_connection(
+                                    tls_stream,
+                                    server_clone,
+                                    client_address,
+                                    store_clone,
+                                    state_clone,
+                                )
+                                .await
+                            }
+                            Err(e) => {
+                                eprintln!("TLS handshake with {} failed: {}", client_address, e);
+                            }
+                        },
+                        None => {
+                            handle_client_connection(
+                                stream,
+                                server_clone,
+                                client_address,
+                                store_clone,
+                                state_clone,
+                            )
+                            .await
+                        }
+                    }
+                });
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+async fn handle_client_connection<S>(
+    stream: S,
+    server: Arc<RwLock<RedisServer>>,
+    client_address: String,
+    store: Arc<Mutex<KeyValueStore>>,
+    state: Arc<Mutex<State>>,
+) where
+    S: AsyncRead + AsyncWrite + Send + Sync + 'static,
+{
+    let role = {
+        let server_guard = server.read().await;
+        server_guard.role.clone()
+    };
+
+    match role {
+        RedisRole::Master => {
+            handle_master_to_client_connection(stream, server, client_address, store, state).await
+        }
+        RedisRole::Replica(_) => {
+            handle_replica_to_client_connection(stream, server, client_address, store, state).await
+        }
+    }
+}
+
+/// Disables Nagle's algorithm so that replies are sent right away and, unless
+/// `tcp_keepalive` is 0, sends keepalive probes after that many idle seconds so
+/// that dead peers are eventually detected
+pub fn configure_client_socket(stream: &TcpStream, tcp_keepalive: u64) -> std::io::Result<()> {
+    stream.set_nodelay(true)?;
+
+    if tcp_keepalive > 0 {
+        let keepalive = TcpKeepalive::new().with_time(Duration::from_secs(tcp_keepalive));
+        SockRef::from(stream).set_tcp_keepalive(&keepalive)?;
+    }
+
+    Ok(())
+}
+
+async fn active_expire_keys(server: Arc<RwLock<RedisServer>>, store: Arc<Mutex<KeyValueStore>>) {
+    let mut interval = tokio::time::interval(ACTIVE_EXPIRE_CYCLE_INTERVAL);
+    // Keys with an expiration that the current pass over the keyspace has not checked yet
+    let mut unchecked_keys: Vec<String> = Vec::new();
+
+    loop {
+        interval.tick().await;
+
+        let (active_expire_enabled, latency_monitor_threshold) = {
+            let server_guard = server.read().await;
+            (
+                server_guard.active_expire_enabled,
+                server_guard.latency_monitor_threshold,
+            )
+        };
+
+        if !active_expire_enabled {
+            continue;
         }
 
-        if let Some(ref replicas) = self.replicas {
-            for replica in replicas.values() {
-                let mut replica_writer_guard = replica.writer.write().await;
-                replica_writer_guard
-                    .write_all(input.encode().as_bytes())
-                    .await?;
-                replica_writer_guard.flush().await?;
-            }
+        let started_at = Instant::now();
+
+        active_expire_cycle(Arc::clone(&server), Arc::clone(&store), &mut unchecked_keys).await;
+
+        let latency = started_at.elapsed();
+
+        if LatencyMonitor::is_spike(latency, latency_monitor_threshold) {
+            let mut server_guard = server.write().await;
+            server_guard.latency_monitor.record("expire-cycle", latency);
         }
+    }
+}
 
-        Ok(())
+/// Removes expired keys in batches of `ACTIVE_EXPIRE_CYCLE_KEYS_PER_LOOP`, taking the store
+/// lock for one batch at a time so clients are served in between. Like Redis, the cycle
+/// stops once a batch is mostly made of live keys, and leaves the rest of the pass to the
+/// next cycles. A new pass starts from the keys that have an expiration at that moment.
+async fn active_expire_cycle(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+    unchecked_keys: &mut Vec<String>,
+) {
+    if unchecked_keys.is_empty() {
+        *unchecked_keys = store
+            .lock()
+            .await
+            .iter()
+            .filter(|(_, value)| value.expiration.is_some())
+            .map(|(key, _)| key.clone())
+            .collect();
     }
 
-    pub async fn run(&self) {
-        let store = Arc::new(Mutex::new(HashMap::new()));
-        let state = Arc::new(Mutex::new(State::new()));
-        let server = Arc::new(RwLock::new(self.clone()));
+    let stats = Arc::clone(&server.read().await.stats);
 
-        if let Err(e) = parse_rdb_file(Arc::clone(&server), Arc::clone(&store)).await {
-            if e.kind() == tokio::io::ErrorKind::NotFound {
-                eprintln!("RDB file not found, proceeding without it");
-            } else {
-                eprintln!("Failed to parse RDB file: {}", e);
-                return;
-            }
-        }
+    while !unchecked_keys.is_empty() {
+        let batch = unchecked_keys.split_off(
+            unchecked_keys
+                .len()
+                .saturating_sub(ACTIVE_EXPIRE_CYCLE_KEYS_PER_LOOP),
+        );
 
-        match &self.role {
-            RedisRole::Replica((address, port)) => {
-                let master_address = format!("{}:{}", address, port);
+        let batch_size = batch.len();
 
-                let mut stream = match TcpStream::connect(&master_address).await {
-                    Ok(stream) => stream,
-                    Err(e) => {
-                        eprintln!("Failed to connect to replica: {}", e);
-                        return;
-                    }
-                };
+        let expired_keys: Vec<String> = {
+            let mut store_guard = store.lock().await;
+            let expired_keys: Vec<String> = batch
+                .into_iter()
+                .filter(|key| store_guard.get(key).is_some_and(is_value_expired))
+                .collect();
 
-                let server_clone = Arc::clone(&server);
-                let store_clone = Arc::clone(&store);
-                let state_clone = Arc::clone(&state);
+            for key in &expired_keys {
+                store_guard.remove(key);
+            }
 
-                if let Err(e) =
-                    handshake(&mut stream, Arc::clone(&server), Arc::clone(&store)).await
-                {
-                    eprintln!("Failed to perform handshake: {}", e);
-                    return;
-                };
+            expired_keys
+        };
 
-                tokio::spawn(async move {
-                    handle_master_to_replica_connection(
-                        &master_address,
-                        &mut stream,
-                        server_clone,
-                        store_clone,
-                        state_clone,
-                    )
-                    .await;
-                });
+        stats.record_expired_keys(expired_keys.len());
+
+        if !expired_keys.is_empty() {
+            let mut server_guard = server.write().await;
+            for key in &expired_keys {
+                server_guard.forget_key(key);
             }
-            _ => (),
         }
 
-        let listener = match TcpListener::bind(format!("127.0.0.1:{}", self.port)).await {
-            Ok(listener) => listener,
-            Err(e) => {
-                eprintln!("Failed to bind TCP listener: {}", e);
-                return;
-            }
+        if expired_keys.len() * 100 <= batch_size * ACTIVE_EXPIRE_CYCLE_ACCEPTABLE_STALE_PERCENT {
+            break;
+        }
+    }
+}
+
+/// Starts a background save whenever one of the configured save points is reached
+pub async fn save_on_save_points(
+    server: Arc<RwLock<RedisServer>>,
+    store: Arc<Mutex<KeyValueStore>>,
+) {
+    let mut interval = tokio::time::interval(SAVE_POINTS_CHECK_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let is_save_due = {
+            let server_guard = server.read().await;
+            let changes_since_last_save = server_guard.stats.changes_since_last_save();
+            let seconds_since_last_save = (Timestamp::now() - server_guard.last_save_time)
+                .total(jiff::Unit::Second)
+                .unwrap_or(0.0) as u64;
+
+            !server_guard.is_background_save_in_progress
+                && server_guard.save_points.iter().any(|save_point| {
+                    save_point.is_due(seconds_since_last_save, changes_since_last_save)
+                })
         };
 
-        loop {
-            match listener.accept().await {
-                Ok((stream, client_address)) => {
-                    let server_clone = Arc::clone(&server);
-                    let store_clone = Arc::clone(&store);
-                    let state_clone = Arc::clone(&state);
-
-                    tokio::spawn(async move {
-                        let role = {
-                            let server_guard = server_clone.read().await;
-                            server_guard.role.clone()
-                        };
-
-                        match role {
-                            RedisRole::Master => {
-                                handle_master_to_client_connection(
-                                    stream,
-                                    server_clone,
-                                    client_address.to_string(),
-                                    store_clone,
-                                    state_clone,
-                                )
-                                .await
-                            }
-                            RedisRole::Replica(_) => {
-                                handle_replica_to_client_connection(
-                                    stream,
-                                    server_clone,
-                                    client_address.to_string(),
-                                    store_clone,
-                                    state_clone,
-                                )
-                                .await;
-                            }
-                        }
-                    });
-                }
-                Err(e) => {
-                    eprintln!("error: {}", e);
-                    break;
-                }
-            }
+        if is_save_due {
+            // A BGSAVE started in the meantime already covers these changes
+            let _ = start_background_save(Arc::clone(&server), Arc::clone(&store)).await;
         }
     }
 }
 
+/// Generates a random 40 character replication ID
+pub fn generate_replication_id() -> String {
+    Alphanumeric.sample_string(&mut rand::rng(), 40)
+}
+
+fn validate_list_max_listpack_size_flag(value: &str) -> Result<i64, CliError> {
+    value
+        .parse::<i64>()
+        .ok()
+        .filter(|size| is_valid_max_listpack_size(*size))
+        .ok_or(CliError::InvalidListMaxListpackSizeFlagValue)
+}
+
+fn validate_appendonly_flag(value: &str) -> Result<bool, CliError> {
+    match value.to_lowercase().as_str() {
+        "yes" => Ok(true),
+        "no" => Ok(false),
+        _ => Err(CliError::InvalidAppendOnlyFlagValue),
+    }
+}
+
 fn validate_port_flag(port: &str) -> Result<u32, CliError> {
     validate_port_with_error(port, CliError::InvalidPortFlagValue)
 }
@@ -320,6 +1511,23 @@ fn validate_master_address(master_address: &str) -> Result<(String, u32), CliErr
     Ok((address.to_string(), port_number))
 }
 
+fn validate_bind_addresses(addresses: &str) -> Result<Vec<IpAddr>, CliError> {
+    let addresses = addresses
+        .split_whitespace()
+        .map(|address| {
+            address
+                .parse::<IpAddr>()
+                .map_err(|_| CliError::InvalidBindAddress)
+        })
+        .collect::<Result<Vec<IpAddr>, CliError>>()?;
+
+    if addresses.is_empty() {
+        return Err(CliError::InvalidBindAddress);
+    }
+
+    Ok(addresses)
+}
+
 fn validate_directory_path(dir: String) -> Result<String, CliError> {
     let regex = Regex::new(r"^(\/|\.{1,2}|(\.?\.?\/)?[a-zA-Z0-9-_]+(\/[a-zA-Z0-9-_]+)*)$").unwrap();
     if regex.is_match(&dir) {
@@ -340,7 +1548,12 @@ fn validate_rdb_file_name(file_name: String) -> Result<String, CliError> {
 
 #[cfg(test)]
 mod tests {
+    use std::net::Ipv6Addr;
+
+    use jiff::SignedDuration;
+
     use super::*;
+    use crate::key_value_store::{DataType, Value};
 
     #[test]
     fn test_validate_port_flag() {
@@ -513,138 +1726,735 @@ mod tests {
     }
 
     #[test]
-    fn test_redis_server_creation_with_invalid_flags() {
+    fn test_redis_server_creation_with_invalid_flags() {
+        let test_cases = vec![
+            (
+                vec!["codecrafters-redis".to_string(), "--port".to_string()],
+                CliError::InvalidCommandLineFlag,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--port".to_string(),
+                    "invalid".to_string(),
+                ],
+                CliError::InvalidPortFlagValue,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--port".to_string(),
+                    "70000".to_string(),
+                ],
+                CliError::InvalidPortFlagValue,
+            ),
+            (
+                vec!["codecrafters-redis".to_string(), "invalid".to_string()],
+                CliError::InvalidCommandLineFlag,
+            ),
+            (
+                vec!["codecrafters-redis".to_string(), "--replicaof".to_string()],
+                CliError::InvalidCommandLineFlag,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--replicaof".to_string(),
+                    "invalid".to_string(),
+                ],
+                CliError::InvalidMasterAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--replicaof".to_string(),
+                    "127.0.0.1 invalid".to_string(),
+                ],
+                CliError::InvalidMasterPort,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--replicaof".to_string(),
+                    "127.0.0.1".to_string(),
+                ],
+                CliError::InvalidMasterAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--replicaof".to_string(),
+                    "256.0.0.1 6379".to_string(),
+                ],
+                CliError::InvalidMasterAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--replicaof".to_string(),
+                    "my_host! 6379".to_string(),
+                ],
+                CliError::InvalidMasterAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--replicaof".to_string(),
+                    "127.0.0.1 70000".to_string(),
+                ],
+                CliError::InvalidMasterPort,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--dir".to_string(),
+                    "//tmp/redis-files".to_string(),
+                ],
+                CliError::InvalidRdbDirectoryPath,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--dir".to_string(),
+                    "/tmp.setup/redis".to_string(),
+                ],
+                CliError::InvalidRdbDirectoryPath,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--dir".to_string(),
+                    "/tmp/redis/".to_string(),
+                ],
+                CliError::InvalidRdbDirectoryPath,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--dbfilename".to_string(),
+                    "dump".to_string(),
+                ],
+                CliError::InvalidRdbFileName,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--dbfilename".to_string(),
+                    "dump.pdf".to_string(),
+                ],
+                CliError::InvalidRdbFileName,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--dbfilename".to_string(),
+                    "dump.development.rdb".to_string(),
+                ],
+                CliError::InvalidRdbFileName,
+            ),
+            (
+                vec!["codecrafters-redis".to_string(), "--bind".to_string()],
+                CliError::InvalidCommandLineFlag,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--bind".to_string(),
+                    "localhost".to_string(),
+                ],
+                CliError::InvalidBindAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--bind".to_string(),
+                    "127.0.0.1 ::g".to_string(),
+                ],
+                CliError::InvalidBindAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--bind".to_string(),
+                    " ".to_string(),
+                ],
+                CliError::InvalidBindAddress,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--appendonly".to_string(),
+                    "maybe".to_string(),
+                ],
+                CliError::InvalidAppendOnlyFlagValue,
+            ),
+            (
+                vec![
+                    "codecrafters-redis".to_string(),
+                    "--appendfsync".to_string(),
+                    "sometimes".to_string(),
+                ],
+                CliError::InvalidAppendFsyncFlagValue,
+            ),
+        ];
+
+        for (args, expected_error) in test_cases {
+            let result = RedisServer::new(args);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), expected_error);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_bind_addresses() {
         let test_cases = vec![
             (
-                vec!["codecrafters-redis".to_string(), "--port".to_string()],
-                CliError::InvalidCommandLineFlag,
+                vec!["codecrafters-redis".to_string()],
+                vec![IpAddr::V4(Ipv4Addr::LOCALHOST)],
             ),
             (
                 vec![
                     "codecrafters-redis".to_string(),
-                    "--port".to_string(),
-                    "invalid".to_string(),
+                    "--bind".to_string(),
+                    "::1".to_string(),
                 ],
-                CliError::InvalidPortFlagValue,
+                vec![IpAddr::V6(Ipv6Addr::LOCALHOST)],
             ),
             (
                 vec![
                     "codecrafters-redis".to_string(),
-                    "--port".to_string(),
-                    "70000".to_string(),
+                    "--bind".to_string(),
+                    "127.0.0.1 ::1 0.0.0.0".to_string(),
+                ],
+                vec![
+                    IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    IpAddr::V6(Ipv6Addr::LOCALHOST),
+                    IpAddr::V4(Ipv4Addr::UNSPECIFIED),
                 ],
-                CliError::InvalidPortFlagValue,
             ),
+        ];
+
+        for (args, expected_addresses) in test_cases {
+            let server = RedisServer::new(args).unwrap();
+            assert_eq!(server.bind_addresses, expected_addresses);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_tls_flags() {
+        let tls_directory = "/tmp/redis-files-tls-flags";
+        let certified_key =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let other_key_pair = rcgen::KeyPair::generate().unwrap();
+
+        std::fs::create_dir_all(tls_directory).unwrap();
+        std::fs::write(
+            format!("{}/redis.crt", tls_directory),
+            certified_key.cert.pem(),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("{}/redis.key", tls_directory),
+            certified_key.key_pair.serialize_pem(),
+        )
+        .unwrap();
+        std::fs::write(
+            format!("{}/other.key", tls_directory),
+            other_key_pair.serialize_pem(),
+        )
+        .unwrap();
+        std::fs::write(format!("{}/invalid.pem", tls_directory), "not a pem file").unwrap();
+
+        let test_cases = vec![
+            (vec![], Ok((None, false))),
             (
-                vec!["codecrafters-redis".to_string(), "invalid".to_string()],
-                CliError::InvalidCommandLineFlag,
+                vec![
+                    "--tls-port",
+                    "6380",
+                    "--tls-cert-file",
+                    "redis.crt",
+                    "--tls-key-file",
+                    "redis.key",
+                ],
+                Ok((Some(6380), true)),
             ),
             (
-                vec!["codecrafters-redis".to_string(), "--replicaof".to_string()],
-                CliError::InvalidCommandLineFlag,
+                vec!["--tls-port", "0"],
+                Err(CliError::InvalidTlsPortFlagValue),
             ),
             (
-                vec![
-                    "codecrafters-redis".to_string(),
-                    "--replicaof".to_string(),
-                    "invalid".to_string(),
-                ],
-                CliError::InvalidMasterAddress,
+                vec!["--tls-port", "6380", "--tls-cert-file", "redis.crt"],
+                Err(CliError::MissingTlsCertificateOrKey),
             ),
             (
-                vec![
-                    "codecrafters-redis".to_string(),
-                    "--replicaof".to_string(),
-                    "127.0.0.1 invalid".to_string(),
-                ],
-                CliError::InvalidMasterPort,
+                vec!["--tls-port", "6380", "--tls-key-file", "redis.key"],
+                Err(CliError::MissingTlsCertificateOrKey),
             ),
             (
                 vec![
-                    "codecrafters-redis".to_string(),
-                    "--replicaof".to_string(),
-                    "127.0.0.1".to_string(),
+                    "--tls-port",
+                    "6380",
+                    "--tls-cert-file",
+                    "missing.crt",
+                    "--tls-key-file",
+                    "redis.key",
                 ],
-                CliError::InvalidMasterAddress,
+                Err(CliError::InvalidTlsCertFileFlagValue),
             ),
             (
                 vec![
-                    "codecrafters-redis".to_string(),
-                    "--replicaof".to_string(),
-                    "256.0.0.1 6379".to_string(),
+                    "--tls-port",
+                    "6380",
+                    "--tls-cert-file",
+                    "invalid.pem",
+                    "--tls-key-file",
+                    "redis.key",
                 ],
-                CliError::InvalidMasterAddress,
+                Err(CliError::InvalidTlsCertFileFlagValue),
             ),
             (
                 vec![
-                    "codecrafters-redis".to_string(),
-                    "--replicaof".to_string(),
-                    "my_host! 6379".to_string(),
+                    "--tls-port",
+                    "6380",
+                    "--tls-cert-file",
+                    "redis.crt",
+                    "--tls-key-file",
+                    "invalid.pem",
                 ],
-                CliError::InvalidMasterAddress,
+                Err(CliError::InvalidTlsKeyFileFlagValue),
             ),
             (
                 vec![
-                    "codecrafters-redis".to_string(),
-                    "--replicaof".to_string(),
-                    "127.0.0.1 70000".to_string(),
+                    "--tls-port",
+                    "6380",
+                    "--tls-cert-file",
+                    "redis.crt",
+                    "--tls-key-file",
+                    "other.key",
                 ],
-                CliError::InvalidMasterPort,
+                Err(CliError::InvalidTlsCertificateOrKey),
             ),
             (
-                vec![
-                    "codecrafters-redis".to_string(),
-                    "--dir".to_string(),
-                    "//tmp/redis-files".to_string(),
-                ],
-                CliError::InvalidRdbDirectoryPath,
+                vec!["--tls-cert-file"],
+                Err(CliError::InvalidCommandLineFlag),
             ),
+        ];
+
+        for (flags, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+            let mut flags = flags.into_iter();
+
+            while let Some(flag) = flags.next() {
+                args.push(flag.to_string());
+
+                if let Some(value) = flags.next() {
+                    // The certificate and key files are looked up in the test directory
+                    if flag == "--tls-cert-file" || flag == "--tls-key-file" {
+                        args.push(format!("{}/{}", tls_directory, value));
+                    } else {
+                        args.push(value.to_string());
+                    }
+                }
+            }
+
+            let result =
+                RedisServer::new(args).map(|server| (server.tls_port, server.tls_config.is_some()));
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_append_only_flags() {
+        let test_cases = vec![
             (
-                vec![
-                    "codecrafters-redis".to_string(),
-                    "--dir".to_string(),
-                    "/tmp.setup/redis".to_string(),
-                ],
-                CliError::InvalidRdbDirectoryPath,
+                vec!["codecrafters-redis".to_string()],
+                false,
+                AppendFsync::EverySec,
             ),
             (
                 vec![
                     "codecrafters-redis".to_string(),
-                    "--dir".to_string(),
-                    "/tmp/redis/".to_string(),
+                    "--appendonly".to_string(),
+                    "yes".to_string(),
                 ],
-                CliError::InvalidRdbDirectoryPath,
+                true,
+                AppendFsync::EverySec,
             ),
             (
                 vec![
                     "codecrafters-redis".to_string(),
-                    "--dbfilename".to_string(),
-                    "dump".to_string(),
+                    "--appendonly".to_string(),
+                    "yes".to_string(),
+                    "--appendfsync".to_string(),
+                    "always".to_string(),
                 ],
-                CliError::InvalidRdbFileName,
+                true,
+                AppendFsync::Always,
             ),
             (
                 vec![
                     "codecrafters-redis".to_string(),
-                    "--dbfilename".to_string(),
-                    "dump.pdf".to_string(),
+                    "--appendonly".to_string(),
+                    "no".to_string(),
+                    "--appendfsync".to_string(),
+                    "no".to_string(),
                 ],
-                CliError::InvalidRdbFileName,
+                false,
+                AppendFsync::No,
+            ),
+        ];
+
+        for (args, expected_appendonly, expected_appendfsync) in test_cases {
+            let server = RedisServer::new(args).unwrap();
+            assert_eq!(server.appendonly, expected_appendonly);
+            assert_eq!(server.appendfsync, expected_appendfsync);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_list_max_listpack_size_flag() {
+        let test_cases = vec![
+            (None, Ok(DEFAULT_LIST_MAX_LISTPACK_SIZE)),
+            (Some("128"), Ok(128)),
+            (Some("-5"), Ok(-5)),
+            (
+                Some("0"),
+                Err(CliError::InvalidListMaxListpackSizeFlagValue),
             ),
             (
-                vec![
-                    "codecrafters-redis".to_string(),
-                    "--dbfilename".to_string(),
-                    "dump.development.rdb".to_string(),
-                ],
-                CliError::InvalidRdbFileName,
+                Some("-6"),
+                Err(CliError::InvalidListMaxListpackSizeFlagValue),
+            ),
+            (
+                Some("many"),
+                Err(CliError::InvalidListMaxListpackSizeFlagValue),
             ),
         ];
 
-        for (args, expected_error) in test_cases {
-            let result = RedisServer::new(args);
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err(), expected_error);
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--list-max-listpack-size".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.list_max_listpack_size);
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_set_encoding_flags() {
+        let server = RedisServer::new(vec!["codecrafters-redis".to_string()]).unwrap();
+        assert_eq!(server.set_encoding_limits, SetEncodingLimits::default());
+
+        let server = RedisServer::new(vec![
+            "codecrafters-redis".to_string(),
+            "--set-max-intset-entries".to_string(),
+            "3".to_string(),
+            "--set-max-listpack-entries".to_string(),
+            "2".to_string(),
+            "--set-max-listpack-value".to_string(),
+            "5".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(
+            server.set_encoding_limits,
+            SetEncodingLimits {
+                max_intset_entries: 3,
+                max_listpack_entries: 2,
+                max_listpack_value: 5,
+            }
+        );
+
+        let test_cases = vec![
+            (
+                "--set-max-intset-entries",
+                CliError::InvalidSetMaxIntsetEntriesFlagValue,
+            ),
+            (
+                "--set-max-listpack-entries",
+                CliError::InvalidSetMaxListpackEntriesFlagValue,
+            ),
+            (
+                "--set-max-listpack-value",
+                CliError::InvalidSetMaxListpackValueFlagValue,
+            ),
+        ];
+
+        for (flag, expected_error) in test_cases {
+            let args = vec![
+                "codecrafters-redis".to_string(),
+                flag.to_string(),
+                "-1".to_string(),
+            ];
+
+            assert_eq!(RedisServer::new(args).err(), Some(expected_error));
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_slowlog_log_slower_than_flag() {
+        let test_cases = vec![
+            (None, Ok(DEFAULT_SLOWLOG_LOG_SLOWER_THAN)),
+            (Some("0"), Ok(0)),
+            (Some("-1"), Ok(-1)),
+            (
+                Some("slow"),
+                Err(CliError::InvalidSlowlogLogSlowerThanFlagValue),
+            ),
+        ];
+
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--slowlog-log-slower-than".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.slowlog_log_slower_than);
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_zset_encoding_flags() {
+        let server = RedisServer::new(vec!["codecrafters-redis".to_string()]).unwrap();
+        assert_eq!(
+            server.zset_encoding_limits,
+            SortedSetEncodingLimits::default()
+        );
+
+        let server = RedisServer::new(vec![
+            "codecrafters-redis".to_string(),
+            "--zset-max-listpack-entries".to_string(),
+            "2".to_string(),
+            "--zset-max-listpack-value".to_string(),
+            "5".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(
+            server.zset_encoding_limits,
+            SortedSetEncodingLimits {
+                max_listpack_entries: 2,
+                max_listpack_value: 5,
+            }
+        );
+
+        let test_cases = vec![
+            (
+                "--zset-max-listpack-entries",
+                CliError::InvalidZsetMaxListpackEntriesFlagValue,
+            ),
+            (
+                "--zset-max-listpack-value",
+                CliError::InvalidZsetMaxListpackValueFlagValue,
+            ),
+        ];
+
+        for (flag, expected_error) in test_cases {
+            let args = vec![
+                "codecrafters-redis".to_string(),
+                flag.to_string(),
+                "-1".to_string(),
+            ];
+
+            assert_eq!(RedisServer::new(args).err(), Some(expected_error));
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_hash_encoding_flags() {
+        let server = RedisServer::new(vec!["codecrafters-redis".to_string()]).unwrap();
+        assert_eq!(server.hash_encoding_limits, HashEncodingLimits::default());
+
+        let server = RedisServer::new(vec![
+            "codecrafters-redis".to_string(),
+            "--hash-max-listpack-entries".to_string(),
+            "2".to_string(),
+            "--hash-max-listpack-value".to_string(),
+            "5".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(
+            server.hash_encoding_limits,
+            HashEncodingLimits {
+                max_listpack_entries: 2,
+                max_listpack_value: 5,
+            }
+        );
+
+        let test_cases = vec![
+            (
+                "--hash-max-listpack-entries",
+                CliError::InvalidHashMaxListpackEntriesFlagValue,
+            ),
+            (
+                "--hash-max-listpack-value",
+                CliError::InvalidHashMaxListpackValueFlagValue,
+            ),
+        ];
+
+        for (flag, expected_error) in test_cases {
+            let args = vec![
+                "codecrafters-redis".to_string(),
+                flag.to_string(),
+                "-1".to_string(),
+            ];
+
+            assert_eq!(RedisServer::new(args).err(), Some(expected_error));
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_stream_node_max_entries_flag() {
+        let test_cases = vec![
+            (None, Ok(DEFAULT_STREAM_NODE_MAX_ENTRIES)),
+            (Some("1"), Ok(1)),
+            (Some("0"), Ok(0)),
+            (
+                Some("-1"),
+                Err(CliError::InvalidStreamNodeMaxEntriesFlagValue),
+            ),
+        ];
+
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--stream-node-max-entries".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.stream_node_max_entries);
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_replica_read_only_flag() {
+        let test_cases = vec![
+            (None, Ok(true)),
+            (Some("yes"), Ok(true)),
+            (Some("NO"), Ok(false)),
+            (
+                Some("maybe"),
+                Err(CliError::InvalidReplicaReadOnlyFlagValue),
+            ),
+        ];
+
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--replica-read-only".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.replica_read_only);
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_save_flags() {
+        let hour = SavePoint {
+            seconds: 3600,
+            changes: 1,
+        };
+        let minute = SavePoint {
+            seconds: 60,
+            changes: 10000,
+        };
+
+        let test_cases = vec![
+            (vec![], Ok(vec![])),
+            (vec!["3600 1"], Ok(vec![hour])),
+            (vec!["3600 1 60 10000"], Ok(vec![hour, minute])),
+            (vec!["3600 1", "60 10000"], Ok(vec![hour, minute])),
+            (vec!["3600 1", "", "60 10000"], Ok(vec![minute])),
+            (vec!["3600 1", ""], Ok(vec![])),
+            (vec!["3600"], Err(CliError::InvalidSaveFlagValue)),
+            (vec!["3600 often"], Err(CliError::InvalidSaveFlagValue)),
+        ];
+
+        for (values, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            for value in values {
+                args.push("--save".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.save_points);
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_requirepass_flag() {
+        let test_cases = vec![
+            (None, None),
+            (Some("secret"), Some("secret".to_string())),
+            (Some(""), None),
+        ];
+
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--requirepass".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.requirepass);
+            assert_eq!(result, Ok(expected_result));
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_proto_max_bulk_len_flag() {
+        let test_cases = vec![
+            (None, Ok(DEFAULT_PROTO_MAX_BULK_LEN)),
+            (Some("1048576"), Ok(1048576)),
+            (Some("0"), Err(CliError::InvalidProtoMaxBulkLenFlagValue)),
+            (Some("-1"), Err(CliError::InvalidProtoMaxBulkLenFlagValue)),
+        ];
+
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--proto-max-bulk-len".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.proto_max_bulk_len);
+            assert_eq!(result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_redis_server_creation_with_tcp_keepalive_flag() {
+        let test_cases = vec![
+            (None, Ok(DEFAULT_TCP_KEEPALIVE)),
+            (Some("60"), Ok(60)),
+            (Some("0"), Ok(0)),
+            (Some("-1"), Err(CliError::InvalidTcpKeepaliveFlagValue)),
+        ];
+
+        for (value, expected_result) in test_cases {
+            let mut args = vec!["codecrafters-redis".to_string()];
+
+            if let Some(value) = value {
+                args.push("--tcp-keepalive".to_string());
+                args.push(value.to_string());
+            }
+
+            let result = RedisServer::new(args).map(|server| server.tcp_keepalive);
+            assert_eq!(result, expected_result);
         }
     }
 
@@ -773,4 +2583,55 @@ mod tests {
             assert_eq!(server.rdb_filename, expected_rdb_filename);
         }
     }
+
+    fn store_with_expirations(expired: usize, live: usize) -> KeyValueStore {
+        let expirations = [
+            (expired, Timestamp::now() - SignedDuration::from_secs(1)),
+            (live, Timestamp::now() + SignedDuration::from_secs(60)),
+        ];
+
+        expirations
+            .into_iter()
+            .flat_map(|(count, expiration)| (0..count).map(move |_| expiration))
+            .enumerate()
+            .map(|(index, expiration)| {
+                (
+                    format!("key:{}", index),
+                    Value {
+                        data: DataType::String("value".to_string()),
+                        expiration: Some(expiration),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_active_expire_cycle_stops_after_a_mostly_live_batch() {
+        let server = Arc::new(RwLock::new(
+            RedisServer::new(vec!["codecrafters-redis".to_string()]).unwrap(),
+        ));
+        let store = Arc::new(Mutex::new(store_with_expirations(0, 50)));
+        let mut unchecked_keys = Vec::new();
+
+        active_expire_cycle(Arc::clone(&server), Arc::clone(&store), &mut unchecked_keys).await;
+
+        assert_eq!(unchecked_keys.len(), 50 - ACTIVE_EXPIRE_CYCLE_KEYS_PER_LOOP);
+        assert_eq!(store.lock().await.len(), 50);
+    }
+
+    #[tokio::test]
+    async fn test_active_expire_cycle_continues_while_batches_are_mostly_expired() {
+        let server = Arc::new(RwLock::new(
+            RedisServer::new(vec!["codecrafters-redis".to_string()]).unwrap(),
+        ));
+        let store = Arc::new(Mutex::new(store_with_expirations(50, 0)));
+        let mut unchecked_keys = Vec::new();
+
+        active_expire_cycle(Arc::clone(&server), Arc::clone(&store), &mut unchecked_keys).await;
+
+        assert!(unchecked_keys.is_empty());
+        assert!(store.lock().await.is_empty());
+        assert_eq!(server.read().await.stats.expired_keys(), 50);
+    }
 }
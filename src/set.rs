@@ -0,0 +1,243 @@
+use std::{
+    collections::HashSet,
+    ops::{Deref, DerefMut},
+};
+
+use crate::key_value_store::is_integer_encodable;
+
+/// Default of Redis' `set-max-intset-entries`
+pub const DEFAULT_SET_MAX_INTSET_ENTRIES: usize = 512;
+/// Default of Redis' `set-max-listpack-entries`
+pub const DEFAULT_SET_MAX_LISTPACK_ENTRIES: usize = 128;
+/// Default of Redis' `set-max-listpack-value`
+pub const DEFAULT_SET_MAX_LISTPACK_VALUE: usize = 64;
+
+/// Thresholds past which a set leaves the intset and listpack encodings
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SetEncodingLimits {
+    pub max_intset_entries: usize,
+    pub max_listpack_entries: usize,
+    pub max_listpack_value: usize,
+}
+
+impl Default for SetEncodingLimits {
+    fn default() -> Self {
+        Self {
+            max_intset_entries: DEFAULT_SET_MAX_INTSET_ENTRIES,
+            max_listpack_entries: DEFAULT_SET_MAX_LISTPACK_ENTRIES,
+            max_listpack_value: DEFAULT_SET_MAX_LISTPACK_VALUE,
+        }
+    }
+}
+
+// Ordered from the most to the least compact, as a set only ever moves down this list
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy)]
+enum SetEncoding {
+    #[default]
+    Intset,
+    Listpack,
+    Hashtable,
+}
+
+/// A set is always kept in a `HashSet`, but remembers the most compact encoding Redis
+/// would still use for it. Like in Redis, a set never converts back to a more compact
+/// encoding after outgrowing one, even when members are removed.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Set {
+    members: HashSet<String>,
+    encoding: SetEncoding,
+}
+
+impl Set {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts out in the most compact encoding that fits the members under the given limits
+    pub fn with_encoding_limits(members: HashSet<String>, limits: &SetEncodingLimits) -> Self {
+        let encoding = required_encoding(
+            SetEncoding::Intset,
+            &members,
+            members.iter().map(String::as_str),
+            limits,
+        );
+
+        Self { members, encoding }
+    }
+
+    /// Converts the set to a less compact encoding once its members no longer fit the
+    /// current one. Only the members just added are checked, as the others already fitted.
+    pub fn update_encoding<'a>(
+        &mut self,
+        added_members: impl IntoIterator<Item = &'a str>,
+        limits: &SetEncodingLimits,
+    ) {
+        if self.encoding == SetEncoding::Hashtable {
+            return;
+        }
+
+        self.encoding = required_encoding(self.encoding, &self.members, added_members, limits);
+    }
+
+    pub fn encoding(&self) -> &str {
+        match self.encoding {
+            SetEncoding::Intset => "intset",
+            SetEncoding::Listpack => "listpack",
+            SetEncoding::Hashtable => "hashtable",
+        }
+    }
+}
+
+impl Deref for Set {
+    type Target = HashSet<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.members
+    }
+}
+
+impl DerefMut for Set {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.members
+    }
+}
+
+fn required_encoding<'a>(
+    encoding: SetEncoding,
+    members: &HashSet<String>,
+    added_members: impl IntoIterator<Item = &'a str>,
+    limits: &SetEncodingLimits,
+) -> SetEncoding {
+    let (mut are_added_integers, mut do_added_fit_listpack) = (true, true);
+
+    for member in added_members {
+        are_added_integers = are_added_integers && is_integer_encodable(member);
+        do_added_fit_listpack = do_added_fit_listpack && member.len() <= limits.max_listpack_value;
+    }
+
+    let fits_listpack_entries = members.len() <= limits.max_listpack_entries;
+
+    let required_encoding = match encoding {
+        SetEncoding::Intset if are_added_integers => {
+            match members.len() <= limits.max_intset_entries {
+                true => SetEncoding::Intset,
+                // An intset that grows past its limit is converted straight to a hashtable
+                false => SetEncoding::Hashtable,
+            }
+        }
+        // Leaving the intset, the integers already in the set have to fit the listpack too
+        SetEncoding::Intset
+            if fits_listpack_entries
+                && do_added_fit_listpack
+                && members
+                    .iter()
+                    .all(|member| member.len() <= limits.max_listpack_value) =>
+        {
+            SetEncoding::Listpack
+        }
+        SetEncoding::Listpack if fits_listpack_entries && do_added_fit_listpack => {
+            SetEncoding::Listpack
+        }
+        _ => SetEncoding::Hashtable,
+    };
+
+    encoding.max(required_encoding)
+}
+
+/// Starts out in the most compact encoding that fits the members under the default limits
+impl From<HashSet<String>> for Set {
+    fn from(members: HashSet<String>) -> Self {
+        Self::with_encoding_limits(members, &SetEncodingLimits::default())
+    }
+}
+
+impl FromIterator<String> for Set {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<HashSet<String>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Set, SetEncodingLimits};
+
+    const LIMITS: SetEncodingLimits = SetEncodingLimits {
+        max_intset_entries: 3,
+        max_listpack_entries: 2,
+        max_listpack_value: 5,
+    };
+
+    #[test]
+    fn test_update_encoding_from_intset() {
+        let mut set = Set::from_iter(["1".to_string(), "2".to_string()]);
+        set.update_encoding(["1", "2"], &LIMITS);
+        assert_eq!(set.encoding(), "intset");
+
+        set.insert("apple".to_string());
+        set.update_encoding(["apple"], &LIMITS);
+        assert_eq!(set.encoding(), "hashtable");
+
+        let mut set = Set::from_iter(["1".to_string()]);
+        set.insert("apple".to_string());
+        set.update_encoding(["apple"], &LIMITS);
+        assert_eq!(set.encoding(), "listpack");
+
+        // Removing the non-integer member does not bring the intset back
+        set.remove("apple");
+        set.update_encoding([], &LIMITS);
+        assert_eq!(set.encoding(), "listpack");
+
+        // The integers already in the intset must fit the listpack as well
+        let mut set = Set::from_iter(["123456".to_string()]);
+        set.insert("apple".to_string());
+        set.update_encoding(["apple"], &LIMITS);
+        assert_eq!(set.encoding(), "hashtable");
+    }
+
+    #[test]
+    fn test_update_encoding_past_intset_entries() {
+        let mut set = Set::from_iter((0..3).map(|i| i.to_string()));
+        set.update_encoding([], &LIMITS);
+        assert_eq!(set.encoding(), "intset");
+
+        set.insert("3".to_string());
+        set.update_encoding(["3"], &LIMITS);
+        assert_eq!(set.encoding(), "hashtable");
+
+        set.clear();
+        set.update_encoding([], &LIMITS);
+        assert_eq!(set.encoding(), "hashtable");
+    }
+
+    #[test]
+    fn test_update_encoding_past_listpack_value() {
+        let mut set = Set::from_iter(["apple".to_string()]);
+        set.update_encoding(["apple"], &LIMITS);
+        assert_eq!(set.encoding(), "listpack");
+
+        set.insert("banana".to_string());
+        set.update_encoding(["banana"], &LIMITS);
+        assert_eq!(set.encoding(), "hashtable");
+    }
+
+    #[test]
+    fn test_with_encoding_limits() {
+        let numbers = (0..3).map(|i| i.to_string()).collect();
+        assert_eq!(
+            Set::with_encoding_limits(numbers, &LIMITS).encoding(),
+            "intset"
+        );
+
+        let numbers = (0..4).map(|i| i.to_string()).collect();
+        assert_eq!(
+            Set::with_encoding_limits(numbers, &LIMITS).encoding(),
+            "hashtable"
+        );
+
+        let fruits = ["apple".to_string(), "kiwi".to_string()].into();
+        assert_eq!(
+            Set::with_encoding_limits(fruits, &LIMITS).encoding(),
+            "listpack"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use std::{collections::VecDeque, time::Duration};
+
+use jiff::Timestamp;
+
+/// Default of Redis' `slowlog-log-slower-than`, logging commands that take over 10 ms
+pub const DEFAULT_SLOWLOG_LOG_SLOWER_THAN: i64 = 10_000;
+/// Default of Redis' `slowlog-max-len`
+pub const DEFAULT_SLOWLOG_MAX_LEN: usize = 128;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SlowLogEntry {
+    pub id: u64,
+    pub timestamp: Timestamp,
+    pub duration_micros: u64,
+    pub arguments: Vec<String>,
+    pub client_address: String,
+}
+
+/// Bounded log of the commands whose execution exceeded `slowlog-log-slower-than`.
+/// Entries are kept newest first and the oldest one is dropped once the log is full.
+#[derive(Debug, Clone)]
+pub struct SlowLog {
+    entries: VecDeque<SlowLogEntry>,
+    next_id: u64,
+    max_len: usize,
+}
+
+impl Default for SlowLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_SLOWLOG_MAX_LEN)
+    }
+}
+
+impl SlowLog {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            next_id: 0,
+            max_len,
+        }
+    }
+
+    /// Whether a command that ran for `duration` should be logged. A negative threshold
+    /// disables the log and a threshold of 0 logs every command.
+    pub fn is_slow(duration: Duration, log_slower_than: i64) -> bool {
+        log_slower_than >= 0 && duration.as_micros() >= log_slower_than as u128
+    }
+
+    pub fn push(&mut self, duration: Duration, arguments: Vec<String>, client_address: &str) {
+        let entry = SlowLogEntry {
+            id: self.next_id,
+            timestamp: Timestamp::now(),
+            duration_micros: duration.as_micros() as u64,
+            arguments,
+            client_address: client_address.to_string(),
+        };
+
+        self.next_id += 1;
+        self.entries.push_front(entry);
+        self.entries.truncate(self.max_len);
+    }
+
+    /// Returns up to `count` of the newest entries, or every entry when `count` is None
+    pub fn get(&self, count: Option<usize>) -> Vec<&SlowLogEntry> {
+        self.entries
+            .iter()
+            .take(count.unwrap_or(self.entries.len()))
+            .collect()
+    }
+
+    /// Empties the log. Ids keep increasing so that entries logged afterwards stay unique.
+    pub fn reset(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_slow() {
+        let test_cases = vec![
+            (Duration::from_micros(20), 10, true),
+            (Duration::from_micros(10), 10, true),
+            (Duration::from_micros(5), 10, false),
+            (Duration::ZERO, 0, true),
+            (Duration::from_secs(1), -1, false),
+        ];
+
+        for (duration, log_slower_than, expected_result) in test_cases {
+            assert_eq!(SlowLog::is_slow(duration, log_slower_than), expected_result);
+        }
+    }
+
+    #[test]
+    fn test_push_keeps_newest_entries_up_to_max_len() {
+        let mut slowlog = SlowLog::new(2);
+
+        for command in ["SET", "GET", "INCR"] {
+            slowlog.push(
+                Duration::from_millis(15),
+                vec![command.to_string()],
+                "127.0.0.1:41844",
+            );
+        }
+
+        let entries = slowlog.get(None);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, 2);
+        assert_eq!(entries[0].arguments, vec!["INCR".to_string()]);
+        assert_eq!(entries[1].id, 1);
+        assert_eq!(slowlog.get(Some(1)).len(), 1);
+
+        slowlog.reset();
+        assert!(slowlog.get(None).is_empty());
+
+        slowlog.push(Duration::from_millis(15), vec![], "127.0.0.1:41844");
+        assert_eq!(slowlog.get(None)[0].id, 3);
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+};
+
+use crate::resp::RespValue;
+
+/// Default of Redis' `zset-max-listpack-entries`
+pub const DEFAULT_ZSET_MAX_LISTPACK_ENTRIES: usize = 128;
+/// Default of Redis' `zset-max-listpack-value`
+pub const DEFAULT_ZSET_MAX_LISTPACK_VALUE: usize = 64;
+
+/// Thresholds past which a sorted set leaves the listpack encoding
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SortedSetEncodingLimits {
+    pub max_listpack_entries: usize,
+    pub max_listpack_value: usize,
+}
+
+impl Default for SortedSetEncodingLimits {
+    fn default() -> Self {
+        Self {
+            max_listpack_entries: DEFAULT_ZSET_MAX_LISTPACK_ENTRIES,
+            max_listpack_value: DEFAULT_ZSET_MAX_LISTPACK_VALUE,
+        }
+    }
+}
+
+// Ordered from the most to the least compact, as a sorted set only ever moves down this list
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy)]
+enum SortedSetEncoding {
+    #[default]
+    Listpack,
+    Skiplist,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Score(pub f64);
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Members are kept both in a member -> score map for constant time lookups and in a set ordered
+/// by (score, member) so that equal scores are ordered lexicographically, like in Redis.
+/// The set also remembers whether Redis would still store it as a listpack, which it stops
+/// doing for good once the set outgrows the listpack limits.
+#[derive(Debug, PartialEq, Default)]
+pub struct SortedSet {
+    scores: HashMap<String, f64>,
+    ordered: BTreeSet<(Score, String)>,
+    encoding: SortedSetEncoding,
+}
+
+impl SortedSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+
+    pub fn score(&self, member: &str) -> Option<f64> {
+        self.scores.get(member).copied()
+    }
+
+    /// Inserts or updates a member, returning true if the member did not exist before
+    pub fn insert(&mut self, member: String, score: f64) -> bool {
+        // Normalize -0 so that it sorts together with 0
+        let score = if score == 0.0 { 0.0 } else { score };
+
+        let is_new_member = match self.scores.insert(member.clone(), score) {
+            Some(previous_score) => {
+                self.ordered
+                    .remove(&(Score(previous_score), member.clone()));
+                false
+            }
+            None => true,
+        };
+
+        self.ordered.insert((Score(score), member));
+
+        is_new_member
+    }
+
+    /// Removes a member, returning true if it existed
+    pub fn remove(&mut self, member: &str) -> bool {
+        let Some(score) = self.scores.remove(member) else {
+            return false;
+        };
+
+        self.ordered.remove(&(Score(score), member.to_string()));
+
+        true
+    }
+
+    /// Iterates over the members in ascending (score, member) order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&String, f64)> {
+        self.ordered.iter().map(|(score, member)| (member, score.0))
+    }
+
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Removes and returns the member with the lowest score
+    pub fn pop_min(&mut self) -> Option<(String, f64)> {
+        let (score, member) = self.ordered.pop_first()?;
+        self.scores.remove(&member);
+
+        Some((member, score.0))
+    }
+
+    /// Removes and returns the member with the highest score
+    pub fn pop_max(&mut self) -> Option<(String, f64)> {
+        let (score, member) = self.ordered.pop_last()?;
+        self.scores.remove(&member);
+
+        Some((member, score.0))
+    }
+
+    /// Converts the set to a skiplist once it has too many members, or a member longer
+    /// than the listpack allows. Only the members just added are checked, as the others
+    /// already fitted the listpack.
+    pub fn update_encoding<'a>(
+        &mut self,
+        added_members: impl IntoIterator<Item = &'a str>,
+        limits: &SortedSetEncodingLimits,
+    ) {
+        if self.encoding == SortedSetEncoding::Skiplist {
+            return;
+        }
+
+        let fits_listpack = self.scores.len() <= limits.max_listpack_entries
+            && added_members
+                .into_iter()
+                .all(|member| member.len() <= limits.max_listpack_value);
+
+        if !fits_listpack {
+            self.encoding = SortedSetEncoding::Skiplist;
+        }
+    }
+
+    pub fn encoding(&self) -> &str {
+        match self.encoding {
+            SortedSetEncoding::Listpack => "listpack",
+            SortedSetEncoding::Skiplist => "skiplist",
+        }
+    }
+
+    /// Zero based position of the member in ascending (score, member) order
+    pub fn rank(&self, member: &str) -> Option<usize> {
+        let score = self.score(member)?;
+
+        Some(
+            self.ordered
+                .range(..(Score(score), member.to_string()))
+                .count(),
+        )
+    }
+}
+
+pub fn parse_score(score: &str) -> Option<f64> {
+    let score = match score.to_lowercase().as_str() {
+        "inf" | "+inf" => f64::INFINITY,
+        "-inf" => f64::NEG_INFINITY,
+        _ => score.parse::<f64>().ok()?,
+    };
+
+    if score.is_nan() {
+        return None;
+    }
+
+    Some(score)
+}
+
+/// A `min`/`max` score range bound, which is exclusive when prefixed with `(`
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ScoreBound {
+    pub score: f64,
+    pub is_exclusive: bool,
+}
+
+impl ScoreBound {
+    pub fn parse(bound: &str) -> Option<Self> {
+        match bound.strip_prefix('(') {
+            Some(score) => Some(Self {
+                score: parse_score(score)?,
+                is_exclusive: true,
+            }),
+            None => Some(Self {
+                score: parse_score(bound)?,
+                is_exclusive: false,
+            }),
+        }
+    }
+}
+
+/// Whether the score lies between the two bounds
+pub fn is_score_in_range(score: f64, min: &ScoreBound, max: &ScoreBound) -> bool {
+    let is_above_min = if min.is_exclusive {
+        score > min.score
+    } else {
+        score >= min.score
+    };
+    let is_below_max = if max.is_exclusive {
+        score < max.score
+    } else {
+        score <= max.score
+    };
+
+    is_above_min && is_below_max
+}
+
+/// Replies with a score as a RESP3 double, or as a bulk string for RESP2 clients
+pub fn encode_score(score: f64, is_resp3_client: bool) -> RespValue {
+    match is_resp3_client {
+        true => RespValue::Double(score),
+        false => RespValue::BulkString(format_score(score)),
+    }
+}
+
+pub fn format_score(score: f64) -> String {
+    if score == f64::INFINITY {
+        "inf".to_string()
+    } else if score == f64::NEG_INFINITY {
+        "-inf".to_string()
+    } else {
+        score.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ScoreBound, SortedSet, SortedSetEncodingLimits, format_score, is_score_in_range,
+        parse_score,
+    };
+
+    const LIMITS: SortedSetEncodingLimits = SortedSetEncodingLimits {
+        max_listpack_entries: 2,
+        max_listpack_value: 5,
+    };
+
+    #[test]
+    fn test_sorted_set_ordering() {
+        let mut sorted_set = SortedSet::new();
+
+        assert!(sorted_set.insert("mango".to_string(), 2.0));
+        assert!(sorted_set.insert("apple".to_string(), 2.0));
+        assert!(sorted_set.insert("grape".to_string(), 1.0));
+        assert!(!sorted_set.insert("grape".to_string(), 3.0));
+
+        let members = sorted_set
+            .iter()
+            .map(|(member, score)| (member.as_str(), score))
+            .collect::<Vec<(&str, f64)>>();
+
+        assert_eq!(
+            members,
+            vec![("apple", 2.0), ("mango", 2.0), ("grape", 3.0)]
+        );
+        assert_eq!(sorted_set.score("grape"), Some(3.0));
+        assert_eq!(sorted_set.score("banana"), None);
+        assert_eq!(sorted_set.rank("mango"), Some(1));
+        assert_eq!(sorted_set.rank("banana"), None);
+        assert_eq!(sorted_set.pop_min(), Some(("apple".to_string(), 2.0)));
+        assert_eq!(sorted_set.pop_max(), Some(("grape".to_string(), 3.0)));
+        assert_eq!(sorted_set.len(), 1);
+        assert_eq!(sorted_set.score("apple"), None);
+        assert!(sorted_set.remove("mango"));
+        assert!(!sorted_set.remove("mango"));
+        assert!(sorted_set.is_empty());
+        assert_eq!(sorted_set.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_update_encoding_by_number_of_entries() {
+        let mut sorted_set = SortedSet::new();
+        sorted_set.insert("a".to_string(), 1.0);
+        sorted_set.insert("b".to_string(), 2.0);
+        sorted_set.update_encoding(["a", "b"], &LIMITS);
+        assert_eq!(sorted_set.encoding(), "listpack");
+
+        sorted_set.insert("c".to_string(), 3.0);
+        sorted_set.update_encoding(["c"], &LIMITS);
+        assert_eq!(sorted_set.encoding(), "skiplist");
+
+        // Shrinking back under the limit does not convert the set back
+        sorted_set.pop_min();
+        sorted_set.update_encoding([], &LIMITS);
+        assert_eq!(sorted_set.encoding(), "skiplist");
+    }
+
+    #[test]
+    fn test_update_encoding_by_member_length() {
+        let mut sorted_set = SortedSet::new();
+        sorted_set.insert("apple".to_string(), 1.0);
+        sorted_set.update_encoding(["apple"], &LIMITS);
+        assert_eq!(sorted_set.encoding(), "listpack");
+
+        sorted_set.insert("banana".to_string(), 2.0);
+        sorted_set.update_encoding(["banana"], &LIMITS);
+        assert_eq!(sorted_set.encoding(), "skiplist");
+    }
+
+    #[test]
+    fn test_parse_score() {
+        let test_cases = vec![
+            ("1", Some(1.0)),
+            ("-2.5", Some(-2.5)),
+            ("inf", Some(f64::INFINITY)),
+            ("+INF", Some(f64::INFINITY)),
+            ("-inf", Some(f64::NEG_INFINITY)),
+            ("nan", None),
+            ("mango", None),
+            ("", None),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(parse_score(input), expected, "Failed for input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_score_bound() {
+        let test_cases = vec![
+            (
+                "1.5",
+                Some(ScoreBound {
+                    score: 1.5,
+                    is_exclusive: false,
+                }),
+            ),
+            (
+                "(1.5",
+                Some(ScoreBound {
+                    score: 1.5,
+                    is_exclusive: true,
+                }),
+            ),
+            (
+                "-inf",
+                Some(ScoreBound {
+                    score: f64::NEG_INFINITY,
+                    is_exclusive: false,
+                }),
+            ),
+            ("(", None),
+            ("((1", None),
+            ("mango", None),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                ScoreBound::parse(input),
+                expected,
+                "Failed for input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_score_in_range() {
+        let min = ScoreBound::parse("(1").unwrap();
+        let max = ScoreBound::parse("3").unwrap();
+
+        assert!(!is_score_in_range(1.0, &min, &max));
+        assert!(is_score_in_range(2.0, &min, &max));
+        assert!(is_score_in_range(3.0, &min, &max));
+        assert!(!is_score_in_range(3.5, &min, &max));
+    }
+
+    #[test]
+    fn test_format_score() {
+        let test_cases = vec![
+            (1.0, "1"),
+            (1.5, "1.5"),
+            (-0.25, "-0.25"),
+            (f64::INFINITY, "inf"),
+            (f64::NEG_INFINITY, "-inf"),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(format_score(input), expected);
+        }
+    }
+}
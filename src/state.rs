@@ -33,9 +33,17 @@ pub struct XreadSubscriber {
     pub sender: mpsc::Sender<bool>,
 }
 
+/// Waiter of BZPOPMIN/BZPOPMAX, registered under every key it blocks on
+#[derive(Debug, Clone)]
+pub struct SortedSetSubscriber {
+    pub client_address: String,
+    pub sender: mpsc::Sender<bool>,
+}
+
 #[derive(Debug)]
 pub struct State {
     pub blpop_subscribers: HashMap<String, VecDeque<BlpopSubscriber>>, // key --> subscriber
+    pub sorted_set_subscribers: HashMap<String, VecDeque<SortedSetSubscriber>>, // key --> subscriber
     pub xread_subscribers: HashMap<String, HashMap<String, Vec<XreadSubscriber>>>, // key --> stream id --> subscriber
     pub transactions: HashMap<String, Vec<CommandHandler>>,
 }
@@ -44,6 +52,7 @@ impl State {
     pub fn new() -> Self {
         State {
             blpop_subscribers: HashMap::new(),
+            sorted_set_subscribers: HashMap::new(),
             xread_subscribers: HashMap::new(),
             transactions: HashMap::new(),
         }
@@ -79,6 +88,35 @@ impl State {
         }
     }
 
+    pub fn add_sorted_set_subscriber(&mut self, key: String, subscriber: SortedSetSubscriber) {
+        self.sorted_set_subscribers
+            .entry(key)
+            .or_default()
+            .push_back(subscriber);
+    }
+
+    pub fn remove_sorted_set_subscriber(&mut self, key: &str, client_address: &str) {
+        if let Some(subscriber_vec) = self.sorted_set_subscribers.get_mut(key) {
+            subscriber_vec.retain(|subscriber| subscriber.client_address != client_address);
+
+            if subscriber_vec.is_empty() {
+                self.sorted_set_subscribers.remove(key);
+            }
+        }
+    }
+
+    pub fn send_to_sorted_set_subscriber(&mut self, key: &str, message: bool) {
+        if let Some(subscriber_vec) = self.sorted_set_subscribers.get_mut(key) {
+            if let Some(subscriber) = subscriber_vec.pop_front() {
+                let _ = subscriber.sender.try_send(message);
+            }
+
+            if subscriber_vec.is_empty() {
+                self.sorted_set_subscribers.remove(key);
+            }
+        }
+    }
+
     pub fn add_xread_subscriber(
         &mut self,
         key: String,
@@ -123,7 +161,8 @@ impl State {
         let mut stream_ids_to_remove = Vec::new();
 
         for (waiting_stream_id, subscriber_vec) in streams.iter() {
-            let waiting_id = match validate_stream_id(waiting_stream_id, true) {
+            // XREAD accepts 0-0 as the ID to wait after
+            let waiting_id = match validate_stream_id(waiting_stream_id, false) {
                 Ok(id) => id,
                 Err(_) => {
                     // Log this as a warning in a real system
@@ -193,7 +232,7 @@ impl State {
 
 #[cfg(test)]
 mod tests {
-    use super::{BlpopSubscriber, CommandError, State, XreadSubscriber};
+    use super::{BlpopSubscriber, CommandError, SortedSetSubscriber, State, XreadSubscriber};
     use tokio::sync::{mpsc, oneshot};
 
     #[test]
@@ -348,6 +387,41 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_send_to_sorted_set_subscriber_fifo_order() {
+        let mut state = State::new();
+
+        let (sender1, mut receiver1) = mpsc::channel(1);
+        let (sender2, mut receiver2) = mpsc::channel(1);
+
+        for key in ["zset1", "zset2"] {
+            state.add_sorted_set_subscriber(
+                key.to_string(),
+                SortedSetSubscriber {
+                    client_address: "127.0.0.1:8080".to_string(),
+                    sender: sender1.clone(),
+                },
+            );
+        }
+        state.add_sorted_set_subscriber(
+            "zset1".to_string(),
+            SortedSetSubscriber {
+                client_address: "127.0.0.1:8081".to_string(),
+                sender: sender2,
+            },
+        );
+
+        state.send_to_sorted_set_subscriber("zset1", true);
+
+        assert_eq!(receiver1.recv().await, Some(true));
+        assert!(receiver2.try_recv().is_err());
+        assert_eq!(state.sorted_set_subscribers["zset1"].len(), 1);
+
+        // The woken subscriber removes itself from the other keys it was blocking on
+        state.remove_sorted_set_subscriber("zset2", "127.0.0.1:8080");
+        assert!(!state.sorted_set_subscribers.contains_key("zset2"));
+    }
+
     #[test]
     fn test_add_xread_subscriber_new_key() {
         let mut state = State::new();
@@ -0,0 +1,221 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Deref, DerefMut},
+};
+
+/// Default of Redis' `stream-node-max-entries`
+pub const DEFAULT_STREAM_NODE_MAX_ENTRIES: usize = 100;
+
+/// Fields and values of a stream entry
+pub type StreamEntry = BTreeMap<String, String>;
+
+/// A stream's entries keyed by ID. It also remembers the last ID it generated, which
+/// XADD builds new IDs on and which XSETID can move past the last entry.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Stream {
+    entries: BTreeMap<String, StreamEntry>,
+    last_id: (u128, u128),
+}
+
+impl Stream {
+    /// Adds an entry, moving the last generated ID forward if the entry's ID is higher
+    pub fn insert(&mut self, stream_id: String, entry: StreamEntry) -> Option<StreamEntry> {
+        if let Some(parsed_stream_id) = parse_stream_id(&stream_id) {
+            self.last_id = self.last_id.max(parsed_stream_id);
+        }
+
+        self.entries.insert(stream_id, entry)
+    }
+
+    pub fn last_id(&self) -> (u128, u128) {
+        self.last_id
+    }
+
+    pub fn set_last_id(&mut self, last_id: (u128, u128)) {
+        self.last_id = last_id;
+    }
+
+    /// Removes the oldest entries until at most `max_len` are left and returns how many were
+    /// removed. Approximate trimming only removes whole nodes of `node_max_entries` entries,
+    /// like Redis does with its radix tree, so it can leave more than `max_len` entries.
+    pub fn trim_to_max_len(
+        &mut self,
+        max_len: usize,
+        is_approximate: bool,
+        node_max_entries: usize,
+    ) -> usize {
+        let mut excess = self.entries.len().saturating_sub(max_len);
+
+        if is_approximate && node_max_entries > 0 {
+            excess -= excess % node_max_entries;
+        }
+
+        let mut stream_ids = self
+            .entries
+            .keys()
+            .filter_map(|id| parse_stream_id(id).map(|parsed_id| (parsed_id, id.clone())))
+            .collect::<Vec<((u128, u128), String)>>();
+        stream_ids.sort();
+
+        for (_, stream_id) in stream_ids.into_iter().take(excess) {
+            self.entries.remove(&stream_id);
+        }
+
+        excess
+    }
+
+    /// Number of radix tree nodes Redis would need for the entries, with `node_max_entries`
+    /// per node. 0 leaves the node size unlimited, so everything fits in a single node.
+    pub fn radix_tree_nodes(&self, node_max_entries: usize) -> usize {
+        match node_max_entries {
+            0 => usize::from(!self.entries.is_empty()),
+            _ => self.entries.len().div_ceil(node_max_entries),
+        }
+    }
+
+    /// Lowest ID among the entries, compared numerically rather than as strings
+    pub fn first_entry_id(&self) -> Option<(u128, u128)> {
+        self.entries
+            .keys()
+            .filter_map(|id| parse_stream_id(id))
+            .min()
+    }
+
+    /// Highest ID among the entries, compared numerically rather than as strings
+    pub fn top_entry_id(&self) -> Option<(u128, u128)> {
+        self.entries
+            .keys()
+            .filter_map(|id| parse_stream_id(id))
+            .max()
+    }
+}
+
+impl Deref for Stream {
+    type Target = BTreeMap<String, StreamEntry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl DerefMut for Stream {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.entries
+    }
+}
+
+impl From<BTreeMap<String, StreamEntry>> for Stream {
+    fn from(entries: BTreeMap<String, StreamEntry>) -> Self {
+        let mut stream = Self {
+            entries,
+            last_id: (0, 0),
+        };
+        stream.last_id = stream.top_entry_id().unwrap_or_default();
+
+        stream
+    }
+}
+
+impl<const N: usize> From<[(String, StreamEntry); N]> for Stream {
+    fn from(entries: [(String, StreamEntry); N]) -> Self {
+        Self::from(BTreeMap::from(entries))
+    }
+}
+
+pub fn format_stream_id((timestamp, sequence): (u128, u128)) -> String {
+    format!("{}-{}", timestamp, sequence)
+}
+
+/// Parses a complete `<timestamp>-<sequence>` ID
+pub fn parse_stream_id(stream_id: &str) -> Option<(u128, u128)> {
+    let (timestamp, sequence) = stream_id.split_once('-')?;
+
+    Some((timestamp.parse().ok()?, sequence.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_id_follows_highest_entry() {
+        let mut stream = Stream::from([
+            ("9-1".to_string(), StreamEntry::new()),
+            ("10-0".to_string(), StreamEntry::new()),
+        ]);
+        assert_eq!(stream.last_id(), (10, 0));
+        assert_eq!(stream.top_entry_id(), Some((10, 0)));
+
+        stream.set_last_id((15, 3));
+        stream.insert("12-0".to_string(), StreamEntry::new());
+        assert_eq!(stream.last_id(), (15, 3));
+        assert_eq!(stream.top_entry_id(), Some((12, 0)));
+
+        stream.insert("15-4".to_string(), StreamEntry::new());
+        assert_eq!(stream.last_id(), (15, 4));
+    }
+
+    #[test]
+    fn test_radix_tree_nodes() {
+        let stream = Stream::from(
+            (1..=10)
+                .map(|timestamp| (format_stream_id((timestamp, 0)), StreamEntry::new()))
+                .collect::<BTreeMap<String, StreamEntry>>(),
+        );
+        assert_eq!(stream.first_entry_id(), Some((1, 0)));
+
+        let test_cases = vec![(100, 1), (4, 3), (1, 10), (0, 1)];
+
+        for (node_max_entries, expected_nodes) in test_cases {
+            assert_eq!(stream.radix_tree_nodes(node_max_entries), expected_nodes);
+        }
+
+        assert_eq!(Stream::default().radix_tree_nodes(0), 0);
+        assert_eq!(Stream::default().first_entry_id(), None);
+    }
+
+    #[test]
+    fn test_trim_to_max_len() {
+        // The oldest entries go first, even though "10-0" sorts before "2-0" as a string
+        let test_cases = vec![
+            (3, false, 4, 7, Some((8, 0))),
+            (3, true, 4, 4, Some((5, 0))),
+            (3, true, 1, 7, Some((8, 0))),
+            (3, true, 100, 0, Some((1, 0))),
+            (20, false, 4, 0, Some((1, 0))),
+            (0, false, 4, 10, None),
+        ];
+
+        for (max_len, is_approximate, node_max_entries, expected_removed, expected_first_id) in
+            test_cases
+        {
+            let mut stream = Stream::from(
+                (1..=10)
+                    .map(|timestamp| (format_stream_id((timestamp, 0)), StreamEntry::new()))
+                    .collect::<BTreeMap<String, StreamEntry>>(),
+            );
+
+            let removed = stream.trim_to_max_len(max_len, is_approximate, node_max_entries);
+            assert_eq!(removed, expected_removed);
+            assert_eq!(stream.len(), 10 - expected_removed);
+
+            assert_eq!(stream.first_entry_id(), expected_first_id);
+            assert_eq!(stream.last_id(), (10, 0));
+        }
+    }
+
+    #[test]
+    fn test_parse_stream_id() {
+        let test_cases = vec![
+            ("5-6", Some((5, 6))),
+            ("0-0", Some((0, 0))),
+            ("5", None),
+            ("5-*", None),
+            ("a-1", None),
+        ];
+
+        for (stream_id, expected_result) in test_cases {
+            assert_eq!(parse_stream_id(stream_id), expected_result);
+        }
+    }
+}
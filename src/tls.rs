@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use tokio_rustls::rustls::{
+    ServerConfig,
+    crypto::ring,
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+};
+
+use crate::server::CliError;
+
+/// Loads the PEM encoded certificate chain and private key that TLS client connections
+/// are served with, like Redis' `tls-cert-file` and `tls-key-file`
+pub fn load_tls_config(cert_file: &str, key_file: &str) -> Result<Arc<ServerConfig>, CliError> {
+    let certificates = CertificateDer::pem_file_iter(cert_file)
+        .and_then(|certificates| certificates.collect::<Result<Vec<_>, _>>())
+        .ok()
+        .filter(|certificates| !certificates.is_empty())
+        .ok_or(CliError::InvalidTlsCertFileFlagValue)?;
+    let private_key =
+        PrivateKeyDer::from_pem_file(key_file).map_err(|_| CliError::InvalidTlsKeyFileFlagValue)?;
+
+    let config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .and_then(|builder| {
+            builder
+                .with_no_client_auth()
+                .with_single_cert(certificates, private_key)
+        })
+        .map_err(|_| CliError::InvalidTlsCertificateOrKey)?;
+
+    Ok(Arc::new(config))
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use codecrafters_redis::resp::RespValue;
+use tokio::net::TcpStream;
+
+use crate::test_utils::TestUtils;
+
+#[tokio::test]
+async fn test_append_only_file_restores_store_on_restart() {
+    let directory = "/tmp/redis-files-aof";
+    let _ = tokio::fs::remove_dir_all(directory).await;
+    tokio::fs::create_dir_all(directory).await.unwrap();
+
+    TestUtils::run_master_server_with_append_only_file(6397, directory).await;
+
+    // Give master server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = TcpStream::connect("127.0.0.1:6397").await.unwrap();
+    let mut buffer = [0; 1024];
+
+    let commands = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["apple", "banana"]),
+            RespValue::Integer(2),
+        ),
+        (TestUtils::incr_command("counter"), RespValue::Integer(1)),
+        (TestUtils::incr_command("counter"), RespValue::Integer(2)),
+        (
+            TestUtils::get_command("grape"),
+            RespValue::BulkString("mango".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in commands {
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+
+    // Only write commands are logged
+    let append_only_file = tokio::fs::read_to_string(format!("{}/appendonly.aof", directory))
+        .await
+        .unwrap();
+    assert!(!append_only_file.contains("GET"));
+
+    TestUtils::run_master_server_with_append_only_file(6398, directory).await;
+
+    // Give the restarted server time to replay the append-only file
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = TcpStream::connect("127.0.0.1:6398").await.unwrap();
+
+    let commands = vec![
+        (
+            TestUtils::get_command("grape"),
+            RespValue::BulkString("mango".to_string()),
+        ),
+        (
+            TestUtils::lrange_command("fruits", 0, -1),
+            RespValue::Array(vec![
+                RespValue::BulkString("apple".to_string()),
+                RespValue::BulkString("banana".to_string()),
+            ]),
+        ),
+        (
+            TestUtils::get_command("counter"),
+            RespValue::BulkString("2".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in commands {
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_append_only_file_is_created_from_rdb_file() {
+    let directory = "/tmp/redis-files-aof-from-rdb";
+    let _ = tokio::fs::remove_dir_all(directory).await;
+    tokio::fs::create_dir_all(directory).await.unwrap();
+
+    TestUtils::run_master_server_with_custom_rdb_file(6402, directory, "dump.rdb").await;
+
+    // Give master server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = TcpStream::connect("127.0.0.1:6402").await.unwrap();
+    let mut buffer = [0; 1024];
+
+    let commands = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["apple", "banana"]),
+            RespValue::Integer(2),
+        ),
+        (
+            TestUtils::save_command(),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in commands {
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+
+    // Enabling the append-only file loads the RDB file and writes its keys to the new file
+    TestUtils::run_master_server_with_append_only_file(6403, directory).await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = TcpStream::connect("127.0.0.1:6403").await.unwrap();
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::incr_command("counter"),
+        RespValue::Integer(1),
+    )
+    .await;
+
+    // The next restart prefers the append-only file, so the RDB file is no longer needed
+    tokio::fs::remove_file(format!("{}/dump.rdb", directory))
+        .await
+        .unwrap();
+
+    TestUtils::run_master_server_with_append_only_file(6404, directory).await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = TcpStream::connect("127.0.0.1:6404").await.unwrap();
+
+    let commands = vec![
+        (
+            TestUtils::get_command("grape"),
+            RespValue::BulkString("mango".to_string()),
+        ),
+        (
+            TestUtils::lrange_command("fruits", 0, -1),
+            RespValue::Array(vec![
+                RespValue::BulkString("apple".to_string()),
+                RespValue::BulkString("banana".to_string()),
+            ]),
+        ),
+        (
+            TestUtils::get_command("counter"),
+            RespValue::BulkString("1".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in commands {
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,66 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_append_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::append_command("fruit", "straw"),
+            TestUtils::expected_integer(5),
+        ),
+        (
+            TestUtils::append_command("fruit", "berry"),
+            TestUtils::expected_integer(10),
+        ),
+        (
+            TestUtils::get_command("fruit"),
+            TestUtils::expected_bulk_string("strawberry"),
+        ),
+        (
+            TestUtils::append_command("fruit", ""),
+            TestUtils::expected_integer(10),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_append_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["APPEND", "fruit"]),
+            CommandError::WrongNumberOfArguments("APPEND".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["APPEND", "fruit", "mango", "kiwi"]),
+            CommandError::WrongNumberOfArguments("APPEND".to_string()),
+        ),
+        (
+            TestUtils::append_command("fruits", "kiwi"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
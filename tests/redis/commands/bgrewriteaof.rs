@@ -0,0 +1,170 @@
+use std::{path::Path, time::Duration};
+
+use codecrafters_redis::{
+    aof::{AppendFsync, AppendOnlyFile, load_append_only_file, rewrite_append_only_file},
+    commands::CommandError,
+};
+use jiff::{Timestamp, ToSpan};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_bgrewriteaof_command() {
+    let directory = "/tmp/redis-files-bgrewriteaof";
+    let _ = tokio::fs::remove_dir_all(directory).await;
+    tokio::fs::create_dir_all(directory).await.unwrap();
+
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    env.server.write().await.rdb_directory = directory.to_string();
+
+    let expiration = Timestamp::now()
+        .checked_add(1.hour())
+        .unwrap()
+        .as_millisecond();
+
+    let test_cases = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::pexpireat_command("grape", expiration),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["apple", "banana", "apple"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::hset_command("prices", &[("apple", "1"), ("banana", "2")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::sadd_command("colors", &["red", "green"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zadd_command("scores", &[], &[("1.5", "apple"), ("-inf", "banana")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::xadd_command("events", "9-1", &["type", "start"]),
+            TestUtils::expected_bulk_string("9-1"),
+        ),
+        (
+            TestUtils::xadd_command("events", "10-0", &["type", "stop"]),
+            TestUtils::expected_bulk_string("10-0"),
+        ),
+        (
+            TestUtils::bgrewriteaof_command(),
+            TestUtils::expected_simple_string("Background append only file rewriting started"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    for _ in 0..250 {
+        if !env.server.read().await.is_aof_rewrite_in_progress {
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    // Replaying the rewritten file from scratch rebuilds the same store
+    let replayed_env = TestEnv::new_master_server();
+    replayed_env.server.write().await.rdb_directory = directory.to_string();
+    let (store, state, server) = replayed_env.clone_env();
+
+    load_append_only_file(server, store, state).await.unwrap();
+
+    assert_eq!(*replayed_env.store.lock().await, *env.store.lock().await);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_rewrite_append_only_file_during_concurrent_writes() {
+    let directory = "/tmp/redis-files-bgrewriteaof-concurrent";
+    let _ = tokio::fs::remove_dir_all(directory).await;
+    tokio::fs::create_dir_all(directory).await.unwrap();
+
+    let env = TestEnv::new_master_server();
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.rdb_directory = directory.to_string();
+        server_guard.append_only_file = Some(
+            AppendOnlyFile::open(
+                &Path::new(directory).join("appendonly.aof"),
+                AppendFsync::No,
+            )
+            .await
+            .unwrap(),
+        );
+    }
+
+    let mut writers = Vec::new();
+
+    for client in 0..8 {
+        let mut env = env.clone();
+
+        writers.push(tokio::spawn(async move {
+            let client_address = TestUtils::client_address(41844 + client);
+
+            for _ in 0..200 {
+                let result = env
+                    .exec_command(TestUtils::incr_command("counter"), &client_address)
+                    .await;
+                assert!(result.is_ok());
+            }
+        }));
+    }
+
+    // Rewrite once in the middle of the writes. A write that lands in the rewrite's
+    // snapshot must not also be appended to the new file.
+    while env.store.lock().await.is_empty() {
+        tokio::task::yield_now().await;
+    }
+
+    let (store, _, server) = env.clone_env();
+    rewrite_append_only_file(server, store).await.unwrap();
+
+    for writer in writers {
+        writer.await.unwrap();
+    }
+
+    let replayed_env = TestEnv::new_master_server();
+    replayed_env.server.write().await.rdb_directory = directory.to_string();
+    let (store, state, server) = replayed_env.clone_env();
+
+    load_append_only_file(server, store, state).await.unwrap();
+
+    assert_eq!(*replayed_env.store.lock().await, *env.store.lock().await);
+}
+
+#[tokio::test]
+async fn test_handle_bgrewriteaof_command_already_in_progress() {
+    let mut env = TestEnv::new_master_server();
+    env.server.write().await.is_aof_rewrite_in_progress = true;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::bgrewriteaof_command(),
+        &TestUtils::client_address(41844),
+        CommandError::AofRewriteInProgress,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_bgrewriteaof_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["BGREWRITEAOF", "now"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("BGREWRITEAOF".to_string()),
+    )
+    .await;
+}
@@ -0,0 +1,175 @@
+use codecrafters_redis::{commands::CommandError, resp::RespValue};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+fn expected_bitfield_response(values: &[Option<i64>]) -> String {
+    let response = values
+        .iter()
+        .map(|value| match value {
+            Some(value) => RespValue::Integer(*value),
+            None => RespValue::NullBulkString,
+        })
+        .collect::<Vec<RespValue>>();
+
+    RespValue::Array(response).encode()
+}
+
+#[tokio::test]
+async fn test_handle_bitfield_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::bitfield_command("counters", &["GET", "u8", "0"]),
+            expected_bitfield_response(&[Some(0)]),
+        ),
+        (
+            TestUtils::type_command("counters"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["SET", "u8", "0", "200", "GET", "u8", "0"]),
+            expected_bitfield_response(&[Some(0), Some(200)]),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "i8", "0", "GET", "u4", "0"]),
+            expected_bitfield_response(&[Some(-56), Some(12)]),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["SET", "u8", "#1", "42", "GET", "u16", "0"]),
+            expected_bitfield_response(&[Some(0), Some(51242)]),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["INCRBY", "u8", "0", "100"]),
+            expected_bitfield_response(&[Some(44)]),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["INCRBY", "i8", "#1", "-50"]),
+            expected_bitfield_response(&[Some(-8)]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_bitfield_command_overflow() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::bitfield_command("counters", &["SET", "u8", "0", "250"]),
+            expected_bitfield_response(&[Some(0)]),
+        ),
+        (
+            TestUtils::bitfield_command(
+                "counters",
+                &[
+                    "OVERFLOW", "SAT", "INCRBY", "u8", "0", "10", "INCRBY", "i8", "8", "-200",
+                ],
+            ),
+            expected_bitfield_response(&[Some(255), Some(-128)]),
+        ),
+        (
+            TestUtils::bitfield_command(
+                "counters",
+                &[
+                    "OVERFLOW", "FAIL", "INCRBY", "u8", "0", "1", "GET", "u8", "0",
+                ],
+            ),
+            expected_bitfield_response(&[None, Some(255)]),
+        ),
+        (
+            TestUtils::bitfield_command(
+                "counters",
+                &[
+                    "OVERFLOW", "WRAP", "INCRBY", "u8", "0", "1", "SET", "i8", "8", "128",
+                ],
+            ),
+            expected_bitfield_response(&[Some(0), Some(-128)]),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "i8", "8"]),
+            expected_bitfield_response(&[Some(-128)]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_bitfield_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["BITFIELD"]),
+            CommandError::WrongNumberOfArguments("BITFIELD".to_string()),
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "u8"]),
+            CommandError::InvalidBitfieldCommand,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["DECRBY", "u8", "0", "1"]),
+            CommandError::InvalidBitfieldCommand,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "u64", "0"]),
+            CommandError::InvalidBitfieldType,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "i65", "0"]),
+            CommandError::InvalidBitfieldType,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "x8", "0"]),
+            CommandError::InvalidBitfieldType,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "u8", "-1"]),
+            CommandError::InvalidBitfieldOffset,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["GET", "u8", "4294967296"]),
+            CommandError::InvalidBitfieldOffset,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["SET", "u8", "#4000000000000", "1"]),
+            CommandError::InvalidBitfieldOffset,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["SET", "u8", "0", "ten"]),
+            CommandError::InvalidBitfieldValue,
+        ),
+        (
+            TestUtils::bitfield_command("counters", &["OVERFLOW", "CLAMP"]),
+            CommandError::InvalidBitfieldOverflowType,
+        ),
+        (
+            TestUtils::bitfield_command("fruits", &["GET", "u8", "0"]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -24,6 +24,32 @@ async fn test_handle_blpop_command_direct_response() {
     .await;
 }
 
+#[tokio::test]
+async fn test_handle_blpop_command_removes_empty_list() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("grape", &["mango"]),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::blpop_command("grape", "0"),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_bulk_string_array(&["grape", "mango"]),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::type_command("grape"),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_simple_string("none"),
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_blpop_concurrent_clients_simple_blocking() {
     let env = TestEnv::new_master_server();
@@ -283,7 +309,7 @@ async fn test_blpop_invalid_arguments() {
     env.exec_command_immediate_error_response(
         TestUtils::invalid_command(&["BLPOP", "test_list"]),
         &TestUtils::client_address(12400),
-        CommandError::InvalidBLPopCommand,
+        CommandError::WrongNumberOfArguments("BLPOP".to_string()),
     )
     .await;
 
@@ -353,7 +379,7 @@ async fn test_handle_blpop_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["BLPOP"]),
-            CommandError::InvalidBLPopCommand,
+            CommandError::WrongNumberOfArguments("BLPOP".to_string()),
         ),
         (
             TestUtils::invalid_command(&["BLPOP", "grape", "2", "mango"]),
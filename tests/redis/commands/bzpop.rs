@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_bzpop_command_immediate() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zadd_command("fruits", &[], &[("1", "apple"), ("2", "banana")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::bzpop_command("BZPOPMIN", &["missing", "fruits"], "0"),
+            TestUtils::expected_bulk_string_array(&["fruits", "apple", "1"]),
+        ),
+        (
+            TestUtils::bzpop_command("BZPOPMAX", &["fruits"], "0"),
+            TestUtils::expected_bulk_string_array(&["fruits", "banana", "2"]),
+        ),
+        (
+            TestUtils::zcard_command("fruits"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::bzpop_command("BZPOPMIN", &["fruits"], "0.1"),
+            "*-1\r\n".to_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_bzpop_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["BZPOPMIN", "fruits"]),
+            CommandError::WrongNumberOfArguments("BZPOPMIN".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["BZPOPMAX", "fruits"]),
+            CommandError::WrongNumberOfArguments("BZPOPMAX".to_string()),
+        ),
+        (
+            TestUtils::bzpop_command("BZPOPMIN", &["fruits"], "-1"),
+            CommandError::InvalidBZPopTimeout,
+        ),
+        (
+            TestUtils::bzpop_command("BZPOPMAX", &["fruits"], "abc"),
+            CommandError::InvalidBZPopTimeout,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_bzpop_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("fruits", "apple"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::bzpop_command("BZPOPMIN", &["fruits"], "0"),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_bzpop_unblocked_by_zadd() {
+    let env = TestEnv::new_master_server();
+
+    // Client blocks on an empty sorted set
+    let client_task = TestUtils::spawn_bzpop_task(
+        &env,
+        "BZPOPMIN",
+        &["leaderboard", "scores"],
+        "2",
+        &TestUtils::client_address(12345),
+    );
+
+    // Give client time to register as subscriber
+    TestUtils::sleep_ms(200).await;
+
+    let mut env_mut = env.clone();
+
+    env_mut
+        .exec_command_immediate_success_response(
+            TestUtils::zadd_command("scores", &[], &[("5", "bob"), ("3", "alice")]),
+            &TestUtils::client_address(12346),
+            &TestUtils::expected_integer(2),
+        )
+        .await;
+
+    let client_result = TestUtils::wait_for_completion(client_task, Duration::from_secs(3)).await;
+
+    assert_eq!(
+        client_result,
+        Ok(TestUtils::expected_bulk_string_array(&[
+            "scores", "alice", "3"
+        ]))
+    );
+
+    env_mut
+        .exec_command_immediate_success_response(
+            TestUtils::zcard_command("scores"),
+            &TestUtils::client_address(12346),
+            &TestUtils::expected_integer(1),
+        )
+        .await;
+}
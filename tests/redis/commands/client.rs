@@ -0,0 +1,285 @@
+use codecrafters_redis::commands::CommandError;
+use jiff::{SignedDuration, Timestamp};
+use tokio::sync::mpsc;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_client_info_command() {
+    let mut env = TestEnv::new_master_server();
+    let first_client_address = TestUtils::client_address(41844);
+    let second_client_address = TestUtils::client_address(41845);
+
+    let test_cases = vec![
+        (
+            &first_client_address,
+            TestUtils::expected_bulk_string(&format!(
+                "id=1 addr={} age=0 flags=N db=0 resp=2\n",
+                first_client_address
+            )),
+        ),
+        (
+            &second_client_address,
+            TestUtils::expected_bulk_string(&format!(
+                "id=2 addr={} age=0 flags=N db=0 resp=2\n",
+                second_client_address
+            )),
+        ),
+        (
+            &first_client_address,
+            TestUtils::expected_bulk_string(&format!(
+                "id=1 addr={} age=0 flags=N db=0 resp=2\n",
+                first_client_address
+            )),
+        ),
+    ];
+
+    for (client_address, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::client_info_command(),
+            client_address,
+            &expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_client_no_evict_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    for (mode, expected_no_evict, expected_flags) in [("ON", true, "e"), ("off", false, "N")] {
+        env.exec_command_immediate_success_response(
+            TestUtils::client_no_evict_command(mode),
+            &client_address,
+            &TestUtils::expected_simple_string("OK"),
+        )
+        .await;
+
+        {
+            let (_, _, server) = env.clone_env();
+            let server_guard = server.read().await;
+            assert_eq!(
+                server_guard
+                    .clients
+                    .get(&client_address)
+                    .map(|client| client.no_evict),
+                Some(expected_no_evict)
+            );
+        }
+
+        env.exec_command_immediate_success_response(
+            TestUtils::client_info_command(),
+            &client_address,
+            &TestUtils::expected_bulk_string(&format!(
+                "id=1 addr={} age=0 flags={} db=0 resp=2\n",
+                client_address, expected_flags
+            )),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_client_no_touch_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("fruit", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    // Pretend the key was last accessed 100 seconds ago
+    env.server.write().await.key_access_times.insert(
+        "fruit".to_string(),
+        Timestamp::now() - SignedDuration::from_secs(100),
+    );
+
+    let test_cases = vec![
+        (
+            TestUtils::client_no_touch_command("ON"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::get_command("fruit"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::object_idletime_command("fruit"),
+            TestUtils::expected_integer(100),
+        ),
+        (
+            TestUtils::client_info_command(),
+            TestUtils::expected_bulk_string(&format!(
+                "id=1 addr={} age=0 flags=T db=0 resp=2\n",
+                client_address
+            )),
+        ),
+        (
+            TestUtils::client_no_touch_command("off"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::get_command("fruit"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::object_idletime_command("fruit"),
+            TestUtils::expected_integer(0),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_client_tracking_command() {
+    let mut env = TestEnv::new_master_server();
+    let tracking_client_address = TestUtils::client_address(41844);
+    let writing_client_address = TestUtils::client_address(41845);
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+
+    env.exec_command(
+        TestUtils::hello_command(Some("3")),
+        &tracking_client_address,
+    )
+    .await
+    .unwrap();
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::client_tracking_command("ON", &[]),
+        &tracking_client_address,
+        sender.clone(),
+        Some(TestUtils::expected_simple_string("OK")),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            &writing_client_address,
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::get_command("grape"),
+            &tracking_client_address,
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::set_command("grape", "kiwi"),
+            &writing_client_address,
+            TestUtils::expected_simple_string("OK"),
+        ),
+    ];
+
+    for (command, client_address, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, client_address, &expected_response)
+            .await;
+    }
+
+    assert_eq!(
+        String::from_utf8(receiver.try_recv().unwrap()).unwrap(),
+        ">2\r\n$10\r\ninvalidate\r\n*1\r\n$5\r\ngrape\r\n"
+    );
+
+    // The key is only invalidated once until it is read again
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "apple"),
+        &writing_client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+    assert!(receiver.try_recv().is_err());
+
+    // Turning tracking off forgets the keys the connection read
+    env.exec_command(TestUtils::get_command("grape"), &tracking_client_address)
+        .await
+        .unwrap();
+    env.exec_pub_sub_command_success_response(
+        TestUtils::client_tracking_command("OFF", &[]),
+        &tracking_client_address,
+        sender,
+        Some(TestUtils::expected_simple_string("OK")),
+    )
+    .await;
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "banana"),
+        &writing_client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_handle_client_tracking_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::client_tracking_command("MAYBE", &[]),
+            CommandError::InvalidClientCommandArgument,
+        ),
+        (
+            TestUtils::client_tracking_command("ON", &["REDIRECT", "many"]),
+            CommandError::InvalidTrackingRedirectClientId,
+        ),
+        (
+            TestUtils::client_tracking_command("ON", &["REDIRECT", "99"]),
+            CommandError::TrackingRedirectClientNotFound,
+        ),
+        (
+            TestUtils::client_tracking_command("ON", &["BCAST"]),
+            CommandError::UnsupportedTrackingBroadcastMode,
+        ),
+        (
+            TestUtils::client_tracking_command("ON", &["PREFIX", "fruit:"]),
+            CommandError::TrackingPrefixRequiresBroadcastMode,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        let (sender, _receiver) = mpsc::unbounded_channel();
+
+        env.exec_pub_sub_command_error_response(command, &client_address, sender, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_client_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["CLIENT", "INFO", "extra"]),
+            CommandError::WrongNumberOfArguments("CLIENT INFO".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["CLIENT", "NO-EVICT"]),
+            CommandError::WrongNumberOfArguments("CLIENT NO-EVICT".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["CLIENT", "NO-EVICT", "maybe"]),
+            CommandError::InvalidClientCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["CLIENT", "NO-TOUCH"]),
+            CommandError::WrongNumberOfArguments("CLIENT NO-TOUCH".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,94 @@
+use codecrafters_redis::commands::{CommandError, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_command_list_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let stream_commands = TestUtils::expected_bulk_string_array(&[
+        "xadd", "xdel", "xlen", "xrange", "xread", "xsetid", "xtrim",
+    ]);
+
+    let test_cases = vec![
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN", "x*"]),
+            stream_commands.clone(),
+        ),
+        (
+            TestUtils::command_list_command(&["filterby", "pattern", "X*"]),
+            stream_commands,
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN", "h?et"]),
+            TestUtils::expected_bulk_string_array(&["hget", "hset"]),
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN", "*subscribe"]),
+            TestUtils::expected_bulk_string_array(&["ssubscribe", "subscribe", "sunsubscribe"]),
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN", "sort*"]),
+            TestUtils::expected_bulk_string_array(&["sort", "sort_ro"]),
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN", "unknown*"]),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "MODULE", "json"]),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "ACLCAT", "read"]),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let Ok(CommandResult::Response(response)) = env
+        .exec_command(TestUtils::command_list_command(&[]), &client_address)
+        .await
+    else {
+        panic!("COMMAND LIST should respond with the command names");
+    };
+
+    for name in ["get", "set", "command", "client", "debug"] {
+        assert!(
+            response.contains(&format!("${}\r\n{}\r\n", name.len(), name)),
+            "{} should be listed",
+            name
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_handle_command_list_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::command_list_command(&["FILTERBY", "NAME", "x*"]),
+            CommandError::InvalidCommandListCommand,
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN"]),
+            CommandError::InvalidCommandListCommand,
+        ),
+        (
+            TestUtils::command_list_command(&["FILTERBY", "PATTERN", "x*", "y*"]),
+            CommandError::WrongNumberOfArguments("COMMAND LIST".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -24,6 +24,77 @@ async fn test_handle_config_get_command() {
                 "dump.rdb",
             ]),
         ),
+        (
+            TestUtils::config_get_command(&["save"]),
+            TestUtils::expected_bulk_string_array(&["save", ""]),
+        ),
+        (
+            TestUtils::config_get_command(&["appendonly", "appendfsync"]),
+            TestUtils::expected_bulk_string_array(&["appendonly", "no", "appendfsync", "everysec"]),
+        ),
+        (
+            TestUtils::config_get_command(&["list-max-listpack-size"]),
+            TestUtils::expected_bulk_string_array(&["list-max-listpack-size", "-2"]),
+        ),
+        (
+            TestUtils::config_get_command(&[
+                "set-max-intset-entries",
+                "set-max-listpack-entries",
+                "set-max-listpack-value",
+            ]),
+            TestUtils::expected_bulk_string_array(&[
+                "set-max-intset-entries",
+                "512",
+                "set-max-listpack-entries",
+                "128",
+                "set-max-listpack-value",
+                "64",
+            ]),
+        ),
+        (
+            TestUtils::config_get_command(&[
+                "zset-max-listpack-entries",
+                "zset-max-listpack-value",
+            ]),
+            TestUtils::expected_bulk_string_array(&[
+                "zset-max-listpack-entries",
+                "128",
+                "zset-max-listpack-value",
+                "64",
+            ]),
+        ),
+        (
+            TestUtils::config_get_command(&[
+                "hash-max-listpack-entries",
+                "hash-max-listpack-value",
+            ]),
+            TestUtils::expected_bulk_string_array(&[
+                "hash-max-listpack-entries",
+                "128",
+                "hash-max-listpack-value",
+                "64",
+            ]),
+        ),
+        (
+            TestUtils::config_get_command(&["stream-node-max-entries"]),
+            TestUtils::expected_bulk_string_array(&["stream-node-max-entries", "100"]),
+        ),
+        (
+            TestUtils::config_get_command(&["slowlog-log-slower-than"]),
+            TestUtils::expected_bulk_string_array(&["slowlog-log-slower-than", "10000"]),
+        ),
+        (
+            TestUtils::config_get_command(&["latency-monitor-threshold"]),
+            TestUtils::expected_bulk_string_array(&["latency-monitor-threshold", "0"]),
+        ),
+        (
+            TestUtils::config_get_command(&["tcp-keepalive"]),
+            TestUtils::expected_bulk_string_array(&["tcp-keepalive", "300"]),
+        ),
+        (
+            TestUtils::config_get_command(&["proto-max-bulk-len"]),
+            TestUtils::expected_bulk_string_array(&["proto-max-bulk-len", "536870912"]),
+        ),
     ];
 
     for (command, expected_response) in test_cases {
@@ -36,6 +107,23 @@ async fn test_handle_config_get_command() {
     }
 }
 
+#[tokio::test]
+async fn test_handle_config_get_command_resp3() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(TestUtils::hello_command(Some("3")), &client_address)
+        .await
+        .unwrap();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::config_get_command(&["dir", "dbfilename"]),
+        &client_address,
+        "%2\r\n$3\r\ndir\r\n$16\r\n/tmp/redis-files\r\n$10\r\ndbfilename\r\n$8\r\ndump.rdb\r\n",
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_handle_config_get_command_invalid() {
     let mut env = TestEnv::new_master_server();
@@ -43,7 +131,7 @@ async fn test_handle_config_get_command_invalid() {
     env.exec_command_immediate_error_response(
         TestUtils::invalid_command(&["CONFIG GET"]),
         &TestUtils::client_address(41844),
-        CommandError::InvalidConfigGetCommand,
+        CommandError::WrongNumberOfArguments("CONFIG GET".to_string()),
     )
     .await;
 }
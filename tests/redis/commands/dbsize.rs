@@ -0,0 +1,39 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_dbsize_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (TestUtils::dbsize_command(), TestUtils::expected_integer(0)),
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["apple", "banana"]),
+            TestUtils::expected_integer(2),
+        ),
+        (TestUtils::dbsize_command(), TestUtils::expected_integer(2)),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_dbsize_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["DBSIZE", "0"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("DBSIZE".to_string()),
+    )
+    .await;
+}
@@ -0,0 +1,594 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    key_value_store::{DataType, Value},
+    list::List,
+    stream::Stream,
+};
+use jiff::Timestamp;
+use tokio::time::Instant;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_debug_set_active_expire_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![("0", false), ("1", true)];
+
+    for (enabled, expected_flag) in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::debug_set_active_expire_command(enabled),
+            &client_address,
+            &TestUtils::expected_simple_string("OK"),
+        )
+        .await;
+
+        let server_guard = env.server.read().await;
+        assert_eq!(server_guard.active_expire_enabled, expected_flag);
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_set_active_expire_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["DEBUG", "SET-ACTIVE-EXPIRE"]),
+            CommandError::InvalidDebugCommand,
+        ),
+        (
+            TestUtils::debug_set_active_expire_command("yes"),
+            CommandError::InvalidDebugCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["DEBUG", "UNKNOWN-SUBCOMMAND"]),
+            CommandError::InvalidCommandArgument,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_change_repl_id_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let original_replid = master_replid(&mut env, &client_address).await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::debug_change_repl_id_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let new_replid = master_replid(&mut env, &client_address).await;
+
+    assert_ne!(new_replid, original_replid);
+    assert_eq!(new_replid.len(), 40);
+    assert!(new_replid.chars().all(|c| c.is_ascii_alphanumeric()));
+}
+
+#[tokio::test]
+async fn test_handle_debug_change_repl_id_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["DEBUG", "CHANGE-REPL-ID", "now"]),
+        &client_address,
+        CommandError::InvalidDebugCommand,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_debug_object_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = vec![
+        TestUtils::rpush_command("fruits", &["apple", "banana", "mango"]),
+        TestUtils::xadd_command("events", "1-0", &["type", "start"]),
+        TestUtils::set_command("fruit", "mango"),
+        TestUtils::zadd_command("scores", &[], &[("1", "apple")]),
+        TestUtils::zadd_command("ranking", &[], &[("1", &"mango".repeat(20))]),
+    ];
+
+    for command in setup_commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    // The list is serialized as a length byte followed by each length prefixed item
+    let test_cases = vec![
+        (
+            "fruits",
+            vec![
+                "encoding:listpack",
+                "serializedlength:20",
+                "ql_nodes:1",
+                "ql_avg_node:3.00",
+                "ql_listpack_max:-2",
+            ],
+        ),
+        ("events", vec!["encoding:stream", "radix-tree-keys:1"]),
+        ("fruit", vec!["encoding:embstr", "serializedlength:6"]),
+        ("scores", vec!["encoding:listpack"]),
+        ("ranking", vec!["encoding:skiplist"]),
+    ];
+
+    for (key, expected_fields) in test_cases {
+        let Ok(CommandResult::Response(response)) = env
+            .exec_command(TestUtils::debug_object_command(key), &client_address)
+            .await
+        else {
+            panic!("Expected DEBUG OBJECT {} to succeed", key);
+        };
+
+        assert!(response.starts_with("+Value at:"));
+
+        let fields = response.trim_end().split(' ').collect::<Vec<&str>>();
+
+        for expected_field in expected_fields {
+            assert!(
+                fields.contains(&expected_field),
+                "{} is missing {}",
+                response,
+                expected_field
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_stream_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::xadd_command("events", "1-1", &["type", "start"]),
+            TestUtils::expected_bulk_string("1-1"),
+        ),
+        (
+            TestUtils::xadd_command("events", "2-1", &["type", "pause"]),
+            TestUtils::expected_bulk_string("2-1"),
+        ),
+        (
+            TestUtils::xadd_command("events", "10-1", &["type", "stop"]),
+            TestUtils::expected_bulk_string("10-1"),
+        ),
+        (
+            TestUtils::debug_stream_command("events"),
+            TestUtils::expected_simple_string(
+                "entries:3 first-id:1-1 last-id:10-1 radix-tree-keys:1 stream-node-max-entries:100",
+            ),
+        ),
+        (
+            TestUtils::debug_set_stream_max_entries_command("2"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        // Deleting the last entry keeps the last generated ID
+        (
+            TestUtils::xdel_command("events", &["10-1"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::xadd_command("events", "11-1", &["type", "start"]),
+            TestUtils::expected_bulk_string("11-1"),
+        ),
+        (
+            TestUtils::xadd_command("events", "12-1", &["type", "stop"]),
+            TestUtils::expected_bulk_string("12-1"),
+        ),
+        (
+            TestUtils::xdel_command("events", &["12-1"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::debug_stream_command("events"),
+            TestUtils::expected_simple_string(
+                "entries:3 first-id:1-1 last-id:12-1 radix-tree-keys:2 stream-node-max-entries:2",
+            ),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_stream_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(TestUtils::set_command("fruit", "mango"), &client_address)
+        .await
+        .unwrap();
+
+    let test_cases = vec![
+        (
+            TestUtils::debug_stream_command("missing"),
+            CommandError::NoSuchKey,
+        ),
+        (
+            TestUtils::debug_stream_command("fruit"),
+            CommandError::WrongType,
+        ),
+        (
+            TestUtils::invalid_command(&["DEBUG", "STREAM"]),
+            CommandError::InvalidDebugCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_object_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::debug_object_command("missing"),
+            CommandError::NoSuchKey,
+        ),
+        (
+            TestUtils::invalid_command(&["DEBUG", "OBJECT"]),
+            CommandError::InvalidDebugCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+
+    assert_eq!(CommandError::NoSuchKey.as_string(), "-ERR no such key\r\n");
+}
+
+#[tokio::test]
+async fn test_handle_debug_listpack_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = vec![
+        TestUtils::rpush_command("fruits", &["apple", "banana", "mango"]),
+        TestUtils::zadd_command("scores", &[], &[("1.5", "kiwi")]),
+    ];
+
+    for command in setup_commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    // Each entry takes a length byte, its contents and a back length byte
+    let test_cases = vec![
+        (
+            TestUtils::debug_listpack_command("fruits"),
+            TestUtils::expected_bulk_string(
+                "{total_bytes 29} {num_elements 3}\n{element 0} apple\n{element 1} banana\n{element 2} mango",
+            ),
+        ),
+        (
+            TestUtils::debug_listpack_command("scores"),
+            TestUtils::expected_bulk_string(
+                "{total_bytes 18} {num_elements 2}\n{element 0} kiwi\n{element 1} 1.5",
+            ),
+        ),
+        (
+            TestUtils::invalid_command(&["DEBUG", "LISTPACK-ENTRIES"]),
+            TestUtils::expected_simple_string("OK"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_listpack_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    env.server.write().await.list_max_listpack_size = 3;
+
+    let setup_commands = vec![
+        TestUtils::rpush_command("fruits", &["apple", "banana", "mango", "kiwi"]),
+        TestUtils::set_command("fruit", "mango"),
+    ];
+
+    for command in setup_commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::debug_listpack_command("fruits"),
+            CommandError::NotListpackEncoded,
+        ),
+        (
+            TestUtils::debug_listpack_command("fruit"),
+            CommandError::NotListpackEncoded,
+        ),
+        (
+            TestUtils::debug_listpack_command("missing"),
+            CommandError::NoSuchKey,
+        ),
+        (
+            TestUtils::invalid_command(&["DEBUG", "LISTPACK"]),
+            CommandError::InvalidDebugCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["DEBUG", "LISTPACK-ENTRIES", "fruits"]),
+            CommandError::InvalidDebugCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_reload_command() {
+    let rdb_directory = "/tmp/redis-files-debug-reload";
+    tokio::fs::create_dir_all(rdb_directory).await.unwrap();
+
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.rdb_directory = rdb_directory.to_string();
+        server_guard.rdb_filename = "dump.rdb".to_string();
+    }
+
+    let setup_commands = vec![
+        TestUtils::set_command("fruit", "mango"),
+        TestUtils::set_command_with_expiration("berry", "blueberry", 60000),
+        TestUtils::rpush_command("fruits", &["apple", "banana", "mango"]),
+        TestUtils::xadd_command("events", "1-0", &["type", "start", "fruit", "kiwi"]),
+        TestUtils::xadd_command("events", "2-0", &["type", "stop"]),
+    ];
+
+    for command in setup_commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    // Expirations are saved with millisecond precision
+    let berry_expiration = env.get_store().await["berry"]
+        .expiration
+        .map(|expiration| Timestamp::from_millisecond(expiration.as_millisecond()).unwrap());
+    assert!(berry_expiration.is_some());
+
+    env.exec_command_immediate_success_response(
+        TestUtils::debug_reload_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let expected_store = HashMap::from([
+        (
+            "fruit".to_string(),
+            Value {
+                data: DataType::String("mango".to_string()),
+                expiration: None,
+            },
+        ),
+        (
+            "berry".to_string(),
+            Value {
+                data: DataType::String("blueberry".to_string()),
+                expiration: berry_expiration,
+            },
+        ),
+        (
+            "fruits".to_string(),
+            Value {
+                data: DataType::Array(List::from_iter(
+                    ["apple", "banana", "mango"].map(String::from),
+                )),
+                expiration: None,
+            },
+        ),
+        (
+            "events".to_string(),
+            Value {
+                data: DataType::Stream(Stream::from([
+                    (
+                        "1-0".to_string(),
+                        BTreeMap::from([
+                            ("type".to_string(), "start".to_string()),
+                            ("fruit".to_string(), "kiwi".to_string()),
+                        ]),
+                    ),
+                    (
+                        "2-0".to_string(),
+                        BTreeMap::from([("type".to_string(), "stop".to_string())]),
+                    ),
+                ])),
+                expiration: None,
+            },
+        ),
+    ]);
+
+    assert_eq!(*env.get_store().await, expected_store);
+}
+
+#[tokio::test]
+async fn test_handle_debug_reload_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["DEBUG", "RELOAD", "now"]),
+        &client_address,
+        CommandError::InvalidDebugCommand,
+    )
+    .await;
+
+    // The save fails because the RDB directory does not exist, so the store is left untouched
+    env.server.write().await.rdb_directory = "/tmp/redis-files-missing/debug-reload".to_string();
+    env.exec_command(TestUtils::set_command("fruit", "mango"), &client_address)
+        .await
+        .unwrap();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::debug_reload_command(),
+        &client_address,
+        CommandError::RdbReloadFailed,
+    )
+    .await;
+
+    assert!(env.get_store().await.contains_key("fruit"));
+}
+
+#[tokio::test]
+async fn test_handle_debug_stringmatch_len_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        ("*", "mango", 1),
+        ("m?ngo", "mango", 1),
+        ("m?ngo", "mngo", 0),
+        // Character classes, including ranges, negation and brackets inside a class
+        ("m[a-c]ngo", "mango", 1),
+        ("m[^a]ngo", "mango", 0),
+        ("m[^b]ngo", "mango", 1),
+        ("[[]mango", "[mango", 1),
+        ("[]]mango", "]mango", 1),
+        ("[a-c[]x", "[x", 1),
+        ("[a-c[]x", "dx", 0),
+        // Escaped metacharacters only match themselves
+        ("m\\*ngo", "m*ngo", 1),
+        ("m\\*ngo", "mango", 0),
+        ("m\\?ngo", "m?ngo", 1),
+        ("m\\?ngo", "mango", 0),
+        ("\\[mango\\]", "[mango]", 1),
+        ("\\[mango\\]", "m", 0),
+    ];
+
+    for (pattern, string, expected) in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::debug_stringmatch_len_command(pattern, string),
+            &client_address,
+            &TestUtils::expected_integer(expected),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_debug_stringmatch_len_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["DEBUG", "STRINGMATCH-LEN", "*"]),
+        &client_address,
+        CommandError::InvalidDebugCommand,
+    )
+    .await;
+
+    let Err(CommandError::InvalidGlobPattern(_)) = env
+        .exec_command(
+            TestUtils::debug_stringmatch_len_command("m[a-", "mango"),
+            &client_address,
+        )
+        .await
+    else {
+        panic!("Expected an unclosed character class to be rejected");
+    };
+}
+
+#[tokio::test]
+async fn test_handle_debug_debug_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let started_at = Instant::now();
+    let mut delayed_env = env.clone();
+    let delayed_client_address = client_address.clone();
+    let delayed_task = tokio::spawn(async move {
+        delayed_env
+            .exec_command(
+                TestUtils::debug_debug_command("0.2"),
+                &delayed_client_address,
+            )
+            .await
+    });
+
+    TestUtils::sleep_ms(50).await;
+
+    // Other connections keep being served during the delay
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("fruit", "mango"),
+        &TestUtils::client_address(41845),
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+    assert!(!delayed_task.is_finished());
+
+    let result = TestUtils::wait_for_completion(delayed_task, Duration::from_secs(1)).await;
+    assert_eq!(result, Ok(TestUtils::expected_simple_string("OK")));
+    assert!(started_at.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_handle_debug_debug_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["DEBUG", "DEBUG"]),
+            CommandError::InvalidDebugCommand,
+        ),
+        (
+            TestUtils::debug_debug_command("soon"),
+            CommandError::InvalidDebugCommandArgument,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+async fn master_replid(env: &mut TestEnv, client_address: &str) -> String {
+    let Ok(CommandResult::Response(response)) = env
+        .exec_command(TestUtils::info_command(Some("replication")), client_address)
+        .await
+    else {
+        panic!("Expected INFO replication to succeed");
+    };
+
+    response
+        .split("\r\n")
+        .find_map(|line| line.strip_prefix("master_replid:"))
+        .expect("INFO replication should contain master_replid")
+        .to_string()
+}
@@ -21,11 +21,11 @@ async fn test_handle_echo_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["ECHO"]),
-            CommandError::InvalidEchoCommand,
+            CommandError::WrongNumberOfArguments("ECHO".to_string()),
         ),
         (
             TestUtils::invalid_command(&["ECHO", "grape", "mango"]),
-            CommandError::InvalidEchoCommand,
+            CommandError::WrongNumberOfArguments("ECHO".to_string()),
         ),
     ];
 
@@ -0,0 +1,292 @@
+use codecrafters_redis::{commands::CommandError, resp::RespValue, server::Replica};
+use jiff::{Timestamp, ToSpan};
+use tokio::sync::mpsc;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_expire_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::expire_command("grape", 100),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::expire_command("grape", 100),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::pexpire_command("grape", 100_000),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::get_command("grape"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::expire_command("grape", -1),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::get_command("grape"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let store_guard = env.store.lock().await;
+    assert!(!store_guard.contains_key("grape"));
+}
+
+#[tokio::test]
+async fn test_relative_expirations_are_propagated_as_absolute_timestamps() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    let (sender, mut replication_stream) = mpsc::unbounded_channel();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.replicas.as_mut().unwrap().insert(
+            TestUtils::client_address(6380),
+            Replica { sender, offset: 0 },
+        );
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::set_command_with_expiration("grape", "mango", 60_000),
+            TestUtils::expected_simple_string("OK"),
+            vec!["SET", "grape", "mango", "PXAT"],
+            60.seconds(),
+        ),
+        (
+            TestUtils::expire_command("grape", 100),
+            TestUtils::expected_integer(1),
+            vec!["PEXPIREAT", "grape"],
+            100.seconds(),
+        ),
+        (
+            TestUtils::pexpire_command("grape", 5_000),
+            TestUtils::expected_integer(1),
+            vec!["PEXPIREAT", "grape"],
+            5.seconds(),
+        ),
+    ];
+
+    for (command, expected_response, expected_propagated_prefix, ttl) in test_cases {
+        let started_at = Timestamp::now();
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+        let finished_at = Timestamp::now();
+
+        // The absolute timestamp is the one the master applied to its own copy of the key
+        let expiration = env.store.lock().await["grape"]
+            .expiration
+            .unwrap()
+            .as_millisecond();
+        assert!(expiration >= started_at.checked_add(ttl).unwrap().as_millisecond());
+        assert!(expiration <= finished_at.checked_add(ttl).unwrap().as_millisecond());
+
+        let expiration_ms = expiration.to_string();
+        let mut expected_propagated = expected_propagated_prefix;
+        expected_propagated.push(&expiration_ms);
+
+        assert_eq!(
+            String::from_utf8(replication_stream.try_recv().unwrap()).unwrap(),
+            TestUtils::raw_command(&expected_propagated).encode()
+        );
+    }
+
+    assert!(replication_stream.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_writes_to_expired_keys_start_from_new_values() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = [
+        TestUtils::rpush_command("fruits", &["mango", "kiwi"]),
+        TestUtils::hset_command("basket", &[("mango", "1"), ("kiwi", "2")]),
+        TestUtils::sadd_command("colors", &["green", "yellow"]),
+        TestUtils::xadd_command("events", "5-1", &["fruit", "mango"]),
+        TestUtils::zadd_command("scores", &[], &[("1", "mango"), ("2", "kiwi")]),
+        TestUtils::set_command("count", "41"),
+        TestUtils::bitfield_command("bits", &["SET", "u8", "0", "200"]),
+        TestUtils::pfadd_command("visitors", &["mango", "kiwi"]),
+        TestUtils::pfadd_command("merged", &["mango"]),
+        TestUtils::pfadd_command("old-visitors", &["kiwi"]),
+    ];
+
+    for command in setup_commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    {
+        let mut store_guard = env.store.lock().await;
+        for value in store_guard.values_mut() {
+            value.expiration = Some(Timestamp::now() - 1.second());
+        }
+    }
+
+    // The expired values are neither added to nor made to keep their expiration
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("fruits", &["grape"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::hset_command("basket", &[("grape", "3")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::sadd_command("colors", &["red"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::xadd_command("events", "1-1", &["fruit", "grape"]),
+            TestUtils::expected_bulk_string("1-1"),
+        ),
+        (
+            TestUtils::zadd_command("scores", &[], &[("3", "grape")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::incr_command("count"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::bitfield_command("bits", &["INCRBY", "u8", "0", "1"]),
+            RespValue::Array(vec![RespValue::Integer(1)]).encode(),
+        ),
+        (
+            TestUtils::pfadd_command("visitors", &["mango"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::pfmerge_command("merged", &["old-visitors"]),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::pfcount_command(&["merged"]),
+            TestUtils::expected_integer(0),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let store_guard = env.store.lock().await;
+    assert!(store_guard.values().all(|value| value.expiration.is_none()));
+}
+
+#[tokio::test]
+async fn test_reads_of_expired_keys_find_no_value() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = [
+        TestUtils::rpush_command("fruits", &["mango", "kiwi"]),
+        TestUtils::hset_command("basket", &[("mango", "1"), ("kiwi", "2")]),
+        TestUtils::sadd_command("colors", &["green", "yellow"]),
+        TestUtils::zadd_command("scores", &[], &[("1", "mango"), ("2", "kiwi")]),
+        TestUtils::xadd_command("events", "5-1", &["fruit", "mango"]),
+    ];
+
+    for command in setup_commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    {
+        let mut store_guard = env.store.lock().await;
+        for value in store_guard.values_mut() {
+            value.expiration = Some(Timestamp::now() - 1.second());
+        }
+    }
+
+    // The keys are expired on lookup, before the active expire cycle gets to them
+    let test_cases = vec![
+        (
+            TestUtils::lrange_command("fruits", 0, -1),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::llen_command("fruits"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::hget_command("basket", "mango"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::hgetall_command("basket"),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::sintercard_command(&["colors"], None),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zscore_command("scores", "mango"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zcard_command("scores"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::xlen_command("events"),
+            TestUtils::expected_integer(0),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    assert!(env.store.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_handle_expire_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["EXPIRE", "grape"]),
+            CommandError::WrongNumberOfArguments("EXPIRE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["PEXPIRE", "grape", "1", "NX"]),
+            CommandError::InvalidPexpireCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["EXPIRE", "grape", "soon"]),
+            CommandError::InvalidExpireTime,
+        ),
+        (
+            TestUtils::invalid_command(&["EXPIRE", "grape", &i64::MAX.to_string()]),
+            CommandError::InvalidExpireTime,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,40 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_failover_abort_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    for argument in ["ABORT", "abort"] {
+        env.exec_command_immediate_success_response(
+            TestUtils::failover_command(&[argument]),
+            &client_address,
+            &TestUtils::expected_simple_string("OK"),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_failover_command_not_supported() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        TestUtils::failover_command(&[]),
+        TestUtils::failover_command(&["TO", "127.0.0.1", "6380"]),
+        TestUtils::failover_command(&["FORCE"]),
+        TestUtils::failover_command(&["ABORT", "TIMEOUT", "100"]),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &client_address,
+            CommandError::FailoverNotSupported,
+        )
+        .await;
+    }
+}
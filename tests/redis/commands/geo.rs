@@ -0,0 +1,397 @@
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_geoadd_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::geoadd_command(
+                "Sicily",
+                &[],
+                &[
+                    ("13.361389", "38.115556", "Palermo"),
+                    ("15.087269", "37.502669", "Catania"),
+                ],
+            ),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zscore_command("Sicily", "Palermo"),
+            TestUtils::expected_bulk_string("3479099956230698"),
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &["NX"], &[("13.5", "38.1", "Palermo")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &["XX"], &[("13.5", "38.1", "Agrigento")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &["XX", "CH"], &[("13.5", "38.1", "Palermo")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zcard_command("Sicily"),
+            TestUtils::expected_integer(2),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_geopos_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::geoadd_command(
+            "Sicily",
+            &[],
+            &[
+                ("13.361389", "38.115556", "Palermo"),
+                ("-122.4194", "37.7749", "San Francisco"),
+            ],
+        ),
+        &client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    let result = env
+        .exec_command(
+            TestUtils::geopos_command("Sicily", &["Palermo", "Agrigento", "San Francisco"]),
+            &client_address,
+        )
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected GEOPOS to succeed");
+    };
+
+    let parsed = RespValue::parse(response.split_terminator("\r\n").collect()).unwrap();
+    let [RespValue::Array(positions)] = parsed.as_slice() else {
+        panic!("Expected GEOPOS to reply with an array");
+    };
+
+    let expected_positions = [
+        Some((13.361389, 38.115556)),
+        None,
+        Some((-122.4194, 37.7749)),
+    ];
+    assert_eq!(positions.len(), expected_positions.len());
+
+    for (position, expected_position) in positions.iter().zip(expected_positions) {
+        match (position, expected_position) {
+            (RespValue::Array(coordinates), Some((longitude, latitude))) => {
+                let [
+                    RespValue::BulkString(decoded_longitude),
+                    RespValue::BulkString(decoded_latitude),
+                ] = coordinates.as_slice()
+                else {
+                    panic!("Expected a longitude and latitude pair");
+                };
+
+                assert!((decoded_longitude.parse::<f64>().unwrap() - longitude).abs() < 1e-5);
+                assert!((decoded_latitude.parse::<f64>().unwrap() - latitude).abs() < 1e-5);
+            }
+            (RespValue::NullArray, None) => (),
+            _ => panic!("Unexpected GEOPOS position {:?}", position),
+        }
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::geopos_command("missing", &["Palermo"]),
+        &client_address,
+        &RespValue::Array(vec![RespValue::NullArray]).encode(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_geo_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["GEOADD", "Sicily", "13.361389", "38.115556"]),
+            CommandError::WrongNumberOfArguments("GEOADD".to_string()),
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &["NX", "XX"], &[("1", "1", "Palermo")]),
+            CommandError::IncompatibleZAddNxXxOptions,
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &[], &[("east", "38.115556", "Palermo")]),
+            CommandError::InvalidSortedSetScore,
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &[], &[("181", "10", "Palermo")]),
+            CommandError::InvalidGeoCoordinates("181.000000,10.000000".to_string()),
+        ),
+        (
+            TestUtils::geoadd_command("Sicily", &[], &[("10", "86", "Palermo")]),
+            CommandError::InvalidGeoCoordinates("10.000000,86.000000".to_string()),
+        ),
+        (
+            TestUtils::geoadd_command("grape", &[], &[("1", "1", "Palermo")]),
+            CommandError::WrongType,
+        ),
+        (
+            TestUtils::invalid_command(&["GEOPOS"]),
+            CommandError::WrongNumberOfArguments("GEOPOS".to_string()),
+        ),
+        (
+            TestUtils::geopos_command("grape", &["Palermo"]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+async fn add_sicily_members(env: &mut TestEnv, client_address: &str) {
+    env.exec_command_immediate_success_response(
+        TestUtils::geoadd_command(
+            "Sicily",
+            &[],
+            &[
+                ("13.361389", "38.115556", "Palermo"),
+                ("15.087269", "37.502669", "Catania"),
+                ("13.583333", "37.316667", "Agrigento"),
+            ],
+        ),
+        client_address,
+        &TestUtils::expected_integer(3),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_geodist_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    add_sicily_members(&mut env, &client_address).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Catania", None),
+            TestUtils::expected_bulk_string("166274.1516"),
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Catania", Some("km")),
+            TestUtils::expected_bulk_string("166.2742"),
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Catania", Some("MI")),
+            TestUtils::expected_bulk_string("103.3182"),
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Palermo", Some("ft")),
+            TestUtils::expected_bulk_string("0.0000"),
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Syracuse", None),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::geodist_command("missing", "Palermo", "Catania", None),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_geodist_command_resp3() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    add_sicily_members(&mut env, &client_address).await;
+
+    env.exec_command(TestUtils::hello_command(Some("3")), &client_address)
+        .await
+        .unwrap();
+
+    let test_cases = vec![
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Catania", None),
+            ",166274.1516\r\n",
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Catania", Some("km")),
+            ",166.2742\r\n",
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Syracuse", None),
+            "$-1\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_geosearch_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    add_sicily_members(&mut env, &client_address).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMLONLAT", "15", "37", "BYRADIUS", "200", "km", "ASC"],
+            ),
+            TestUtils::expected_bulk_string_array(&["Catania", "Agrigento", "Palermo"]),
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMLONLAT", "15", "37", "BYRADIUS", "100", "km", "DESC"],
+            ),
+            TestUtils::expected_bulk_string_array(&["Catania"]),
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMMEMBER", "Palermo", "BYRADIUS", "100", "km", "ASC"],
+            ),
+            TestUtils::expected_bulk_string_array(&["Palermo", "Agrigento"]),
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &[
+                    "FROMLONLAT",
+                    "15",
+                    "37",
+                    "BYRADIUS",
+                    "200",
+                    "km",
+                    "COUNT",
+                    "2",
+                ],
+            ),
+            TestUtils::expected_bulk_string_array(&["Catania", "Agrigento"]),
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMLONLAT", "0", "0", "BYRADIUS", "10", "m"],
+            ),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::geosearch_command(
+                "missing",
+                &["FROMLONLAT", "15", "37", "BYRADIUS", "200", "km"],
+            ),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_geodist_and_geosearch_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    add_sicily_members(&mut env, &client_address).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["GEODIST", "Sicily", "Palermo"]),
+            CommandError::WrongNumberOfArguments("GEODIST".to_string()),
+        ),
+        (
+            TestUtils::geodist_command("Sicily", "Palermo", "Catania", Some("yards")),
+            CommandError::UnsupportedGeoUnit,
+        ),
+        (
+            TestUtils::geosearch_command("Sicily", &["BYRADIUS", "200", "km"]),
+            CommandError::WrongNumberOfArguments("GEOSEARCH".to_string()),
+        ),
+        (
+            TestUtils::geosearch_command("Sicily", &["FROMMEMBER", "Palermo"]),
+            CommandError::WrongNumberOfArguments("GEOSEARCH".to_string()),
+        ),
+        (
+            TestUtils::geosearch_command("Sicily", &["FROMMEMBER", "Palermo", "ASC", "COUNT", "2"]),
+            CommandError::InvalidGeoSearchCommand,
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMMEMBER", "Syracuse", "BYRADIUS", "200", "km"],
+            ),
+            CommandError::GeoMemberNotFound,
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMMEMBER", "Palermo", "BYRADIUS", "far", "km"],
+            ),
+            CommandError::InvalidGeoSearchRadius,
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &["FROMMEMBER", "Palermo", "BYRADIUS", "200", "yards"],
+            ),
+            CommandError::UnsupportedGeoUnit,
+        ),
+        (
+            TestUtils::geosearch_command(
+                "Sicily",
+                &[
+                    "FROMMEMBER",
+                    "Palermo",
+                    "BYRADIUS",
+                    "200",
+                    "km",
+                    "COUNT",
+                    "0",
+                ],
+            ),
+            CommandError::InvalidGeoSearchCount,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -99,11 +99,11 @@ async fn test_handle_get_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["GET"]),
-            CommandError::InvalidGetCommand,
+            CommandError::WrongNumberOfArguments("GET".to_string()),
         ),
         (
             TestUtils::invalid_command(&["GET", "grape", "mango"]),
-            CommandError::InvalidGetCommand,
+            CommandError::WrongNumberOfArguments("GET".to_string()),
         ),
     ];
 
@@ -117,6 +117,27 @@ async fn test_handle_get_command_invalid() {
     }
 }
 
+#[tokio::test]
+async fn test_handle_get_command_wrong_number_of_arguments() {
+    let mut env = TestEnv::new_master_server();
+
+    let result = env
+        .exec_command(
+            TestUtils::invalid_command(&["GET"]),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+
+    let Err(err) = result else {
+        panic!("Expected GET with the wrong number of arguments to fail");
+    };
+
+    assert_eq!(
+        err.as_string(),
+        "-ERR wrong number of arguments for 'get' command\r\n"
+    );
+}
+
 #[tokio::test]
 async fn test_handle_get_command_not_found() {
     let mut env = TestEnv::new_master_server();
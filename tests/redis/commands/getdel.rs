@@ -0,0 +1,137 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_getdel_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::getdel_command("fruit"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::set_command("fruit", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::getdel_command("fruit"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::type_command("fruit"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::getdel_command("fruit"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_getdel_command_after_in_place_modifications() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    // Growing an integer in place makes it raw, and GETDEL returns every appended byte
+    let test_cases = vec![
+        (
+            TestUtils::set_command("count", "42"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::object_encoding_command("count"),
+            TestUtils::expected_bulk_string("int"),
+        ),
+        (
+            TestUtils::append_command("count", "7"),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::object_encoding_command("count"),
+            TestUtils::expected_bulk_string("raw"),
+        ),
+        (
+            TestUtils::get_command("count"),
+            TestUtils::expected_bulk_string("427"),
+        ),
+        (
+            TestUtils::setrange_command("count", 3, "0"),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::object_encoding_command("count"),
+            TestUtils::expected_bulk_string("raw"),
+        ),
+        (
+            TestUtils::getdel_command("count"),
+            TestUtils::expected_bulk_string("4270"),
+        ),
+        (
+            TestUtils::get_command("count"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::set_command("number", "12"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::setrange_command("number", 0, "3"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("number"),
+            TestUtils::expected_bulk_string("raw"),
+        ),
+        (
+            TestUtils::getdel_command("number"),
+            TestUtils::expected_bulk_string("32"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    // Deleted keys are no longer remembered as modified in place
+    assert!(env.server.read().await.raw_string_keys.is_empty());
+}
+
+#[tokio::test]
+async fn test_handle_getdel_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let test_cases = vec![
+        (TestUtils::getdel_command("fruits"), CommandError::WrongType),
+        (
+            TestUtils::invalid_command(&["GETDEL"]),
+            CommandError::WrongNumberOfArguments("GETDEL".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["GETDEL", "fruits", "grape"]),
+            CommandError::WrongNumberOfArguments("GETDEL".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use codecrafters_redis::{commands::CommandError, key_value_store::DataType};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_getset_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::getset_command("grape", "mango"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::getset_command("grape", "apple"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::get_command("grape"),
+            TestUtils::expected_bulk_string("apple"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_getset_command_clears_expiration() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command_with_expiration("grape", "mango", 100),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::getset_command("grape", "apple"),
+        &client_address,
+        &TestUtils::expected_bulk_string("mango"),
+    )
+    .await;
+
+    let store_guard = env.get_store().await;
+    let value = store_guard.get("grape").unwrap();
+    assert_eq!(value.data, DataType::String("apple".to_string()));
+    assert_eq!(value.expiration, None);
+    drop(store_guard);
+
+    // An expired previous value is treated as missing
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command_with_expiration("raspberry", "kiwi", 50),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::getset_command("raspberry", "pear"),
+        &client_address,
+        &TestUtils::expected_null_bulk_string(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_getset_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["GETSET", "grape"]),
+            CommandError::WrongNumberOfArguments("GETSET".to_string()),
+        ),
+        (
+            TestUtils::getset_command("fruits", "mango"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::llen_command("fruits"),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+}
@@ -0,0 +1,120 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_hello_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            None,
+            "*12\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.4.0\r\n$5\r\nproto\r\n:2\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n$4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n",
+        ),
+        (
+            Some("3"),
+            "%6\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.4.0\r\n$5\r\nproto\r\n:3\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n$4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n",
+        ),
+        (
+            None,
+            "%6\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.4.0\r\n$5\r\nproto\r\n:3\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n$4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n",
+        ),
+        (
+            Some("2"),
+            "*12\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.4.0\r\n$5\r\nproto\r\n:2\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n$4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n",
+        ),
+    ];
+
+    for (protocol_version, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::hello_command(protocol_version),
+            &client_address,
+            expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hello_command_with_auth() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    env.server.write().await.requirepass = Some("secret".to_string());
+
+    let test_cases = vec![
+        TestUtils::hello_auth_command("3", "default", "wrong"),
+        TestUtils::hello_auth_command("3", "admin", "secret"),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &client_address,
+            CommandError::WrongPassword,
+        )
+        .await;
+    }
+
+    // The failed attempts neither authenticated the connection nor switched its protocol
+    {
+        let server_guard = env.server.read().await;
+        assert!(!server_guard.resp3_clients.contains(&client_address));
+        assert!(
+            !server_guard
+                .clients
+                .get(&client_address)
+                .is_some_and(|client| client.is_authenticated)
+        );
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hello_auth_command("3", "default", "secret"),
+        &client_address,
+        "%6\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.4.0\r\n$5\r\nproto\r\n:3\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n$4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n",
+    )
+    .await;
+
+    let server_guard = env.server.read().await;
+    assert!(server_guard.resp3_clients.contains(&client_address));
+    assert!(server_guard.clients[&client_address].is_authenticated);
+
+    assert_eq!(
+        CommandError::WrongPassword.as_string(),
+        "-WRONGPASS invalid username-password pair or user is disabled.\r\n"
+    );
+}
+
+#[tokio::test]
+async fn test_handle_hello_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::hello_command(Some("4")),
+            CommandError::UnsupportedProtocolVersion,
+        ),
+        (
+            TestUtils::hello_command(Some("three")),
+            CommandError::InvalidHelloCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["HELLO", "3", "extra"]),
+            CommandError::InvalidHelloCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["HELLO", "3", "AUTH", "default"]),
+            CommandError::InvalidHelloCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["HELLO", "AUTH", "default", "secret"]),
+            CommandError::InvalidHelloCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
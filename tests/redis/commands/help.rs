@@ -0,0 +1,69 @@
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_config_help_command() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::help_command("CONFIG"),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_bulk_string_array(&[
+            "CONFIG <subcommand> [<arg> [value] [opt] ...]. Subcommands are:",
+            "GET <pattern>",
+            "    Return parameters matching the glob-like <pattern> and their values.",
+            "HELP",
+            "    Print this help.",
+        ]),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_help_command_for_each_family() {
+    let mut env = TestEnv::new_master_server();
+
+    for family in ["client", "CONFIG", "Debug"] {
+        let result = env
+            .exec_command(
+                TestUtils::help_command(family),
+                &TestUtils::client_address(41844),
+            )
+            .await;
+
+        let Ok(CommandResult::Response(response)) = result else {
+            panic!("Expected {} HELP to succeed", family);
+        };
+
+        let usage_line = format!(
+            "{} <subcommand> [<arg> [value] [opt] ...]. Subcommands are:",
+            family.to_uppercase()
+        );
+
+        assert!(
+            response.starts_with('*'),
+            "Expected an array reply for {} HELP",
+            family
+        );
+        assert!(response.contains(&RespValue::BulkString(usage_line).encode()));
+        assert!(
+            response.ends_with(&RespValue::BulkString("    Print this help.".to_string()).encode())
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_handle_help_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["CLIENT", "HELP", "INFO"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("CLIENT HELP".to_string()),
+    )
+    .await;
+}
@@ -0,0 +1,108 @@
+use codecrafters_redis::commands::{CommandError, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn exec_hgetall(env: &mut TestEnv, key: &str, client_address: &str) -> String {
+    let result = env
+        .exec_command(TestUtils::hgetall_command(key), client_address)
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected HGETALL to succeed");
+    };
+
+    response
+}
+
+#[tokio::test]
+async fn test_handle_hgetall_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &[("mango", "yellow"), ("grape", "purple")]),
+        &client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    // Hash fields have no defined order
+    let response = exec_hgetall(&mut env, "fruits", &client_address).await;
+    assert!(
+        response == "*4\r\n$5\r\nmango\r\n$6\r\nyellow\r\n$5\r\ngrape\r\n$6\r\npurple\r\n"
+            || response == "*4\r\n$5\r\ngrape\r\n$6\r\npurple\r\n$5\r\nmango\r\n$6\r\nyellow\r\n",
+        "Unexpected HGETALL response {:?}",
+        response
+    );
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hgetall_command("vegetables"),
+        &client_address,
+        "*0\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hgetall_command_resp3() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(TestUtils::hello_command(Some("3")), &client_address)
+        .await
+        .unwrap();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &[("mango", "yellow"), ("grape", "purple")]),
+        &client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    let response = exec_hgetall(&mut env, "fruits", &client_address).await;
+    assert!(
+        response == "%2\r\n$5\r\nmango\r\n$6\r\nyellow\r\n$5\r\ngrape\r\n$6\r\npurple\r\n"
+            || response == "%2\r\n$5\r\ngrape\r\n$6\r\npurple\r\n$5\r\nmango\r\n$6\r\nyellow\r\n",
+        "Unexpected HGETALL response {:?}",
+        response
+    );
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hgetall_command("vegetables"),
+        &client_address,
+        "%0\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hgetall_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::hgetall_command("fruits"),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hgetall_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["HGETALL"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("HGETALL".to_string()),
+    )
+    .await;
+}
@@ -0,0 +1,64 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_hmget_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &[("mango", "yellow"), ("grape", "purple")]),
+        &client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::hmget_command("fruits", &["grape", "banana", "mango"]),
+            "*3\r\n$6\r\npurple\r\n$-1\r\n$6\r\nyellow\r\n",
+        ),
+        (
+            TestUtils::hmget_command("vegetables", &["carrot", "potato"]),
+            "*2\r\n$-1\r\n$-1\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hmget_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::hmget_command("fruits", &["mango"]),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hmget_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["HMGET", "fruits"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("HMGET".to_string()),
+    )
+    .await;
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn exec_hrandfield(env: &mut TestEnv, options: &[&str]) -> Vec<String> {
+    let result = env
+        .exec_command(
+            TestUtils::hrandfield_command("fruits", options),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected HRANDFIELD to succeed");
+    };
+
+    let parsed = RespValue::parse(response.split_terminator("\r\n").collect()).unwrap();
+
+    match parsed.as_slice() {
+        [RespValue::BulkString(field)] => vec![field.clone()],
+        [RespValue::Array(items)] => items
+            .iter()
+            .map(|item| match item {
+                RespValue::BulkString(item) => item.clone(),
+                _ => panic!("Expected HRANDFIELD to reply with bulk strings"),
+            })
+            .collect(),
+        _ => panic!("Unexpected HRANDFIELD response {:?}", parsed),
+    }
+}
+
+async fn setup_fruits(env: &mut TestEnv) -> HashMap<String, String> {
+    let fruits = [("mango", "yellow"), ("grape", "purple"), ("kiwi", "green")];
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &fruits),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(3),
+    )
+    .await;
+
+    fruits
+        .iter()
+        .map(|(field, value)| (field.to_string(), value.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_handle_hrandfield_command_without_count() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    let fields = exec_hrandfield(&mut env, &[]).await;
+    assert_eq!(fields.len(), 1);
+    assert!(fruits.contains_key(&fields[0]));
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hrandfield_command("vegetables", &[]),
+        &TestUtils::client_address(41844),
+        "$-1\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hrandfield_command_distinct_fields() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    for (count, expected_length) in [("2", 2), ("3", 3), ("10", 3)] {
+        let fields = exec_hrandfield(&mut env, &[count]).await;
+        let distinct_fields: HashSet<&String> = fields.iter().collect();
+
+        assert_eq!(fields.len(), expected_length);
+        assert_eq!(distinct_fields.len(), expected_length);
+        assert!(fields.iter().all(|field| fruits.contains_key(field)));
+    }
+
+    let test_cases = vec![
+        (TestUtils::hrandfield_command("fruits", &["0"]), "*0\r\n"),
+        (
+            TestUtils::hrandfield_command("vegetables", &["2"]),
+            "*0\r\n",
+        ),
+        (
+            TestUtils::hrandfield_command("vegetables", &["-2"]),
+            "*0\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hrandfield_command_repeating_fields() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    let fields = exec_hrandfield(&mut env, &["-10"]).await;
+    let distinct_fields: HashSet<&String> = fields.iter().collect();
+
+    // Ten fields picked out of three must repeat some of them
+    assert_eq!(fields.len(), 10);
+    assert!(distinct_fields.len() <= 3);
+    assert!(fields.iter().all(|field| fruits.contains_key(field)));
+}
+
+#[tokio::test]
+async fn test_handle_hrandfield_command_with_values() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    for (count, expected_pairs) in [("2", 2), ("-5", 5)] {
+        let items = exec_hrandfield(&mut env, &[count, "WITHVALUES"]).await;
+        assert_eq!(items.len(), expected_pairs * 2);
+
+        for pair in items.chunks(2) {
+            assert_eq!(fruits.get(&pair[0]), Some(&pair[1]));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hrandfield_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::hrandfield_command("fruits", &[]),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hrandfield_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["HRANDFIELD"]),
+            CommandError::WrongNumberOfArguments("HRANDFIELD".to_string()),
+        ),
+        (
+            TestUtils::hrandfield_command("fruits", &["WITHVALUES"]),
+            CommandError::InvalidHRandFieldCount,
+        ),
+        (
+            TestUtils::hrandfield_command("fruits", &["2", "WITHSCORES"]),
+            CommandError::InvalidHRandFieldCommand,
+        ),
+        (
+            TestUtils::hrandfield_command("fruits", &["2", "WITHVALUES", "extra"]),
+            CommandError::InvalidHRandFieldCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
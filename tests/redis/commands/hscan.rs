@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+/// Runs HSCAN and returns the next cursor along with the reply's elements
+async fn exec_hscan(env: &mut TestEnv, cursor: &str, options: &[&str]) -> (String, Vec<String>) {
+    let result = env
+        .exec_command(
+            TestUtils::hscan_command("fruits", cursor, options),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected HSCAN to succeed");
+    };
+
+    let parsed = RespValue::parse(response.split_terminator("\r\n").collect()).unwrap();
+
+    let [RespValue::Array(reply)] = parsed.as_slice() else {
+        panic!("Unexpected HSCAN response {:?}", parsed);
+    };
+
+    let [RespValue::BulkString(next_cursor), RespValue::Array(items)] = reply.as_slice() else {
+        panic!("Unexpected HSCAN response {:?}", reply);
+    };
+
+    let items = items
+        .iter()
+        .map(|item| match item {
+            RespValue::BulkString(item) => item.clone(),
+            _ => panic!("Expected HSCAN to reply with bulk strings"),
+        })
+        .collect();
+
+    (next_cursor.clone(), items)
+}
+
+#[tokio::test]
+async fn test_handle_hscan_command_with_match_across_calls() {
+    let mut env = TestEnv::new_master_server();
+
+    let fields = (0..300)
+        .map(|i| {
+            let field = if i % 3 == 0 {
+                format!("mango:{}", i)
+            } else {
+                format!("kiwi:{}", i)
+            };
+
+            (field, i.to_string())
+        })
+        .collect::<Vec<(String, String)>>();
+    let field_refs = fields
+        .iter()
+        .map(|(field, value)| (field.as_str(), value.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &field_refs),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(300),
+    )
+    .await;
+
+    let mut cursor = "0".to_string();
+    let mut number_of_calls = 0;
+    let mut scanned = HashMap::new();
+
+    loop {
+        let (next_cursor, items) =
+            exec_hscan(&mut env, &cursor, &["MATCH", "mango:*", "COUNT", "25"]).await;
+        number_of_calls += 1;
+
+        for pair in items.chunks(2) {
+            assert!(pair[0].starts_with("mango:"));
+            assert!(scanned.insert(pair[0].clone(), pair[1].clone()).is_none());
+        }
+
+        if next_cursor == "0" {
+            break;
+        }
+
+        cursor = next_cursor;
+    }
+
+    assert_eq!(number_of_calls, 12);
+    assert_eq!(
+        scanned,
+        fields
+            .into_iter()
+            .filter(|(field, _)| field.starts_with("mango:"))
+            .collect::<HashMap<String, String>>()
+    );
+}
+
+#[tokio::test]
+async fn test_handle_hscan_command() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command(
+            "fruits",
+            &[("mango", "yellow"), ("grape", "purple"), ("kiwi", "green")],
+        ),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(3),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            "0",
+            vec![],
+            "0",
+            vec!["grape", "purple", "kiwi", "green", "mango", "yellow"],
+        ),
+        (
+            "0",
+            vec!["COUNT", "2"],
+            "2",
+            vec!["grape", "purple", "kiwi", "green"],
+        ),
+        ("2", vec!["count", "2"], "0", vec!["mango", "yellow"]),
+        ("0", vec!["NOVALUES"], "0", vec!["grape", "kiwi", "mango"]),
+        ("0", vec!["match", "*i*", "novalues"], "0", vec!["kiwi"]),
+        ("7", vec![], "0", vec![]),
+    ];
+
+    for (cursor, options, expected_cursor, expected_items) in test_cases {
+        let (next_cursor, items) = exec_hscan(&mut env, cursor, &options).await;
+
+        assert_eq!(next_cursor, expected_cursor);
+        assert_eq!(items, expected_items);
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hscan_command("vegetables", "0", &[]),
+        &TestUtils::client_address(41844),
+        "*2\r\n$1\r\n0\r\n*0\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hscan_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::sadd_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::hscan_command("fruits", "0", &[]),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_hscan_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["HSCAN", "fruits"]),
+            CommandError::WrongNumberOfArguments("HSCAN".to_string()),
+        ),
+        (
+            TestUtils::hscan_command("fruits", "-1", &[]),
+            CommandError::InvalidScanCursor,
+        ),
+        (
+            TestUtils::hscan_command("fruits", "0", &["COUNT", "many"]),
+            CommandError::InvalidScanCount,
+        ),
+        (
+            TestUtils::hscan_command("fruits", "0", &["COUNT", "0"]),
+            CommandError::ScanSyntaxError,
+        ),
+        (
+            TestUtils::hscan_command("fruits", "0", &["MATCH"]),
+            CommandError::ScanSyntaxError,
+        ),
+        (
+            TestUtils::hscan_command("fruits", "0", &["WITHVALUES"]),
+            CommandError::ScanSyntaxError,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,98 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_hset_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::hset_command("fruits", &[("mango", "yellow"), ("grape", "purple")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::hset_command("fruits", &[("mango", "green"), ("apple", "red")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::hget_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("green"),
+        ),
+        (
+            TestUtils::hget_command("fruits", "banana"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::hget_command("vegetables", "carrot"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("hash"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hset_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        TestUtils::hset_command("grape", &[("mango", "yellow")]),
+        TestUtils::hget_command("grape", "mango"),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &client_address,
+            CommandError::WrongType,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hset_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["HSET", "fruits"]),
+            CommandError::WrongNumberOfArguments("HSET".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["HSET", "fruits", "mango"]),
+            CommandError::WrongNumberOfArguments("HSET".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["HSET", "fruits", "mango", "yellow", "grape"]),
+            CommandError::InvalidHSetCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["HGET", "fruits"]),
+            CommandError::WrongNumberOfArguments("HGET".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,53 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_hsetnx_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::hsetnx_command("fruits", "mango", "yellow"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::hsetnx_command("fruits", "mango", "green"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::hsetnx_command("fruits", "grape", "purple"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::hget_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("yellow"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hsetnx_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        TestUtils::invalid_command(&["HSETNX", "fruits", "mango"]),
+        TestUtils::invalid_command(&["HSETNX", "fruits", "mango", "yellow", "green"]),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &client_address,
+            CommandError::WrongNumberOfArguments("HSETNX".to_string()),
+        )
+        .await;
+    }
+}
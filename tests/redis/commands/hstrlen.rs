@@ -0,0 +1,44 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_hstrlen_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &[("mango", "yellow"), ("kiwi", "")]),
+        &client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    let test_cases = vec![
+        (TestUtils::hstrlen_command("fruits", "mango"), 6),
+        (TestUtils::hstrlen_command("fruits", "kiwi"), 0),
+        (TestUtils::hstrlen_command("fruits", "banana"), 0),
+        (TestUtils::hstrlen_command("vegetables", "carrot"), 0),
+    ];
+
+    for (command, expected_length) in test_cases {
+        env.exec_command_immediate_success_response(
+            command,
+            &client_address,
+            &TestUtils::expected_integer(expected_length),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_hstrlen_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["HSTRLEN", "fruits"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("HSTRLEN".to_string()),
+    )
+    .await;
+}
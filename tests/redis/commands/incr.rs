@@ -52,11 +52,11 @@ async fn test_handle_incr_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["INCR"]),
-            CommandError::InvalidIncrCommand,
+            CommandError::WrongNumberOfArguments("INCR".to_string()),
         ),
         (
             TestUtils::invalid_command(&["INCR", "grape", "mango"]),
-            CommandError::InvalidIncrCommand,
+            CommandError::WrongNumberOfArguments("INCR".to_string()),
         ),
     ];
 
@@ -115,3 +115,54 @@ async fn test_handle_incr_command_invalid_value() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn test_handle_incr_command_non_canonical_or_overflowing_value() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    let max_value = i64::MAX.to_string();
+
+    for value in [" 10", "10 ", "+10", "010", "-0", "10.5", max_value.as_str()] {
+        env.exec_command_immediate_success_response(
+            TestUtils::set_command("grape", value),
+            &client_address,
+            &TestUtils::expected_simple_string("OK"),
+        )
+        .await;
+
+        let result = env
+            .exec_command(TestUtils::incr_command("grape"), &client_address)
+            .await;
+
+        let Err(err) = result else {
+            panic!("Expected INCR of {:?} to fail", value);
+        };
+
+        assert_eq!(err, CommandError::InvalidIncrValue);
+        assert_eq!(
+            err.as_string(),
+            "-ERR value is not an integer or out of range\r\n"
+        );
+
+        // The stored value is left untouched
+        let store_guard = env.get_store().await;
+        assert_eq!(
+            store_guard.get("grape").map(|value| &value.data),
+            Some(&DataType::String(value.to_string()))
+        );
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "-10"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::incr_command("grape"),
+        &client_address,
+        &TestUtils::expected_integer(-9),
+    )
+    .await;
+}
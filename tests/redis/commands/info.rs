@@ -1,4 +1,5 @@
 use codecrafters_redis::commands::CommandError;
+use jiff::Timestamp;
 
 use crate::test_utils::{TestEnv, TestUtils};
 
@@ -75,3 +76,122 @@ async fn test_handle_info_command_invalid_section() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn test_handle_info_command_stats_keyspace_counters() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (TestUtils::get_command("grape"), "$-1\r\n"),
+        (TestUtils::set_command("grape", "mango"), "+OK\r\n"),
+        (TestUtils::get_command("grape"), "$5\r\nmango\r\n"),
+        (
+            TestUtils::info_command(Some("stats")),
+            "$108\r\ntotal_connections_received:0\r\ntotal_commands_processed:4\r\nkeyspace_hits:1\r\nkeyspace_misses:1\r\nexpired_keys:0\r\n",
+        ),
+    ];
+
+    for (command, response) in test_cases {
+        env.exec_command_immediate_success_response(
+            command,
+            &TestUtils::client_address(41844),
+            response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_info_command_keyspace() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    // An empty database is left out of the section
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("keyspace")),
+        &client_address,
+        &TestUtils::expected_bulk_string(""),
+    )
+    .await;
+
+    let commands = vec![
+        TestUtils::set_command("grape", "mango"),
+        TestUtils::set_command_with_expiration("apple", "banana", 10000),
+        TestUtils::rpush_command("fruits", &["kiwi"]),
+    ];
+
+    for command in commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("keyspace")),
+        &client_address,
+        &TestUtils::expected_bulk_string("db0:keys=3,expires=1,avg_ttl=0"),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_info_command_persistence() {
+    let rdb_directory = "/tmp/redis-files-info-persistence";
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    tokio::fs::create_dir_all(rdb_directory).await.unwrap();
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.rdb_directory = rdb_directory.to_string();
+        server_guard.rdb_filename = "dump.rdb".to_string();
+        server_guard.last_save_time = Timestamp::UNIX_EPOCH;
+    }
+
+    let expected_persistence = |changes: u64, last_save_time: i64| {
+        TestUtils::expected_bulk_string(&format!(
+            "loading:0\r\nrdb_changes_since_last_save:{}\r\nrdb_last_save_time:{}\r\naof_enabled:0\r\naof_last_bgrewrite_status:ok",
+            changes, last_save_time
+        ))
+    };
+
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("persistence")),
+        &client_address,
+        &expected_persistence(0, 0),
+    )
+    .await;
+
+    // Only writes count as changes
+    let commands = vec![
+        TestUtils::set_command("grape", "mango"),
+        TestUtils::get_command("grape"),
+        TestUtils::rpush_command("fruits", &["kiwi"]),
+    ];
+
+    for command in commands {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("persistence")),
+        &client_address,
+        &expected_persistence(2, 0),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::save_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let last_save_time = env.server.read().await.last_save_time.as_second();
+    assert!(last_save_time > 0);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("persistence")),
+        &client_address,
+        &expected_persistence(0, last_save_time),
+    )
+    .await;
+}
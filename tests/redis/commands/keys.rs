@@ -57,11 +57,11 @@ async fn test_handle_echo_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["KEYS"]),
-            CommandError::InvalidKeysCommand,
+            CommandError::WrongNumberOfArguments("KEYS".to_string()),
         ),
         (
             TestUtils::invalid_command(&["KEYS", "grape", "mango"]),
-            CommandError::InvalidKeysCommand,
+            CommandError::WrongNumberOfArguments("KEYS".to_string()),
         ),
     ];
 
@@ -0,0 +1,97 @@
+use codecrafters_redis::commands::CommandResult;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+/// Runs the same commands with their keyword options written in upper, lower and mixed
+/// case, each time on a new server, and checks that every run replies identically
+#[tokio::test]
+async fn test_keyword_options_are_case_insensitive() {
+    let client_address = TestUtils::client_address(41844);
+
+    let commands: Vec<Vec<&str>> = vec![
+        vec!["SET", "fruit", "mango", "Px", "100000"],
+        vec!["RPUSH", "numbers", "3", "1", "2"],
+        vec!["SORT", "numbers", "Desc"],
+        vec!["SORT", "numbers", "Alpha", "Limit", "0", "2"],
+        vec!["ZADD", "scores", "Nx", "Ch", "1", "mango"],
+        vec!["ZADD", "scores", "Xx", "2", "mango"],
+        vec!["ZRANGE", "scores", "0", "-1", "WithScores"],
+        vec!["XADD", "events", "MaxLen", "5", "1-1", "type", "start"],
+        vec![
+            "XREAD", "Count", "1", "Block", "10", "Streams", "events", "0-0",
+        ],
+        vec!["HSET", "prices", "mango", "3"],
+        vec!["HRANDFIELD", "prices", "1", "WithValues"],
+        vec!["SINTERCARD", "1", "missing", "Limit", "1"],
+        vec![
+            "BITFIELD", "bits", "Overflow", "Sat", "IncrBy", "u8", "0", "300",
+        ],
+        vec!["MEMORY", "USAGE", "fruit", "Samples", "5"],
+        vec!["CLIENT", "NO-TOUCH", "On"],
+    ];
+
+    let mut responses_per_case = Vec::new();
+
+    for to_case in [
+        |keyword: &str| keyword.to_uppercase(),
+        |keyword: &str| keyword.to_lowercase(),
+        |keyword: &str| keyword.to_string(),
+    ] {
+        let mut env = TestEnv::new_master_server();
+        let mut responses = Vec::new();
+
+        for command in &commands {
+            // Only the options are recased, as keys, members and values are case-sensitive
+            let args = command
+                .iter()
+                .enumerate()
+                .map(|(index, arg)| {
+                    if index > 0 && arg.chars().any(|c| c.is_ascii_uppercase()) {
+                        to_case(arg)
+                    } else {
+                        arg.to_string()
+                    }
+                })
+                .collect::<Vec<String>>();
+            let args = args.iter().map(|arg| arg.as_str()).collect::<Vec<&str>>();
+
+            match env
+                .exec_command(TestUtils::raw_command(&args), &client_address)
+                .await
+            {
+                Ok(CommandResult::Response(response)) => responses.push(response),
+                result => panic!("Expected {:?} to succeed, got {:?}", args, result),
+            }
+        }
+
+        responses_per_case.push(responses);
+    }
+
+    assert_eq!(responses_per_case[0], responses_per_case[1]);
+    assert_eq!(responses_per_case[0], responses_per_case[2]);
+    assert_eq!(
+        responses_per_case[0][2],
+        TestUtils::expected_bulk_string_array(&["3", "2", "1"])
+    );
+    assert_eq!(
+        responses_per_case[0][6],
+        TestUtils::expected_bulk_string_array(&["mango", "2"])
+    );
+}
+
+#[tokio::test]
+async fn test_info_sections_are_case_insensitive() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    for section in ["replication", "REPLICATION", "Replication"] {
+        let Ok(CommandResult::Response(response)) = env
+            .exec_command(TestUtils::info_command(Some(section)), &client_address)
+            .await
+        else {
+            panic!("Expected INFO {} to succeed", section);
+        };
+
+        assert!(response.contains("role:master"));
+    }
+}
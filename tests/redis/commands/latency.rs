@@ -0,0 +1,113 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_latency_command_records_slow_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::debug_sleep_command("0.05"),
+        &client_address,
+        "+OK\r\n",
+    )
+    .await;
+
+    // The monitor is disabled until a threshold is set
+    env.exec_command_immediate_success_response(
+        TestUtils::latency_command("LATEST", &[]),
+        &client_address,
+        "*0\r\n",
+    )
+    .await;
+
+    env.server.write().await.latency_monitor_threshold = 20;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::debug_sleep_command("0.05"),
+        &client_address,
+        "+OK\r\n",
+    )
+    .await;
+
+    let (timestamp, latency_ms) = {
+        let server_guard = env.server.read().await;
+        let history = server_guard.latency_monitor.history("command");
+        assert_eq!(history.len(), 1);
+        (history[0].timestamp, history[0].latency_ms)
+    };
+    assert!(latency_ms >= 50);
+
+    let test_cases = vec![
+        (
+            TestUtils::latency_command("LATEST", &[]),
+            format!(
+                "*1\r\n*4\r\n$7\r\ncommand\r\n:{}\r\n:{}\r\n:{}\r\n",
+                timestamp, latency_ms, latency_ms
+            ),
+        ),
+        (
+            TestUtils::latency_command("HISTORY", &["command"]),
+            format!("*1\r\n*2\r\n:{}\r\n:{}\r\n", timestamp, latency_ms),
+        ),
+        (
+            TestUtils::latency_command("HISTORY", &["expire-cycle"]),
+            "*0\r\n".to_string(),
+        ),
+        (
+            TestUtils::latency_command("RESET", &["expire-cycle"]),
+            ":0\r\n".to_string(),
+        ),
+        (
+            TestUtils::latency_command("RESET", &[]),
+            ":1\r\n".to_string(),
+        ),
+        (
+            TestUtils::latency_command("LATEST", &[]),
+            "*0\r\n".to_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_latency_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::latency_command("LATEST", &["command"]),
+            CommandError::WrongNumberOfArguments("LATENCY LATEST".to_string()),
+        ),
+        (
+            TestUtils::latency_command("HISTORY", &[]),
+            CommandError::WrongNumberOfArguments("LATENCY HISTORY".to_string()),
+        ),
+        (
+            TestUtils::latency_command("HISTORY", &["command", "expire-cycle"]),
+            CommandError::WrongNumberOfArguments("LATENCY HISTORY".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+
+    let result = env
+        .exec_command(
+            TestUtils::latency_command("DOCTOR", &[]),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+    assert!(matches!(result, Err(CommandError::InvalidCommandArgument)));
+}
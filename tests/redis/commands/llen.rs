@@ -44,10 +44,10 @@ async fn test_handle_llen_command_wrong_data_type() {
     )
     .await;
 
-    env.exec_command_immediate_success_response(
+    env.exec_command_immediate_error_response(
         TestUtils::llen_command("grape"),
         &TestUtils::client_address(41844),
-        &TestUtils::expected_integer(0),
+        CommandError::WrongType,
     )
     .await;
 }
@@ -59,11 +59,11 @@ async fn test_handle_llen_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["LLEN"]),
-            CommandError::InvalidLLenCommand,
+            CommandError::WrongNumberOfArguments("LLEN".to_string()),
         ),
         (
             TestUtils::invalid_command(&["LLEN", "grape", "mango"]),
-            CommandError::InvalidLLenCommand,
+            CommandError::WrongNumberOfArguments("LLEN".to_string()),
         ),
     ];
 
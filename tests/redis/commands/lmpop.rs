@@ -0,0 +1,119 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_lmpop_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::lmpop_command(&["empty", "fruits"], "LEFT", None),
+            TestUtils::expected_null_array(),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["mango", "kiwi", "grape", "apple"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::rpush_command("basket", &["pear"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::lmpop_command(&["empty", "fruits", "basket"], "LEFT", Some("2")),
+            "*2\r\n$6\r\nfruits\r\n*2\r\n$5\r\nmango\r\n$4\r\nkiwi\r\n".to_string(),
+        ),
+        (
+            TestUtils::lmpop_command(&["fruits", "basket"], "RIGHT", None),
+            "*2\r\n$6\r\nfruits\r\n*1\r\n$5\r\napple\r\n".to_string(),
+        ),
+        (
+            TestUtils::lmpop_command(&["fruits", "basket"], "right", Some("10")),
+            "*2\r\n$6\r\nfruits\r\n*1\r\n$5\r\ngrape\r\n".to_string(),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::lmpop_command(&["fruits", "basket"], "LEFT", None),
+            "*2\r\n$6\r\nbasket\r\n*1\r\n$4\r\npear\r\n".to_string(),
+        ),
+        (
+            TestUtils::lmpop_command(&["fruits", "basket"], "LEFT", None),
+            TestUtils::expected_null_array(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_lmpop_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::lmpop_command(&["grape"], "LEFT", None),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_lmpop_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["LMPOP", "1", "fruits"]),
+            CommandError::WrongNumberOfArguments("LMPOP".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["LMPOP", "0", "fruits", "LEFT"]),
+            CommandError::InvalidLMPopNumberOfKeys,
+        ),
+        (
+            TestUtils::invalid_command(&["LMPOP", "many", "fruits", "LEFT"]),
+            CommandError::InvalidLMPopNumberOfKeys,
+        ),
+        (
+            TestUtils::invalid_command(&["LMPOP", "3", "fruits", "LEFT"]),
+            CommandError::LMPopNumberOfKeysMismatch,
+        ),
+        (
+            TestUtils::invalid_command(&["LMPOP", "2", "fruits", "LEFT"]),
+            CommandError::InvalidLMPopCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["LMPOP", "1", "fruits", "UP"]),
+            CommandError::InvalidLMPopCommand,
+        ),
+        (
+            TestUtils::lmpop_command(&["fruits"], "LEFT", Some("0")),
+            CommandError::InvalidLMPopCount,
+        ),
+        (
+            TestUtils::invalid_command(&["LMPOP", "1", "fruits", "LEFT", "COUNT"]),
+            CommandError::InvalidLMPopCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,51 @@
+use codecrafters_redis::commands::{CommandError, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_lolwut_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![None, Some("5")];
+
+    for version in test_cases {
+        let result = env
+            .exec_command(TestUtils::lolwut_command(version), &client_address)
+            .await;
+
+        let Ok(CommandResult::Response(response)) = result else {
+            panic!("Expected LOLWUT to succeed");
+        };
+
+        assert!(response.starts_with('$'));
+        assert!(!response.starts_with("$0\r\n"));
+        assert!(response.contains("Redis ver. 7.4.0"));
+    }
+}
+
+#[tokio::test]
+async fn test_handle_lolwut_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["LOLWUT", "COLOR", "5"]),
+            CommandError::InvalidLolwutCommandOption,
+        ),
+        (
+            TestUtils::lolwut_command(Some("five")),
+            CommandError::InvalidLolwutCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["LOLWUT", "VERSION"]),
+            CommandError::InvalidLolwutCommand,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::VecDeque;
 use codecrafters_redis::{
     commands::CommandError,
     key_value_store::{DataType, Value},
+    list::List,
 };
 
 use crate::test_utils::{TestEnv, TestUtils};
@@ -23,11 +24,11 @@ async fn test_handle_lpop_command() {
     assert_eq!(
         value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from([
+            data: DataType::Array(List::from(VecDeque::from([
                 "mango".to_string(),
                 "raspberry".to_string(),
                 "apple".to_string(),
-            ])),
+            ]))),
             expiration: None,
         })
     );
@@ -45,15 +46,55 @@ async fn test_handle_lpop_command() {
     assert_eq!(
         updated_value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from([
+            data: DataType::Array(List::from(VecDeque::from([
                 "raspberry".to_string(),
                 "apple".to_string(),
-            ])),
+            ]))),
             expiration: None,
         })
     );
 }
 
+#[tokio::test]
+async fn test_handle_lpop_command_removes_empty_list() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("grape", &["mango"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::lpop_command("grape"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::type_command("grape"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::rpush_command("grape", &["mango", "raspberry"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("grape", 5),
+            TestUtils::expected_bulk_string_array(&["mango", "raspberry"]),
+        ),
+        (
+            TestUtils::type_command("grape"),
+            TestUtils::expected_simple_string("none"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    assert!(env.get_store().await.get("grape").is_none());
+}
+
 #[tokio::test]
 async fn test_handle_lpop_command_invalid() {
     let mut env = TestEnv::new_master_server();
@@ -61,7 +102,7 @@ async fn test_handle_lpop_command_invalid() {
     env.exec_command_immediate_error_response(
         TestUtils::invalid_command(&["LPOP"]),
         &TestUtils::client_address(41844),
-        CommandError::InvalidLPopCommand,
+        CommandError::WrongNumberOfArguments("LPOP".to_string()),
     )
     .await;
 }
@@ -89,10 +130,10 @@ async fn test_handle_lpop_command_wrong_data_type() {
     )
     .await;
 
-    env.exec_command_immediate_success_response(
+    env.exec_command_immediate_error_response(
         TestUtils::lpop_command("grape"),
         &TestUtils::client_address(41844),
-        &TestUtils::expected_null_bulk_string(),
+        CommandError::WrongType,
     )
     .await;
 }
@@ -113,11 +154,11 @@ async fn test_handle_lpop_command_multiple_elements() {
     assert_eq!(
         value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from([
+            data: DataType::Array(List::from(VecDeque::from([
                 "mango".to_string(),
                 "raspberry".to_string(),
                 "apple".to_string(),
-            ])),
+            ]))),
             expiration: None,
         })
     );
@@ -135,8 +176,54 @@ async fn test_handle_lpop_command_multiple_elements() {
     assert_eq!(
         updated_value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from(["apple".to_string(),])),
+            data: DataType::Array(List::from(VecDeque::from(["apple".to_string(),]))),
             expiration: None,
         })
     );
 }
+
+#[tokio::test]
+async fn test_handle_lpop_command_reply_shape_with_count() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("grape", &["mango", "raspberry", "apple"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::lpop_command("grape"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("grape", 1),
+            TestUtils::expected_bulk_string_array(&["raspberry"]),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("grape", 5),
+            TestUtils::expected_bulk_string_array(&["apple"]),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("grape", 1),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::lpop_command("grape"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("pear", 1),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::lpop_command("pear"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::VecDeque;
 use codecrafters_redis::{
     commands::CommandError,
     key_value_store::{DataType, Value},
+    list::List,
 };
 
 use crate::test_utils::{TestEnv, TestUtils};
@@ -23,11 +24,11 @@ async fn test_handle_lpush_command_insert() {
     assert_eq!(
         value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from([
+            data: DataType::Array(List::from(VecDeque::from([
                 "apple".to_string(),
                 "raspberry".to_string(),
                 "mango".to_string()
-            ])),
+            ]))),
             expiration: None,
         })
     );
@@ -49,11 +50,11 @@ async fn test_handle_lpush_command_update() {
     assert_eq!(
         inserted_value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from([
+            data: DataType::Array(List::from(VecDeque::from([
                 "apple".to_string(),
                 "raspberry".to_string(),
                 "mango".to_string()
-            ])),
+            ]))),
             expiration: None,
         })
     );
@@ -71,12 +72,12 @@ async fn test_handle_lpush_command_update() {
     assert_eq!(
         updated_value,
         Some(&Value {
-            data: DataType::Array(VecDeque::from([
+            data: DataType::Array(List::from(VecDeque::from([
                 "pear".to_string(),
                 "apple".to_string(),
                 "raspberry".to_string(),
                 "mango".to_string(),
-            ])),
+            ]))),
             expiration: None,
         })
     );
@@ -89,7 +90,7 @@ async fn test_handle_lpush_command_invalid() {
     env.exec_command_immediate_error_response(
         TestUtils::invalid_command(&["LPUSH", "grape"]),
         &TestUtils::client_address(41844),
-        CommandError::InvalidLPushCommand,
+        CommandError::WrongNumberOfArguments("LPUSH".to_string()),
     )
     .await;
 }
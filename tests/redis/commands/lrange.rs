@@ -49,11 +49,11 @@ async fn test_handle_lrange_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["LRANGE", "grape", "0"]),
-            CommandError::InvalidLRangeCommand,
+            CommandError::WrongNumberOfArguments("LRANGE".to_string()),
         ),
         (
             TestUtils::invalid_command(&["LRANGE", "grape", "0", "1", "mango"]),
-            CommandError::InvalidLRangeCommand,
+            CommandError::WrongNumberOfArguments("LRANGE".to_string()),
         ),
     ];
 
@@ -66,3 +66,30 @@ async fn test_handle_lrange_command_invalid() {
         .await;
     }
 }
+
+#[tokio::test]
+async fn test_handle_lrange_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::lrange_command("grape", 0, -1),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::lrange_command("raspberry", 0, -1),
+        &client_address,
+        &TestUtils::expected_bulk_string_array(&[]),
+    )
+    .await;
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn exec_memory_usage(env: &mut TestEnv, key: &str, options: &[&str]) -> i64 {
+    let result = env
+        .exec_command(
+            TestUtils::memory_usage_command(key, options),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected MEMORY USAGE to succeed");
+    };
+
+    response
+        .strip_prefix(':')
+        .and_then(|usage| usage.strip_suffix("\r\n"))
+        .and_then(|usage| usage.parse().ok())
+        .expect("Expected MEMORY USAGE to reply with an integer")
+}
+
+#[tokio::test]
+async fn test_handle_memory_usage_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (TestUtils::set_command("short", "kiwi"), "+OK\r\n"),
+        (
+            TestUtils::set_command("long", &"passionfruit".repeat(10)),
+            "+OK\r\n",
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["mango", "grape", "kiwi"]),
+            ":3\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+
+    let short_usage = exec_memory_usage(&mut env, "short", &[]).await;
+    let long_usage = exec_memory_usage(&mut env, "long", &[]).await;
+    assert!(long_usage > short_usage);
+
+    let list_usage = exec_memory_usage(&mut env, "fruits", &[]).await;
+    let sampled_list_usage = exec_memory_usage(&mut env, "fruits", &["SAMPLES", "0"]).await;
+    assert!(list_usage > short_usage);
+    assert_eq!(list_usage, sampled_list_usage);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::memory_usage_command("missing", &[]),
+        &client_address,
+        "$-1\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_memory_usage_command_stream() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::xadd_command("small", "1-1", &["temperature", "36"]),
+            "$3\r\n1-1\r\n",
+        ),
+        (
+            TestUtils::xadd_command("large", "1-1", &["temperature", "36"]),
+            "$3\r\n1-1\r\n",
+        ),
+        (
+            TestUtils::xadd_command("large", "1-2", &["temperature", "37", "humidity", "95"]),
+            "$3\r\n1-2\r\n",
+        ),
+        (
+            TestUtils::xadd_command("large", "2-1", &["temperature", "38"]),
+            "$3\r\n2-1\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+
+    let small_usage = exec_memory_usage(&mut env, "small", &[]).await;
+    let large_usage = exec_memory_usage(&mut env, "large", &[]).await;
+    assert!(large_usage > small_usage);
+}
+
+#[tokio::test]
+async fn test_handle_memory_usage_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["MEMORY", "USAGE"]),
+            CommandError::WrongNumberOfArguments("MEMORY USAGE".to_string()),
+        ),
+        (
+            TestUtils::memory_usage_command("fruits", &["SAMPLES"]),
+            CommandError::InvalidMemoryCommand,
+        ),
+        (
+            TestUtils::memory_usage_command("fruits", &["COUNT", "5"]),
+            CommandError::InvalidMemoryCommand,
+        ),
+        (
+            TestUtils::memory_usage_command("fruits", &["SAMPLES", "-1"]),
+            CommandError::InvalidMemoryUsageSamples,
+        ),
+        (
+            TestUtils::memory_command("STATS", &["fruits"]),
+            CommandError::WrongNumberOfArguments("MEMORY STATS".to_string()),
+        ),
+        (
+            TestUtils::memory_command("DOCTOR", &["fruits"]),
+            CommandError::WrongNumberOfArguments("MEMORY DOCTOR".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_memory_stats_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (TestUtils::set_command("grape", "purple"), "+OK\r\n"),
+        (
+            TestUtils::rpush_command("fruits", &["mango", "grape"]),
+            ":2\r\n",
+        ),
+        (
+            TestUtils::hset_command("colors", &[("kiwi", "green")]),
+            ":1\r\n",
+        ),
+        (TestUtils::dbsize_command(), ":3\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+
+    let result = env
+        .exec_command(TestUtils::memory_command("STATS", &[]), &client_address)
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected MEMORY STATS to succeed");
+    };
+
+    let parsed = RespValue::parse(response.split_terminator("\r\n").collect()).unwrap();
+    let [RespValue::Array(entries)] = parsed.as_slice() else {
+        panic!("Expected MEMORY STATS to reply with an array");
+    };
+
+    let stats = entries
+        .chunks(2)
+        .map(|entry| match entry {
+            [RespValue::BulkString(name), RespValue::Integer(value)] => (name.as_str(), *value),
+            _ => panic!("Unexpected MEMORY STATS entry {:?}", entry),
+        })
+        .collect::<HashMap<&str, i64>>();
+
+    assert_eq!(stats["keys.count"], 3);
+    assert_eq!(
+        stats["total.allocated"],
+        stats["overhead.total"] + stats["dataset.bytes"]
+    );
+    assert_eq!(stats["keys.bytes-per-key"], stats["total.allocated"] / 3);
+
+    let mut dataset_bytes = 0;
+    for key in ["grape", "fruits", "colors"] {
+        dataset_bytes += exec_memory_usage(&mut env, key, &["SAMPLES", "0"]).await;
+    }
+    assert_eq!(stats["total.allocated"], dataset_bytes);
+}
+
+#[tokio::test]
+async fn test_handle_memory_doctor_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let empty_report = "Hi Sam, this instance is empty or is using very little memory, my issues detector can't be used in these conditions. Please, leave for your mission on Earth and fill it with some data. The new Sam and I will be back to our programming as soon as I finished rebooting.";
+    env.exec_command_immediate_success_response(
+        TestUtils::memory_command("DOCTOR", &[]),
+        &client_address,
+        &format!("${}\r\n{}\r\n", empty_report.len(), empty_report),
+    )
+    .await;
+
+    let value = "a".repeat(1024 * 1024);
+    for key in [
+        "fruit:1", "fruit:2", "fruit:3", "fruit:4", "fruit:5", "fruit:6",
+    ] {
+        env.exec_command_immediate_success_response(
+            TestUtils::set_command(key, &value),
+            &client_address,
+            "+OK\r\n",
+        )
+        .await;
+    }
+
+    let healthy_report = "Hi Sam, I can't find any memory issue in your instance. I can only account for what occurs on this base.";
+    env.exec_command_immediate_success_response(
+        TestUtils::memory_command("DOCTOR", &[]),
+        &client_address,
+        &format!("${}\r\n{}\r\n", healthy_report.len(), healthy_report),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("fruit:big", &"a".repeat(4 * 1024 * 1024)),
+        &client_address,
+        "+OK\r\n",
+    )
+    .await;
+
+    let result = env
+        .exec_command(TestUtils::memory_command("DOCTOR", &[]), &client_address)
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected MEMORY DOCTOR to succeed");
+    };
+
+    assert!(response.contains("Sam, I detected a few issues"));
+    assert!(response.contains("Big keys: 1 key(s)"));
+    assert!(response.contains("fruit:big"));
+}
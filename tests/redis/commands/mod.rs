@@ -1,24 +1,81 @@
+mod append;
+mod bgrewriteaof;
+mod bitfield;
 mod blpop;
+mod bzpop;
+mod client;
+mod command_list;
 mod config_get;
+mod dbsize;
+mod debug;
 mod echo;
+mod expire;
+mod failover;
+mod geo;
 mod get;
+mod getdel;
+mod getset;
+mod hello;
+mod help;
+mod hgetall;
+mod hmget;
+mod hrandfield;
+mod hscan;
+mod hset;
+mod hsetnx;
+mod hstrlen;
 mod incr;
 mod info;
 mod keys;
+mod keyword_options;
+mod latency;
 mod llen;
+mod lmpop;
+mod lolwut;
 mod lpop;
 mod lpush;
 mod lrange;
+mod memory;
+mod object;
+mod pexpireat;
+mod pfadd;
 mod ping;
 mod psync;
+mod quit;
 mod replconf;
+mod replica_read_only;
 mod replication;
+mod reset;
 mod rpush;
+mod save;
 mod set;
+mod set_store_operations;
+mod setrange;
+mod sintercard;
+mod slowlog;
+mod smove;
+mod sort;
+mod sscan;
+mod ssubscribe;
 mod subscribe;
 mod subscribe_ping;
 mod transaction;
 mod type_command;
+mod wait;
+mod waitaof;
 mod xadd;
+mod xdel;
+mod xlen;
 mod xrange;
 mod xread;
+mod xsetid;
+mod xtrim;
+mod zadd;
+mod zincrby;
+mod zmpop;
+mod zmscore;
+mod zpop;
+mod zrandmember;
+mod zrange;
+mod zrank;
+mod zrem;
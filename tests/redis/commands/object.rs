@@ -0,0 +1,561 @@
+use std::time::Duration;
+
+use codecrafters_redis::commands::CommandError;
+use jiff::{SignedDuration, Timestamp};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_lists() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.server.write().await.list_max_listpack_size = 3;
+
+    // Crossing the threshold flips the list to a quicklist, which it stays after shrinking
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("fruits", &["apple", "banana"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::lpush_command("fruits", &["mango"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["grape"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("quicklist"),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("fruits", 3),
+            TestUtils::expected_bulk_string_array(&["mango", "apple", "banana"]),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("quicklist"),
+        ),
+        (
+            TestUtils::object_encoding_command("missing"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_large_list_entries() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    // The default threshold limits a listpack to 8 kb rather than a number of entries
+    let large_entry = "x".repeat(8192);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("small", &["apple"; 200]),
+            TestUtils::expected_integer(200),
+        ),
+        (
+            TestUtils::object_encoding_command("small"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::rpush_command("large", &[&large_entry]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("large"),
+            TestUtils::expected_bulk_string("quicklist"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_sets() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.set_encoding_limits.max_intset_entries = 4;
+        server_guard.set_encoding_limits.max_listpack_entries = 3;
+    }
+
+    // The encoding only ever moves from intset to listpack to hashtable
+    let test_cases = vec![
+        (
+            TestUtils::sadd_command("numbers", &["1", "2"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("numbers"),
+            TestUtils::expected_bulk_string("intset"),
+        ),
+        (
+            TestUtils::sadd_command("numbers", &["mango"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("numbers"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::smove_command("numbers", "fruits", "mango"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("numbers"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::sadd_command("numbers", &["3", "4"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("numbers"),
+            TestUtils::expected_bulk_string("hashtable"),
+        ),
+        (
+            TestUtils::sadd_command("digits", &["1", "2", "3", "4"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::object_encoding_command("digits"),
+            TestUtils::expected_bulk_string("intset"),
+        ),
+        (
+            TestUtils::sadd_command("digits", &["5"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("digits"),
+            TestUtils::expected_bulk_string("hashtable"),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_sorted_sets() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.zset_encoding_limits.max_listpack_entries = 2;
+        server_guard.zset_encoding_limits.max_listpack_value = 5;
+    }
+
+    // A sorted set that outgrows the listpack stays a skiplist even after shrinking
+    let test_cases = vec![
+        (
+            TestUtils::zadd_command("scores", &[], &[("1", "apple"), ("2", "mango")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("scores"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::zincrby_command("scores", "3", "grape"),
+            TestUtils::expected_bulk_string("3"),
+        ),
+        (
+            TestUtils::object_encoding_command("scores"),
+            TestUtils::expected_bulk_string("skiplist"),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMIN", "scores", Some(2)),
+            TestUtils::expected_bulk_string_array(&["apple", "1", "mango", "2"]),
+        ),
+        (
+            TestUtils::object_encoding_command("scores"),
+            TestUtils::expected_bulk_string("skiplist"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &[], &[("1", "kiwi")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &[], &[("2", "banana")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("fruits"),
+            TestUtils::expected_bulk_string("skiplist"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_hashes() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.hash_encoding_limits.max_listpack_entries = 2;
+        server_guard.hash_encoding_limits.max_listpack_value = 5;
+    }
+
+    // A hash that outgrows the listpack stays a hashtable even if it would fit again
+    let test_cases = vec![
+        (
+            TestUtils::hset_command("prices", &[("mango", "3"), ("kiwi", "1")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("prices"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::hset_command("prices", &[("kiwi", "12.50")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::object_encoding_command("prices"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::hset_command("prices", &[("kiwi", "125.50")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::object_encoding_command("prices"),
+            TestUtils::expected_bulk_string("hashtable"),
+        ),
+        (
+            TestUtils::hset_command("prices", &[("kiwi", "1")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::object_encoding_command("prices"),
+            TestUtils::expected_bulk_string("hashtable"),
+        ),
+        (
+            TestUtils::hset_command("stock", &[("mango", "3"), ("kiwi", "1")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::object_encoding_command("stock"),
+            TestUtils::expected_bulk_string("listpack"),
+        ),
+        (
+            TestUtils::hsetnx_command("stock", "grape", "7"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::object_encoding_command("stock"),
+            TestUtils::expected_bulk_string("hashtable"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_other_types() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        ("count", TestUtils::set_command("count", "42"), "int"),
+        ("fruit", TestUtils::set_command("fruit", "mango"), "embstr"),
+        (
+            "basket",
+            TestUtils::set_command("basket", &"mango".repeat(10)),
+            "raw",
+        ),
+        (
+            "prices",
+            TestUtils::hset_command("prices", &[("mango", "3")]),
+            "listpack",
+        ),
+        (
+            "events",
+            TestUtils::xadd_command("events", "1-1", &["type", "start"]),
+            "stream",
+        ),
+    ];
+
+    for (key, command, expected_encoding) in test_cases {
+        env.exec_command(command, &client_address).await.unwrap();
+
+        env.exec_command_immediate_success_response(
+            TestUtils::object_encoding_command(key),
+            &client_address,
+            &TestUtils::expected_bulk_string(expected_encoding),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_strings() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    // Strings modified in place stay raw, even when they are short or numeric
+    let test_cases = vec![
+        (TestUtils::set_command("count", "12345"), "count", "int"),
+        (
+            TestUtils::set_command("fruit", "strawberry"),
+            "fruit",
+            "embstr",
+        ),
+        (
+            TestUtils::set_command("basket", &"x".repeat(50)),
+            "basket",
+            "raw",
+        ),
+        (TestUtils::append_command("fruit", "s"), "fruit", "raw"),
+        (TestUtils::append_command("count", "6"), "count", "raw"),
+        (TestUtils::append_command("new", "mango"), "new", "embstr"),
+        (
+            TestUtils::setrange_command("kiwi", 0, "kiwi"),
+            "kiwi",
+            "raw",
+        ),
+        (TestUtils::set_command("fruit", "mango"), "fruit", "embstr"),
+    ];
+
+    for (command, key, expected_encoding) in test_cases {
+        env.exec_command(command, &client_address).await.unwrap();
+
+        env.exec_command_immediate_success_response(
+            TestUtils::object_encoding_command(key),
+            &client_address,
+            &TestUtils::expected_bulk_string(expected_encoding),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_for_raw_strings_that_are_replaced() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    for command in [
+        TestUtils::setrange_command("kiwi", 0, "kiwi"),
+        TestUtils::setrange_command("count", 0, "12"),
+        TestUtils::set_command_with_expiration("fruit", "mango", 50),
+        TestUtils::append_command("fruit", "s"),
+    ] {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    // A failed INCR leaves the string as it was, while a successful one replaces it
+    env.exec_command_immediate_error_response(
+        TestUtils::incr_command("kiwi"),
+        &client_address,
+        CommandError::InvalidIncrValue,
+    )
+    .await;
+    env.exec_command_immediate_success_response(
+        TestUtils::incr_command("count"),
+        &client_address,
+        &TestUtils::expected_integer(13),
+    )
+    .await;
+
+    // An expired string is no longer remembered as raw
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    env.exec_command_immediate_success_response(
+        TestUtils::get_command("fruit"),
+        &client_address,
+        &TestUtils::expected_null_bulk_string(),
+    )
+    .await;
+    assert!(!env.server.read().await.raw_string_keys.contains("fruit"));
+
+    let test_cases = vec![("kiwi", "raw"), ("count", "int")];
+
+    for (key, expected_encoding) in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::object_encoding_command(key),
+            &client_address,
+            &TestUtils::expected_bulk_string(expected_encoding),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_idletime_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("fruit", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.server.write().await.key_access_times.insert(
+        "fruit".to_string(),
+        Timestamp::now() - SignedDuration::from_secs(42),
+    );
+
+    let test_cases = vec![
+        (
+            TestUtils::object_idletime_command("fruit"),
+            TestUtils::expected_integer(42),
+        ),
+        // Overwriting the value resets its idle time
+        (
+            TestUtils::set_command("fruit", "grape"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::object_idletime_command("fruit"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::object_idletime_command("missing"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_object_idletime_command_for_other_types() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    for command in [
+        TestUtils::rpush_command("fruits", &["mango", "kiwi"]),
+        TestUtils::hset_command("basket", &[("mango", "1")]),
+    ] {
+        env.exec_command(command, &client_address).await.unwrap();
+    }
+
+    {
+        let mut server_guard = env.server.write().await;
+        for key in ["fruits", "basket"] {
+            server_guard.key_access_times.insert(
+                key.to_string(),
+                Timestamp::now() - SignedDuration::from_secs(42),
+            );
+        }
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::object_idletime_command("basket"),
+            TestUtils::expected_integer(42),
+        ),
+        // Reading any type of value counts as an access
+        (
+            TestUtils::hget_command("basket", "mango"),
+            TestUtils::expected_bulk_string("1"),
+        ),
+        (
+            TestUtils::object_idletime_command("basket"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::lrange_command("fruits", 0, -1),
+            TestUtils::expected_bulk_string_array(&["mango", "kiwi"]),
+        ),
+        (
+            TestUtils::object_idletime_command("fruits"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::lpop_command_multiple_items("fruits", 2),
+            TestUtils::expected_bulk_string_array(&["mango", "kiwi"]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    // A key removed once emptied is forgotten, so it cannot pass its idle time on
+    assert!(
+        !env.server
+            .read()
+            .await
+            .key_access_times
+            .contains_key("fruits")
+    );
+}
+
+#[tokio::test]
+async fn test_handle_object_encoding_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["OBJECT", "ENCODING"]),
+            CommandError::WrongNumberOfArguments("OBJECT ENCODING".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["OBJECT", "ENCODING", "fruits", "extra"]),
+            CommandError::WrongNumberOfArguments("OBJECT ENCODING".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["OBJECT", "IDLETIME"]),
+            CommandError::WrongNumberOfArguments("OBJECT IDLETIME".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["OBJECT", "REFCOUNT", "fruits"]),
+            CommandError::InvalidCommandArgument,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
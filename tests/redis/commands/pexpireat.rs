@@ -0,0 +1,99 @@
+use codecrafters_redis::commands::CommandError;
+use jiff::{Timestamp, ToSpan};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_pexpireat_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let expiration = Timestamp::now()
+        .checked_add(1.hour())
+        .unwrap()
+        .as_millisecond();
+
+    let test_cases = vec![
+        (
+            TestUtils::pexpireat_command("grape", expiration),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::pexpireat_command("grape", expiration),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::get_command("grape"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::pexpireat_command("grape", 1000),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::get_command("grape"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let store_guard = env.store.lock().await;
+    assert!(!store_guard.contains_key("grape"));
+}
+
+#[tokio::test]
+async fn test_handle_pexpireat_command_sets_expiration() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let expiration = Timestamp::now().checked_add(1.hour()).unwrap();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["apple"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+    env.exec_command_immediate_success_response(
+        TestUtils::pexpireat_command("fruits", expiration.as_millisecond()),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let store_guard = env.store.lock().await;
+    assert_eq!(
+        store_guard["fruits"].expiration,
+        Some(Timestamp::from_millisecond(expiration.as_millisecond()).unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_handle_pexpireat_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["PEXPIREAT", "grape"]),
+            CommandError::WrongNumberOfArguments("PEXPIREAT".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["PEXPIREAT", "grape", "soon"]),
+            CommandError::InvalidPexpireatTimestamp,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
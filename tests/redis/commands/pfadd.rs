@@ -0,0 +1,228 @@
+use codecrafters_redis::commands::{CommandError, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn pfcount(env: &mut TestEnv, keys: &[&str], client_address: &str) -> i64 {
+    let result = env
+        .exec_command(TestUtils::pfcount_command(keys), client_address)
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected PFCOUNT response");
+    };
+
+    response
+        .trim_start_matches(':')
+        .trim_end()
+        .parse::<i64>()
+        .expect("PFCOUNT should reply with an integer")
+}
+
+#[tokio::test]
+async fn test_handle_pfadd_and_pfcount_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let elements = (0..1000)
+        .map(|element| format!("fruit:{}", element))
+        .collect::<Vec<String>>();
+    let elements = elements.iter().map(String::as_str).collect::<Vec<&str>>();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::pfadd_command("fruits", &elements),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let count = pfcount(&mut env, &["fruits"], &client_address).await;
+    assert!(
+        (count - 1000).abs() <= 30,
+        "estimated count {} is too far from 1000",
+        count
+    );
+
+    // Re-adding an existing element does not change the estimate
+    env.exec_command_immediate_success_response(
+        TestUtils::pfadd_command("fruits", &["fruit:1"]),
+        &client_address,
+        &TestUtils::expected_integer(0),
+    )
+    .await;
+
+    assert_eq!(pfcount(&mut env, &["fruits"], &client_address).await, count);
+
+    let test_cases = vec![
+        (
+            TestUtils::pfadd_command("vegetables", &[]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::pfadd_command("vegetables", &[]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::pfcount_command(&["vegetables", "berries"]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::type_command("vegetables"),
+            TestUtils::expected_simple_string("string"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_pfadd_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::rpush_command("fruits", &["mango"]),
+            TestUtils::expected_integer(1),
+        ),
+    ];
+
+    for (command, expected_response) in setup_commands {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["PFADD"]),
+            CommandError::WrongNumberOfArguments("PFADD".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["PFCOUNT"]),
+            CommandError::WrongNumberOfArguments("PFCOUNT".to_string()),
+        ),
+        (
+            TestUtils::pfadd_command("grape", &["mango"]),
+            CommandError::InvalidHyperLogLogValue,
+        ),
+        (
+            TestUtils::pfcount_command(&["grape"]),
+            CommandError::InvalidHyperLogLogValue,
+        ),
+        (
+            TestUtils::pfcount_command(&["fruits"]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_pfmerge_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let first_elements = (0..600)
+        .map(|element| format!("fruit:{}", element))
+        .collect::<Vec<String>>();
+    let second_elements = (400..1000)
+        .map(|element| format!("fruit:{}", element))
+        .collect::<Vec<String>>();
+
+    for (key, elements) in [("fruits", &first_elements), ("berries", &second_elements)] {
+        let elements = elements.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        env.exec_command_immediate_success_response(
+            TestUtils::pfadd_command(key, &elements),
+            &client_address,
+            &TestUtils::expected_integer(1),
+        )
+        .await;
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::pfmerge_command("basket", &["fruits", "berries", "vegetables"]),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let count = pfcount(&mut env, &["basket"], &client_address).await;
+    assert!(
+        (count - 1000).abs() <= 30,
+        "estimated count {} is too far from 1000",
+        count
+    );
+    assert_eq!(
+        pfcount(&mut env, &["fruits", "berries"], &client_address).await,
+        count
+    );
+
+    // Merging nothing into a missing key creates an empty HyperLogLog
+    env.exec_command_immediate_success_response(
+        TestUtils::pfmerge_command("empty", &["vegetables"]),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+    assert_eq!(pfcount(&mut env, &["empty"], &client_address).await, 0);
+}
+
+#[tokio::test]
+async fn test_handle_pfmerge_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::pfadd_command("fruits", &["mango"]),
+            TestUtils::expected_integer(1),
+        ),
+    ];
+
+    for (command, expected_response) in setup_commands {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["PFMERGE", "basket"]),
+            CommandError::InvalidPfMergeCommand,
+        ),
+        (
+            TestUtils::pfmerge_command("basket", &["fruits", "grape"]),
+            CommandError::InvalidHyperLogLogValue,
+        ),
+        (
+            TestUtils::pfmerge_command("grape", &["fruits"]),
+            CommandError::InvalidHyperLogLogValue,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::type_command("basket"),
+        &client_address,
+        &TestUtils::expected_simple_string("none"),
+    )
+    .await;
+}
@@ -0,0 +1,39 @@
+use codecrafters_redis::commands::{CommandError, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_quit_command_inside_transaction() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::multi_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let result = env
+        .exec_command(TestUtils::quit_command(), &client_address)
+        .await;
+
+    match result {
+        Ok(CommandResult::Quit(response)) => {
+            assert_eq!(response, TestUtils::expected_simple_string("OK"));
+        }
+        _ => panic!("Expected QUIT to bypass the transaction queue"),
+    }
+}
+
+#[tokio::test]
+async fn test_handle_quit_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["QUIT", "now"]),
+        &TestUtils::client_address(41844),
+        CommandError::InvalidQuitCommand,
+    )
+    .await;
+}
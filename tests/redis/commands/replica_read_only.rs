@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use codecrafters_redis::commands::{CommandError, CommandHandler, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_replica_rejects_writes_by_default() {
+    let env = TestEnv::new_replica_server(6380);
+    let (store, state, server) = env.clone_env();
+    let client_address = TestUtils::client_address(41844);
+
+    let result = CommandHandler::new(TestUtils::set_command("fruit", "mango"))
+        .unwrap()
+        .handle_command_for_replica_server(
+            &client_address,
+            Arc::clone(&server),
+            Arc::clone(&store),
+            Arc::clone(&state),
+        )
+        .await;
+
+    assert_eq!(result.err(), Some(CommandError::ReplicaReadOnlyCommands));
+    assert_eq!(store.lock().await.get("fruit"), None);
+}
+
+#[tokio::test]
+async fn test_replica_applies_writes_locally_when_not_read_only() {
+    let env = TestEnv::new_replica_server(6380);
+    let (store, state, server) = env.clone_env();
+    let client_address = TestUtils::client_address(41844);
+
+    server.write().await.replica_read_only = false;
+
+    let test_cases = vec![
+        (
+            TestUtils::set_command("fruit", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::get_command("fruit"),
+            TestUtils::expected_bulk_string("mango"),
+        ),
+        (
+            TestUtils::config_get_command(&["replica-read-only"]),
+            TestUtils::expected_bulk_string_array(&["replica-read-only", "no"]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        let result = CommandHandler::new(command)
+            .unwrap()
+            .handle_command_for_replica_server(
+                &client_address,
+                Arc::clone(&server),
+                Arc::clone(&store),
+                Arc::clone(&state),
+            )
+            .await;
+
+        match result {
+            Ok(CommandResult::Response(response)) => assert_eq!(response, expected_response),
+            _ => panic!("Expected the command to succeed on a writable replica"),
+        }
+    }
+
+    // The local write is not part of the replication stream from the master
+    assert_eq!(server.read().await.repl_offset, 0);
+    assert_eq!(server.read().await.stats.total_commands_processed(), 3);
+}
@@ -1,18 +1,17 @@
-use std::sync::Arc;
-
 use codecrafters_redis::commands::CommandError;
+use tokio::sync::mpsc;
 
 use crate::test_utils::{TestEnv, TestUtils};
 
 #[tokio::test]
 async fn test_handle_publish_command_without_subscribers() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::publish_command("channel1", "hello there"),
         &client_address,
-        writer,
+        sender,
         Some(TestUtils::expected_integer(0)),
     )
     .await;
@@ -21,14 +20,14 @@ async fn test_handle_publish_command_without_subscribers() {
 #[tokio::test]
 async fn test_handle_publish_command_with_subscribers() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
-    let (client_address2, writer2) = TestEnv::new_client_connection().await;
-    let (client_address3, writer3) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+    let (client_address2, sender2) = TestEnv::new_client_connection().await;
+    let (client_address3, sender3) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address,
-        Arc::clone(&writer),
+        sender.clone(),
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -36,7 +35,7 @@ async fn test_handle_publish_command_with_subscribers() {
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address2,
-        writer2,
+        sender2,
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -44,7 +43,7 @@ async fn test_handle_publish_command_with_subscribers() {
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel2"),
         &client_address3,
-        writer3,
+        sender3,
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel2\r\n:1\r\n".to_string()),
     )
     .await;
@@ -52,25 +51,77 @@ async fn test_handle_publish_command_with_subscribers() {
     env.exec_pub_sub_command_success_response(
         TestUtils::publish_command("channel1", "hello there"),
         &client_address,
-        writer,
+        sender,
+        Some(TestUtils::expected_integer(2)),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_publish_command_uses_each_subscriber_protocol() {
+    let mut env = TestEnv::new_master_server();
+    let (resp3_sender, mut resp3_receiver) = mpsc::unbounded_channel();
+    let (resp2_sender, mut resp2_receiver) = mpsc::unbounded_channel();
+    let (publisher_address, publisher_sender) = TestEnv::new_client_connection().await;
+
+    env.exec_command(TestUtils::hello_command(Some("3")), "127.0.0.1:50001")
+        .await
+        .unwrap();
+
+    let test_cases = vec![
+        (
+            "127.0.0.1:50001",
+            resp3_sender,
+            ">3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n",
+        ),
+        (
+            "127.0.0.1:50002",
+            resp2_sender,
+            "*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n",
+        ),
+    ];
+
+    for (client_address, sender, expected_response) in test_cases {
+        env.exec_pub_sub_command_success_response(
+            TestUtils::subscribe_command("channel1"),
+            client_address,
+            sender,
+            Some(expected_response.to_string()),
+        )
+        .await;
+    }
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::publish_command("channel1", "hello there"),
+        &publisher_address,
+        publisher_sender,
         Some(TestUtils::expected_integer(2)),
     )
     .await;
+
+    assert_eq!(
+        String::from_utf8(resp3_receiver.recv().await.unwrap()).unwrap(),
+        ">3\r\n$7\r\nmessage\r\n$8\r\nchannel1\r\n$11\r\nhello there\r\n"
+    );
+    assert_eq!(
+        String::from_utf8(resp2_receiver.recv().await.unwrap()).unwrap(),
+        "*3\r\n$7\r\nmessage\r\n$8\r\nchannel1\r\n$11\r\nhello there\r\n"
+    );
 }
 
 #[tokio::test]
 async fn test_handle_publish_command_invalid() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["PUBLISH"]),
-            CommandError::InvalidPublishCommand,
+            CommandError::WrongNumberOfArguments("PUBLISH".to_string()),
         ),
         (
             TestUtils::invalid_command(&["PUBLISH", "channel1", "hey", "there"]),
-            CommandError::InvalidPublishCommand,
+            CommandError::WrongNumberOfArguments("PUBLISH".to_string()),
         ),
     ];
 
@@ -78,7 +129,7 @@ async fn test_handle_publish_command_invalid() {
         env.exec_pub_sub_command_error_response(
             command,
             &client_address,
-            Arc::clone(&writer),
+            sender.clone(),
             expected_error,
         )
         .await;
@@ -0,0 +1,97 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_reset_command_exits_subscribed_mode() {
+    let mut env = TestEnv::new_master_server();
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::subscribe_command("channel1"),
+        &client_address,
+        sender.clone(),
+        Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::reset_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("RESET"),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::get_command("grape"),
+        &client_address,
+        &TestUtils::expected_null_bulk_string(),
+    )
+    .await;
+
+    let server_guard = env.server.read().await;
+    assert!(server_guard.pub_sub_channels.is_empty());
+}
+
+#[tokio::test]
+async fn test_handle_reset_command_discards_transaction_and_protocol() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::multi_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hello_command(Some("3")),
+        &client_address,
+        &TestUtils::expected_simple_string("QUEUED"),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::reset_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("RESET"),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::exec_command(),
+        &client_address,
+        CommandError::ExecWithoutMulti,
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hello_command(Some("3")),
+        &client_address,
+        "%6\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nversion\r\n$5\r\n7.4.0\r\n$5\r\nproto\r\n:3\r\n$4\r\nmode\r\n$10\r\nstandalone\r\n$4\r\nrole\r\n$6\r\nmaster\r\n$7\r\nmodules\r\n*0\r\n",
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::reset_command(),
+        &client_address,
+        &TestUtils::expected_simple_string("RESET"),
+    )
+    .await;
+
+    let server_guard = env.server.read().await;
+    assert!(server_guard.resp3_clients.is_empty());
+}
+
+#[tokio::test]
+async fn test_handle_reset_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["RESET", "now"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("RESET".to_string()),
+    )
+    .await;
+}
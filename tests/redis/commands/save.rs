@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use codecrafters_redis::{
+    commands::CommandError,
+    key_value_store::{DataType, Value},
+    rdb::{RdbParser, SavePoint},
+    server::save_on_save_points,
+};
+use jiff::Timestamp;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn use_rdb_file(env: &TestEnv, rdb_directory: &str) {
+    tokio::fs::create_dir_all(rdb_directory).await.unwrap();
+
+    let mut server_guard = env.server.write().await;
+    server_guard.rdb_directory = rdb_directory.to_string();
+    server_guard.rdb_filename = "dump.rdb".to_string();
+    server_guard.last_save_time = Timestamp::UNIX_EPOCH;
+}
+
+async fn parse_saved_keys(rdb_directory: &str) -> RdbParser {
+    let rdb_file = tokio::fs::read(format!("{}/dump.rdb", rdb_directory))
+        .await
+        .unwrap();
+
+    let mut rdb_parser = RdbParser::new();
+    rdb_parser.parse(rdb_file).unwrap();
+    rdb_parser
+}
+
+#[tokio::test]
+async fn test_handle_save_command() {
+    let rdb_directory = "/tmp/redis-files-save";
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    use_rdb_file(&env, rdb_directory).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::lastsave_command(),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::save_command(),
+            TestUtils::expected_simple_string("OK"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let last_save_time = env.server.read().await.last_save_time.as_second();
+    assert!(last_save_time > 0);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::lastsave_command(),
+        &client_address,
+        &TestUtils::expected_integer(last_save_time),
+    )
+    .await;
+
+    let rdb_parser = parse_saved_keys(rdb_directory).await;
+    assert_eq!(
+        rdb_parser.databases[&0].get("grape"),
+        Some(&Value {
+            data: DataType::String("mango".to_string()),
+            expiration: None,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_handle_bgsave_command() {
+    let rdb_directory = "/tmp/redis-files-bgsave";
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    use_rdb_file(&env, rdb_directory).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::bgsave_command(),
+            TestUtils::expected_simple_string("Background saving started"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    for _ in 0..250 {
+        if !env.server.read().await.is_background_save_in_progress {
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    assert!(env.server.read().await.last_save_time > Timestamp::UNIX_EPOCH);
+
+    let rdb_parser = parse_saved_keys(rdb_directory).await;
+    assert!(rdb_parser.databases[&0].contains_key("grape"));
+}
+
+#[tokio::test]
+async fn test_handle_bgsave_command_already_in_progress() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    env.server.write().await.is_background_save_in_progress = true;
+
+    for command in [TestUtils::save_command(), TestUtils::bgsave_command()] {
+        env.exec_command_immediate_error_response(
+            command,
+            &client_address,
+            CommandError::BackgroundSaveInProgress,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_save_points_trigger_background_save() {
+    let rdb_directory = "/tmp/redis-files-save-points";
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+    use_rdb_file(&env, rdb_directory).await;
+    env.server.write().await.save_points = vec![SavePoint {
+        seconds: 1,
+        changes: 3,
+    }];
+
+    let (store, _, server) = env.clone_env();
+    tokio::spawn(save_on_save_points(server, store));
+
+    for (key, value) in [("grape", "mango"), ("kiwi", "lime")] {
+        env.exec_command_immediate_success_response(
+            TestUtils::set_command(key, value),
+            &client_address,
+            &TestUtils::expected_simple_string("OK"),
+        )
+        .await;
+    }
+
+    // Fewer writes than the save point asks for do not trigger a save
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(
+        env.server.read().await.last_save_time,
+        Timestamp::UNIX_EPOCH
+    );
+    assert_eq!(env.server.read().await.stats.changes_since_last_save(), 2);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("peach", "pear"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while env.server.read().await.last_save_time == Timestamp::UNIX_EPOCH {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("save point should trigger a background save");
+
+    let last_save_time = env.server.read().await.last_save_time.as_second();
+    env.exec_command_immediate_success_response(
+        TestUtils::lastsave_command(),
+        &client_address,
+        &TestUtils::expected_integer(last_save_time),
+    )
+    .await;
+    assert_eq!(env.server.read().await.stats.changes_since_last_save(), 0);
+
+    let rdb_parser = parse_saved_keys(rdb_directory).await;
+    for key in ["grape", "kiwi", "peach"] {
+        assert!(rdb_parser.databases[&0].contains_key(key));
+    }
+}
+
+#[tokio::test]
+async fn test_handle_persistence_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            vec!["SAVE", "now"],
+            CommandError::WrongNumberOfArguments("SAVE".to_string()),
+        ),
+        (vec!["BGSAVE", "now"], CommandError::InvalidBgSaveCommand),
+        (
+            vec!["LASTSAVE", "now"],
+            CommandError::WrongNumberOfArguments("LASTSAVE".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            TestUtils::invalid_command(&command),
+            &client_address,
+            expected_error,
+        )
+        .await;
+    }
+}
@@ -30,6 +30,70 @@ async fn test_handle_set_command() {
     );
 }
 
+#[tokio::test]
+async fn test_handle_set_command_replaces_other_types() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("fruits", &["mango", "kiwi"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::xadd_command("stream", "1-1", &["grape", "mango"]),
+            TestUtils::expected_bulk_string("1-1"),
+        ),
+        (
+            TestUtils::set_command("fruits", "apple"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::set_command("stream", "pear"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("string"),
+        ),
+        (
+            TestUtils::type_command("stream"),
+            TestUtils::expected_simple_string("string"),
+        ),
+        (
+            TestUtils::get_command("fruits"),
+            TestUtils::expected_bulk_string("apple"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    env.exec_command_immediate_error_response(
+        TestUtils::llen_command("fruits"),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+    env.exec_command_immediate_error_response(
+        TestUtils::xlen_command("stream"),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+
+    let store_guard = env.get_store().await;
+    assert_eq!(
+        store_guard.get("fruits"),
+        Some(&Value {
+            data: DataType::String("apple".to_string()),
+            expiration: None,
+        })
+    );
+}
+
 #[tokio::test]
 async fn test_handle_set_command_with_expiration() {
     let mut env = TestEnv::new_master_server();
@@ -71,7 +135,7 @@ async fn test_handle_set_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["SET", "grape"]),
-            CommandError::InvalidSetCommand,
+            CommandError::WrongNumberOfArguments("SET".to_string()),
         ),
         (
             TestUtils::invalid_command(&["SET", "grape", "mango", "px"]),
@@ -96,3 +160,24 @@ async fn test_handle_set_command_invalid() {
         .await;
     }
 }
+
+#[tokio::test]
+async fn test_handle_set_command_wrong_number_of_arguments() {
+    let mut env = TestEnv::new_master_server();
+
+    let result = env
+        .exec_command(
+            TestUtils::invalid_command(&["SET", "grape"]),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+
+    let Err(err) = result else {
+        panic!("Expected SET with the wrong number of arguments to fail");
+    };
+
+    assert_eq!(
+        err.as_string(),
+        "-ERR wrong number of arguments for 'set' command\r\n"
+    );
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use codecrafters_redis::{commands::CommandError, key_value_store::DataType};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn setup_sets(env: &mut TestEnv, client_address: &str) {
+    let setup_commands = vec![
+        (
+            TestUtils::sadd_command("fruits", &["mango", "grape", "apple", "kiwi"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::sadd_command("basket", &["grape", "kiwi", "pear"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sadd_command("berries", &["grape", "raspberry"]),
+            TestUtils::expected_integer(2),
+        ),
+    ];
+
+    for (command, expected_response) in setup_commands {
+        env.exec_command_immediate_success_response(command, client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_set_store_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    setup_sets(&mut env, &client_address).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::set_store_command("SINTERSTORE", "result", &["fruits", "basket"]),
+            2,
+            vec!["grape", "kiwi"],
+        ),
+        (
+            TestUtils::set_store_command("SINTERSTORE", "result", &["fruits", "basket", "berries"]),
+            1,
+            vec!["grape"],
+        ),
+        (
+            TestUtils::set_store_command(
+                "SUNIONSTORE",
+                "result",
+                &["basket", "berries", "vegetables"],
+            ),
+            4,
+            vec!["grape", "kiwi", "pear", "raspberry"],
+        ),
+        (
+            TestUtils::set_store_command("SDIFFSTORE", "result", &["fruits", "basket"]),
+            2,
+            vec!["mango", "apple"],
+        ),
+        (
+            TestUtils::set_store_command("SDIFFSTORE", "result", &["fruits", "vegetables"]),
+            4,
+            vec!["mango", "grape", "apple", "kiwi"],
+        ),
+    ];
+
+    for (command, expected_cardinality, expected_members) in test_cases {
+        env.exec_command_immediate_success_response(
+            command,
+            &client_address,
+            &TestUtils::expected_integer(expected_cardinality),
+        )
+        .await;
+
+        let store_guard = env.get_store().await;
+        let expected_members = expected_members
+            .into_iter()
+            .map(|member| member.to_string())
+            .collect::<HashSet<String>>();
+
+        assert_eq!(
+            store_guard
+                .get("result")
+                .and_then(|value| match value.data {
+                    DataType::Set(ref set) => Some(&**set),
+                    _ => None,
+                }),
+            Some(&expected_members)
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_handle_set_store_commands_empty_result() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    setup_sets(&mut env, &client_address).await;
+
+    let test_cases = vec![
+        TestUtils::set_store_command("SINTERSTORE", "result", &["basket", "vegetables"]),
+        TestUtils::set_store_command("SUNIONSTORE", "result", &["vegetables"]),
+        TestUtils::set_store_command("SDIFFSTORE", "result", &["berries", "berries"]),
+        TestUtils::set_store_command("SDIFFSTORE", "result", &["vegetables", "fruits"]),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::set_command("result", "mango"),
+            &client_address,
+            &TestUtils::expected_simple_string("OK"),
+        )
+        .await;
+
+        env.exec_command_immediate_success_response(
+            command,
+            &client_address,
+            &TestUtils::expected_integer(0),
+        )
+        .await;
+
+        env.exec_command_immediate_success_response(
+            TestUtils::type_command("result"),
+            &client_address,
+            &TestUtils::expected_simple_string("none"),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_set_store_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SINTERSTORE", "result"]),
+            CommandError::WrongNumberOfArguments("SINTERSTORE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["SUNIONSTORE", "result"]),
+            CommandError::WrongNumberOfArguments("SUNIONSTORE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["SDIFFSTORE", "result"]),
+            CommandError::WrongNumberOfArguments("SDIFFSTORE".to_string()),
+        ),
+        (
+            TestUtils::set_store_command("SUNIONSTORE", "result", &["fruits", "grape"]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,100 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_setrange_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::set_command("greeting", "Hello World"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::setrange_command("greeting", 6, "Redis"),
+            TestUtils::expected_integer(11),
+        ),
+        (
+            TestUtils::get_command("greeting"),
+            TestUtils::expected_bulk_string("Hello Redis"),
+        ),
+        (
+            TestUtils::setrange_command("greeting", 6, "Redis!"),
+            TestUtils::expected_integer(12),
+        ),
+        (
+            TestUtils::get_command("greeting"),
+            TestUtils::expected_bulk_string("Hello Redis!"),
+        ),
+        // Missing bytes are padded with zeros
+        (
+            TestUtils::setrange_command("padded", 3, "kiwi"),
+            TestUtils::expected_integer(7),
+        ),
+        (
+            TestUtils::get_command("padded"),
+            TestUtils::expected_bulk_string("\0\0\0kiwi"),
+        ),
+        // An empty value does not create the key
+        (
+            TestUtils::setrange_command("missing", 5, ""),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::get_command("missing"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::setrange_command("greeting", 20, ""),
+            TestUtils::expected_integer(12),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_setrange_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["mango"]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SETRANGE", "fruit", "0"]),
+            CommandError::WrongNumberOfArguments("SETRANGE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["SETRANGE", "fruit", "first", "kiwi"]),
+            CommandError::InvalidSetRangeOffset,
+        ),
+        (
+            TestUtils::setrange_command("fruit", -1, "kiwi"),
+            CommandError::SetRangeOffsetOutOfRange,
+        ),
+        (
+            TestUtils::setrange_command("fruit", 512 * 1024 * 1024, "kiwi"),
+            CommandError::StringExceedsMaximumSize,
+        ),
+        (
+            TestUtils::setrange_command("fruits", 0, "kiwi"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,114 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_sintercard_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::sadd_command("fruits", &["mango", "grape", "apple", "kiwi", "mango"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::sadd_command("basket", &["mango", "grape", "kiwi", "pear"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::sadd_command("basket", &["pear", "banana"]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::type_command("basket"),
+            TestUtils::expected_simple_string("set"),
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits"], None),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits", "basket"], None),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits", "basket"], Some("2")),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits", "basket"], Some("10")),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits", "basket"], Some("0")),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits", "basket", "vegetables"], None),
+            TestUtils::expected_integer(0),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_sintercard_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SADD", "fruits"]),
+            CommandError::WrongNumberOfArguments("SADD".to_string()),
+        ),
+        (
+            TestUtils::sadd_command("grape", &["mango"]),
+            CommandError::WrongType,
+        ),
+        (
+            TestUtils::invalid_command(&["SINTERCARD", "1"]),
+            CommandError::WrongNumberOfArguments("SINTERCARD".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["SINTERCARD", "0", "fruits"]),
+            CommandError::InvalidSInterCardNumberOfKeys,
+        ),
+        (
+            TestUtils::invalid_command(&["SINTERCARD", "two", "fruits"]),
+            CommandError::InvalidSInterCardNumberOfKeys,
+        ),
+        (
+            TestUtils::invalid_command(&["SINTERCARD", "3", "fruits", "basket"]),
+            CommandError::SInterCardNumberOfKeysMismatch,
+        ),
+        (
+            TestUtils::invalid_command(&["SINTERCARD", "1", "fruits", "basket"]),
+            CommandError::InvalidSInterCardCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["SINTERCARD", "1", "fruits", "LIMIT", "-1"]),
+            CommandError::InvalidSInterCardLimit,
+        ),
+        (
+            TestUtils::sintercard_command(&["fruits", "grape"], None),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
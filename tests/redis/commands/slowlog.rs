@@ -0,0 +1,148 @@
+use codecrafters_redis::commands::{CommandError, CommandResult};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_slowlog_command_logs_slow_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::debug_sleep_command("0.05"),
+        &client_address,
+        "+OK\r\n",
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::slowlog_command("LEN", &[]),
+        &client_address,
+        ":1\r\n",
+    )
+    .await;
+
+    let Ok(CommandResult::Response(response)) = env
+        .exec_command(TestUtils::slowlog_command("GET", &[]), &client_address)
+        .await
+    else {
+        panic!("Expected SLOWLOG GET to succeed");
+    };
+
+    let entry = &env.server.read().await.slowlog.get(None)[0].clone();
+    assert_eq!(entry.id, 0);
+    assert!(entry.duration_micros >= 50_000);
+    assert_eq!(
+        entry.arguments,
+        vec!["DEBUG".to_string(), "SLEEP".to_string(), "0.05".to_string()]
+    );
+    assert_eq!(
+        response,
+        format!(
+            "*1\r\n*6\r\n:0\r\n:{}\r\n:{}\r\n*3\r\n$5\r\nDEBUG\r\n$5\r\nSLEEP\r\n$4\r\n0.05\r\n${}\r\n{}\r\n$0\r\n\r\n",
+            entry.timestamp.as_second(),
+            entry.duration_micros,
+            client_address.len(),
+            client_address
+        )
+    );
+
+    env.exec_command_immediate_success_response(
+        TestUtils::slowlog_command("RESET", &[]),
+        &client_address,
+        "+OK\r\n",
+    )
+    .await;
+
+    let test_cases = vec![
+        (TestUtils::slowlog_command("LEN", &[]), ":0\r\n"),
+        (TestUtils::slowlog_command("GET", &["-1"]), "*0\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_slowlog_command_threshold() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.server.write().await.slowlog_log_slower_than = -1;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::debug_sleep_command("0.02"),
+        &client_address,
+        "+OK\r\n",
+    )
+    .await;
+
+    env.server.write().await.slowlog_log_slower_than = 0;
+
+    let test_cases = vec![
+        (TestUtils::set_command("grape", "mango"), "+OK\r\n"),
+        (TestUtils::get_command("grape"), "$5\r\nmango\r\n"),
+        (TestUtils::slowlog_command("LEN", &[]), ":2\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+
+    // SLOWLOG LEN is logged too once it has replied
+    let server_guard = env.server.read().await;
+    let logged_commands: Vec<(u64, &str)> = server_guard
+        .slowlog
+        .get(None)
+        .into_iter()
+        .map(|entry| (entry.id, entry.arguments[0].as_str()))
+        .collect();
+    assert_eq!(
+        logged_commands,
+        vec![(2, "SLOWLOG"), (1, "GET"), (0, "SET")]
+    );
+    assert_eq!(server_guard.slowlog.get(Some(1)).len(), 1);
+}
+
+#[tokio::test]
+async fn test_handle_slowlog_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::slowlog_command("GET", &["-2"]),
+            CommandError::InvalidSlowlogCount,
+        ),
+        (
+            TestUtils::slowlog_command("GET", &["ten"]),
+            CommandError::InvalidSlowlogCount,
+        ),
+        (
+            TestUtils::slowlog_command("GET", &["1", "2"]),
+            CommandError::WrongNumberOfArguments("SLOWLOG GET".to_string()),
+        ),
+        (
+            TestUtils::slowlog_command("LEN", &["1"]),
+            CommandError::WrongNumberOfArguments("SLOWLOG LEN".to_string()),
+        ),
+        (
+            TestUtils::slowlog_command("RESET", &["now"]),
+            CommandError::WrongNumberOfArguments("SLOWLOG RESET".to_string()),
+        ),
+        (
+            TestUtils::debug_sleep_command("soon"),
+            CommandError::InvalidDebugCommandArgument,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use codecrafters_redis::{commands::CommandError, key_value_store::DataType};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn assert_set_members(env: &TestEnv, key: &str, expected_members: &[&str]) {
+    let store_guard = env.get_store().await;
+    let expected_members = expected_members
+        .iter()
+        .map(|member| member.to_string())
+        .collect::<HashSet<String>>();
+
+    assert_eq!(
+        store_guard.get(key).and_then(|value| match value.data {
+            DataType::Set(ref set) => Some(&**set),
+            _ => None,
+        }),
+        Some(&expected_members),
+        "members of {}",
+        key
+    );
+}
+
+#[tokio::test]
+async fn test_handle_smove_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::sadd_command("fruits", &["mango", "grape"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::sadd_command("basket", &["grape", "kiwi"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::smove_command("fruits", "basket", "mango"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::smove_command("fruits", "basket", "pear"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::smove_command("vegetables", "basket", "carrot"),
+            TestUtils::expected_integer(0),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    assert_set_members(&env, "fruits", &["grape"]).await;
+    assert_set_members(&env, "basket", &["grape", "kiwi", "mango"]).await;
+
+    // Moving a member the destination already has still removes it from the source
+    env.exec_command_immediate_success_response(
+        TestUtils::smove_command("fruits", "basket", "grape"),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    assert_set_members(&env, "basket", &["grape", "kiwi", "mango"]).await;
+
+    let test_cases = vec![
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::smove_command("basket", "berries", "kiwi"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::type_command("berries"),
+            TestUtils::expected_simple_string("set"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_smove_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::sadd_command("fruits", &["mango"]),
+            TestUtils::expected_integer(1),
+        ),
+    ];
+
+    for (command, expected_response) in setup_commands {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SMOVE", "fruits", "basket"]),
+            CommandError::WrongNumberOfArguments("SMOVE".to_string()),
+        ),
+        (
+            TestUtils::smove_command("grape", "fruits", "mango"),
+            CommandError::WrongType,
+        ),
+        (
+            TestUtils::smove_command("fruits", "grape", "mango"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+
+    // The failed move left the source untouched
+    assert_set_members(&env, "fruits", &["mango"]).await;
+}
@@ -0,0 +1,331 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use codecrafters_redis::{
+    commands::{CommandError, CommandHandler, CommandResult},
+    key_value_store::{DataType, Value},
+    list::List,
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_sort_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("numbers", &["10", "2", "33", "1.5"]),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::sort_command("numbers", &[]),
+            TestUtils::expected_bulk_string_array(&["1.5", "2", "10", "33"]),
+        ),
+        (
+            TestUtils::sort_command("numbers", &["ALPHA"]),
+            TestUtils::expected_bulk_string_array(&["1.5", "10", "2", "33"]),
+        ),
+        (
+            TestUtils::sort_command("numbers", &["DESC"]),
+            TestUtils::expected_bulk_string_array(&["33", "10", "2", "1.5"]),
+        ),
+        (
+            TestUtils::sort_command("numbers", &["LIMIT", "1", "2"]),
+            TestUtils::expected_bulk_string_array(&["2", "10"]),
+        ),
+        (
+            TestUtils::sort_command("numbers", &["LIMIT", "3", "10", "DESC"]),
+            TestUtils::expected_bulk_string_array(&["1.5"]),
+        ),
+        (
+            TestUtils::sort_command("missing", &[]),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::sadd_command("fruits", &["pear", "apple", "mango"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sort_command("fruits", &["ALPHA", "DESC"]),
+            TestUtils::expected_bulk_string_array(&["pear", "mango", "apple"]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_sort_command_by_and_get_patterns() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("users", &["1", "2", "3"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::set_command("weight_1", "30"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::set_command("weight_2", "10"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::set_command("weight_3", "20"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::hset_command("user_1", &[("name", "alice")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::hset_command("user_2", &[("name", "bob")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::sort_command("users", &["BY", "weight_*"]),
+            TestUtils::expected_bulk_string_array(&["2", "3", "1"]),
+        ),
+        (
+            TestUtils::sort_command("users", &["BY", "weight_*", "DESC"]),
+            TestUtils::expected_bulk_string_array(&["1", "3", "2"]),
+        ),
+        (
+            TestUtils::sort_command("users", &["BY", "nosort", "DESC"]),
+            TestUtils::expected_bulk_string_array(&["1", "2", "3"]),
+        ),
+        (
+            TestUtils::sort_command("users", &["BY", "user_*->name", "ALPHA"]),
+            TestUtils::expected_bulk_string_array(&["3", "1", "2"]),
+        ),
+        (
+            TestUtils::sort_command(
+                "users",
+                &["BY", "weight_*", "GET", "#", "GET", "user_*->name"],
+            ),
+            RespValue::Array(vec![
+                RespValue::BulkString("2".to_string()),
+                RespValue::BulkString("bob".to_string()),
+                RespValue::BulkString("3".to_string()),
+                RespValue::NullBulkString,
+                RespValue::BulkString("1".to_string()),
+                RespValue::BulkString("alice".to_string()),
+            ])
+            .encode(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_sort_command_errors() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("fruits", &["pear", "apple"]),
+        &client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("name", "alice"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SORT"]),
+            CommandError::WrongNumberOfArguments("SORT".to_string()),
+        ),
+        (
+            TestUtils::sort_command("fruits", &["LIMIT", "1"]),
+            CommandError::SortSyntaxError,
+        ),
+        (
+            TestUtils::sort_command("fruits", &["ASCENDING"]),
+            CommandError::SortSyntaxError,
+        ),
+        (
+            TestUtils::sort_command("fruits", &["LIMIT", "a", "1"]),
+            CommandError::InvalidSortLimit,
+        ),
+        (
+            TestUtils::sort_command("fruits", &[]),
+            CommandError::SortScoreNotANumber,
+        ),
+        (
+            TestUtils::sort_command("name", &[]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_sort_command_store() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::rpush_command("numbers", &["3", "1", "2"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sort_command("numbers", &["DESC", "STORE", "sorted"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::sort_command("numbers", &["GET", "missing_*", "STORE", "lookups"]),
+            TestUtils::expected_integer(3),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let store_guard = env.get_store().await;
+    assert_eq!(
+        store_guard.get("sorted"),
+        Some(&Value {
+            data: DataType::Array(List::from(VecDeque::from([
+                "3".to_string(),
+                "2".to_string(),
+                "1".to_string(),
+            ]))),
+            expiration: None,
+        })
+    );
+    assert_eq!(
+        store_guard.get("lookups"),
+        Some(&Value {
+            data: DataType::Array(List::from(VecDeque::from([
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+            ]))),
+            expiration: None,
+        })
+    );
+    drop(store_guard);
+
+    // Storing an empty result removes the destination
+    env.exec_command_immediate_success_response(
+        TestUtils::sort_command("missing", &["STORE", "sorted"]),
+        &client_address,
+        &TestUtils::expected_integer(0),
+    )
+    .await;
+
+    let store_guard = env.get_store().await;
+    assert_eq!(store_guard.get("sorted"), None);
+}
+
+#[tokio::test]
+async fn test_handle_sort_ro_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::rpush_command("numbers", &["3", "1", "2"]),
+        &client_address,
+        &TestUtils::expected_integer(3),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::sort_ro_command("numbers", &["DESC"]),
+        &client_address,
+        &TestUtils::expected_bulk_string_array(&["3", "2", "1"]),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SORT_RO"]),
+            CommandError::InvalidSortRoCommand,
+        ),
+        (
+            TestUtils::sort_ro_command("numbers", &["STORE", "sorted"]),
+            CommandError::SortSyntaxError,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+
+    let store_guard = env.get_store().await;
+    assert_eq!(store_guard.get("sorted"), None);
+}
+
+#[tokio::test]
+async fn test_handle_sort_ro_command_on_replica() {
+    let env = TestEnv::new_replica_server(6380);
+    let (store, state, server) = env.clone_env();
+    let client_address = TestUtils::client_address(41844);
+
+    let mut store_guard = store.lock().await;
+    store_guard.insert(
+        "numbers".to_string(),
+        Value {
+            data: DataType::Array(List::from(VecDeque::from([
+                "2".to_string(),
+                "1".to_string(),
+            ]))),
+            expiration: None,
+        },
+    );
+    drop(store_guard);
+
+    let result = CommandHandler::new(TestUtils::sort_ro_command("numbers", &[]))
+        .unwrap()
+        .handle_command_for_replica_server(
+            &client_address,
+            Arc::clone(&server),
+            Arc::clone(&store),
+            Arc::clone(&state),
+        )
+        .await;
+
+    match result {
+        Ok(CommandResult::Response(response)) => {
+            assert_eq!(response, TestUtils::expected_bulk_string_array(&["1", "2"]))
+        }
+        _ => panic!("Expected SORT_RO to succeed on a replica"),
+    }
+
+    let result = CommandHandler::new(TestUtils::sort_command("numbers", &["STORE", "sorted"]))
+        .unwrap()
+        .handle_command_for_replica_server(
+            &client_address,
+            Arc::clone(&server),
+            Arc::clone(&store),
+            Arc::clone(&state),
+        )
+        .await;
+
+    assert_eq!(result.err(), Some(CommandError::ReplicaReadOnlyCommands));
+}
@@ -0,0 +1,92 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_sscan_command() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::sadd_command("fruits", &["mango", "grape", "kiwi", "melon"]),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(4),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::sscan_command("fruits", "0", &[]),
+            "*2\r\n$1\r\n0\r\n*4\r\n$5\r\ngrape\r\n$4\r\nkiwi\r\n$5\r\nmango\r\n$5\r\nmelon\r\n",
+        ),
+        (
+            TestUtils::sscan_command("fruits", "0", &["COUNT", "3", "MATCH", "m*"]),
+            "*2\r\n$1\r\n3\r\n*1\r\n$5\r\nmango\r\n",
+        ),
+        (
+            TestUtils::sscan_command("fruits", "3", &["COUNT", "3", "MATCH", "m*"]),
+            "*2\r\n$1\r\n0\r\n*1\r\n$5\r\nmelon\r\n",
+        ),
+        (
+            TestUtils::sscan_command("vegetables", "0", &[]),
+            "*2\r\n$1\r\n0\r\n*0\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_sscan_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::hset_command("fruits", &[("mango", "yellow")]),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::sscan_command("fruits", "0", &[]),
+        &client_address,
+        CommandError::WrongType,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_sscan_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SSCAN", "fruits"]),
+            CommandError::WrongNumberOfArguments("SSCAN".to_string()),
+        ),
+        (
+            TestUtils::sscan_command("fruits", "cursor", &[]),
+            CommandError::InvalidScanCursor,
+        ),
+        (
+            TestUtils::sscan_command("fruits", "0", &["NOVALUES"]),
+            CommandError::ScanSyntaxError,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
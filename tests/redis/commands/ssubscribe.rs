@@ -0,0 +1,142 @@
+use codecrafters_redis::commands::CommandError;
+use tokio::sync::mpsc;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_spublish_delivers_to_shard_subscribers() {
+    let mut env = TestEnv::new_master_server();
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let (subscriber_address, subscriber_sender) = TestEnv::new_client_connection().await;
+    let (publisher_address, publisher_sender) = TestEnv::new_client_connection().await;
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::ssubscribe_command(&["orders", "payments"]),
+        "127.0.0.1:50001",
+        sender,
+        Some(
+            "*3\r\n$10\r\nssubscribe\r\n$6\r\norders\r\n:1\r\n*3\r\n$10\r\nssubscribe\r\n$8\r\npayments\r\n:2\r\n"
+                .to_string(),
+        ),
+    )
+    .await;
+
+    // Regular subscribers of a channel with the same name don't receive shard messages
+    env.exec_pub_sub_command_success_response(
+        TestUtils::subscribe_command("orders"),
+        &subscriber_address,
+        subscriber_sender,
+        Some("*3\r\n$9\r\nsubscribe\r\n$6\r\norders\r\n:1\r\n".to_string()),
+    )
+    .await;
+
+    let test_cases = vec![
+        (TestUtils::spublish_command("orders", "mango"), ":1\r\n"),
+        (TestUtils::spublish_command("refunds", "grape"), ":0\r\n"),
+        (TestUtils::publish_command("payments", "apple"), ":0\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_pub_sub_command_success_response(
+            command,
+            &publisher_address,
+            publisher_sender.clone(),
+            Some(expected_response.to_string()),
+        )
+        .await;
+    }
+
+    assert_eq!(
+        String::from_utf8(receiver.recv().await.unwrap()).unwrap(),
+        "*3\r\n$8\r\nsmessage\r\n$6\r\norders\r\n$5\r\nmango\r\n"
+    );
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_handle_sunsubscribe_command() {
+    let mut env = TestEnv::new_master_server();
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::ssubscribe_command(&["orders", "payments", "refunds"]),
+        &client_address,
+        sender.clone(),
+        Some(
+            "*3\r\n$10\r\nssubscribe\r\n$6\r\norders\r\n:1\r\n*3\r\n$10\r\nssubscribe\r\n$8\r\npayments\r\n:2\r\n*3\r\n$10\r\nssubscribe\r\n$7\r\nrefunds\r\n:3\r\n"
+                .to_string(),
+        ),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::get_command("grape"),
+        &client_address,
+        CommandError::InvalidCommandInSubscribedMode("GET".to_string()),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::sunsubscribe_command(&["payments"]),
+            "*3\r\n$12\r\nsunsubscribe\r\n$8\r\npayments\r\n:2\r\n",
+        ),
+        (
+            TestUtils::sunsubscribe_command(&[]),
+            "*3\r\n$12\r\nsunsubscribe\r\n$6\r\norders\r\n:1\r\n*3\r\n$12\r\nsunsubscribe\r\n$7\r\nrefunds\r\n:0\r\n",
+        ),
+        (
+            TestUtils::sunsubscribe_command(&[]),
+            "*3\r\n$12\r\nsunsubscribe\r\n$-1\r\n:0\r\n",
+        ),
+        (TestUtils::spublish_command("orders", "mango"), ":0\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_pub_sub_command_success_response(
+            command,
+            &client_address,
+            sender.clone(),
+            Some(expected_response.to_string()),
+        )
+        .await;
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::get_command("grape"),
+        &client_address,
+        "$-1\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_shard_pub_sub_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["SSUBSCRIBE"]),
+            CommandError::WrongNumberOfArguments("SSUBSCRIBE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["SPUBLISH", "orders"]),
+            CommandError::WrongNumberOfArguments("SPUBLISH".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["SPUBLISH", "orders", "mango", "grape"]),
+            CommandError::WrongNumberOfArguments("SPUBLISH".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_pub_sub_command_error_response(
+            command,
+            &client_address,
+            sender.clone(),
+            expected_error,
+        )
+        .await;
+    }
+}
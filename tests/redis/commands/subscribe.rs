@@ -1,5 +1,3 @@
-use std::sync::Arc;
-
 use codecrafters_redis::commands::CommandError;
 
 use crate::test_utils::{TestEnv, TestUtils};
@@ -7,12 +5,12 @@ use crate::test_utils::{TestEnv, TestUtils};
 #[tokio::test]
 async fn test_handle_subscribe_command() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address,
-        writer,
+        sender,
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -25,12 +23,12 @@ async fn test_handle_subscribe_command() {
 #[tokio::test]
 async fn test_handle_consecutive_subscribe_commands_for_same_channel() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address,
-        Arc::clone(&writer),
+        sender.clone(),
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -38,7 +36,7 @@ async fn test_handle_consecutive_subscribe_commands_for_same_channel() {
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address,
-        writer,
+        sender,
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -51,12 +49,12 @@ async fn test_handle_consecutive_subscribe_commands_for_same_channel() {
 #[tokio::test]
 async fn test_handle_consecutive_subscribe_commands_for_different_channel() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address,
-        Arc::clone(&writer),
+        sender.clone(),
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -64,7 +62,7 @@ async fn test_handle_consecutive_subscribe_commands_for_different_channel() {
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel2"),
         &client_address,
-        writer,
+        sender,
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel2\r\n:2\r\n".to_string()),
     )
     .await;
@@ -78,12 +76,12 @@ async fn test_handle_consecutive_subscribe_commands_for_different_channel() {
 #[tokio::test]
 async fn test_handle_subscribe_command_invalid() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["SUBSCRIBE"]),
-            CommandError::InvalidSubscribeCommand,
+            CommandError::WrongNumberOfArguments("SUBSCRIBE".to_string()),
         ),
         (
             TestUtils::invalid_command(&["SUBSCRIBE", "channel1", "channel2"]),
@@ -95,9 +93,116 @@ async fn test_handle_subscribe_command_invalid() {
         env.exec_pub_sub_command_error_response(
             command,
             &client_address,
-            Arc::clone(&writer),
+            sender.clone(),
             expected_error,
         )
         .await;
     }
 }
+
+#[tokio::test]
+async fn test_handle_regular_command_in_resp2_subscribed_mode() {
+    let mut env = TestEnv::new_master_server();
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::subscribe_command("channel1"),
+        &client_address,
+        sender,
+        Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
+    )
+    .await;
+
+    env.exec_command_immediate_error_response(
+        TestUtils::get_command("grape"),
+        &client_address,
+        CommandError::InvalidCommandInSubscribedMode("GET".to_string()),
+    )
+    .await;
+
+    assert_eq!(
+        CommandError::InvalidCommandInSubscribedMode("GET".to_string()).as_string(),
+        "-ERR Can't execute 'get': only (P|S)SUBSCRIBE / (P|S)UNSUBSCRIBE / PING / QUIT / RESET are allowed in this context\r\n"
+    );
+
+    let test_cases = vec![
+        (TestUtils::reset_command(), "+RESET\r\n"),
+        (TestUtils::get_command("grape"), "$-1\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_regular_command_in_resp3_subscribed_mode() {
+    let mut env = TestEnv::new_master_server();
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+
+    env.exec_command(TestUtils::hello_command(Some("3")), &client_address)
+        .await
+        .unwrap();
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::subscribe_command("channel1"),
+        &client_address,
+        sender.clone(),
+        Some(">3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
+    )
+    .await;
+
+    env.exec_pub_sub_command_success_response(
+        TestUtils::ping_command(),
+        &client_address,
+        sender,
+        None,
+    )
+    .await;
+
+    let test_cases = vec![
+        (TestUtils::set_command("grape", "mango"), "+OK\r\n"),
+        (TestUtils::get_command("grape"), "$5\r\nmango\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_subscribe_command_in_transaction() {
+    let mut env = TestEnv::new_master_server();
+    let (client_address, sender) = TestEnv::new_client_connection().await;
+
+    let test_cases = vec![
+        (TestUtils::multi_command(), "+OK\r\n"),
+        (TestUtils::set_command("grape", "mango"), "+QUEUED\r\n"),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, expected_response)
+            .await;
+    }
+
+    env.exec_pub_sub_command_error_response(
+        TestUtils::subscribe_command("channel1"),
+        &client_address,
+        sender,
+        CommandError::CommandNotAllowedInTransaction("SUBSCRIBE".to_string()),
+    )
+    .await;
+
+    assert_eq!(
+        CommandError::CommandNotAllowedInTransaction("SUBSCRIBE".to_string()).as_string(),
+        "-ERR SUBSCRIBE is not allowed in transactions\r\n"
+    );
+
+    // The connection did not subscribe and the queued commands still run on EXEC
+    assert!(env.server.read().await.pub_sub_channels.is_empty());
+
+    env.exec_command_transaction_success_response(&client_address, "*1\r\n+OK\r\n")
+        .await;
+}
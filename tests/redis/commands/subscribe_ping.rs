@@ -1,5 +1,3 @@
-use std::sync::Arc;
-
 use codecrafters_redis::commands::CommandError;
 
 use crate::test_utils::{TestEnv, TestUtils};
@@ -7,12 +5,12 @@ use crate::test_utils::{TestEnv, TestUtils};
 #[tokio::test]
 async fn test_handle_ping_after_subscribe_command() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::subscribe_command("channel1"),
         &client_address,
-        Arc::clone(&writer),
+        sender.clone(),
         Some("*3\r\n$9\r\nsubscribe\r\n$8\r\nchannel1\r\n:1\r\n".to_string()),
     )
     .await;
@@ -20,7 +18,7 @@ async fn test_handle_ping_after_subscribe_command() {
     env.exec_pub_sub_command_success_response(
         TestUtils::ping_command(),
         &client_address,
-        writer,
+        sender,
         Some("*2\r\n$4\r\npong\r\n$0\r\n\r\n".to_string()),
     )
     .await;
@@ -29,12 +27,12 @@ async fn test_handle_ping_after_subscribe_command() {
 #[tokio::test]
 async fn test_handle_ping_without_subscribe_command() {
     let mut env = TestEnv::new_master_server();
-    let (client_address, writer) = TestEnv::new_client_connection().await;
+    let (client_address, sender) = TestEnv::new_client_connection().await;
 
     env.exec_pub_sub_command_success_response(
         TestUtils::ping_command(),
         &client_address,
-        writer,
+        sender,
         None,
     )
     .await;
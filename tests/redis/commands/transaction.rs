@@ -1,4 +1,8 @@
-use codecrafters_redis::commands::{CommandError, CommandHandler};
+use codecrafters_redis::{
+    commands::{CommandError, CommandHandler},
+    server::Replica,
+};
+use tokio::sync::mpsc;
 
 use crate::test_utils::{TestEnv, TestUtils};
 
@@ -116,7 +120,7 @@ async fn test_handle_fail_to_add_invalid_command_to_transaction() {
     env.exec_command_immediate_error_response(
         TestUtils::invalid_command(&["GET", "key", "value"]),
         &TestUtils::client_address(41844),
-        CommandError::InvalidGetCommand,
+        CommandError::WrongNumberOfArguments("GET".to_string()),
     )
     .await;
 }
@@ -279,3 +283,153 @@ async fn test_handle_should_run_all_queued_commands_even_if_errors_occur() {
     let transaction = state_guard.get_transaction(&TestUtils::client_address(41844));
     assert_eq!(transaction, None);
 }
+
+#[tokio::test]
+async fn test_handle_exec_command_propagates_writes_wrapped_in_multi_exec() {
+    let mut env = TestEnv::new_master_server();
+    let (sender, mut replication_stream) = mpsc::unbounded_channel();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.replicas.as_mut().unwrap().insert(
+            TestUtils::client_address(6380),
+            Replica { sender, offset: 0 },
+        );
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::multi_command(),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    for command in [
+        TestUtils::set_command("grapes", "4"),
+        TestUtils::get_command("grapes"),
+        TestUtils::rpush_command("grapes", &["mango"]),
+        TestUtils::incr_command("grapes"),
+    ] {
+        env.exec_command_immediate_success_response(
+            command,
+            &TestUtils::client_address(41844),
+            &TestUtils::expected_simple_string("QUEUED"),
+        )
+        .await;
+    }
+
+    env.exec_command_transaction_success_response(
+        &TestUtils::client_address(41844),
+        "*4\r\n+OK\r\n$1\r\n4\r\n-WRONGTYPE Operation against a key holding the wrong kind of value\r\n:5\r\n",
+    )
+    .await;
+
+    // The GET and the failed RPUSH are left out of the replication stream
+    let expected_stream = [
+        TestUtils::multi_command(),
+        TestUtils::set_command("grapes", "4"),
+        TestUtils::incr_command("grapes"),
+        TestUtils::exec_command(),
+    ]
+    .iter()
+    .map(|command| command.encode())
+    .collect::<String>();
+
+    assert_eq!(
+        String::from_utf8(replication_stream.try_recv().unwrap()).unwrap(),
+        expected_stream
+    );
+    assert!(replication_stream.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_handle_exec_command_propagates_relative_expirations_as_absolute_timestamps() {
+    let mut env = TestEnv::new_master_server();
+    let (sender, mut replication_stream) = mpsc::unbounded_channel();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.replicas.as_mut().unwrap().insert(
+            TestUtils::client_address(6380),
+            Replica { sender, offset: 0 },
+        );
+    }
+
+    for (command, expected_response) in [
+        (TestUtils::multi_command(), "OK"),
+        (TestUtils::set_command("grapes", "4"), "QUEUED"),
+        (TestUtils::expire_command("grapes", 100), "QUEUED"),
+    ] {
+        env.exec_command_immediate_success_response(
+            command,
+            &TestUtils::client_address(41844),
+            &TestUtils::expected_simple_string(expected_response),
+        )
+        .await;
+    }
+
+    env.exec_command_transaction_success_response(
+        &TestUtils::client_address(41844),
+        "*2\r\n+OK\r\n:1\r\n",
+    )
+    .await;
+
+    // Replicas expire the key at the moment the master applied to its own copy
+    let expiration_ms = env.store.lock().await["grapes"]
+        .expiration
+        .unwrap()
+        .as_millisecond()
+        .to_string();
+
+    let expected_stream = [
+        TestUtils::multi_command(),
+        TestUtils::set_command("grapes", "4"),
+        TestUtils::raw_command(&["PEXPIREAT", "grapes", &expiration_ms]),
+        TestUtils::exec_command(),
+    ]
+    .iter()
+    .map(|command| command.encode())
+    .collect::<String>();
+
+    assert_eq!(
+        String::from_utf8(replication_stream.try_recv().unwrap()).unwrap(),
+        expected_stream
+    );
+    assert!(replication_stream.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_handle_exec_command_without_writes_propagates_nothing() {
+    let mut env = TestEnv::new_master_server();
+    let (sender, mut replication_stream) = mpsc::unbounded_channel();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.replicas.as_mut().unwrap().insert(
+            TestUtils::client_address(6380),
+            Replica { sender, offset: 0 },
+        );
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::multi_command(),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::get_command("grapes"),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_simple_string("QUEUED"),
+    )
+    .await;
+
+    env.exec_command_transaction_success_response(
+        &TestUtils::client_address(41844),
+        "*1\r\n$-1\r\n",
+    )
+    .await;
+
+    assert!(replication_stream.try_recv().is_err());
+}
@@ -80,11 +80,11 @@ async fn test_handle_type_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["TYPE"]),
-            CommandError::InvalidTypeCommand,
+            CommandError::WrongNumberOfArguments("TYPE".to_string()),
         ),
         (
             TestUtils::invalid_command(&["TYPE", "grape", "mango"]),
-            CommandError::InvalidTypeCommand,
+            CommandError::WrongNumberOfArguments("TYPE".to_string()),
         ),
     ];
 
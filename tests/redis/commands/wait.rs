@@ -0,0 +1,129 @@
+use std::{sync::Arc, time::Duration};
+
+use codecrafters_redis::server::register_replica;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::RwLock,
+    time::Instant,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_wait_command_without_replicas() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let start = Instant::now();
+    env.exec_command_immediate_success_response(
+        TestUtils::wait_command(0, 100),
+        &client_address,
+        &TestUtils::expected_integer(0),
+    )
+    .await;
+    assert!(start.elapsed() < Duration::from_millis(100));
+
+    // Waiting for more replicas than are connected still lasts until the timeout
+    let start = Instant::now();
+    env.exec_command_immediate_success_response(
+        TestUtils::wait_command(1, 100),
+        &client_address,
+        &TestUtils::expected_integer(0),
+    )
+    .await;
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_handle_wait_command_with_one_replica() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let replica_addr = listener.local_addr().unwrap();
+
+    let master_side = TcpStream::connect(replica_addr).await.unwrap();
+    let (_mock_replica, _) = listener.accept().await.unwrap();
+    let (_reader, writer) = master_side.into_split();
+
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    register_replica(
+        Arc::clone(&env.server),
+        &replica_addr.to_string(),
+        Arc::new(RwLock::new(writer)),
+    )
+    .await;
+
+    let start = Instant::now();
+    env.exec_command_immediate_success_response(
+        TestUtils::wait_command(0, 100),
+        &client_address,
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+    assert!(start.elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_handle_wait_command_after_replica_disconnects() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let replica_addr = listener.local_addr().unwrap();
+
+    let master_side = TcpStream::connect(replica_addr).await.unwrap();
+    let (mock_replica, _) = listener.accept().await.unwrap();
+    let (_reader, writer) = master_side.into_split();
+
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    register_replica(
+        Arc::clone(&env.server),
+        &replica_addr.to_string(),
+        Arc::new(RwLock::new(writer)),
+    )
+    .await;
+
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("replication")),
+        &client_address,
+        &TestUtils::expected_bulk_string(&format!(
+            "role:master\r\nconnected_slaves:1\r\nmaster_replid:{}\r\nmaster_repl_offset:0",
+            env.server.read().await.repl_id
+        )),
+    )
+    .await;
+
+    drop(mock_replica);
+
+    // Writes to the closed connection start failing once the peer resets it
+    for _ in 0..50 {
+        let server_guard = env.server.read().await;
+        if server_guard.replicas.as_ref().unwrap().is_empty() {
+            break;
+        }
+
+        server_guard
+            .should_replicate_write_command(TestUtils::set_command("grape", "mango"), "SET");
+        drop(server_guard);
+
+        TestUtils::sleep_ms(20).await;
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::info_command(Some("replication")),
+        &client_address,
+        &TestUtils::expected_bulk_string(&format!(
+            "role:master\r\nconnected_slaves:0\r\nmaster_replid:{}\r\nmaster_repl_offset:0",
+            env.server.read().await.repl_id
+        )),
+    )
+    .await;
+
+    let start = Instant::now();
+    env.exec_command_immediate_success_response(
+        TestUtils::wait_command(1, 100),
+        &client_address,
+        &TestUtils::expected_integer(0),
+    )
+    .await;
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
@@ -0,0 +1,68 @@
+use codecrafters_redis::{commands::CommandError, resp::RespValue};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_waitaof_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let expected_response =
+        RespValue::Array(vec![RespValue::Integer(0), RespValue::Integer(0)]).encode();
+
+    let test_cases = vec![
+        TestUtils::waitaof_command(0, 0, 0),
+        TestUtils::waitaof_command(0, 1, 10),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_waitaof_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["WAITAOF", "0", "0"]),
+            CommandError::WrongNumberOfArguments("WAITAOF".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["WAITAOF", "-1", "0", "0"]),
+            CommandError::InvalidWaitaofCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["WAITAOF", "0", "one", "0"]),
+            CommandError::InvalidWaitaofCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["WAITAOF", "0", "0", "soon"]),
+            CommandError::InvalidWaitaofCommandArgument,
+        ),
+        (
+            TestUtils::waitaof_command(1, 0, 0),
+            CommandError::WaitaofAppendOnlyDisabled,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_waitaof_command_on_replica() {
+    let mut env = TestEnv::new_replica_server(6380);
+
+    env.exec_command_immediate_error_response(
+        TestUtils::waitaof_command(0, 0, 0),
+        &TestUtils::client_address(41844),
+        CommandError::InvalidWaitaofCommandForReplica,
+    )
+    .await;
+}
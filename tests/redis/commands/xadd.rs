@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use codecrafters_redis::{
     commands::CommandError,
     key_value_store::{DataType, Value},
+    stream::Stream,
 };
 
 use crate::test_utils::{TestEnv, TestUtils};
@@ -28,7 +29,7 @@ async fn test_handle_xadd_command() {
     assert_eq!(
         value,
         Some(&Value {
-            data: DataType::Stream(BTreeMap::from([(
+            data: DataType::Stream(Stream::from([(
                 stream_id.to_string(),
                 BTreeMap::from([
                     ("mango".to_string(), "apple".to_string()),
@@ -59,7 +60,7 @@ async fn test_handle_xadd_command_invalid_data_type() {
             &["mango", "apple", "raspberry", "pear"],
         ),
         &TestUtils::client_address(41844),
-        CommandError::InvalidDataTypeForKey,
+        CommandError::WrongType,
     )
     .await;
 }
@@ -71,11 +72,11 @@ async fn test_handle_xadd_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["XADD"]),
-            CommandError::InvalidXAddCommand,
+            CommandError::WrongNumberOfArguments("XADD".to_string()),
         ),
         (
             TestUtils::invalid_command(&["XADD", "fruits", "1526919030474-0", "mango"]),
-            CommandError::InvalidXAddCommand,
+            CommandError::WrongNumberOfArguments("XADD".to_string()),
         ),
         (
             TestUtils::invalid_command(&[
@@ -90,7 +91,9 @@ async fn test_handle_xadd_command_invalid() {
         ),
         (
             TestUtils::invalid_command(&["XADD", "fruits", "invalid_stream_id", "mango", "apple"]),
-            CommandError::InvalidStreamId("Invalid stream ID format".to_string()),
+            CommandError::InvalidStreamId(
+                "Invalid stream ID specified as stream command argument".to_string(),
+            ),
         ),
     ];
 
@@ -103,3 +106,43 @@ async fn test_handle_xadd_command_invalid() {
         .await;
     }
 }
+
+#[tokio::test]
+async fn test_handle_xadd_command_stream_id_error_messages() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::xadd_command("fruits", "5-5", &["mango", "apple"]),
+        &client_address,
+        &TestUtils::expected_bulk_string("5-5"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::xadd_command("fruits", "0-0", &["mango", "apple"]),
+            "-ERR The ID specified in XADD must be greater than 0-0\r\n",
+        ),
+        (
+            TestUtils::xadd_command("fruits", "5-5", &["mango", "apple"]),
+            "-ERR The ID specified in XADD is equal or smaller than the target stream top item\r\n",
+        ),
+        (
+            TestUtils::xadd_command("fruits", "4-9", &["mango", "apple"]),
+            "-ERR The ID specified in XADD is equal or smaller than the target stream top item\r\n",
+        ),
+        (
+            TestUtils::xadd_command("fruits", "mango-1", &["mango", "apple"]),
+            "-ERR Invalid stream ID specified as stream command argument\r\n",
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        let Err(err) = env.exec_command(command, &client_address).await else {
+            panic!("Expected XADD with an invalid stream ID to fail");
+        };
+
+        assert_eq!(err.as_string(), expected_error);
+    }
+}
@@ -0,0 +1,81 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_xdel_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::xdel_command("fruits", &["1-1"]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-0", &["mango", "yellow"]),
+            TestUtils::expected_bulk_string("1-0"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-1", &["grape", "purple"]),
+            TestUtils::expected_bulk_string("1-1"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-2", &["kiwi", "green"]),
+            TestUtils::expected_bulk_string("1-2"),
+        ),
+        (
+            TestUtils::xdel_command("fruits", &["1", "1-2", "5-5"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::xdel_command("fruits", &["1-1"]),
+            TestUtils::expected_integer(1),
+        ),
+        // The stream outlives its last entry and keeps generating IDs after it
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-*", &["pear", "green"]),
+            TestUtils::expected_bulk_string("1-3"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_xdel_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["XDEL", "fruits"]),
+            CommandError::WrongNumberOfArguments("XDEL".to_string()),
+        ),
+        (
+            TestUtils::xdel_command("fruits", &["invalid-id"]),
+            CommandError::InvalidStreamId(
+                "Invalid stream ID specified as stream command argument".to_string(),
+            ),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,80 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_xlen_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-1", &["mango", "yellow"]),
+            TestUtils::expected_bulk_string("1-1"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-2", &["grape", "purple"]),
+            TestUtils::expected_bulk_string("1-2"),
+        ),
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(2),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_stream_commands_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("fruit", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let commands = vec![
+        TestUtils::xadd_command("fruit", "1-1", &["mango", "yellow"]),
+        TestUtils::xrange_command("fruit", "-", "+"),
+        TestUtils::xread_command(&["fruit"], &["0-0"]),
+        TestUtils::xlen_command("fruit"),
+        TestUtils::xdel_command("fruit", &["1-1"]),
+    ];
+
+    for command in commands {
+        let result = env.exec_command(command, &client_address).await;
+
+        let Err(err) = result else {
+            panic!("Expected stream command against a string key to fail");
+        };
+
+        assert_eq!(err, CommandError::WrongType);
+        assert_eq!(
+            err.as_string(),
+            "-WRONGTYPE Operation against a key holding the wrong kind of value\r\n"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_handle_xlen_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    env.exec_command_immediate_error_response(
+        TestUtils::invalid_command(&["XLEN"]),
+        &TestUtils::client_address(41844),
+        CommandError::WrongNumberOfArguments("XLEN".to_string()),
+    )
+    .await;
+}
@@ -130,7 +130,7 @@ async fn test_handle_xrange_command_invalid_data_type() {
     env.exec_command_immediate_error_response(
         TestUtils::xrange_command("fruit", "1526919030424-0", "1526919030424-2"),
         &TestUtils::client_address(41844),
-        CommandError::InvalidDataTypeForKey,
+        CommandError::WrongType,
     )
     .await;
 }
@@ -181,7 +181,7 @@ async fn test_handle_xrange_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["XRANGE", "grape", "1526919030424-0"]),
-            CommandError::InvalidXRangeCommand,
+            CommandError::WrongNumberOfArguments("XRANGE".to_string()),
         ),
         (
             TestUtils::invalid_command(&[
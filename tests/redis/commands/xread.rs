@@ -132,7 +132,7 @@ async fn test_handle_xread_command_invalid_data_type() {
     env.exec_command_immediate_error_response(
         TestUtils::xread_command(&["fruit"], &["1526919030424-0"]),
         &TestUtils::client_address(41844),
-        CommandError::InvalidDataTypeForKey,
+        CommandError::WrongType,
     )
     .await;
 }
@@ -156,7 +156,7 @@ async fn test_handle_xread_command_invalid() {
     let test_cases = vec![
         (
             TestUtils::invalid_command(&["XREAD", "STREAMS"]),
-            CommandError::InvalidXReadCommand,
+            CommandError::WrongNumberOfArguments("XREAD".to_string()),
         ),
         (
             TestUtils::invalid_command(&[
@@ -215,6 +215,111 @@ async fn test_handle_xread_command_invalid() {
         )
         .await;
     }
+
+    let test_cases = vec![
+        (&["COUNT", "many"][..], CommandError::InvalidXReadCount),
+        (&["COUNT", "-1"][..], CommandError::InvalidXReadCount),
+        (
+            &["BLOCK", "100", "COUNT", "x"][..],
+            CommandError::InvalidXReadCount,
+        ),
+    ];
+
+    for (options, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            TestUtils::xread_command_with_options(options, &["mango"], &["0-0"]),
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+
+    for stream_id in ["+", "-", "invalid", "1526919030424-invalid", "1-2-3"] {
+        env.exec_command_immediate_error_response(
+            TestUtils::xread_command(&["mango"], &[stream_id]),
+            &TestUtils::client_address(41844),
+            CommandError::InvalidStreamId(
+                "Invalid stream ID specified as stream command argument".to_string(),
+            ),
+        )
+        .await;
+    }
+}
+
+/// Options, keys and start stream IDs of an XREAD command, with the expected response
+type XreadOptionsTestCase = (
+    &'static [&'static str],
+    &'static [&'static str],
+    &'static [&'static str],
+    &'static str,
+);
+
+#[tokio::test]
+async fn test_handle_xread_command_with_count() {
+    let mut env = TestEnv::new_master_server();
+
+    for key in ["fruits", "berries"] {
+        for stream_id in ["1-0", "2-0", "3-0"] {
+            env.exec_command_immediate_success_response(
+                TestUtils::xadd_command(key, stream_id, &["name", key]),
+                &TestUtils::client_address(41844),
+                &TestUtils::expected_bulk_string(stream_id),
+            )
+            .await;
+        }
+    }
+
+    let test_cases: Vec<XreadOptionsTestCase> = vec![
+        (
+            &["COUNT", "2"],
+            &["fruits", "berries"],
+            &["0-0", "1-0"],
+            "*2\r\n*2\r\n$6\r\nfruits\r\n*2\r\n*2\r\n$3\r\n1-0\r\n*2\r\n$4\r\nname\r\n$6\r\nfruits\r\n*2\r\n$3\r\n2-0\r\n*2\r\n$4\r\nname\r\n$6\r\nfruits\r\n*2\r\n$7\r\nberries\r\n*2\r\n*2\r\n$3\r\n2-0\r\n*2\r\n$4\r\nname\r\n$7\r\nberries\r\n*2\r\n$3\r\n3-0\r\n*2\r\n$4\r\nname\r\n$7\r\nberries\r\n",
+        ),
+        (
+            &["count", "5"],
+            &["fruits"],
+            &["1-0"],
+            "*1\r\n*2\r\n$6\r\nfruits\r\n*2\r\n*2\r\n$3\r\n2-0\r\n*2\r\n$4\r\nname\r\n$6\r\nfruits\r\n*2\r\n$3\r\n3-0\r\n*2\r\n$4\r\nname\r\n$6\r\nfruits\r\n",
+        ),
+        (
+            &["BLOCK", "100", "COUNT", "1"],
+            &["fruits"],
+            &["1-0"],
+            "*1\r\n*2\r\n$6\r\nfruits\r\n*1\r\n*2\r\n$3\r\n2-0\r\n*2\r\n$4\r\nname\r\n$6\r\nfruits\r\n",
+        ),
+    ];
+
+    for (options, keys, start_stream_ids, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(
+            TestUtils::xread_command_with_options(options, keys, start_stream_ids),
+            &TestUtils::client_address(41844),
+            expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_xread_command_bare_timestamp() {
+    let mut env = TestEnv::new_master_server();
+
+    for stream_id in ["1526919030404-0", "1526919030404-1", "1526919030414-0"] {
+        env.exec_command_immediate_success_response(
+            TestUtils::xadd_command("fruits", stream_id, &["mango", "apple"]),
+            &TestUtils::client_address(41844),
+            &TestUtils::expected_bulk_string(stream_id),
+        )
+        .await;
+    }
+
+    // A bare timestamp is read as sequence 0, so entries after 1526919030404-0 are returned
+    env.exec_command_immediate_success_response(
+        TestUtils::xread_command(&["fruits"], &["1526919030404"]),
+        &TestUtils::client_address(41844),
+        "*1\r\n*2\r\n$6\r\nfruits\r\n*2\r\n*2\r\n$15\r\n1526919030404-1\r\n*2\r\n$5\r\nmango\r\n$5\r\napple\r\n*2\r\n$15\r\n1526919030414-0\r\n*2\r\n$5\r\nmango\r\n$5\r\napple\r\n",
+    )
+    .await;
 }
 
 #[tokio::test]
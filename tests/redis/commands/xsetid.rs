@@ -0,0 +1,121 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_xsetid_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::xadd_command("fruits", "5-1", &["mango", "apple"]),
+            TestUtils::expected_bulk_string("5-1"),
+        ),
+        (
+            TestUtils::xsetid_command("fruits", "5-5"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "5-*", &["mango", "pear"]),
+            TestUtils::expected_bulk_string("5-6"),
+        ),
+        (
+            TestUtils::xsetid_command("fruits", "99999999999999"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "*", &["mango", "kiwi"]),
+            TestUtils::expected_bulk_string("99999999999999-1"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    env.exec_command_immediate_error_response(
+        TestUtils::xadd_command("fruits", "99999999999999-1", &["mango", "plum"]),
+        &client_address,
+        CommandError::InvalidStreamId(
+            "The ID specified in XADD is equal or smaller than the target stream top item"
+                .to_string(),
+        ),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_xsetid_command_compares_ids_numerically() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::xadd_command("fruits", "9-0", &["mango", "apple"]),
+            TestUtils::expected_bulk_string("9-0"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "10-0", &["mango", "pear"]),
+            TestUtils::expected_bulk_string("10-0"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "10-*", &["mango", "kiwi"]),
+            TestUtils::expected_bulk_string("10-1"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    env.exec_command_immediate_error_response(
+        TestUtils::xsetid_command("fruits", "9-5"),
+        &client_address,
+        CommandError::InvalidStreamId(
+            "The ID specified in XSETID is smaller than the target stream top item".to_string(),
+        ),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_xsetid_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("mango", "apple"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["XSETID", "fruits"]),
+            CommandError::WrongNumberOfArguments("XSETID".to_string()),
+        ),
+        (
+            TestUtils::xsetid_command("fruits", "invalid-id"),
+            CommandError::InvalidStreamId(
+                "Invalid stream ID specified as stream command argument".to_string(),
+            ),
+        ),
+        (
+            TestUtils::xsetid_command("fruits", "5-5"),
+            CommandError::NoSuchKey,
+        ),
+        (
+            TestUtils::xsetid_command("mango", "5-5"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
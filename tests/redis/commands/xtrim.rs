@@ -0,0 +1,174 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_xtrim_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let mut test_cases = vec![(
+        TestUtils::xtrim_command("fruits", &["MAXLEN", "3"]),
+        TestUtils::expected_integer(0),
+    )];
+
+    for sequence in 1..=10 {
+        let stream_id = format!("1-{}", sequence);
+        test_cases.push((
+            TestUtils::xadd_command("fruits", &stream_id, &["mango", "yellow"]),
+            TestUtils::expected_bulk_string(&stream_id),
+        ));
+    }
+
+    test_cases.extend(vec![
+        // Approximate trimming only removes whole nodes of 100 entries by default
+        (
+            TestUtils::xtrim_command("fruits", &["MAXLEN", "~", "3"]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::xtrim_command("fruits", &["maxlen", "=", "8"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::xrange_command("fruits", "-", "1-3"),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::debug_set_stream_max_entries_command("1"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::xtrim_command("fruits", &["MAXLEN", "~", "3"]),
+            TestUtils::expected_integer(5),
+        ),
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(3),
+        ),
+    ]);
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_xadd_command_with_maxlen() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::debug_set_stream_max_entries_command("1"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-1", &["mango", "yellow"]),
+            TestUtils::expected_bulk_string("1-1"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-2", &["grape", "purple"]),
+            TestUtils::expected_bulk_string("1-2"),
+        ),
+        (
+            TestUtils::xadd_command("fruits", "1-3", &["kiwi", "green"]),
+            TestUtils::expected_bulk_string("1-3"),
+        ),
+        (
+            TestUtils::xadd_with_trim_command(
+                "fruits",
+                &["MAXLEN", "~", "3"],
+                "1-4",
+                &["pear", "green"],
+            ),
+            TestUtils::expected_bulk_string("1-4"),
+        ),
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::xadd_with_trim_command(
+                "fruits",
+                &["MAXLEN", "1"],
+                "1-5",
+                &["fig", "purple"],
+            ),
+            TestUtils::expected_bulk_string("1-5"),
+        ),
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(1),
+        ),
+        // A field literally named MAXLEN is still read as an entry after the ID
+        (
+            TestUtils::xadd_command("fruits", "1-6", &["MAXLEN", "1"]),
+            TestUtils::expected_bulk_string("1-6"),
+        ),
+        (
+            TestUtils::xlen_command("fruits"),
+            TestUtils::expected_integer(2),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_xtrim_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["XTRIM", "fruits"]),
+            CommandError::WrongNumberOfArguments("XTRIM".to_string()),
+        ),
+        (
+            TestUtils::xtrim_command("fruits", &["MINID", "3"]),
+            CommandError::InvalidXTrimCommand,
+        ),
+        (
+            TestUtils::xtrim_command("fruits", &["MAXLEN", "3", "extra"]),
+            CommandError::InvalidXTrimCommand,
+        ),
+        (
+            TestUtils::xtrim_command("fruits", &["MAXLEN", "~", "-3"]),
+            CommandError::InvalidStreamTrimStrategy(
+                "value is not an integer or out of range".to_string(),
+            ),
+        ),
+        (
+            TestUtils::xadd_with_trim_command(
+                "fruits",
+                &["MAXLEN", "x"],
+                "1-1",
+                &["kiwi", "green"],
+            ),
+            CommandError::InvalidStreamTrimStrategy(
+                "value is not an integer or out of range".to_string(),
+            ),
+        ),
+        (
+            TestUtils::xadd_with_trim_command("fruits", &["MAXLEN", "3"], "1-1", &["kiwi"]),
+            CommandError::InvalidXAddCommand,
+        ),
+        (
+            TestUtils::debug_set_stream_max_entries_command("-1"),
+            CommandError::InvalidDebugCommandArgument,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_error,
+        )
+        .await;
+    }
+}
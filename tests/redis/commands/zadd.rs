@@ -0,0 +1,254 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zadd_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zadd_command("fruits", &[], &[("1", "mango"), ("2.5", "grape")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &[], &[("3", "mango"), ("4", "apple")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["CH"], &[("5", "mango"), ("1", "banana")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["NX"], &[("10", "mango"), ("6", "kiwi")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["XX"], &[("7", "kiwi"), ("8", "pear")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("5"),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "grape"),
+            TestUtils::expected_bulk_string("2.5"),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "kiwi"),
+            TestUtils::expected_bulk_string("7"),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "pear"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zadd_command("vegetables", &["XX"], &[("1", "carrot")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::type_command("vegetables"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::zscore_command("vegetables", "carrot"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("zset"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zadd_command_with_gt_and_lt() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zadd_command("fruits", &[], &[("5", "mango")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["GT", "CH"], &[("3", "mango")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("5"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["GT", "CH"], &[("8", "mango"), ("1", "grape")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("8"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["LT", "CH"], &[("9", "mango")]),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["LT", "CH"], &[("-inf", "mango")]),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("-inf"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zadd_command_with_incr() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zadd_command("fruits", &["INCR"], &[("5", "mango")]),
+            TestUtils::expected_bulk_string("5"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["incr"], &[("2.5", "mango")]),
+            TestUtils::expected_bulk_string("7.5"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["NX", "INCR"], &[("5", "mango")]),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["XX", "INCR"], &[("5", "grape")]),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["GT", "INCR"], &[("-1", "mango")]),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["LT", "INCR"], &[("-1", "mango")]),
+            TestUtils::expected_bulk_string("6.5"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &["NX", "INCR"], &[("3", "grape")]),
+            TestUtils::expected_bulk_string("3"),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", true),
+            TestUtils::expected_bulk_string_array(&["grape", "3", "mango", "6.5"]),
+        ),
+        (
+            TestUtils::zadd_command("vegetables", &["XX", "INCR"], &[("1", "leek")]),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::type_command("vegetables"),
+            TestUtils::expected_simple_string("none"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zadd_command_wrong_data_type() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        TestUtils::zadd_command("grape", &[], &[("1", "mango")]),
+        TestUtils::zscore_command("grape", "mango"),
+    ];
+
+    for command in test_cases {
+        env.exec_command_immediate_error_response(
+            command,
+            &client_address,
+            CommandError::WrongType,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zadd_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits"]),
+            CommandError::WrongNumberOfArguments("ZADD".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "1", "mango", "2"]),
+            CommandError::InvalidZAddCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "CH", "GT"]),
+            CommandError::InvalidZAddCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "one", "mango"]),
+            CommandError::InvalidSortedSetScore,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "nan", "mango"]),
+            CommandError::InvalidSortedSetScore,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "NX", "XX", "1", "mango"]),
+            CommandError::IncompatibleZAddNxXxOptions,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "GT", "LT", "1", "mango"]),
+            CommandError::IncompatibleZAddGtLtNxOptions,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "NX", "GT", "1", "mango"]),
+            CommandError::IncompatibleZAddGtLtNxOptions,
+        ),
+        (
+            TestUtils::invalid_command(&["ZADD", "fruits", "INCR", "1", "mango", "2", "kiwi"]),
+            CommandError::InvalidZAddIncrPairs,
+        ),
+        (
+            TestUtils::invalid_command(&["ZSCORE", "fruits"]),
+            CommandError::WrongNumberOfArguments("ZSCORE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZSCORE", "fruits", "mango", "grape"]),
+            CommandError::WrongNumberOfArguments("ZSCORE".to_string()),
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,148 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zincrby_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zincrby_command("fruits", "2.5", "mango"),
+            TestUtils::expected_bulk_string("2.5"),
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "-1", "mango"),
+            TestUtils::expected_bulk_string("1.5"),
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "3", "grape"),
+            TestUtils::expected_bulk_string("3"),
+        ),
+        (
+            TestUtils::zcard_command("fruits"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zrank_command("fruits", "grape"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "inf", "kiwi"),
+            TestUtils::expected_bulk_string("inf"),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            TestUtils::expected_bulk_string("1.5"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zincrby_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let setup_commands = vec![
+        (
+            TestUtils::set_command("grape", "mango"),
+            TestUtils::expected_simple_string("OK"),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &[], &[("+inf", "mango")]),
+            TestUtils::expected_integer(1),
+        ),
+    ];
+
+    for (command, expected_response) in setup_commands {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZINCRBY", "fruits", "1"]),
+            CommandError::WrongNumberOfArguments("ZINCRBY".to_string()),
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "one", "mango"),
+            CommandError::InvalidSortedSetScore,
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "nan", "mango"),
+            CommandError::InvalidSortedSetScore,
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "-inf", "mango"),
+            CommandError::SortedSetScoreNaN,
+        ),
+        (
+            TestUtils::zincrby_command("grape", "1", "mango"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zscore_command("fruits", "mango"),
+        &client_address,
+        &TestUtils::expected_bulk_string("inf"),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_score_replies_follow_client_protocol() {
+    let mut env = TestEnv::new_master_server();
+    let resp2_client_address = TestUtils::client_address(41844);
+    let resp3_client_address = TestUtils::client_address(41845);
+
+    env.exec_command(TestUtils::hello_command(Some("3")), &resp3_client_address)
+        .await
+        .unwrap();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zadd_command("fruits", &[], &[("3.14", "mango"), ("2", "grape")]),
+        &resp2_client_address,
+        &TestUtils::expected_integer(2),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            &resp2_client_address,
+            "$4\r\n3.14\r\n",
+        ),
+        (
+            TestUtils::zscore_command("fruits", "mango"),
+            &resp3_client_address,
+            ",3.14\r\n",
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "1.5", "grape"),
+            &resp2_client_address,
+            "$3\r\n3.5\r\n",
+        ),
+        (
+            TestUtils::zincrby_command("fruits", "-0.5", "grape"),
+            &resp3_client_address,
+            ",3\r\n",
+        ),
+    ];
+
+    for (command, client_address, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, client_address, expected_response)
+            .await;
+    }
+}
@@ -0,0 +1,80 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zmpop_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zmpop_command(&["empty", "fruits"], "MIN", None),
+            TestUtils::expected_null_array(),
+        ),
+        (
+            TestUtils::zadd_command(
+                "fruits",
+                &[],
+                &[("1", "kiwi"), ("2", "apple"), ("3.5", "grape")],
+            ),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::zmpop_command(&["empty", "fruits"], "MIN", Some("2")),
+            "*2\r\n$6\r\nfruits\r\n*2\r\n*2\r\n$4\r\nkiwi\r\n$1\r\n1\r\n*2\r\n$5\r\napple\r\n$1\r\n2\r\n"
+                .to_string(),
+        ),
+        (
+            TestUtils::zmpop_command(&["fruits"], "max", None),
+            "*2\r\n$6\r\nfruits\r\n*1\r\n*2\r\n$5\r\ngrape\r\n$3\r\n3.5\r\n".to_string(),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::zmpop_command(&["fruits"], "MAX", None),
+            TestUtils::expected_null_array(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zmpop_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZMPOP", "1", "fruits"]),
+            CommandError::WrongNumberOfArguments("ZMPOP".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZMPOP", "-1", "fruits", "MIN"]),
+            CommandError::InvalidZMPopNumberOfKeys,
+        ),
+        (
+            TestUtils::invalid_command(&["ZMPOP", "3", "fruits", "MIN"]),
+            CommandError::ZMPopNumberOfKeysMismatch,
+        ),
+        (
+            TestUtils::invalid_command(&["ZMPOP", "1", "fruits", "LEFT"]),
+            CommandError::InvalidZMPopCommand,
+        ),
+        (
+            TestUtils::zmpop_command(&["fruits"], "MIN", Some("-2")),
+            CommandError::InvalidZMPopCount,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,78 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zmscore_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zmscore_command("fruits", &["kiwi", "mango"]),
+            "*2\r\n$-1\r\n$-1\r\n".to_string(),
+        ),
+        (
+            TestUtils::zadd_command("fruits", &[], &[("1", "kiwi"), ("2.5", "mango")]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zmscore_command("fruits", &["kiwi", "banana", "mango"]),
+            "*3\r\n$1\r\n1\r\n$-1\r\n$3\r\n2.5\r\n".to_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zmscore_command_resp3() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(TestUtils::hello_command(Some("3")), &client_address)
+        .await
+        .unwrap();
+    env.exec_command(
+        TestUtils::zadd_command("fruits", &[], &[("1", "kiwi")]),
+        &client_address,
+    )
+    .await
+    .unwrap();
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zmscore_command("fruits", &["kiwi", "banana"]),
+        &client_address,
+        "*2\r\n,1\r\n$-1\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_zmscore_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(TestUtils::set_command("fruit", "mango"), &client_address)
+        .await
+        .unwrap();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZMSCORE", "fruits"]),
+            CommandError::WrongNumberOfArguments("ZMSCORE".to_string()),
+        ),
+        (
+            TestUtils::zmscore_command("fruit", &["mango"]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
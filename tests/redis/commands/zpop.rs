@@ -0,0 +1,96 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zpopmin_and_zpopmax_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zadd_command(
+                "fruits",
+                &[],
+                &[
+                    ("1", "kiwi"),
+                    ("2", "apple"),
+                    ("2", "mango"),
+                    ("3.5", "grape"),
+                    ("5", "pear"),
+                ],
+            ),
+            TestUtils::expected_integer(5),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMIN", "fruits", None),
+            TestUtils::expected_bulk_string_array(&["kiwi", "1"]),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMAX", "fruits", None),
+            TestUtils::expected_bulk_string_array(&["pear", "5"]),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMIN", "fruits", Some(2)),
+            TestUtils::expected_bulk_string_array(&["apple", "2", "mango", "2"]),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMAX", "fruits", Some(0)),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMAX", "fruits", Some(10)),
+            TestUtils::expected_bulk_string_array(&["grape", "3.5"]),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMIN", "fruits", None),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zpop_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZPOPMIN"]),
+            CommandError::WrongNumberOfArguments("ZPOPMIN".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZPOPMAX", "fruits", "1", "2"]),
+            CommandError::InvalidZPopMaxCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["ZPOPMIN", "fruits", "-1"]),
+            CommandError::InvalidZPopCount,
+        ),
+        (
+            TestUtils::zpop_command("ZPOPMAX", "grape", None),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet};
+
+use codecrafters_redis::{
+    commands::{CommandError, CommandResult},
+    resp::RespValue,
+};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+async fn exec_zrandmember(env: &mut TestEnv, options: &[&str]) -> Vec<String> {
+    let result = env
+        .exec_command(
+            TestUtils::zrandmember_command("fruits", options),
+            &TestUtils::client_address(41844),
+        )
+        .await;
+
+    let Ok(CommandResult::Response(response)) = result else {
+        panic!("Expected ZRANDMEMBER to succeed");
+    };
+
+    let parsed = RespValue::parse(response.split_terminator("\r\n").collect()).unwrap();
+
+    match parsed.as_slice() {
+        [RespValue::BulkString(member)] => vec![member.clone()],
+        [RespValue::Array(items)] => items
+            .iter()
+            .map(|item| match item {
+                RespValue::BulkString(item) => item.clone(),
+                _ => panic!("Expected ZRANDMEMBER to reply with bulk strings"),
+            })
+            .collect(),
+        _ => panic!("Unexpected ZRANDMEMBER response {:?}", parsed),
+    }
+}
+
+async fn setup_fruits(env: &mut TestEnv) -> HashMap<String, String> {
+    let fruits = [("1", "mango"), ("2", "grape"), ("3.5", "kiwi")];
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zadd_command("fruits", &[], &fruits),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(3),
+    )
+    .await;
+
+    fruits
+        .iter()
+        .map(|(score, member)| (member.to_string(), score.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_handle_zrandmember_command_without_count() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    let members = exec_zrandmember(&mut env, &[]).await;
+    assert_eq!(members.len(), 1);
+    assert!(fruits.contains_key(&members[0]));
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zrandmember_command("vegetables", &[]),
+        &TestUtils::client_address(41844),
+        "$-1\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_handle_zrandmember_command_distinct_members() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    for (count, expected_length) in [("2", 2), ("3", 3), ("10", 3)] {
+        let members = exec_zrandmember(&mut env, &[count]).await;
+        let distinct_members: HashSet<&String> = members.iter().collect();
+
+        assert_eq!(members.len(), expected_length);
+        assert_eq!(distinct_members.len(), expected_length);
+        assert!(members.iter().all(|member| fruits.contains_key(member)));
+    }
+
+    let test_cases = vec![
+        (TestUtils::zrandmember_command("fruits", &["0"]), "*0\r\n"),
+        (
+            TestUtils::zrandmember_command("vegetables", &["-2"]),
+            "*0\r\n",
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(
+            command,
+            &TestUtils::client_address(41844),
+            expected_response,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zrandmember_command_repeating_members() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    let members = exec_zrandmember(&mut env, &["-10"]).await;
+    let distinct_members: HashSet<&String> = members.iter().collect();
+
+    // Ten members picked out of three must repeat some of them
+    assert_eq!(members.len(), 10);
+    assert!(distinct_members.len() <= 3);
+    assert!(members.iter().all(|member| fruits.contains_key(member)));
+}
+
+#[tokio::test]
+async fn test_handle_zrandmember_command_with_scores() {
+    let mut env = TestEnv::new_master_server();
+    let fruits = setup_fruits(&mut env).await;
+
+    for (count, expected_pairs) in [("2", 2), ("-5", 5)] {
+        let items = exec_zrandmember(&mut env, &[count, "withscores"]).await;
+        assert_eq!(items.len(), expected_pairs * 2);
+
+        for pair in items.chunks(2) {
+            assert_eq!(fruits.get(&pair[0]), Some(&pair[1]));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zrandmember_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(
+        TestUtils::set_command("vegetables", "leek"),
+        &client_address,
+    )
+    .await
+    .unwrap();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZRANDMEMBER"]),
+            CommandError::WrongNumberOfArguments("ZRANDMEMBER".to_string()),
+        ),
+        (
+            TestUtils::zrandmember_command("fruits", &["WITHSCORES"]),
+            CommandError::InvalidZRandMemberCount,
+        ),
+        (
+            TestUtils::zrandmember_command("fruits", &["2", "WITHVALUES"]),
+            CommandError::InvalidZRandMemberCommand,
+        ),
+        (
+            TestUtils::zrandmember_command("vegetables", &[]),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,144 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zrange_and_zrevrange_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zadd_command(
+            "fruits",
+            &[],
+            &[
+                ("2", "mango"),
+                ("1", "kiwi"),
+                ("2", "apple"),
+                ("3.5", "grape"),
+                ("2", "banana"),
+            ],
+        ),
+        &client_address,
+        &TestUtils::expected_integer(5),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", false),
+            TestUtils::expected_bulk_string_array(&["kiwi", "apple", "banana", "mango", "grape"]),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "1", "3", false),
+            TestUtils::expected_bulk_string_array(&["apple", "banana", "mango"]),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "-2", "10", false),
+            TestUtils::expected_bulk_string_array(&["mango", "grape"]),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "3", "1", false),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "5", "10", false),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::zrange_command("vegetables", "0", "-1", false),
+            TestUtils::expected_bulk_string_array(&[]),
+        ),
+        (
+            TestUtils::zrevrange_command("fruits", "0", "-1", false),
+            TestUtils::expected_bulk_string_array(&["grape", "mango", "banana", "apple", "kiwi"]),
+        ),
+        (
+            TestUtils::zrevrange_command("fruits", "-2", "-1", false),
+            TestUtils::expected_bulk_string_array(&["apple", "kiwi"]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zrange_command_with_scores() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::zadd_command(
+            "fruits",
+            &[],
+            &[("1.5", "mango"), ("1", "kiwi"), ("-inf", "apple")],
+        ),
+        &client_address,
+        &TestUtils::expected_integer(3),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", true),
+            TestUtils::expected_bulk_string_array(&["apple", "-inf", "kiwi", "1", "mango", "1.5"]),
+        ),
+        (
+            TestUtils::zrevrange_command("fruits", "0", "1", true),
+            TestUtils::expected_bulk_string_array(&["mango", "1.5", "kiwi", "1"]),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zrange_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZRANGE", "fruits", "0"]),
+            CommandError::WrongNumberOfArguments("ZRANGE".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZRANGE", "fruits", "0", "1", "WITHSCORE"]),
+            CommandError::InvalidZRangeCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["ZREVRANGE", "fruits", "0", "1", "2", "3"]),
+            CommandError::InvalidZRevRangeCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["ZRANGE", "fruits", "a", "1"]),
+            CommandError::InvalidZRangeCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["ZREVRANGE", "fruits", "0", "1.5"]),
+            CommandError::InvalidZRangeCommandArgument,
+        ),
+        (
+            TestUtils::zrange_command("grape", "0", "-1", false),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
@@ -0,0 +1,100 @@
+use codecrafters_redis::commands::CommandError;
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+#[tokio::test]
+async fn test_handle_zrank_and_zcard_commands() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zcard_command("fruits"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zadd_command(
+                "fruits",
+                &[],
+                &[
+                    ("3", "mango"),
+                    ("1", "kiwi"),
+                    ("2", "grape"),
+                    ("2", "apple"),
+                ],
+            ),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::zcard_command("fruits"),
+            TestUtils::expected_integer(4),
+        ),
+        (
+            TestUtils::zrank_command("fruits", "grape"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zrank_command("fruits", "kiwi"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zrevrank_command("fruits", "grape"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zrevrank_command("fruits", "mango"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zrank_command("fruits", "banana"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zrevrank_command("vegetables", "carrot"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zrank_command_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command_immediate_success_response(
+        TestUtils::set_command("grape", "mango"),
+        &client_address,
+        &TestUtils::expected_simple_string("OK"),
+    )
+    .await;
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZRANK", "fruits"]),
+            CommandError::WrongNumberOfArguments("ZRANK".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZREVRANK", "fruits", "mango", "grape"]),
+            CommandError::InvalidZRevRankCommand,
+        ),
+        (
+            TestUtils::invalid_command(&["ZCARD"]),
+            CommandError::WrongNumberOfArguments("ZCARD".to_string()),
+        ),
+        (
+            TestUtils::zrank_command("grape", "mango"),
+            CommandError::WrongType,
+        ),
+        (TestUtils::zcard_command("grape"), CommandError::WrongType),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
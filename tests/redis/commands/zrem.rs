@@ -0,0 +1,184 @@
+use codecrafters_redis::{commands::CommandError, resp::RespValue};
+
+use crate::test_utils::{TestEnv, TestUtils};
+
+fn zadd_fruits_command() -> RespValue {
+    TestUtils::zadd_command(
+        "fruits",
+        &[],
+        &[
+            ("1", "kiwi"),
+            ("2", "apple"),
+            ("2", "mango"),
+            ("3.5", "grape"),
+            ("5", "pear"),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn test_handle_zrem_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (
+            TestUtils::zrem_command("fruits", &["kiwi"]),
+            TestUtils::expected_integer(0),
+        ),
+        (zadd_fruits_command(), TestUtils::expected_integer(5)),
+        (
+            TestUtils::zrem_command("fruits", &["kiwi", "banana", "pear", "kiwi"]),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", true),
+            TestUtils::expected_bulk_string_array(&["apple", "2", "mango", "2", "grape", "3.5"]),
+        ),
+        (
+            TestUtils::zscore_command("fruits", "kiwi"),
+            TestUtils::expected_null_bulk_string(),
+        ),
+        (
+            TestUtils::zrem_command("fruits", &["apple", "mango", "grape"]),
+            TestUtils::expected_integer(3),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zremrangebyrank_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (zadd_fruits_command(), TestUtils::expected_integer(5)),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYRANK", "fruits", "1", "2"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", false),
+            TestUtils::expected_bulk_string_array(&["kiwi", "grape", "pear"]),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYRANK", "fruits", "-1", "-1"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYRANK", "fruits", "5", "10"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", false),
+            TestUtils::expected_bulk_string_array(&["kiwi", "grape"]),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYRANK", "fruits", "0", "-1"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zremrangebyscore_command() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    let test_cases = vec![
+        (zadd_fruits_command(), TestUtils::expected_integer(5)),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYSCORE", "fruits", "(1", "2"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::zrange_command("fruits", "0", "-1", false),
+            TestUtils::expected_bulk_string_array(&["kiwi", "grape", "pear"]),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYSCORE", "fruits", "3.5", "(5"),
+            TestUtils::expected_integer(1),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYSCORE", "fruits", "(5", "+inf"),
+            TestUtils::expected_integer(0),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYSCORE", "fruits", "-inf", "+inf"),
+            TestUtils::expected_integer(2),
+        ),
+        (
+            TestUtils::type_command("fruits"),
+            TestUtils::expected_simple_string("none"),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        env.exec_command_immediate_success_response(command, &client_address, &expected_response)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zrem_commands_invalid() {
+    let mut env = TestEnv::new_master_server();
+    let client_address = TestUtils::client_address(41844);
+
+    env.exec_command(TestUtils::set_command("fruit", "mango"), &client_address)
+        .await
+        .unwrap();
+
+    let test_cases = vec![
+        (
+            TestUtils::invalid_command(&["ZREM", "fruits"]),
+            CommandError::WrongNumberOfArguments("ZREM".to_string()),
+        ),
+        (
+            TestUtils::invalid_command(&["ZREMRANGEBYRANK", "fruits", "0"]),
+            CommandError::WrongNumberOfArguments("ZREMRANGEBYRANK".to_string()),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYRANK", "fruits", "first", "1"),
+            CommandError::InvalidZRangeCommandArgument,
+        ),
+        (
+            TestUtils::invalid_command(&["ZREMRANGEBYSCORE", "fruits", "0"]),
+            CommandError::WrongNumberOfArguments("ZREMRANGEBYSCORE".to_string()),
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYSCORE", "fruits", "(", "1"),
+            CommandError::InvalidSortedSetScoreRange,
+        ),
+        (
+            TestUtils::zrem_command("fruit", &["mango"]),
+            CommandError::WrongType,
+        ),
+        (
+            TestUtils::zremrange_command("ZREMRANGEBYSCORE", "fruit", "0", "1"),
+            CommandError::WrongType,
+        ),
+    ];
+
+    for (command, expected_error) in test_cases {
+        env.exec_command_immediate_error_response(command, &client_address, expected_error)
+            .await;
+    }
+}
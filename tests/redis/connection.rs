@@ -1,8 +1,8 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use tokio::{
-    io::AsyncWriteExt,
+    io::{AsyncReadExt, AsyncWriteExt},
     net::{TcpListener, TcpStream},
-    time::timeout,
+    time::{Instant, timeout},
 };
 
 use codecrafters_redis::{
@@ -10,6 +10,7 @@ use codecrafters_redis::{
         handle_master_to_client_connection, handle_master_to_replica_connection,
         handle_replica_to_client_connection,
     },
+    input::read_and_parse_resp,
     key_value_store::{DataType, Value},
     resp::RespValue,
 };
@@ -71,6 +72,99 @@ async fn test_handle_master_to_client_connection_basic_commands() {
     let _ = timeout(Duration::from_secs(2), server_handle).await;
 }
 
+#[tokio::test]
+async fn test_handle_master_to_client_connection_over_duplex_stream() {
+    let (mut client, server_stream) = tokio::io::duplex(1024);
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = (env.store.clone(), env.state.clone(), env.server.clone());
+
+    let server_handle = tokio::spawn(async move {
+        handle_master_to_client_connection(
+            server_stream,
+            server,
+            "duplex-client".to_string(),
+            store,
+            state,
+        )
+        .await;
+    });
+
+    let mut buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::set_command("test_key", "test_value"),
+        RespValue::SimpleString("OK".to_string()),
+    )
+    .await;
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::get_command("test_key"),
+        RespValue::BulkString("test_value".to_string()),
+    )
+    .await;
+
+    // Closing the client side ends the connection and unregisters the client
+    drop(client);
+
+    timeout(Duration::from_secs(2), server_handle)
+        .await
+        .expect("Connection handler did not stop after the client closed")
+        .unwrap();
+
+    assert!(
+        !env.server
+            .read()
+            .await
+            .clients
+            .contains_key("duplex-client")
+    );
+}
+
+#[tokio::test]
+async fn test_handle_master_to_client_connection_unknown_command() {
+    let (mut client, server_stream) = tokio::io::duplex(1024);
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = (env.store.clone(), env.state.clone(), env.server.clone());
+
+    tokio::spawn(async move {
+        handle_master_to_client_connection(
+            server_stream,
+            server,
+            "duplex-client".to_string(),
+            store,
+            state,
+        )
+        .await;
+    });
+
+    let mut buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::invalid_command(&["FooBar", "a", "b"]),
+        RespValue::Error(
+            "ERR unknown command 'FooBar', with args beginning with: 'a' 'b' ".to_string(),
+        ),
+    )
+    .await;
+
+    // The connection keeps serving commands after the error
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::ping_command(),
+        RespValue::SimpleString("PONG".to_string()),
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_handle_replica_to_client_connection_forbidden_write_commands() {
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -180,6 +274,102 @@ async fn test_handle_master_to_client_connection_processes_commands() {
     let _ = timeout(Duration::from_secs(2), replica_handle).await;
 }
 
+#[tokio::test]
+async fn test_handle_master_to_client_connection_reads_replica_acks() {
+    let (mock_replica, master_stream) = tokio::io::duplex(4096);
+    let (mut replica_reader, mut replica_writer) = tokio::io::split(mock_replica);
+
+    let mut env = TestEnv::new_master_server();
+    let (store, state, server) = (env.store.clone(), env.state.clone(), env.server.clone());
+
+    tokio::spawn(async move {
+        handle_master_to_client_connection(
+            master_stream,
+            server,
+            "mock-replica".to_string(),
+            store,
+            state,
+        )
+        .await;
+    });
+
+    // Discard the full resynchronization and the propagated commands
+    tokio::spawn(async move {
+        let mut buffer = [0; 1024];
+        while let Ok(n) = replica_reader.read(&mut buffer).await {
+            if n == 0 {
+                break;
+            }
+        }
+    });
+
+    replica_writer
+        .write_all(TestUtils::psync_command("?", "-1").encode().as_bytes())
+        .await
+        .unwrap();
+
+    for _ in 0..50 {
+        let server_guard = env.server.read().await;
+        if server_guard
+            .replicas
+            .as_ref()
+            .unwrap()
+            .contains_key("mock-replica")
+        {
+            break;
+        }
+        drop(server_guard);
+
+        TestUtils::sleep_ms(10).await;
+    }
+
+    env.server.write().await.repl_offset = 300;
+
+    // The replica acknowledges its progress periodically while WAIT is blocked
+    let acks = tokio::spawn(async move {
+        for offset in ["100", "200", "300", "100"] {
+            TestUtils::sleep_ms(50).await;
+            replica_writer
+                .write_all(
+                    TestUtils::replconf_command("ACK", offset)
+                        .encode()
+                        .as_bytes(),
+                )
+                .await
+                .unwrap();
+        }
+    });
+
+    // The tracked offset advances with the first ack, before anything asks for it
+    let mut replica_offset = 0;
+    for _ in 0..50 {
+        replica_offset = env.server.read().await.replicas.as_ref().unwrap()["mock-replica"].offset;
+        if replica_offset > 0 {
+            break;
+        }
+
+        TestUtils::sleep_ms(10).await;
+    }
+    assert_eq!(replica_offset, 100);
+
+    let start = Instant::now();
+    env.exec_command_immediate_success_response(
+        TestUtils::wait_command(1, 2000),
+        &TestUtils::client_address(41844),
+        &TestUtils::expected_integer(1),
+    )
+    .await;
+    assert!(start.elapsed() < Duration::from_millis(1000));
+
+    // A late ack for an older offset does not move the replica back
+    acks.await.unwrap();
+    TestUtils::sleep_ms(20).await;
+    assert_eq!(
+        env.server.read().await.replicas.as_ref().unwrap()["mock-replica"].offset,
+        300
+    );
+}
+
 #[tokio::test]
 async fn test_handle_master_to_replica_connection_incrementing_offset() {
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -304,6 +494,88 @@ async fn test_handle_master_to_replica_connection_invalid_commands() {
     let _ = timeout(Duration::from_secs(2), replica_handle).await;
 }
 
+#[tokio::test]
+async fn test_handle_master_to_replica_connection_replicated_transaction() {
+    let (mut master_stream, mut replica_stream) = tokio::io::duplex(1024);
+
+    let env = TestEnv::new_replica_server(6387);
+    let (store, state, server) = env.clone_env();
+
+    let replica_handle = tokio::spawn(async move {
+        handle_master_to_replica_connection(
+            "duplex-master",
+            &mut replica_stream,
+            server,
+            store,
+            state,
+        )
+        .await;
+    });
+
+    // A transaction is replicated as MULTI, its write commands and EXEC in one batch
+    let transaction = [
+        TestUtils::multi_command(),
+        TestUtils::set_command("mango", "juice"),
+        TestUtils::incr_command("counter"),
+        TestUtils::incr_command("counter"),
+        TestUtils::exec_command(),
+    ]
+    .iter()
+    .map(|command| command.encode())
+    .collect::<String>();
+
+    master_stream
+        .write_all(transaction.as_bytes())
+        .await
+        .unwrap();
+    master_stream
+        .write_all(
+            TestUtils::replconf_command("GETACK", "*")
+                .encode()
+                .as_bytes(),
+        )
+        .await
+        .unwrap();
+    master_stream.flush().await.unwrap();
+
+    // The acknowledgement is the only reply the master gets, without any +QUEUED before it
+    let mut buffer = [0; 1024];
+    let responses = timeout(
+        Duration::from_secs(2),
+        read_and_parse_resp(&mut master_stream, &mut buffer),
+    )
+    .await
+    .expect("Replica did not acknowledge the transaction")
+    .unwrap();
+
+    assert_eq!(responses.len(), 1);
+    let RespValue::Array(ack) = &responses[0] else {
+        panic!("Expected a REPLCONF ACK array, got {:?}", responses[0]);
+    };
+    assert_eq!(
+        ack[..2],
+        [
+            RespValue::BulkString("REPLCONF".to_string()),
+            RespValue::BulkString("ACK".to_string()),
+        ]
+    );
+
+    {
+        let store = env.get_store().await;
+        assert_eq!(
+            store.get("mango").map(|value| &value.data),
+            Some(&DataType::String("juice".to_string()))
+        );
+        assert_eq!(
+            store.get("counter").map(|value| &value.data),
+            Some(&DataType::String("2".to_string()))
+        );
+    }
+
+    drop(master_stream);
+    let _ = timeout(Duration::from_secs(2), replica_handle).await;
+}
+
 /// Test connection handling with connection close scenarios
 #[tokio::test]
 async fn test_replica_to_client_connection_close_handling() {
@@ -336,3 +608,468 @@ async fn test_replica_to_client_connection_close_handling() {
         "Server handler should terminate gracefully on connection close"
     );
 }
+
+#[tokio::test]
+async fn test_master_to_client_connection_quit_closes_socket() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, addr) = listener.accept().await.unwrap();
+        let client_address = addr.to_string();
+
+        handle_master_to_client_connection(stream, server, client_address, store, state).await;
+    });
+
+    let mut client = TcpStream::connect(server_addr).await.unwrap();
+    let mut buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::subscribe_command("channel1"),
+        RespValue::Array(vec![
+            RespValue::BulkString("subscribe".to_string()),
+            RespValue::BulkString("channel1".to_string()),
+            RespValue::Integer(1),
+        ]),
+    )
+    .await;
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::quit_command(),
+        RespValue::SimpleString("OK".to_string()),
+    )
+    .await;
+
+    // The server closes its side of the socket, so the client reads EOF
+    let bytes_read = timeout(Duration::from_secs(2), client.read(&mut buffer))
+        .await
+        .expect("Server should close the connection after QUIT")
+        .unwrap();
+    assert_eq!(bytes_read, 0);
+
+    let timeout_result = timeout(Duration::from_secs(2), server_handle).await;
+    assert!(timeout_result.is_ok(), "Server handler should terminate");
+
+    let server_guard = env.server.read().await;
+    assert!(server_guard.pub_sub_channels.is_empty());
+}
+
+#[tokio::test]
+async fn test_master_to_client_connection_quit_aborts_transaction() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, addr) = listener.accept().await.unwrap();
+        let client_address = addr.to_string();
+
+        handle_master_to_client_connection(stream, server, client_address, store, state).await;
+    });
+
+    let mut client = TcpStream::connect(server_addr).await.unwrap();
+    let mut buffer = [0; 1024];
+
+    let test_cases = vec![
+        (
+            TestUtils::multi_command(),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+        (
+            TestUtils::set_command("grape", "mango"),
+            RespValue::SimpleString("QUEUED".to_string()),
+        ),
+        (
+            TestUtils::quit_command(),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+
+    let timeout_result = timeout(Duration::from_secs(2), server_handle).await;
+    assert!(timeout_result.is_ok(), "Server handler should terminate");
+
+    assert!(env.get_state().await.transactions.is_empty());
+    assert!(env.get_store().await.get("grape").is_none());
+}
+
+#[tokio::test]
+async fn test_handle_master_to_client_connection_protocol_errors() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+
+    let server_handle = tokio::spawn(async move {
+        for _ in 0..2 {
+            let (stream, addr) = listener.accept().await.unwrap();
+            let client_address = addr.to_string();
+
+            handle_master_to_client_connection(
+                stream,
+                Arc::clone(&server),
+                client_address,
+                Arc::clone(&store),
+                Arc::clone(&state),
+            )
+            .await;
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // A stray line outside of a request is rejected but the next request still runs
+    let mut client = TcpStream::connect(server_addr).await.unwrap();
+    client
+        .write_all(b"+OK\r\n*1\r\n$4\r\nPING\r\n")
+        .await
+        .unwrap();
+
+    let expected_response = "-ERR Protocol error: expected '*', got '+'\r\n+PONG\r\n";
+    let mut response = Vec::new();
+    let mut buffer = [0; 1024];
+
+    while response.len() < expected_response.len() {
+        let bytes_read = timeout(Duration::from_secs(1), client.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(bytes_read > 0, "Connection closed unexpectedly");
+
+        response.extend_from_slice(&buffer[..bytes_read]);
+    }
+
+    assert_eq!(String::from_utf8_lossy(&response), expected_response);
+    drop(client);
+
+    // A multibulk claiming 3 elements but sending 2 desyncs the stream and closes the connection
+    let mut client = TcpStream::connect(server_addr).await.unwrap();
+    client
+        .write_all(b"*3\r\n$3\r\nSET\r\n$1\r\na\r\n*1\r\n$4\r\nPING\r\n")
+        .await
+        .unwrap();
+
+    let mut response = Vec::new();
+
+    loop {
+        let bytes_read = timeout(Duration::from_secs(1), client.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+
+        if bytes_read == 0 {
+            break;
+        }
+
+        response.extend_from_slice(&buffer[..bytes_read]);
+    }
+
+    assert_eq!(
+        String::from_utf8_lossy(&response),
+        "-ERR Protocol error: expected '$', got '*'\r\n"
+    );
+
+    let _ = timeout(Duration::from_secs(2), server_handle).await;
+}
+
+#[tokio::test]
+async fn test_handle_master_to_client_connection_rejects_oversized_lengths() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+    server.write().await.proto_max_bulk_len = 1024;
+
+    let server_handle = tokio::spawn(async move {
+        for _ in 0..2 {
+            let (stream, addr) = listener.accept().await.unwrap();
+            let client_address = addr.to_string();
+
+            handle_master_to_client_connection(
+                stream,
+                Arc::clone(&server),
+                client_address,
+                Arc::clone(&store),
+                Arc::clone(&state),
+            )
+            .await;
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // The announced lengths are rejected before their data arrives and the connection is closed
+    let test_cases: Vec<(&[u8], &str)> = vec![
+        (
+            b"*2\r\n$4\r\nECHO\r\n$1025\r\n",
+            "-ERR Protocol error: invalid bulk length\r\n",
+        ),
+        (
+            b"*1025\r\n$4\r\nECHO\r\n",
+            "-ERR Protocol error: invalid multibulk length\r\n",
+        ),
+    ];
+
+    for (request, expected_response) in test_cases {
+        let mut client = TcpStream::connect(server_addr).await.unwrap();
+        client.write_all(request).await.unwrap();
+
+        let mut response = Vec::new();
+        let mut buffer = [0; 1024];
+
+        loop {
+            let bytes_read = timeout(Duration::from_secs(1), client.read(&mut buffer))
+                .await
+                .unwrap()
+                .unwrap();
+
+            if bytes_read == 0 {
+                break;
+            }
+
+            response.extend_from_slice(&buffer[..bytes_read]);
+        }
+
+        assert_eq!(String::from_utf8_lossy(&response), expected_response);
+    }
+
+    let _ = timeout(Duration::from_secs(2), server_handle).await;
+}
+
+#[tokio::test]
+async fn test_handle_master_to_client_connection_ignores_empty_requests() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, addr) = listener.accept().await.unwrap();
+        let client_address = addr.to_string();
+
+        handle_master_to_client_connection(
+            stream,
+            Arc::clone(&server),
+            client_address,
+            Arc::clone(&store),
+            Arc::clone(&state),
+        )
+        .await;
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut client = TcpStream::connect(server_addr).await.unwrap();
+    client
+        .write_all(b"*0\r\n   \r\n*-1\r\n*1\r\n$4\r\nPING\r\n")
+        .await
+        .unwrap();
+
+    let mut buffer = [0; 1024];
+    let bytes_read = timeout(Duration::from_secs(1), client.read(&mut buffer))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&buffer[..bytes_read]), "+PONG\r\n");
+
+    // Nothing else follows the PONG
+    let result = timeout(Duration::from_millis(200), client.read(&mut buffer)).await;
+    assert!(result.is_err());
+
+    drop(client);
+    let _ = timeout(Duration::from_secs(2), server_handle).await;
+}
+
+#[tokio::test]
+async fn test_handle_master_to_client_connection_stops_reading_behind_blocked_command() {
+    let (mut client, server_stream) = tokio::io::duplex(1024);
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+
+    let server_handle = tokio::spawn(async move {
+        handle_master_to_client_connection(
+            server_stream,
+            server,
+            "duplex-client".to_string(),
+            store,
+            state,
+        )
+        .await;
+    });
+
+    client
+        .write_all(TestUtils::blpop_command("grape", "0").encode().as_bytes())
+        .await
+        .unwrap();
+
+    // Requests pipelined behind the blocked BLPOP are only queued up to a limit, after
+    // which the server stops reading and the client can no longer write
+    let pipeline = TestUtils::ping_command().encode().repeat(100_000);
+
+    assert!(
+        timeout(
+            Duration::from_millis(500),
+            client.write_all(pipeline.as_bytes())
+        )
+        .await
+        .is_err(),
+        "Server should stop reading requests while BLPOP is blocked"
+    );
+
+    drop(client);
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_handle_master_to_client_connection_pipelined_replies_order() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    let env = TestEnv::new_master_server();
+    let (store, state, server) = env.clone_env();
+
+    let server_handle = tokio::spawn(async move {
+        let mut handles = Vec::new();
+
+        for _ in 0..2 {
+            let (stream, addr) = listener.accept().await.unwrap();
+            let client_address = addr.to_string();
+
+            handles.push(tokio::spawn(handle_master_to_client_connection(
+                stream,
+                Arc::clone(&server),
+                client_address,
+                Arc::clone(&store),
+                Arc::clone(&state),
+            )));
+        }
+
+        for handle in handles {
+            let _ = handle.await;
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut client = TcpStream::connect(server_addr).await.unwrap();
+    let mut buffer = [0; 1024];
+
+    // RPUSH, BLPOP and PING sent in a single write are answered in order
+    let pipeline = [
+        TestUtils::rpush_command("grape", &["mango"]),
+        TestUtils::blpop_command("grape", "0"),
+        TestUtils::ping_command(),
+    ]
+    .iter()
+    .map(|command| command.encode())
+    .collect::<String>();
+
+    client.write_all(pipeline.as_bytes()).await.unwrap();
+
+    let expected_response = [
+        RespValue::Integer(1),
+        RespValue::Array(vec![
+            RespValue::BulkString("grape".to_string()),
+            RespValue::BulkString("mango".to_string()),
+        ]),
+        RespValue::SimpleString("PONG".to_string()),
+    ]
+    .iter()
+    .map(|response| response.encode())
+    .collect::<String>();
+
+    let mut response = Vec::new();
+
+    while response.len() < expected_response.len() {
+        let bytes_read = timeout(Duration::from_secs(1), client.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(bytes_read > 0, "Connection closed unexpectedly");
+
+        response.extend_from_slice(&buffer[..bytes_read]);
+    }
+
+    assert_eq!(String::from_utf8_lossy(&response), expected_response);
+
+    // A PING pipelined behind a BLPOP that blocks is answered after the BLPOP
+    let pipeline = [
+        TestUtils::blpop_command("grape", "0"),
+        TestUtils::ping_command(),
+    ]
+    .iter()
+    .map(|command| command.encode())
+    .collect::<String>();
+
+    client.write_all(pipeline.as_bytes()).await.unwrap();
+
+    assert!(
+        timeout(Duration::from_millis(100), client.read(&mut buffer))
+            .await
+            .is_err(),
+        "PING should not be answered while BLPOP is blocked"
+    );
+
+    let mut other_client = TcpStream::connect(server_addr).await.unwrap();
+    let mut other_buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut other_client,
+        &mut other_buffer,
+        TestUtils::rpush_command("grape", &["kiwi"]),
+        RespValue::Integer(1),
+    )
+    .await;
+
+    let expected_response = [
+        RespValue::Array(vec![
+            RespValue::BulkString("grape".to_string()),
+            RespValue::BulkString("kiwi".to_string()),
+        ]),
+        RespValue::SimpleString("PONG".to_string()),
+    ]
+    .iter()
+    .map(|response| response.encode())
+    .collect::<String>();
+
+    let mut response = Vec::new();
+
+    while response.len() < expected_response.len() {
+        let bytes_read = timeout(Duration::from_secs(1), client.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(bytes_read > 0, "Connection closed unexpectedly");
+
+        response.extend_from_slice(&buffer[..bytes_read]);
+    }
+
+    assert_eq!(String::from_utf8_lossy(&response), expected_response);
+
+    drop(client);
+    drop(other_client);
+
+    let _ = timeout(Duration::from_secs(2), server_handle).await;
+}
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use tokio::{
     io::AsyncWriteExt,
     net::{TcpListener, TcpStream},
@@ -140,6 +140,64 @@ async fn test_handshake_success() {
     let _ = timeout(Duration::from_secs(2), master_handle).await;
 }
 
+#[tokio::test]
+async fn test_handshake_adopts_master_replication_offset() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let master_addr = listener.local_addr().unwrap();
+
+    // Spawn mock master server that has already streamed 1234 bytes to other replicas
+    let master_handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buffer = [0; 1024];
+
+        // Answer PING, REPLCONF listening-port and REPLCONF capa
+        for response in ["+PONG\r\n", "+OK\r\n", "+OK\r\n"] {
+            let _ = read_and_parse_resp(&mut stream, &mut buffer).await.unwrap();
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.flush().await.unwrap();
+        }
+
+        // Answer PSYNC with a non-zero offset followed by an empty RDB file
+        let _ = read_and_parse_resp(&mut stream, &mut buffer).await.unwrap();
+        let fullresync_response = "+FULLRESYNC 8371b4fb1155b71f4a04d3e1bc3e18c4a990aeeb 1234\r\n";
+        stream
+            .write_all(fullresync_response.as_bytes())
+            .await
+            .unwrap();
+        let empty_rdb = b"$88\r\nREDIS0011\xfa\x09redis-ver\x057.2.0\xfa\nredis-bits\xc0@\xfa\x05ctime\xc2m\x08\xbc\x65\xfa\x08used-mem\xc2\xb0\xc4\x10\x00\xfa\x08aof-base\xc0\x00\xff\xf0n;\xfe\xc0\xff\x5a\xa2";
+        stream.write_all(empty_rdb).await.unwrap();
+        stream.flush().await.unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let env = TestEnv::new_replica_server(6384);
+    let mut replica_stream = TcpStream::connect(master_addr).await.unwrap();
+
+    let result = timeout(
+        Duration::from_secs(5),
+        handshake(
+            &mut replica_stream,
+            Arc::clone(&env.server),
+            Arc::clone(&env.store),
+        ),
+    )
+    .await;
+
+    assert!(result.is_ok(), "Handshake should not timeout");
+    assert!(result.unwrap().is_ok(), "Handshake should succeed");
+
+    let server_guard = env.server.read().await;
+    assert_eq!(
+        server_guard.repl_id,
+        "8371b4fb1155b71f4a04d3e1bc3e18c4a990aeeb"
+    );
+    assert_eq!(server_guard.repl_offset, 1234);
+    drop(server_guard);
+
+    let _ = timeout(Duration::from_secs(2), master_handle).await;
+}
+
 /// Test the handshake function with invalid master responses
 #[tokio::test]
 async fn test_handshake_invalid_pong_response() {
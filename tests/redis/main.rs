@@ -1,3 +1,4 @@
+mod aof;
 mod commands;
 mod connection;
 mod input;
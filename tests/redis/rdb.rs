@@ -1,11 +1,18 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 use codecrafters_redis::key_value_store::{DataType, Value};
-use codecrafters_redis::rdb::RdbParser;
+use codecrafters_redis::rdb::{RdbParser, stream_rdb_file};
 use jiff::Timestamp;
 use tokio::io::AsyncReadExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::RwLock;
+use tokio::time::timeout;
 use tokio::{fs::File, io::BufReader};
 
+use crate::test_utils::TestEnv;
+
 #[tokio::test]
 async fn test_rdb_parser_empty_file() {
     let file = File::open("./tests/redis/rdb_files/empty.rdb")
@@ -42,7 +49,7 @@ async fn test_rdb_parser_empty_file() {
     assert_eq!(rdb_parser.db_number, None);
     assert_eq!(rdb_parser.hash_table_size, None);
     assert_eq!(rdb_parser.expiry_hash_table_size, None);
-    assert_eq!(rdb_parser.key_value_store.len(), 0);
+    assert!(rdb_parser.databases.is_empty());
     assert_eq!(rdb_parser.crc64_checksum.unwrap().iter().len(), 8);
 }
 
@@ -79,37 +86,39 @@ async fn test_rdb_parser_with_key_value_pairs_including_expiration() {
     assert_eq!(rdb_parser.db_number, Some("0".to_string()));
     assert_eq!(rdb_parser.hash_table_size, Some("5".to_string()));
     assert_eq!(rdb_parser.expiry_hash_table_size, Some("1".to_string()));
-    assert_eq!(rdb_parser.key_value_store.len(), 5);
+    let key_value_store = &rdb_parser.databases[&0];
+    assert_eq!(rdb_parser.databases.len(), 1);
+    assert_eq!(key_value_store.len(), 5);
     assert_eq!(
-        rdb_parser.key_value_store.get("mango"),
+        key_value_store.get("mango"),
         Some(&Value {
             data: DataType::String("pineapple".to_string()),
             expiration: None
         })
     );
     assert_eq!(
-        rdb_parser.key_value_store.get("banana"),
+        key_value_store.get("banana"),
         Some(&Value {
             data: DataType::String("grape".to_string()),
             expiration: None
         })
     );
     assert_eq!(
-        rdb_parser.key_value_store.get("grape"),
+        key_value_store.get("grape"),
         Some(&Value {
             data: DataType::String("mango".to_string()),
             expiration: None
         })
     );
     assert_eq!(
-        rdb_parser.key_value_store.get("orange"),
+        key_value_store.get("orange"),
         Some(&Value {
             data: DataType::String("raspberry".to_string()),
             expiration: Some("2032-01-01T00:00:00Z".parse::<Timestamp>().unwrap())
         })
     );
     assert_eq!(
-        rdb_parser.key_value_store.get("strawberry"),
+        key_value_store.get("strawberry"),
         Some(&Value {
             data: DataType::String("blueberry".to_string()),
             expiration: None
@@ -117,3 +126,297 @@ async fn test_rdb_parser_with_key_value_pairs_including_expiration() {
     );
     assert_eq!(rdb_parser.crc64_checksum.unwrap().iter().len(), 8);
 }
+
+/// Encode a length using the RDB length encoding
+fn encode_rdb_length(length: usize, bytes: &mut Vec<u8>) {
+    if length < 64 {
+        bytes.push(length as u8);
+    } else if length < 16384 {
+        bytes.push(0b0100_0000 | (length >> 8) as u8);
+        bytes.push(length as u8);
+    } else {
+        bytes.push(0b1000_0000);
+        bytes.extend_from_slice(&(length as u32).to_be_bytes());
+    }
+}
+
+/// Build an RDB file with the given number of string keys, each holding a value of the given size
+fn synthetic_rdb_file(number_of_keys: usize, value_size: usize) -> Vec<u8> {
+    let mut bytes = b"REDIS0011".to_vec();
+
+    bytes.push(0xFE);
+    bytes.push(0x00);
+    bytes.push(0xFB);
+    encode_rdb_length(number_of_keys, &mut bytes);
+    encode_rdb_length(0, &mut bytes);
+
+    for i in 0..number_of_keys {
+        let key = format!("key_{}", i);
+        let value = format!("{:0>width$}", i, width = value_size);
+
+        bytes.push(0x00);
+        encode_rdb_length(key.len(), &mut bytes);
+        bytes.extend_from_slice(key.as_bytes());
+        encode_rdb_length(value.len(), &mut bytes);
+        bytes.extend_from_slice(value.as_bytes());
+    }
+
+    bytes.push(0xFF);
+    bytes.extend_from_slice(&[0; 8]);
+
+    bytes
+}
+
+/// Stream the RDB file configured on the server and return the bytes a replica receives
+async fn receive_streamed_rdb(env: &TestEnv) -> (String, Vec<u8>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let master_side = TcpStream::connect(listener.local_addr().unwrap())
+        .await
+        .unwrap();
+    let (mut replica_side, replica_addr) = listener.accept().await.unwrap();
+    let (_reader, writer) = master_side.into_split();
+
+    let (store, _, server) = env.clone_env();
+    let stream_handle = tokio::spawn(async move {
+        stream_rdb_file(
+            &replica_addr.to_string(),
+            Arc::new(RwLock::new(writer)),
+            server,
+            store,
+        )
+        .await
+    });
+
+    let mut header = Vec::new();
+    let mut byte = [0; 1];
+
+    while !header.ends_with(b"\r\n") {
+        replica_side.read_exact(&mut byte).await.unwrap();
+        header.push(byte[0]);
+    }
+
+    let header = String::from_utf8(header).unwrap();
+    let payload_size = header[1..header.len() - 2].parse::<usize>().unwrap();
+
+    let mut payload = vec![0; payload_size];
+    timeout(
+        Duration::from_secs(5),
+        replica_side.read_exact(&mut payload),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    stream_handle.await.unwrap().unwrap();
+
+    (header, payload)
+}
+
+#[tokio::test]
+async fn test_stream_rdb_file_multi_megabyte_payload() {
+    let rdb_directory = "/tmp/redis-files-rdb-streaming";
+    let rdb_file = synthetic_rdb_file(5000, 1000);
+    assert!(rdb_file.len() > 4 * 1024 * 1024);
+
+    tokio::fs::create_dir_all(rdb_directory).await.unwrap();
+    tokio::fs::write(format!("{}/large.rdb", rdb_directory), &rdb_file)
+        .await
+        .unwrap();
+
+    let env = TestEnv::new_master_server();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.rdb_directory = rdb_directory.to_string();
+        server_guard.rdb_filename = "large.rdb".to_string();
+    }
+
+    let (header, payload) = receive_streamed_rdb(&env).await;
+
+    assert_eq!(header, format!("${}\r\n", rdb_file.len()));
+    assert!(
+        payload == rdb_file,
+        "Streamed RDB payload differs from the file"
+    );
+
+    let mut rdb_parser = RdbParser::new();
+
+    for chunk in payload.chunks(4096) {
+        rdb_parser.parse(chunk.to_vec()).unwrap();
+    }
+
+    let key_value_store = &rdb_parser.databases[&0];
+    assert_eq!(key_value_store.len(), 5000);
+    assert_eq!(
+        key_value_store.get("key_4999"),
+        Some(&Value {
+            data: DataType::String(format!("{:0>1000}", 4999)),
+            expiration: None
+        })
+    );
+
+    // The replica only starts receiving the replication stream once the RDB file was sent
+    let server_guard = env.server.read().await;
+    assert_eq!(server_guard.replicas.as_ref().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_stream_rdb_file_without_saved_file() {
+    let env = TestEnv::new_master_server();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.rdb_directory = "/tmp/redis-files-missing".to_string();
+        server_guard.rdb_filename = "missing.rdb".to_string();
+    }
+
+    env.store.lock().await.insert(
+        "mango".to_string(),
+        Value {
+            data: DataType::String("apple".to_string()),
+            expiration: None,
+        },
+    );
+
+    let (_, payload) = receive_streamed_rdb(&env).await;
+
+    let mut rdb_parser = RdbParser::new();
+    rdb_parser.parse(payload).unwrap();
+
+    assert_eq!(rdb_parser.magic_string, Some("REDIS".to_string()));
+    assert_eq!(rdb_parser.databases[&0], *env.store.lock().await);
+    assert_eq!(rdb_parser.crc64_checksum.unwrap().len(), 8);
+}
+
+#[tokio::test]
+async fn test_stream_rdb_file_with_stale_saved_file() {
+    let rdb_directory = "/tmp/redis-files-rdb-stale";
+    let rdb_file = synthetic_rdb_file(1, 10);
+
+    tokio::fs::create_dir_all(rdb_directory).await.unwrap();
+    tokio::fs::write(format!("{}/stale.rdb", rdb_directory), &rdb_file)
+        .await
+        .unwrap();
+
+    let env = TestEnv::new_master_server();
+
+    {
+        let mut server_guard = env.server.write().await;
+        server_guard.rdb_directory = rdb_directory.to_string();
+        server_guard.rdb_filename = "stale.rdb".to_string();
+        server_guard.stats.record_change();
+    }
+
+    env.store.lock().await.insert(
+        "mango".to_string(),
+        Value {
+            data: DataType::String("apple".to_string()),
+            expiration: None,
+        },
+    );
+
+    let (_, payload) = receive_streamed_rdb(&env).await;
+
+    // The file misses the latest write, so the live dataset is sent instead
+    let mut rdb_parser = RdbParser::new();
+    rdb_parser.parse(payload).unwrap();
+
+    assert_eq!(rdb_parser.databases[&0], *env.store.lock().await);
+}
+
+#[test]
+fn test_rdb_parser_multiple_databases() {
+    let mut rdb_file = b"REDIS0011".to_vec();
+
+    // SELECTDB 0 with one key
+    rdb_file.extend_from_slice(&[0xFE, 0x00, 0xFB, 0x01, 0x00]);
+    rdb_file.extend_from_slice(&[0x00, 0x05]);
+    rdb_file.extend_from_slice(b"mango");
+    rdb_file.extend_from_slice(&[0x05]);
+    rdb_file.extend_from_slice(b"apple");
+
+    // SELECTDB 2 with two keys, one of them also present in DB 0
+    rdb_file.extend_from_slice(&[0xFE, 0x02, 0xFB, 0x02, 0x00]);
+    rdb_file.extend_from_slice(&[0x00, 0x05]);
+    rdb_file.extend_from_slice(b"mango");
+    rdb_file.extend_from_slice(&[0x04]);
+    rdb_file.extend_from_slice(b"pear");
+    rdb_file.extend_from_slice(&[0x00, 0x05]);
+    rdb_file.extend_from_slice(b"grape");
+    rdb_file.extend_from_slice(&[0x04]);
+    rdb_file.extend_from_slice(b"kiwi");
+
+    rdb_file.push(0xFF);
+    rdb_file.extend_from_slice(&[0; 8]);
+
+    let mut rdb_parser = RdbParser::new();
+
+    // Parse in small chunks so opcodes are split across buffers
+    for chunk in rdb_file.chunks(7) {
+        rdb_parser.parse(chunk.to_vec()).unwrap();
+    }
+
+    assert_eq!(rdb_parser.databases.len(), 2);
+    assert_eq!(
+        rdb_parser.databases[&0],
+        HashMap::from([(
+            "mango".to_string(),
+            Value {
+                data: DataType::String("apple".to_string()),
+                expiration: None
+            }
+        )])
+    );
+    assert_eq!(
+        rdb_parser.databases[&2],
+        HashMap::from([
+            (
+                "mango".to_string(),
+                Value {
+                    data: DataType::String("pear".to_string()),
+                    expiration: None
+                }
+            ),
+            (
+                "grape".to_string(),
+                Value {
+                    data: DataType::String("kiwi".to_string()),
+                    expiration: None
+                }
+            ),
+        ])
+    );
+    assert_eq!(rdb_parser.db_number, Some("2".to_string()));
+    assert!(rdb_parser.take_default_database().is_err());
+}
+
+#[test]
+fn test_rdb_parser_take_default_database() {
+    let mut rdb_file = b"REDIS0011".to_vec();
+
+    // SELECTDB 0 claiming a hash table size of 2^64 - 1 keys
+    rdb_file.extend_from_slice(&[0xFE, 0x00, 0xFB, 0x81]);
+    rdb_file.extend_from_slice(&[0xFF; 8]);
+    rdb_file.push(0x00);
+    rdb_file.extend_from_slice(&[0x00, 0x05]);
+    rdb_file.extend_from_slice(b"mango");
+    rdb_file.extend_from_slice(&[0x05]);
+    rdb_file.extend_from_slice(b"apple");
+
+    rdb_file.push(0xFF);
+    rdb_file.extend_from_slice(&[0; 8]);
+
+    let mut rdb_parser = RdbParser::new();
+    rdb_parser.parse(rdb_file).unwrap();
+
+    assert_eq!(
+        rdb_parser.take_default_database().unwrap(),
+        HashMap::from([(
+            "mango".to_string(),
+            Value {
+                data: DataType::String("apple".to_string()),
+                expiration: None
+            }
+        )])
+    );
+}
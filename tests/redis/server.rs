@@ -1,11 +1,22 @@
 use codecrafters_redis::input::read_and_parse_resp;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::TcpStream;
-
-use codecrafters_redis::resp::RespValue;
-
-use crate::test_utils::TestUtils;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::RwLock;
+use tokio::time::timeout;
+use tokio_rustls::{
+    TlsConnector,
+    rustls::{ClientConfig, RootCertStore, crypto::ring, pki_types::ServerName},
+};
+
+use codecrafters_redis::{
+    resp::RespValue,
+    server::{RedisServer, configure_client_socket, register_replica},
+};
+use socket2::SockRef;
+
+use crate::test_utils::{TestEnv, TestUtils};
 
 #[tokio::test]
 async fn test_master_replica_handshake_and_replication() {
@@ -257,6 +268,38 @@ async fn test_wait_command_faulty_replica() {
     .await;
 }
 
+#[tokio::test]
+async fn test_waitaof_command_counts_replica_acks() {
+    TestUtils::run_master_server(6395).await;
+
+    // Give master server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    TestUtils::run_replica_server(6396, 6395).await;
+
+    // Give replica server time to start and complete handshake
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+
+    let mut master_client = TcpStream::connect("127.0.0.1:6395").await.unwrap();
+    let mut buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut master_client,
+        &mut buffer,
+        TestUtils::set_command("test_key", "test_value"),
+        RespValue::SimpleString("OK".to_string()),
+    )
+    .await;
+
+    TestUtils::send_command_and_receive_response(
+        &mut master_client,
+        &mut buffer,
+        TestUtils::waitaof_command(0, 1, 1000),
+        RespValue::Array(vec![RespValue::Integer(0), RespValue::Integer(1)]),
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_master_server_load_rdb_file_on_startup() {
     TestUtils::run_master_server_with_custom_rdb_file(
@@ -327,3 +370,358 @@ async fn test_master_replica_rdb_file_transfer() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn test_master_server_active_expire_toggle() {
+    TestUtils::run_master_server(6420).await;
+
+    // Give master server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut master_client = TcpStream::connect("127.0.0.1:6420").await.unwrap();
+    let mut buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut master_client,
+        &mut buffer,
+        TestUtils::debug_set_active_expire_command("0"),
+        RespValue::SimpleString("OK".to_string()),
+    )
+    .await;
+
+    TestUtils::send_command_and_receive_response(
+        &mut master_client,
+        &mut buffer,
+        TestUtils::set_command_with_expiration("grape", "mango", 50),
+        RespValue::SimpleString("OK".to_string()),
+    )
+    .await;
+
+    // Give several active expire cycles the chance to run
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    // The expired key is only removed lazily once it is accessed
+    let test_cases = vec![
+        (TestUtils::dbsize_command(), RespValue::Integer(1)),
+        (TestUtils::get_command("grape"), RespValue::NullBulkString),
+        (TestUtils::dbsize_command(), RespValue::Integer(0)),
+        (
+            TestUtils::debug_set_active_expire_command("1"),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+        (
+            TestUtils::set_command_with_expiration("grape", "mango", 50),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        TestUtils::send_command_and_receive_response(
+            &mut master_client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    TestUtils::send_command_and_receive_response(
+        &mut master_client,
+        &mut buffer,
+        TestUtils::dbsize_command(),
+        RespValue::Integer(0),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_replication_stream_keeps_order_under_rapid_writes() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let replica_addr = listener.local_addr().unwrap();
+
+    let master_side = TcpStream::connect(replica_addr).await.unwrap();
+    let (mut mock_replica, _) = listener.accept().await.unwrap();
+
+    let (_reader, writer) = master_side.into_split();
+
+    let env = TestEnv::new_master_server();
+    let (_, _, server) = env.clone_env();
+
+    register_replica(
+        Arc::clone(&server),
+        &replica_addr.to_string(),
+        Arc::new(RwLock::new(writer)),
+    )
+    .await;
+
+    let mut expected_stream = String::new();
+
+    {
+        let server_guard = server.read().await;
+
+        for i in 0..500 {
+            let command = TestUtils::set_command(&format!("key_{}", i), &i.to_string());
+            expected_stream.push_str(&command.encode());
+
+            server_guard.should_replicate_write_command(command, "SET");
+        }
+
+        // Read commands are not propagated
+        server_guard.should_replicate_write_command(TestUtils::get_command("key_0"), "GET");
+    }
+
+    let mut received_stream = Vec::new();
+    let mut buffer = [0; 4096];
+
+    while received_stream.len() < expected_stream.len() {
+        let bytes_read = timeout(Duration::from_secs(2), mock_replica.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(bytes_read > 0, "Replication stream closed unexpectedly");
+
+        received_stream.extend_from_slice(&buffer[..bytes_read]);
+    }
+
+    assert_eq!(String::from_utf8_lossy(&received_stream), expected_stream);
+
+    // Nothing else, such as the GET command, follows the propagated writes
+    let result = timeout(Duration::from_millis(200), mock_replica.read(&mut buffer)).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_master_server_accepts_tls_connections() {
+    let certified_key = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+
+    let tls_directory = "/tmp/redis-files-tls";
+    let cert_file = format!("{}/redis.crt", tls_directory);
+    let key_file = format!("{}/redis.key", tls_directory);
+
+    tokio::fs::create_dir_all(tls_directory).await.unwrap();
+    tokio::fs::write(&cert_file, certified_key.cert.pem())
+        .await
+        .unwrap();
+    tokio::fs::write(&key_file, certified_key.key_pair.serialize_pem())
+        .await
+        .unwrap();
+
+    let server_args = vec![
+        "redis-server".to_string(),
+        "--port".to_string(),
+        "6396".to_string(),
+        "--tls-port".to_string(),
+        "6401".to_string(),
+        "--tls-cert-file".to_string(),
+        cert_file,
+        "--tls-key-file".to_string(),
+        key_file,
+    ];
+    let server = RedisServer::new(server_args).unwrap();
+
+    tokio::spawn(async move {
+        server.run().await;
+    });
+
+    // Give server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut root_certificates = RootCertStore::empty();
+    root_certificates
+        .add(certified_key.cert.der().clone())
+        .unwrap();
+
+    let config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_root_certificates(root_certificates)
+        .with_no_client_auth();
+    let connector = TlsConnector::from(Arc::new(config));
+
+    let stream = TcpStream::connect("127.0.0.1:6401").await.unwrap();
+    let mut client = connector
+        .connect(ServerName::try_from("localhost").unwrap(), stream)
+        .await
+        .unwrap();
+    let mut buffer = [0; 1024];
+
+    let test_cases = vec![
+        (
+            TestUtils::ping_command(),
+            RespValue::SimpleString("PONG".to_string()),
+        ),
+        (
+            TestUtils::set_command("fruit", "mango"),
+            RespValue::SimpleString("OK".to_string()),
+        ),
+        (
+            TestUtils::get_command("fruit"),
+            RespValue::BulkString("mango".to_string()),
+        ),
+    ];
+
+    for (command, expected_response) in test_cases {
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            command,
+            expected_response,
+        )
+        .await;
+    }
+
+    // The plaintext listener keeps serving clients next to the TLS one
+    let mut client = TcpStream::connect("127.0.0.1:6396").await.unwrap();
+
+    TestUtils::send_command_and_receive_response(
+        &mut client,
+        &mut buffer,
+        TestUtils::get_command("fruit"),
+        RespValue::BulkString("mango".to_string()),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_master_server_binds_ipv6_address() {
+    let server_args = vec![
+        "redis-server".to_string(),
+        "--port".to_string(),
+        "6394".to_string(),
+        "--bind".to_string(),
+        "127.0.0.1 ::1".to_string(),
+    ];
+    let server = RedisServer::new(server_args).unwrap();
+
+    tokio::spawn(async move {
+        server.run().await;
+    });
+
+    // Give server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    for address in ["[::1]:6394", "127.0.0.1:6394"] {
+        let mut client = TcpStream::connect(address).await.unwrap();
+        let mut buffer = [0; 1024];
+
+        TestUtils::send_command_and_receive_response(
+            &mut client,
+            &mut buffer,
+            TestUtils::ping_command(),
+            RespValue::SimpleString("PONG".to_string()),
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn test_published_messages_keep_order_with_interleaved_replies() {
+    TestUtils::run_master_server(6399).await;
+
+    // Give master server time to start
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut subscriber = TcpStream::connect("127.0.0.1:6399").await.unwrap();
+    let mut buffer = [0; 1024];
+
+    TestUtils::send_command_and_receive_response(
+        &mut subscriber,
+        &mut buffer,
+        TestUtils::subscribe_command("fruits"),
+        RespValue::Array(vec![
+            RespValue::BulkString("subscribe".to_string()),
+            RespValue::BulkString("fruits".to_string()),
+            RespValue::Integer(1),
+        ]),
+    )
+    .await;
+
+    let publisher = tokio::spawn(async move {
+        let mut publisher = TcpStream::connect("127.0.0.1:6399").await.unwrap();
+        let mut buffer = [0; 1024];
+
+        for i in 0..200 {
+            TestUtils::send_command_and_receive_response(
+                &mut publisher,
+                &mut buffer,
+                TestUtils::publish_command("fruits", &format!("mango_{}", i)),
+                RespValue::Integer(1),
+            )
+            .await;
+        }
+    });
+
+    // Replies to the subscriber's own commands are written while messages are being delivered.
+    // PINGs are sent in small batches so each batch fits in a single read on the server.
+    let ping_batch = TestUtils::ping_command().encode().repeat(10);
+
+    for _ in 0..20 {
+        subscriber.write_all(ping_batch.as_bytes()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    let mut received = String::new();
+
+    let values = loop {
+        let bytes_read = timeout(Duration::from_secs(2), subscriber.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(bytes_read > 0, "Subscriber connection closed unexpectedly");
+
+        received.push_str(&String::from_utf8_lossy(&buffer[..bytes_read]));
+
+        if received.ends_with("\r\n")
+            && let Ok(values) = RespValue::parse(received.split_terminator("\r\n").collect())
+            && values.len() == 400
+        {
+            break values;
+        }
+    };
+
+    publisher.await.unwrap();
+
+    let pong = RespValue::Array(vec![
+        RespValue::BulkString("pong".to_string()),
+        RespValue::BulkString("".to_string()),
+    ]);
+    let messages = values
+        .into_iter()
+        .filter(|value| *value != pong)
+        .collect::<Vec<RespValue>>();
+
+    let expected_messages = (0..200)
+        .map(|i| {
+            RespValue::Array(vec![
+                RespValue::BulkString("message".to_string()),
+                RespValue::BulkString("fruits".to_string()),
+                RespValue::BulkString(format!("mango_{}", i)),
+            ])
+        })
+        .collect::<Vec<RespValue>>();
+
+    assert_eq!(messages, expected_messages);
+}
+
+#[tokio::test]
+async fn test_configure_client_socket() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let test_cases = vec![(60, true), (0, false)];
+
+    for (tcp_keepalive, expected_keepalive) in test_cases {
+        let _client = TcpStream::connect(address).await.unwrap();
+        let (stream, _) = listener.accept().await.unwrap();
+
+        configure_client_socket(&stream, tcp_keepalive).unwrap();
+
+        assert!(stream.nodelay().unwrap());
+        assert_eq!(
+            SockRef::from(&stream).keepalive().unwrap(),
+            expected_keepalive
+        );
+    }
+}
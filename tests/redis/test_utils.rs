@@ -1,23 +1,37 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    net::{IpAddr, Ipv4Addr},
+    sync::Arc,
+    time::Duration,
+};
 
 use codecrafters_redis::{
+    aof::AppendFsync,
     commands::{
         CommandError, CommandHandler, CommandResult, run_transaction_commands_for_master_server,
     },
+    hash::HashEncodingLimits,
     input::read_and_parse_resp,
     key_value_store::KeyValueStore,
-    resp::RespValue,
-    server::{RedisRole, RedisServer},
+    latency::{DEFAULT_LATENCY_MONITOR_THRESHOLD, LatencyMonitor},
+    list::DEFAULT_LIST_MAX_LISTPACK_SIZE,
+    resp::{DEFAULT_PROTO_MAX_BULK_LEN, RespValue},
+    server::{DEFAULT_TCP_KEEPALIVE, RedisRole, RedisServer, ServerStats, spawn_outbound_queue},
+    set::SetEncodingLimits,
+    slowlog::{DEFAULT_SLOWLOG_LOG_SLOWER_THAN, SlowLog},
+    sorted_set::SortedSetEncodingLimits,
     state::State,
+    stream::DEFAULT_STREAM_NODE_MAX_ENTRIES,
 };
+use jiff::Timestamp;
 use tokio::{
-    io::AsyncWriteExt,
-    net::{TcpListener, tcp::OwnedWriteHalf},
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
     task::JoinHandle,
 };
 use tokio::{
     net::TcpStream,
-    sync::{Mutex, RwLock},
+    sync::{Mutex, RwLock, mpsc},
     time::timeout,
 };
 
@@ -39,14 +53,79 @@ impl TestEnv {
             state: Arc::new(Mutex::new(State::new())),
             server: Arc::new(RwLock::new(RedisServer {
                 port: 6379,
+                bind_addresses: vec![IpAddr::V4(Ipv4Addr::LOCALHOST)],
+                tls_port: None,
+                tls_config: None,
                 role: RedisRole::Master,
                 repl_id: "8371b4fb1155b71f4a04d3e1bc3e18c4a990aeeb".to_string(),
                 repl_offset: 0,
                 replicas: Some(HashMap::new()),
-                write_commands: vec!["SET", "RPUSH", "LPUSH", "INCR", "LPOP", "BLPOP", "XADD"],
+                write_commands: vec![
+                    "SET",
+                    "RPUSH",
+                    "LPUSH",
+                    "INCR",
+                    "LPOP",
+                    "LMPOP",
+                    "BLPOP",
+                    "XADD",
+                    "XSETID",
+                    "HSET",
+                    "HSETNX",
+                    "ZADD",
+                    "ZINCRBY",
+                    "PFADD",
+                    "PFMERGE",
+                    "BITFIELD",
+                    "GETSET",
+                    "GETDEL",
+                    "SADD",
+                    "SMOVE",
+                    "SINTERSTORE",
+                    "SUNIONSTORE",
+                    "SDIFFSTORE",
+                    "ZPOPMIN",
+                    "ZMPOP",
+                    "ZPOPMAX",
+                    "BZPOPMIN",
+                    "BZPOPMAX",
+                    "SORT",
+                    "PEXPIREAT",
+                    "GEOADD",
+                ],
                 rdb_directory: "/tmp/redis-files".to_string(),
                 rdb_filename: "dump.rdb".to_string(),
                 pub_sub_channels: HashMap::new(),
+                shard_pub_sub_channels: HashMap::new(),
+                resp3_clients: HashSet::new(),
+                active_expire_enabled: true,
+                clients: HashMap::new(),
+                next_client_id: 1,
+                last_save_time: Timestamp::now(),
+                is_background_save_in_progress: false,
+                save_points: Vec::new(),
+                appendonly: false,
+                replica_read_only: true,
+                appendfsync: AppendFsync::EverySec,
+                append_only_file: None,
+                is_aof_rewrite_in_progress: false,
+                is_last_aof_rewrite_ok: true,
+                list_max_listpack_size: DEFAULT_LIST_MAX_LISTPACK_SIZE,
+                set_encoding_limits: SetEncodingLimits::default(),
+                zset_encoding_limits: SortedSetEncodingLimits::default(),
+                hash_encoding_limits: HashEncodingLimits::default(),
+                stream_node_max_entries: DEFAULT_STREAM_NODE_MAX_ENTRIES,
+                stats: Arc::new(ServerStats::default()),
+                slowlog_log_slower_than: DEFAULT_SLOWLOG_LOG_SLOWER_THAN,
+                slowlog: SlowLog::default(),
+                latency_monitor_threshold: DEFAULT_LATENCY_MONITOR_THRESHOLD,
+                latency_monitor: LatencyMonitor::new(),
+                tcp_keepalive: DEFAULT_TCP_KEEPALIVE,
+                key_access_times: HashMap::new(),
+                raw_string_keys: HashSet::new(),
+                tracking_table: HashMap::new(),
+                requirepass: None,
+                proto_max_bulk_len: DEFAULT_PROTO_MAX_BULK_LEN,
             })),
         }
     }
@@ -58,19 +137,84 @@ impl TestEnv {
             state: Arc::new(Mutex::new(State::new())),
             server: Arc::new(RwLock::new(RedisServer {
                 port: replica_port,
+                bind_addresses: vec![IpAddr::V4(Ipv4Addr::LOCALHOST)],
+                tls_port: None,
+                tls_config: None,
                 role: RedisRole::Replica(("127.0.0.1".to_string(), 6379)),
                 repl_id: "c673350b6868f3661bd1231ad1b5389310d0a201".to_string(),
                 repl_offset: 0,
                 replicas: None,
-                write_commands: vec!["SET", "RPUSH", "LPUSH", "INCR", "LPOP", "BLPOP", "XADD"],
+                write_commands: vec![
+                    "SET",
+                    "RPUSH",
+                    "LPUSH",
+                    "INCR",
+                    "LPOP",
+                    "LMPOP",
+                    "BLPOP",
+                    "XADD",
+                    "XSETID",
+                    "HSET",
+                    "HSETNX",
+                    "ZADD",
+                    "ZINCRBY",
+                    "PFADD",
+                    "PFMERGE",
+                    "BITFIELD",
+                    "GETSET",
+                    "GETDEL",
+                    "SADD",
+                    "SMOVE",
+                    "SINTERSTORE",
+                    "SUNIONSTORE",
+                    "SDIFFSTORE",
+                    "ZPOPMIN",
+                    "ZMPOP",
+                    "ZPOPMAX",
+                    "BZPOPMIN",
+                    "BZPOPMAX",
+                    "SORT",
+                    "PEXPIREAT",
+                    "GEOADD",
+                ],
                 rdb_directory: "/tmp/redis-files".to_string(),
                 rdb_filename: "dump.rdb".to_string(),
                 pub_sub_channels: HashMap::new(),
+                shard_pub_sub_channels: HashMap::new(),
+                resp3_clients: HashSet::new(),
+                active_expire_enabled: true,
+                clients: HashMap::new(),
+                next_client_id: 1,
+                last_save_time: Timestamp::now(),
+                is_background_save_in_progress: false,
+                save_points: Vec::new(),
+                appendonly: false,
+                replica_read_only: true,
+                appendfsync: AppendFsync::EverySec,
+                append_only_file: None,
+                is_aof_rewrite_in_progress: false,
+                is_last_aof_rewrite_ok: true,
+                list_max_listpack_size: DEFAULT_LIST_MAX_LISTPACK_SIZE,
+                set_encoding_limits: SetEncodingLimits::default(),
+                zset_encoding_limits: SortedSetEncodingLimits::default(),
+                hash_encoding_limits: HashEncodingLimits::default(),
+                stream_node_max_entries: DEFAULT_STREAM_NODE_MAX_ENTRIES,
+                stats: Arc::new(ServerStats::default()),
+                slowlog_log_slower_than: DEFAULT_SLOWLOG_LOG_SLOWER_THAN,
+                slowlog: SlowLog::default(),
+                latency_monitor_threshold: DEFAULT_LATENCY_MONITOR_THRESHOLD,
+                latency_monitor: LatencyMonitor::new(),
+                tcp_keepalive: DEFAULT_TCP_KEEPALIVE,
+                key_access_times: HashMap::new(),
+                raw_string_keys: HashSet::new(),
+                tracking_table: HashMap::new(),
+                requirepass: None,
+                proto_max_bulk_len: DEFAULT_PROTO_MAX_BULK_LEN,
             })),
         }
     }
 
-    pub async fn new_client_connection() -> (String, Arc<RwLock<OwnedWriteHalf>>) {
+    pub async fn new_client_connection() -> (String, mpsc::UnboundedSender<Vec<u8>>) {
         let client_address = &TestUtils::client_address(0);
         let listener = TcpListener::bind(&client_address).await.unwrap();
         let addr = listener.local_addr().unwrap();
@@ -82,7 +226,10 @@ impl TestEnv {
         let (tcp_stream, _) = listener.accept().await.unwrap();
         let (_, writer) = tcp_stream.into_split();
 
-        (addr.to_string(), Arc::new(RwLock::new(writer)))
+        (
+            addr.to_string(),
+            spawn_outbound_queue(Arc::new(RwLock::new(writer))),
+        )
     }
 
     /// Clone the test environment
@@ -222,15 +369,16 @@ impl TestEnv {
         &mut self,
         command: RespValue,
         client_address: &str,
-        writer: Arc<RwLock<OwnedWriteHalf>>,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
     ) -> Result<Option<CommandResult>, CommandError> {
         let command_handler = CommandHandler::new(command)?;
 
         command_handler
             .handle_pub_sub_commands(
                 &client_address,
-                Arc::clone(&writer),
+                sender,
                 Arc::clone(&self.server),
+                Arc::clone(&self.state),
             )
             .await
     }
@@ -240,11 +388,11 @@ impl TestEnv {
         &mut self,
         command: RespValue,
         client_address: &str,
-        writer: Arc<RwLock<OwnedWriteHalf>>,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
         expected_response: Option<String>,
     ) {
         let result = self
-            .exec_pub_sub_command(command, client_address, writer)
+            .exec_pub_sub_command(command, client_address, sender)
             .await;
         assert!(result.is_ok());
 
@@ -266,11 +414,11 @@ impl TestEnv {
         &mut self,
         command: RespValue,
         client_address: &str,
-        writer: Arc<RwLock<OwnedWriteHalf>>,
+        sender: mpsc::UnboundedSender<Vec<u8>>,
         expected_error: CommandError,
     ) {
         let result = self
-            .exec_pub_sub_command(command, client_address, writer)
+            .exec_pub_sub_command(command, client_address, sender)
             .await;
         assert!(result.is_err());
 
@@ -362,6 +510,48 @@ impl TestUtils {
         ])
     }
 
+    /// Create an LMPOP command
+    pub fn lmpop_command(keys: &[&str], direction: &str, count: Option<&str>) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("LMPOP".to_string()),
+            RespValue::BulkString(keys.len().to_string()),
+        ];
+
+        for key in keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        command.push(RespValue::BulkString(direction.to_string()));
+
+        if let Some(count) = count {
+            command.push(RespValue::BulkString("COUNT".to_string()));
+            command.push(RespValue::BulkString(count.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZMPOP command
+    pub fn zmpop_command(keys: &[&str], direction: &str, count: Option<&str>) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("ZMPOP".to_string()),
+            RespValue::BulkString(keys.len().to_string()),
+        ];
+
+        for key in keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        command.push(RespValue::BulkString(direction.to_string()));
+
+        if let Some(count) = count {
+            command.push(RespValue::BulkString("COUNT".to_string()));
+            command.push(RespValue::BulkString(count.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
     /// Create a PING command
     pub fn ping_command() -> RespValue {
         RespValue::Array(vec![RespValue::BulkString("PING".to_string())])
@@ -436,6 +626,60 @@ impl TestUtils {
         ])
     }
 
+    /// Create a XADD command with a trimming strategy, e.g. `["MAXLEN", "~", "3"]`
+    pub fn xadd_with_trim_command(
+        key: &str,
+        trim_strategy: &[&str],
+        stream_id: &str,
+        entries: &[&str],
+    ) -> RespValue {
+        let mut vec = vec![
+            RespValue::BulkString("XADD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for arg in trim_strategy
+            .iter()
+            .chain([stream_id].iter())
+            .chain(entries)
+        {
+            vec.push(RespValue::BulkString(arg.to_string()));
+        }
+
+        RespValue::Array(vec)
+    }
+
+    /// Create a XLEN command
+    pub fn xlen_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("XLEN".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
+
+    /// Create a XDEL command
+    pub fn xdel_command(key: &str, stream_ids: &[&str]) -> RespValue {
+        let mut vec = vec![
+            RespValue::BulkString("XDEL".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for stream_id in stream_ids {
+            vec.push(RespValue::BulkString(stream_id.to_string()));
+        }
+
+        RespValue::Array(vec)
+    }
+
+    /// Create a XSETID command
+    pub fn xsetid_command(key: &str, last_id: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("XSETID".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(last_id.to_string()),
+        ])
+    }
+
     /// Create a XREAD command
     pub fn xread_command(keys: &[&str], start_stream_ids: &[&str]) -> RespValue {
         let mut vec = vec![
@@ -454,6 +698,31 @@ impl TestUtils {
         RespValue::Array(vec)
     }
 
+    /// Create a XREAD command with options such as COUNT and BLOCK before STREAMS
+    pub fn xread_command_with_options(
+        options: &[&str],
+        keys: &[&str],
+        start_stream_ids: &[&str],
+    ) -> RespValue {
+        let mut vec = vec![RespValue::BulkString("XREAD".to_string())];
+
+        for option in options {
+            vec.push(RespValue::BulkString(option.to_string()));
+        }
+
+        vec.push(RespValue::BulkString("STREAMS".to_string()));
+
+        for key in keys {
+            vec.push(RespValue::BulkString(key.to_string()));
+        }
+
+        for stream_id in start_stream_ids {
+            vec.push(RespValue::BulkString(stream_id.to_string()));
+        }
+
+        RespValue::Array(vec)
+    }
+
     /// Create a blocking XREAD command
     pub fn xread_blocking_command(
         timeout_milliseconds: &str,
@@ -540,6 +809,20 @@ impl TestUtils {
         ])
     }
 
+    /// Create a WAITAOF command
+    pub fn waitaof_command(
+        number_of_local: u32,
+        number_of_replicas: u32,
+        timeout_ms: u32,
+    ) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("WAITAOF".to_string()),
+            RespValue::BulkString(number_of_local.to_string()),
+            RespValue::BulkString(number_of_replicas.to_string()),
+            RespValue::BulkString(timeout_ms.to_string()),
+        ])
+    }
+
     /// Create a CONFIG GET command
     pub fn config_get_command(keys: &[&str]) -> RespValue {
         let mut vec = vec![
@@ -570,143 +853,1088 @@ impl TestUtils {
         ])
     }
 
-    /// Create an invalid command
-    pub fn invalid_command(args: &[&str]) -> RespValue {
-        let mut vec = Vec::new();
+    /// Create a HELLO command
+    pub fn hello_command(protocol_version: Option<&str>) -> RespValue {
+        if let Some(protocol_version) = protocol_version {
+            RespValue::Array(vec![
+                RespValue::BulkString("HELLO".to_string()),
+                RespValue::BulkString(protocol_version.to_string()),
+            ])
+        } else {
+            RespValue::Array(vec![RespValue::BulkString("HELLO".to_string())])
+        }
+    }
 
-        for arg in args {
+    /// Create a HELLO command that authenticates the connection
+    pub fn hello_auth_command(protocol_version: &str, username: &str, password: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("HELLO".to_string()),
+            RespValue::BulkString(protocol_version.to_string()),
+            RespValue::BulkString("AUTH".to_string()),
+            RespValue::BulkString(username.to_string()),
+            RespValue::BulkString(password.to_string()),
+        ])
+    }
+
+    /// Create a RESET command
+    pub fn reset_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("RESET".to_string())])
+    }
+
+    /// Create a QUIT command
+    pub fn quit_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("QUIT".to_string())])
+    }
+
+    /// Create a XTRIM command
+    pub fn xtrim_command(key: &str, trim_strategy: &[&str]) -> RespValue {
+        let mut vec = vec![
+            RespValue::BulkString("XTRIM".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for arg in trim_strategy {
             vec.push(RespValue::BulkString(arg.to_string()));
         }
 
         RespValue::Array(vec)
     }
 
-    /// Create a PUBLISH command
-    pub fn publish_command(channel: &str, message: &str) -> RespValue {
+    /// Create a DEBUG SET-STREAM-MAX-ENTRIES command
+    pub fn debug_set_stream_max_entries_command(node_max_entries: &str) -> RespValue {
         RespValue::Array(vec![
-            RespValue::BulkString("PUBLISH".to_string()),
-            RespValue::BulkString(channel.to_string()),
-            RespValue::BulkString(message.to_string()),
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("SET-STREAM-MAX-ENTRIES".to_string()),
+            RespValue::BulkString(node_max_entries.to_string()),
         ])
     }
 
-    /// Generate a unique server address for testing
-    pub fn client_address(port: u16) -> String {
-        format!("127.0.0.1:{}", port)
+    /// Create a DEBUG SET-ACTIVE-EXPIRE command
+    pub fn debug_set_active_expire_command(enabled: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("SET-ACTIVE-EXPIRE".to_string()),
+            RespValue::BulkString(enabled.to_string()),
+        ])
     }
 
-    /// Spawn a BLPOP task that blocks on the given key
-    pub fn spawn_blpop_task(
-        env: &TestEnv,
-        key: &str,
-        timeout_seconds: &str,
-        client_address: &str,
-    ) -> JoinHandle<Result<CommandResult, CommandError>> {
-        let (store_clone, state_clone, server_clone) = env.clone_env();
-        let blpop_command = Self::blpop_command(key, timeout_seconds);
-        let client_address = client_address.to_string();
+    /// Create an OBJECT ENCODING command
+    pub fn object_encoding_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("OBJECT".to_string()),
+            RespValue::BulkString("ENCODING".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
 
-        tokio::spawn(async move {
-            let command_handler = CommandHandler::new(blpop_command)?;
+    /// Create an OBJECT IDLETIME command
+    pub fn object_idletime_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("OBJECT".to_string()),
+            RespValue::BulkString("IDLETIME".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
 
-            command_handler
-                .handle_command_for_master_server(
-                    &client_address,
-                    Arc::clone(&server_clone),
-                    Arc::clone(&store_clone),
-                    Arc::clone(&state_clone),
-                )
-                .await
-        })
+    /// Create a DEBUG CHANGE-REPL-ID command
+    pub fn debug_change_repl_id_command() -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("CHANGE-REPL-ID".to_string()),
+        ])
     }
 
-    /// Spawn a XREAD task that blocks on the given key
-    pub fn spawn_xread_task(
-        env: &TestEnv,
-        keys: &[&str],
-        stream_ids: &[&str],
-        timeout_milliseconds: &str,
-        client_address: &str,
-    ) -> JoinHandle<Result<CommandResult, CommandError>> {
-        let (store_clone, state_clone, server_clone) = env.clone_env();
-        let xread_blocking_command =
-            Self::xread_blocking_command(timeout_milliseconds, keys, stream_ids);
-        let client_address = client_address.to_string();
+    /// Create a DEBUG OBJECT command
+    pub fn debug_object_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("OBJECT".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
 
-        tokio::spawn(async move {
-            let command_handler = CommandHandler::new(xread_blocking_command)?;
+    /// Create a DEBUG LISTPACK command
+    pub fn debug_listpack_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("LISTPACK".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
 
-            command_handler
-                .handle_command_for_master_server(
-                    &client_address,
-                    Arc::clone(&server_clone),
-                    Arc::clone(&store_clone),
-                    Arc::clone(&state_clone),
-                )
-                .await
-        })
+    /// Create a DEBUG STREAM command
+    pub fn debug_stream_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("STREAM".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
     }
 
-    /// Wait for a task with timeout and expect it to complete (success or failure)
-    pub async fn wait_for_completion(
-        task: JoinHandle<Result<CommandResult, CommandError>>,
-        timeout_duration: Duration,
-    ) -> Result<String, CommandError> {
-        match timeout(timeout_duration, task)
-            .await
-            .expect("Task should complete within timeout")
-            .expect("Task should not panic")
-        {
-            Ok(result) => match result {
-                CommandResult::Response(value) => Ok(value),
-                _ => panic!("Unexpected command result"),
-            },
-            Err(err) => Err(err),
-        }
+    /// Create a DEBUG RELOAD command
+    pub fn debug_reload_command() -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("RELOAD".to_string()),
+        ])
     }
 
-    /// Create expected bulk string response
-    pub fn expected_bulk_string(value: &str) -> String {
-        format!("${}\r\n{}\r\n", value.len(), value)
+    /// Create a DEBUG STRINGMATCH-LEN command
+    pub fn debug_stringmatch_len_command(pattern: &str, string: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("STRINGMATCH-LEN".to_string()),
+            RespValue::BulkString(pattern.to_string()),
+            RespValue::BulkString(string.to_string()),
+        ])
     }
 
-    /// Create expected integer response
-    pub fn expected_integer(value: i64) -> String {
-        format!(":{}\r\n", value)
+    /// Create a DEBUG SLEEP command
+    pub fn debug_sleep_command(seconds: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("SLEEP".to_string()),
+            RespValue::BulkString(seconds.to_string()),
+        ])
     }
 
-    /// Create expected simple string response
-    pub fn expected_simple_string(value: &str) -> String {
-        format!("+{}\r\n", value)
+    /// Create a DEBUG DEBUG command
+    pub fn debug_debug_command(seconds: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString("DEBUG".to_string()),
+            RespValue::BulkString(seconds.to_string()),
+        ])
     }
 
-    /// Create expected null bulk string response
-    pub fn expected_null_bulk_string() -> String {
-        "$-1\r\n".to_string()
+    /// Create a SLOWLOG command with the given subcommand and arguments
+    pub fn slowlog_command(sub_command: &str, arguments: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("SLOWLOG".to_string()),
+            RespValue::BulkString(sub_command.to_string()),
+        ];
+        command.extend(
+            arguments
+                .iter()
+                .map(|argument| RespValue::BulkString(argument.to_string())),
+        );
+
+        RespValue::Array(command)
     }
 
-    /// Create expected null array response
-    pub fn expected_null_array() -> String {
-        "*-1\r\n".to_string()
+    /// Create a LATENCY command with the given subcommand and arguments
+    pub fn latency_command(sub_command: &str, arguments: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("LATENCY".to_string()),
+            RespValue::BulkString(sub_command.to_string()),
+        ];
+        command.extend(
+            arguments
+                .iter()
+                .map(|argument| RespValue::BulkString(argument.to_string())),
+        );
+
+        RespValue::Array(command)
     }
 
-    /// Create expected bulk string array response
-    pub fn expected_bulk_string_array(items: &[&str]) -> String {
-        let mut response = format!("*{}\r\n", items.len());
-        for item in items {
-            response.push_str(&format!("${}\r\n{}\r\n", item.len(), item));
+    /// Create a MEMORY USAGE command
+    pub fn memory_usage_command(key: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("MEMORY".to_string()),
+            RespValue::BulkString("USAGE".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
         }
-        response
+
+        RespValue::Array(command)
     }
 
-    /// Async sleep helper
-    pub async fn sleep_ms(ms: u64) {
-        tokio::time::sleep(Duration::from_millis(ms)).await;
+    /// Create a MEMORY command with the given subcommand and arguments
+    pub fn memory_command(sub_command: &str, arguments: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("MEMORY".to_string()),
+            RespValue::BulkString(sub_command.to_string()),
+        ];
+
+        for argument in arguments {
+            command.push(RespValue::BulkString(argument.to_string()));
+        }
+
+        RespValue::Array(command)
     }
 
-    /// Filter successful results containing a specific substring
-    pub fn filter_successful_results_containing<'a>(
-        results: &'a [Result<String, CommandError>],
+    /// Create a FAILOVER command
+    pub fn failover_command(arguments: &[&str]) -> RespValue {
+        let mut command = vec![RespValue::BulkString("FAILOVER".to_string())];
+        command.extend(
+            arguments
+                .iter()
+                .map(|argument| RespValue::BulkString(argument.to_string())),
+        );
+        RespValue::Array(command)
+    }
+
+    /// Create a DBSIZE command
+    pub fn dbsize_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("DBSIZE".to_string())])
+    }
+
+    /// Create a COMMAND LIST command
+    pub fn command_list_command(filter: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("COMMAND".to_string()),
+            RespValue::BulkString("LIST".to_string()),
+        ];
+
+        for argument in filter {
+            command.push(RespValue::BulkString(argument.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a LOLWUT command
+    pub fn lolwut_command(version: Option<&str>) -> RespValue {
+        if let Some(version) = version {
+            RespValue::Array(vec![
+                RespValue::BulkString("LOLWUT".to_string()),
+                RespValue::BulkString("VERSION".to_string()),
+                RespValue::BulkString(version.to_string()),
+            ])
+        } else {
+            RespValue::Array(vec![RespValue::BulkString("LOLWUT".to_string())])
+        }
+    }
+
+    /// Create an HSET command
+    pub fn hset_command(key: &str, fields: &[(&str, &str)]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("HSET".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for (field, value) in fields {
+            command.push(RespValue::BulkString(field.to_string()));
+            command.push(RespValue::BulkString(value.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create an HGET command
+    pub fn hget_command(key: &str, field: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("HGET".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(field.to_string()),
+        ])
+    }
+
+    /// Create an HGETALL command
+    pub fn hgetall_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("HGETALL".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
+
+    /// Create an HMGET command
+    pub fn hmget_command(key: &str, fields: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("HMGET".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for field in fields {
+            command.push(RespValue::BulkString(field.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create an HSETNX command
+    pub fn hsetnx_command(key: &str, field: &str, value: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("HSETNX".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(field.to_string()),
+            RespValue::BulkString(value.to_string()),
+        ])
+    }
+
+    /// Create an HSTRLEN command
+    pub fn hstrlen_command(key: &str, field: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("HSTRLEN".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(field.to_string()),
+        ])
+    }
+
+    /// Create a HRANDFIELD command
+    pub fn hrandfield_command(key: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("HRANDFIELD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a HSCAN command
+    pub fn hscan_command(key: &str, cursor: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("HSCAN".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(cursor.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZMSCORE command
+    pub fn zmscore_command(key: &str, members: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("ZMSCORE".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for member in members {
+            command.push(RespValue::BulkString(member.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZRANDMEMBER command
+    pub fn zrandmember_command(key: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("ZRANDMEMBER".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZADD command
+    pub fn zadd_command(key: &str, options: &[&str], members: &[(&str, &str)]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("ZADD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        for (score, member) in members {
+            command.push(RespValue::BulkString(score.to_string()));
+            command.push(RespValue::BulkString(member.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZSCORE command
+    pub fn zscore_command(key: &str, member: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("ZSCORE".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(member.to_string()),
+        ])
+    }
+
+    /// Create a ZRANGE command
+    pub fn zrange_command(key: &str, start: &str, stop: &str, with_scores: bool) -> RespValue {
+        Self::zrange_by_rank_command("ZRANGE", key, start, stop, with_scores)
+    }
+
+    /// Create a ZREM command
+    pub fn zrem_command(key: &str, members: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("ZREM".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for member in members {
+            command.push(RespValue::BulkString(member.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZREMRANGEBYRANK or ZREMRANGEBYSCORE command
+    pub fn zremrange_command(command_name: &str, key: &str, start: &str, stop: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString(command_name.to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(start.to_string()),
+            RespValue::BulkString(stop.to_string()),
+        ])
+    }
+
+    /// Create a ZREVRANGE command
+    pub fn zrevrange_command(key: &str, start: &str, stop: &str, with_scores: bool) -> RespValue {
+        Self::zrange_by_rank_command("ZREVRANGE", key, start, stop, with_scores)
+    }
+
+    fn zrange_by_rank_command(
+        command_name: &str,
+        key: &str,
+        start: &str,
+        stop: &str,
+        with_scores: bool,
+    ) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString(command_name.to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(start.to_string()),
+            RespValue::BulkString(stop.to_string()),
+        ];
+
+        if with_scores {
+            command.push(RespValue::BulkString("WITHSCORES".to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZRANK command
+    pub fn zrank_command(key: &str, member: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("ZRANK".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(member.to_string()),
+        ])
+    }
+
+    /// Create a ZREVRANK command
+    pub fn zrevrank_command(key: &str, member: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("ZREVRANK".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(member.to_string()),
+        ])
+    }
+
+    /// Create a ZCARD command
+    pub fn zcard_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("ZCARD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
+
+    /// Create a ZINCRBY command
+    pub fn zincrby_command(key: &str, increment: &str, member: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("ZINCRBY".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(increment.to_string()),
+            RespValue::BulkString(member.to_string()),
+        ])
+    }
+
+    /// Create a PFADD command
+    pub fn pfadd_command(key: &str, elements: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("PFADD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for element in elements {
+            command.push(RespValue::BulkString(element.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a PFCOUNT command
+    pub fn pfcount_command(keys: &[&str]) -> RespValue {
+        let mut command = vec![RespValue::BulkString("PFCOUNT".to_string())];
+
+        for key in keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a PFMERGE command
+    pub fn pfmerge_command(destination_key: &str, source_keys: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("PFMERGE".to_string()),
+            RespValue::BulkString(destination_key.to_string()),
+        ];
+
+        for key in source_keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a BITFIELD command
+    pub fn bitfield_command(key: &str, operations: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("BITFIELD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for operation in operations {
+            command.push(RespValue::BulkString(operation.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a GETSET command
+    pub fn getset_command(key: &str, value: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("GETSET".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(value.to_string()),
+        ])
+    }
+
+    /// Create a GETDEL command
+    pub fn getdel_command(key: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("GETDEL".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ])
+    }
+
+    /// Create an APPEND command
+    pub fn append_command(key: &str, value: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("APPEND".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(value.to_string()),
+        ])
+    }
+
+    /// Create a SETRANGE command
+    pub fn setrange_command(key: &str, offset: i64, value: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("SETRANGE".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(offset.to_string()),
+            RespValue::BulkString(value.to_string()),
+        ])
+    }
+
+    /// Create a CLIENT INFO command
+    pub fn client_info_command() -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("CLIENT".to_string()),
+            RespValue::BulkString("INFO".to_string()),
+        ])
+    }
+
+    /// Create a CLIENT NO-EVICT command
+    pub fn client_no_evict_command(mode: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("CLIENT".to_string()),
+            RespValue::BulkString("NO-EVICT".to_string()),
+            RespValue::BulkString(mode.to_string()),
+        ])
+    }
+
+    /// Create a CLIENT NO-TOUCH command
+    pub fn client_no_touch_command(mode: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("CLIENT".to_string()),
+            RespValue::BulkString("NO-TOUCH".to_string()),
+            RespValue::BulkString(mode.to_string()),
+        ])
+    }
+
+    /// Create a CLIENT TRACKING command
+    pub fn client_tracking_command(mode: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("CLIENT".to_string()),
+            RespValue::BulkString("TRACKING".to_string()),
+            RespValue::BulkString(mode.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create an SADD command
+    pub fn sadd_command(key: &str, members: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("SADD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for member in members {
+            command.push(RespValue::BulkString(member.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create an SSCAN command
+    pub fn sscan_command(key: &str, cursor: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("SSCAN".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(cursor.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create an SINTERCARD command
+    pub fn sintercard_command(keys: &[&str], limit: Option<&str>) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("SINTERCARD".to_string()),
+            RespValue::BulkString(keys.len().to_string()),
+        ];
+
+        for key in keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        if let Some(limit) = limit {
+            command.push(RespValue::BulkString("LIMIT".to_string()));
+            command.push(RespValue::BulkString(limit.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create an SMOVE command
+    pub fn smove_command(source: &str, destination: &str, member: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("SMOVE".to_string()),
+            RespValue::BulkString(source.to_string()),
+            RespValue::BulkString(destination.to_string()),
+            RespValue::BulkString(member.to_string()),
+        ])
+    }
+
+    /// Create an SINTERSTORE, SUNIONSTORE or SDIFFSTORE command
+    pub fn set_store_command(command_name: &str, destination: &str, keys: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString(command_name.to_string()),
+            RespValue::BulkString(destination.to_string()),
+        ];
+
+        for key in keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a ZPOPMIN or ZPOPMAX command
+    pub fn zpop_command(command_name: &str, key: &str, count: Option<u32>) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString(command_name.to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        if let Some(count) = count {
+            command.push(RespValue::BulkString(count.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a BZPOPMIN or BZPOPMAX command
+    pub fn bzpop_command(command_name: &str, keys: &[&str], timeout_seconds: &str) -> RespValue {
+        let mut command = vec![RespValue::BulkString(command_name.to_string())];
+
+        for key in keys {
+            command.push(RespValue::BulkString(key.to_string()));
+        }
+
+        command.push(RespValue::BulkString(timeout_seconds.to_string()));
+
+        RespValue::Array(command)
+    }
+
+    /// Create a SORT command with the given options
+    pub fn sort_command(key: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("SORT".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a SORT_RO command with the given options
+    pub fn sort_ro_command(key: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("SORT_RO".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a HELP command for a subcommand family
+    pub fn help_command(family: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString(family.to_string()),
+            RespValue::BulkString("HELP".to_string()),
+        ])
+    }
+
+    /// Create a SAVE command
+    pub fn save_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("SAVE".to_string())])
+    }
+
+    /// Create a BGSAVE command
+    pub fn bgsave_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("BGSAVE".to_string())])
+    }
+
+    /// Create a LASTSAVE command
+    pub fn lastsave_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("LASTSAVE".to_string())])
+    }
+
+    /// Create a BGREWRITEAOF command
+    pub fn bgrewriteaof_command() -> RespValue {
+        RespValue::Array(vec![RespValue::BulkString("BGREWRITEAOF".to_string())])
+    }
+
+    /// Create an EXPIRE command
+    pub fn expire_command(key: &str, seconds: i64) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("EXPIRE".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(seconds.to_string()),
+        ])
+    }
+
+    /// Create a PEXPIRE command
+    pub fn pexpire_command(key: &str, milliseconds: i64) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("PEXPIRE".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(milliseconds.to_string()),
+        ])
+    }
+
+    /// Create a PEXPIREAT command
+    pub fn pexpireat_command(key: &str, unix_time_milliseconds: i64) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("PEXPIREAT".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(unix_time_milliseconds.to_string()),
+        ])
+    }
+
+    /// Create a GEOADD command
+    pub fn geoadd_command(
+        key: &str,
+        options: &[&str],
+        members: &[(&str, &str, &str)],
+    ) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("GEOADD".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        for (longitude, latitude, member) in members {
+            command.push(RespValue::BulkString(longitude.to_string()));
+            command.push(RespValue::BulkString(latitude.to_string()));
+            command.push(RespValue::BulkString(member.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a GEOPOS command
+    pub fn geopos_command(key: &str, members: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("GEOPOS".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for member in members {
+            command.push(RespValue::BulkString(member.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a GEODIST command
+    pub fn geodist_command(
+        key: &str,
+        first_member: &str,
+        second_member: &str,
+        unit: Option<&str>,
+    ) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("GEODIST".to_string()),
+            RespValue::BulkString(key.to_string()),
+            RespValue::BulkString(first_member.to_string()),
+            RespValue::BulkString(second_member.to_string()),
+        ];
+
+        if let Some(unit) = unit {
+            command.push(RespValue::BulkString(unit.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a GEOSEARCH command
+    pub fn geosearch_command(key: &str, options: &[&str]) -> RespValue {
+        let mut command = vec![
+            RespValue::BulkString("GEOSEARCH".to_string()),
+            RespValue::BulkString(key.to_string()),
+        ];
+
+        for option in options {
+            command.push(RespValue::BulkString(option.to_string()));
+        }
+
+        RespValue::Array(command)
+    }
+
+    /// Create a command from its name and arguments as they are sent
+    pub fn raw_command(args: &[&str]) -> RespValue {
+        RespValue::Array(
+            args.iter()
+                .map(|arg| RespValue::BulkString(arg.to_string()))
+                .collect(),
+        )
+    }
+
+    /// Create an invalid command
+    pub fn invalid_command(args: &[&str]) -> RespValue {
+        let mut vec = Vec::new();
+
+        for arg in args {
+            vec.push(RespValue::BulkString(arg.to_string()));
+        }
+
+        RespValue::Array(vec)
+    }
+
+    /// Create a PUBLISH command
+    pub fn publish_command(channel: &str, message: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("PUBLISH".to_string()),
+            RespValue::BulkString(channel.to_string()),
+            RespValue::BulkString(message.to_string()),
+        ])
+    }
+
+    pub fn ssubscribe_command(channels: &[&str]) -> RespValue {
+        let mut command = vec![RespValue::BulkString("SSUBSCRIBE".to_string())];
+        command.extend(
+            channels
+                .iter()
+                .map(|channel| RespValue::BulkString(channel.to_string())),
+        );
+        RespValue::Array(command)
+    }
+
+    pub fn sunsubscribe_command(channels: &[&str]) -> RespValue {
+        let mut command = vec![RespValue::BulkString("SUNSUBSCRIBE".to_string())];
+        command.extend(
+            channels
+                .iter()
+                .map(|channel| RespValue::BulkString(channel.to_string())),
+        );
+        RespValue::Array(command)
+    }
+
+    pub fn spublish_command(channel: &str, message: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString("SPUBLISH".to_string()),
+            RespValue::BulkString(channel.to_string()),
+            RespValue::BulkString(message.to_string()),
+        ])
+    }
+
+    /// Generate a unique server address for testing
+    pub fn client_address(port: u16) -> String {
+        format!("127.0.0.1:{}", port)
+    }
+
+    /// Spawn a BLPOP task that blocks on the given key
+    pub fn spawn_blpop_task(
+        env: &TestEnv,
+        key: &str,
+        timeout_seconds: &str,
+        client_address: &str,
+    ) -> JoinHandle<Result<CommandResult, CommandError>> {
+        let (store_clone, state_clone, server_clone) = env.clone_env();
+        let blpop_command = Self::blpop_command(key, timeout_seconds);
+        let client_address = client_address.to_string();
+
+        tokio::spawn(async move {
+            let command_handler = CommandHandler::new(blpop_command)?;
+
+            command_handler
+                .handle_command_for_master_server(
+                    &client_address,
+                    Arc::clone(&server_clone),
+                    Arc::clone(&store_clone),
+                    Arc::clone(&state_clone),
+                )
+                .await
+        })
+    }
+
+    /// Spawn a BZPOPMIN or BZPOPMAX task that blocks on the given keys
+    pub fn spawn_bzpop_task(
+        env: &TestEnv,
+        command_name: &str,
+        keys: &[&str],
+        timeout_seconds: &str,
+        client_address: &str,
+    ) -> JoinHandle<Result<CommandResult, CommandError>> {
+        let (store_clone, state_clone, server_clone) = env.clone_env();
+        let bzpop_command = Self::bzpop_command(command_name, keys, timeout_seconds);
+        let client_address = client_address.to_string();
+
+        tokio::spawn(async move {
+            let command_handler = CommandHandler::new(bzpop_command)?;
+
+            command_handler
+                .handle_command_for_master_server(
+                    &client_address,
+                    Arc::clone(&server_clone),
+                    Arc::clone(&store_clone),
+                    Arc::clone(&state_clone),
+                )
+                .await
+        })
+    }
+
+    /// Spawn a XREAD task that blocks on the given key
+    pub fn spawn_xread_task(
+        env: &TestEnv,
+        keys: &[&str],
+        stream_ids: &[&str],
+        timeout_milliseconds: &str,
+        client_address: &str,
+    ) -> JoinHandle<Result<CommandResult, CommandError>> {
+        let (store_clone, state_clone, server_clone) = env.clone_env();
+        let xread_blocking_command =
+            Self::xread_blocking_command(timeout_milliseconds, keys, stream_ids);
+        let client_address = client_address.to_string();
+
+        tokio::spawn(async move {
+            let command_handler = CommandHandler::new(xread_blocking_command)?;
+
+            command_handler
+                .handle_command_for_master_server(
+                    &client_address,
+                    Arc::clone(&server_clone),
+                    Arc::clone(&store_clone),
+                    Arc::clone(&state_clone),
+                )
+                .await
+        })
+    }
+
+    /// Wait for a task with timeout and expect it to complete (success or failure)
+    pub async fn wait_for_completion(
+        task: JoinHandle<Result<CommandResult, CommandError>>,
+        timeout_duration: Duration,
+    ) -> Result<String, CommandError> {
+        match timeout(timeout_duration, task)
+            .await
+            .expect("Task should complete within timeout")
+            .expect("Task should not panic")
+        {
+            Ok(result) => match result {
+                CommandResult::Response(value) => Ok(value),
+                _ => panic!("Unexpected command result"),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Create expected bulk string response
+    pub fn expected_bulk_string(value: &str) -> String {
+        format!("${}\r\n{}\r\n", value.len(), value)
+    }
+
+    /// Create expected integer response
+    pub fn expected_integer(value: i64) -> String {
+        format!(":{}\r\n", value)
+    }
+
+    /// Create expected simple string response
+    pub fn expected_simple_string(value: &str) -> String {
+        format!("+{}\r\n", value)
+    }
+
+    /// Create expected null bulk string response
+    pub fn expected_null_bulk_string() -> String {
+        "$-1\r\n".to_string()
+    }
+
+    /// Create expected null array response
+    pub fn expected_null_array() -> String {
+        "*-1\r\n".to_string()
+    }
+
+    /// Create expected bulk string array response
+    pub fn expected_bulk_string_array(items: &[&str]) -> String {
+        let mut response = format!("*{}\r\n", items.len());
+        for item in items {
+            response.push_str(&format!("${}\r\n{}\r\n", item.len(), item));
+        }
+        response
+    }
+
+    /// Async sleep helper
+    pub async fn sleep_ms(ms: u64) {
+        tokio::time::sleep(Duration::from_millis(ms)).await;
+    }
+
+    /// Filter successful results containing a specific substring
+    pub fn filter_successful_results_containing<'a>(
+        results: &'a [Result<String, CommandError>],
         substring: &str,
     ) -> Vec<&'a String> {
         results
@@ -716,12 +1944,14 @@ impl TestUtils {
             .collect()
     }
 
-    pub async fn send_command_and_receive_response(
-        client: &mut TcpStream,
+    pub async fn send_command_and_receive_response<S>(
+        client: &mut S,
         buffer: &mut [u8; 1024],
         command: RespValue,
         expected_response: RespValue,
-    ) {
+    ) where
+        S: AsyncReadExt + AsyncWriteExt + Unpin,
+    {
         client.write_all(command.encode().as_bytes()).await.unwrap();
         client.flush().await.unwrap();
 
@@ -800,6 +2030,25 @@ impl TestUtils {
         });
     }
 
+    pub async fn run_master_server_with_append_only_file(port: u32, directory: &str) {
+        let master_args = vec![
+            "redis-server".to_string(),
+            "--port".to_string(),
+            port.to_string(),
+            "--dir".to_string(),
+            directory.to_string(),
+            "--appendonly".to_string(),
+            "yes".to_string(),
+            "--appendfsync".to_string(),
+            "always".to_string(),
+        ];
+        let master_server = RedisServer::new(master_args).unwrap();
+
+        tokio::spawn(async move {
+            master_server.run().await;
+        });
+    }
+
     pub async fn run_replica_server(port: u32, master_port: u32) {
         let replica_args = vec![
             "redis-server".to_string(),